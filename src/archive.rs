@@ -2,6 +2,8 @@
 //!
 //! Supports: .zip, .tar, .gz, .gzip, .tar.gz, .tgz, .bz2, .tar.bz2, .xz, .tar.xz,
 //! .zst, .tar.zst, .7z
+//!
+//! Gzip-compressed WARC crawls (.warc.gz) are handed to the WARC converter.
 
 use async_trait::async_trait;
 use bytes::Bytes;
@@ -14,7 +16,9 @@ use xz2::read::XzDecoder;
 use zstd::stream::read::Decoder as ZstdDecoder;
 
 use crate::error::MarkitdownError;
+use crate::mailbox::MaildirConverter;
 use crate::model::{ContentBlock, ConversionOptions, Document, DocumentConverter, Page};
+use crate::webarchive::WarcConverter;
 
 /// Result containing multiple documents from archive extraction
 #[derive(Debug, Clone)]
@@ -224,8 +228,12 @@ impl DocumentConverter for ZipConverter {
     async fn convert_bytes(
         &self,
         bytes: Bytes,
-        _options: Option<ConversionOptions>,
+        options: Option<ConversionOptions>,
     ) -> Result<Document, MarkitdownError> {
+        if MaildirConverter::is_maildir_zip(&bytes) {
+            return MaildirConverter.convert_zip(&bytes, options).await;
+        }
+
         let result = Self::extract_zip(&bytes)?;
         Ok(result.to_document("archive.zip"))
     }
@@ -332,10 +340,17 @@ impl DocumentConverter for GzipConverter {
     async fn convert_bytes(
         &self,
         bytes: Bytes,
-        _options: Option<ConversionOptions>,
+        options: Option<ConversionOptions>,
     ) -> Result<Document, MarkitdownError> {
         let decompressed = Self::decompress_gz(&bytes)?;
 
+        // Crawls are usually stored as .warc.gz (one gzip member per record,
+        // so the WARC converter decompresses the original bytes itself)
+        if decompressed.starts_with(b"WARC/") {
+            let options = options.unwrap_or_default().with_extension(".warc");
+            return WarcConverter.convert_bytes(bytes, Some(options)).await;
+        }
+
         // Check if it's a tar archive inside
         if let Ok(result) = TarConverter::extract_tar(&decompressed) {
             if result.total_files > 0 {
@@ -1,97 +1,1511 @@
 //! iCalendar (.ics) to Markdown converter.
 //!
-//! Supports conversion of iCalendar files (events, todos) to markdown.
+//! Events are listed in a table sorted by start time, followed by one
+//! section per event with its organizer, attendees, reminders and
+//! recurrence, then the calendar's tasks. Times are shown in the time zone
+//! they were written in, with the UTC offset taken from the calendar's
+//! `VTIMEZONE` definitions. Recurrence rules are described in words
+//! ("Every 2 weeks on Monday, 10 times") and can be expanded into their
+//! occurrences within a [`RecurrenceWindow`].
 
 use async_trait::async_trait;
 use bytes::Bytes;
-use icalendar::{Calendar, CalendarComponent, Component, EventLike};
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, NaiveDateTime, Utc, Weekday};
 use object_store::ObjectStore;
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::content_lines::{parse_components, Component, Property};
 use crate::error::MarkitdownError;
 use crate::model::{ContentBlock, ConversionOptions, Document, DocumentConverter, Page};
 
+/// Recurring events are expanded into their occurrences within this range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecurrenceWindow {
+    /// Start of the range (inclusive)
+    pub start: DateTime<Utc>,
+    /// End of the range (exclusive)
+    pub end: DateTime<Utc>,
+}
+
+impl RecurrenceWindow {
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self { start, end }
+    }
+}
+
+/// Occurrences listed per recurring event at most
+const MAX_OCCURRENCES: usize = 500;
+
+/// Recurrence periods (days, weeks, ...) tried before giving up on a rule
+/// that matches nothing
+const MAX_PERIODS: u32 = 100_000;
+
+/// Larger `INTERVAL`s are clamped; they could not repeat within any
+/// representable date anyway
+const MAX_INTERVAL: u32 = 100_000;
+
+// ============================================================================
+// Date-time values
+// ============================================================================
+
+/// What a date-time value is relative to
+#[derive(Debug, Clone, PartialEq)]
+enum Zone {
+    /// Local time wherever the reader is
+    Floating,
+    Utc,
+    /// A `TZID`, usually defined by a `VTIMEZONE` of the calendar
+    Named(String),
+}
+
+/// A `DTSTART`, `DTEND`, `DUE`, ... value
+#[derive(Debug, Clone, PartialEq)]
+struct CalTime {
+    /// Wall-clock time in `zone` (midnight for dates)
+    local: NaiveDateTime,
+    all_day: bool,
+    zone: Zone,
+}
+
+impl CalTime {
+    fn from_property(property: &Property) -> Option<Self> {
+        Self::parse(property.value.trim(), property.param("TZID"))
+    }
+
+    /// All values of a list property such as `EXDATE`
+    fn list(property: &Property) -> Vec<Self> {
+        property
+            .value
+            .split(',')
+            .filter_map(|value| Self::parse(value.trim(), property.param("TZID")))
+            .collect()
+    }
+
+    fn parse(value: &str, tzid: Option<&str>) -> Option<Self> {
+        if value.len() == 8 {
+            let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+            return Some(Self {
+                local: date.and_hms_opt(0, 0, 0)?,
+                all_day: true,
+                zone: Zone::Floating,
+            });
+        }
+        let (value, utc) = match value.strip_suffix(['Z', 'z']) {
+            Some(value) => (value, true),
+            None => (value, false),
+        };
+        let local = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+        let zone = match tzid {
+            _ if utc => Zone::Utc,
+            Some(tzid) if is_utc_name(tzid) => Zone::Utc,
+            Some(tzid) => Zone::Named(tzid.to_string()),
+            None => Zone::Floating,
+        };
+        Some(Self {
+            local,
+            all_day: false,
+            zone,
+        })
+    }
+
+    /// The same kind of value at another wall-clock time
+    fn at(&self, local: NaiveDateTime) -> Self {
+        Self {
+            local,
+            ..self.clone()
+        }
+    }
+}
+
+fn is_utc_name(tzid: &str) -> bool {
+    matches!(
+        tzid.trim_start_matches('/').to_ascii_uppercase().as_str(),
+        "UTC" | "GMT" | "Z" | "ETC/UTC" | "ETC/GMT" | "UNIVERSAL" | "ZULU"
+    )
+}
+
+/// Parse a UTC offset such as `+0100` or `-053000` into seconds
+fn parse_offset(value: &str) -> Option<i32> {
+    let value = value.trim();
+    let (sign, digits) = if let Some(digits) = value.strip_prefix('+') {
+        (1, digits)
+    } else {
+        (-1, value.strip_prefix('-')?)
+    };
+    if !matches!(digits.len(), 4 | 6) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let hours: i32 = digits[0..2].parse().ok()?;
+    let minutes: i32 = digits[2..4].parse().ok()?;
+    let seconds: i32 = digits.get(4..6).map_or(Some(0), |s| s.parse().ok())?;
+    Some(sign * (hours * 3600 + minutes * 60 + seconds))
+}
+
+fn format_offset(seconds: i32) -> String {
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let seconds = seconds.abs();
+    format!(
+        "UTC{}{:02}:{:02}",
+        sign,
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%a %-d %b %Y").to_string()
+}
+
+/// Parse a duration such as `PT1H30M`, `-PT15M` or `P1W`
+fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let value = value.strip_prefix(['P', 'p'])?;
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    let mut seen = false;
+    for c in value.chars() {
+        match c.to_ascii_uppercase() {
+            'T' => {}
+            c if c.is_ascii_digit() => number.push(c),
+            unit => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                seen = true;
+                // Durations chrono cannot represent are dropped
+                let part = match unit {
+                    'W' => Duration::try_weeks(n),
+                    'D' => Duration::try_days(n),
+                    'H' => Duration::try_hours(n),
+                    'M' => Duration::try_minutes(n),
+                    'S' => Duration::try_seconds(n),
+                    _ => return None,
+                }?;
+                total = total.checked_add(&part)?;
+            }
+        }
+    }
+    if !seen || !number.is_empty() {
+        return None;
+    }
+    Some(if negative { -total } else { total })
+}
+
+/// "1 hour 30 minutes", "2 days"
+fn describe_duration(duration: Duration) -> String {
+    let seconds = duration.num_seconds().abs();
+    if seconds == 0 {
+        return "0 minutes".to_string();
+    }
+    let units: &[(i64, &str)] = if seconds % (7 * 86400) == 0 {
+        &[(7 * 86400, "week")]
+    } else {
+        &[
+            (86400, "day"),
+            (3600, "hour"),
+            (60, "minute"),
+            (1, "second"),
+        ]
+    };
+    let mut rest = seconds;
+    let mut parts = Vec::new();
+    for (size, name) in units {
+        let n = rest / size;
+        rest %= size;
+        if n > 0 {
+            parts.push(plural(n, name));
+        }
+    }
+    parts.join(" ")
+}
+
+fn plural(n: i64, unit: &str) -> String {
+    if n == 1 {
+        format!("1 {}", unit)
+    } else {
+        format!("{} {}s", n, unit)
+    }
+}
+
+/// "Monday, Wednesday and Friday"
+fn join_words(words: &[String]) -> String {
+    match words {
+        [] => String::new(),
+        [only] => only.clone(),
+        [init @ .., last] => format!("{} and {}", init.join(", "), last),
+    }
+}
+
+fn ordinal_suffix(n: i32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+/// "first", "second", "last", "second-to-last", ...
+fn ordinal_word(n: i32) -> String {
+    let word = |n: i32| match n {
+        1 => "first".to_string(),
+        2 => "second".to_string(),
+        3 => "third".to_string(),
+        4 => "fourth".to_string(),
+        5 => "fifth".to_string(),
+        n => ordinal_suffix(n),
+    };
+    match n {
+        -1 => "last".to_string(),
+        n if n < 0 => format!("{}-to-last", word(-n)),
+        n => word(n),
+    }
+}
+
+fn weekday_name(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+fn month_name(month: u32) -> &'static str {
+    const NAMES: [&str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+    NAMES[(month.clamp(1, 12) - 1) as usize]
+}
+
+/// "NEEDS-ACTION" -> "Needs action"
+fn humanize_keyword(value: &str) -> String {
+    let lower = value.trim().replace(['-', '_'], " ").to_lowercase();
+    let mut chars = lower.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => lower,
+    }
+}
+
+/// Put a value on one line for a table cell; the renderer escapes pipes
+fn table_cell(text: &str) -> String {
+    text.replace('\n', " ")
+}
+
+// ============================================================================
+// Time zones
+// ============================================================================
+
+/// A `STANDARD` or `DAYLIGHT` block of a `VTIMEZONE`
+#[derive(Debug, Clone)]
+struct Observance {
+    /// First onset, in the local time before the change
+    start: NaiveDateTime,
+    offset_from: i32,
+    offset_to: i32,
+    rule: Option<Rule>,
+    dates: Vec<NaiveDateTime>,
+}
+
+impl Observance {
+    fn parse(component: &Component) -> Option<Self> {
+        let start = CalTime::from_property(component.property("DTSTART")?)?.local;
+        let offset_to = parse_offset(&component.property("TZOFFSETTO")?.value)?;
+        let offset_from = component
+            .property("TZOFFSETFROM")
+            .and_then(|p| parse_offset(&p.value))
+            .unwrap_or(offset_to);
+        Some(Self {
+            start,
+            offset_from,
+            offset_to,
+            rule: component
+                .property("RRULE")
+                .and_then(|p| Rule::parse(&p.value)),
+            dates: component
+                .properties_named("RDATE")
+                .flat_map(CalTime::list)
+                .map(|t| t.local)
+                .collect(),
+        })
+    }
+
+    /// The latest time at or before `local` when this observance began
+    fn last_onset(&self, local: NaiveDateTime) -> Option<NaiveDateTime> {
+        if local < self.start {
+            return None;
+        }
+        let mut last = self.start;
+        if let Some(rule) = &self.rule {
+            let until = rule.until.as_ref().map(|u| u.local);
+            if let Some(onset) = rule
+                .occurrences(self.start, until, self.start, local, usize::MAX)
+                .last()
+            {
+                last = last.max(*onset);
+            }
+        }
+        for date in &self.dates {
+            if *date <= local {
+                last = last.max(*date);
+            }
+        }
+        Some(last)
+    }
+}
+
+/// The `VTIMEZONE` definitions of a calendar, by `TZID`
+#[derive(Debug, Default)]
+struct TimeZones(HashMap<String, Vec<Observance>>);
+
+impl TimeZones {
+    fn from_calendar(calendar: &Component) -> Self {
+        let mut zones = HashMap::new();
+        for timezone in calendar.children("VTIMEZONE") {
+            let Some(tzid) = timezone.text("TZID") else {
+                continue;
+            };
+            let observances: Vec<Observance> = timezone
+                .children("STANDARD")
+                .chain(timezone.children("DAYLIGHT"))
+                .filter_map(Observance::parse)
+                .collect();
+            if !observances.is_empty() {
+                zones.insert(tzid, observances);
+            }
+        }
+        Self(zones)
+    }
+
+    /// UTC offset in seconds of a wall-clock time in `tzid`
+    fn offset(&self, tzid: &str, local: NaiveDateTime) -> Option<i32> {
+        if is_utc_name(tzid) {
+            return Some(0);
+        }
+        let observances = self.0.get(tzid)?;
+        observances
+            .iter()
+            .filter_map(|o| o.last_onset(local).map(|onset| (onset, o.offset_to)))
+            .max_by_key(|(onset, _)| *onset)
+            .map(|(_, offset)| offset)
+            // Before the first onset the zone had its initial offset
+            .or_else(|| {
+                observances
+                    .iter()
+                    .min_by_key(|o| o.start)
+                    .map(|o| o.offset_from)
+            })
+    }
+
+    /// The time in UTC, or `None` for floating times and unknown zones
+    fn utc(&self, time: &CalTime) -> Option<NaiveDateTime> {
+        match &time.zone {
+            Zone::Utc => Some(time.local),
+            Zone::Named(tzid) => self
+                .offset(tzid, time.local)
+                .map(|offset| time.local - Duration::seconds(offset.into())),
+            Zone::Floating => None,
+        }
+    }
+
+    /// Key that orders times from different zones
+    fn sort_key(&self, time: &CalTime) -> NaiveDateTime {
+        self.utc(time).unwrap_or(time.local)
+    }
+
+    /// Wall-clock time of `time` in the zone of `reference`
+    fn local_in(&self, time: &CalTime, reference: &CalTime) -> NaiveDateTime {
+        if time.all_day {
+            // A date bound includes the whole day
+            return time.local + Duration::days(1) - Duration::seconds(1);
+        }
+        match (&time.zone, &reference.zone) {
+            (Zone::Utc, Zone::Named(tzid)) => {
+                let offset = self.offset(tzid, time.local).unwrap_or(0);
+                time.local + Duration::seconds(offset.into())
+            }
+            _ => time.local,
+        }
+    }
+
+    /// "Mon 4 Mar 2024, 09:00 (Europe/Berlin, UTC+01:00)"
+    fn format(&self, time: &CalTime) -> String {
+        if time.all_day {
+            return format_date(time.local.date());
+        }
+        let base = time.local.format("%a %-d %b %Y, %H:%M").to_string();
+        match &time.zone {
+            Zone::Floating => base,
+            Zone::Utc => format!("{} UTC", base),
+            Zone::Named(tzid) => match self.offset(tzid, time.local) {
+                Some(offset) => format!("{} ({}, {})", base, tzid, format_offset(offset)),
+                None => format!("{} ({})", base, tzid),
+            },
+        }
+    }
+
+    /// Start and end of an event as table cells. All-day end dates are
+    /// exclusive, so the last day shown is the one before.
+    fn format_span(&self, start: &CalTime, end: Option<&CalTime>) -> (String, String) {
+        if start.all_day {
+            let last_day = end
+                .map(|end| end.local.date() - Duration::days(1))
+                .filter(|day| *day > start.local.date());
+            return (
+                format!("{} (all day)", format_date(start.local.date())),
+                last_day.map(format_date).unwrap_or_default(),
+            );
+        }
+        (
+            self.format(start),
+            end.map(|end| self.format(end)).unwrap_or_default(),
+        )
+    }
+}
+
+// ============================================================================
+// Recurrence rules
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Frequency {
+    Secondly,
+    Minutely,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+    fn unit(self) -> &'static str {
+        match self {
+            Frequency::Secondly => "second",
+            Frequency::Minutely => "minute",
+            Frequency::Hourly => "hour",
+            Frequency::Daily => "day",
+            Frequency::Weekly => "week",
+            Frequency::Monthly => "month",
+            Frequency::Yearly => "year",
+        }
+    }
+}
+
+/// An `RRULE`
+#[derive(Debug, Clone)]
+struct Rule {
+    freq: Frequency,
+    interval: u32,
+    count: Option<u32>,
+    until: Option<CalTime>,
+    /// Weekdays with an optional ordinal (`2MO`, `-1FR`)
+    by_day: Vec<(Option<i32>, Weekday)>,
+    by_month_day: Vec<i32>,
+    by_month: Vec<u32>,
+    by_set_pos: Vec<i32>,
+}
+
+impl Rule {
+    fn parse(value: &str) -> Option<Self> {
+        let mut rule = Rule {
+            freq: Frequency::Daily,
+            interval: 1,
+            count: None,
+            until: None,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: Vec::new(),
+        };
+        let mut freq = None;
+        for part in value.trim().split(';') {
+            let Some((key, value)) = part.split_once('=') else {
+                continue;
+            };
+            match key.trim().to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    freq = Some(match value.trim().to_ascii_uppercase().as_str() {
+                        "SECONDLY" => Frequency::Secondly,
+                        "MINUTELY" => Frequency::Minutely,
+                        "HOURLY" => Frequency::Hourly,
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return None,
+                    })
+                }
+                "INTERVAL" => {
+                    rule.interval = value
+                        .trim()
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|n| *n > 0)?
+                        .min(MAX_INTERVAL)
+                }
+                "COUNT" => rule.count = value.trim().parse().ok(),
+                "UNTIL" => rule.until = CalTime::parse(value.trim(), None),
+                "BYDAY" => rule.by_day = value.split(',').filter_map(parse_by_day).collect(),
+                "BYMONTHDAY" => rule.by_month_day = parse_numbers(value),
+                "BYMONTH" => {
+                    rule.by_month = parse_numbers(value);
+                    rule.by_month.retain(|m| (1..=12).contains(m));
+                }
+                "BYSETPOS" => rule.by_set_pos = parse_numbers(value),
+                _ => {}
+            }
+        }
+        rule.freq = freq?;
+        Some(rule)
+    }
+
+    /// The rule in words, e.g. "Every 2 weeks on Monday and Thursday, until
+    /// Fri 29 Mar 2024"
+    fn describe(&self, until: Option<NaiveDateTime>) -> String {
+        let mut text = match self.interval {
+            1 => format!("Every {}", self.freq.unit()),
+            n => format!("Every {} {}s", n, self.freq.unit()),
+        };
+
+        let weekdays = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+        ];
+        if self.by_day.len() == 5
+            && weekdays
+                .iter()
+                .all(|day| self.by_day.contains(&(None, *day)))
+        {
+            text.push_str(" on weekdays");
+        } else if !self.by_day.is_empty() {
+            let days: Vec<String> = self
+                .by_day
+                .iter()
+                .map(|(ordinal, day)| match ordinal {
+                    Some(n) => format!("the {} {}", ordinal_word(*n), weekday_name(*day)),
+                    None => weekday_name(*day).to_string(),
+                })
+                .collect();
+            text.push_str(&format!(" on {}", join_words(&days)));
+        }
+
+        if !self.by_month_day.is_empty() {
+            let days: Vec<String> = self
+                .by_month_day
+                .iter()
+                .map(|n| match n {
+                    -1 => "the last day".to_string(),
+                    n if *n < 0 => format!("the {} day", ordinal_word(*n)),
+                    n => format!("the {}", ordinal_suffix(*n)),
+                })
+                .collect();
+            text.push_str(&format!(" on {}", join_words(&days)));
+        }
+
+        if !self.by_month.is_empty() {
+            let months: Vec<String> = self
+                .by_month
+                .iter()
+                .map(|m| month_name(*m).to_string())
+                .collect();
+            text.push_str(&format!(" in {}", join_words(&months)));
+        }
+
+        if !self.by_set_pos.is_empty() {
+            let positions: Vec<String> = self.by_set_pos.iter().map(|n| ordinal_word(*n)).collect();
+            text.push_str(&format!(
+                ", only the {} of these each {}",
+                join_words(&positions),
+                self.freq.unit()
+            ));
+        }
+
+        match (self.count, until) {
+            (Some(1), _) => text.push_str(", once"),
+            (Some(n), _) => text.push_str(&format!(", {} times", n)),
+            (None, Some(until)) => text.push_str(&format!(", until {}", format_date(until.date()))),
+            (None, None) => {}
+        }
+        text
+    }
+
+    /// Occurrences starting at `start` (which counts as the first), up to
+    /// the rule's own `until`. Only those in `from..=to` are returned, at
+    /// most `limit` of them.
+    fn occurrences(
+        &self,
+        start: NaiveDateTime,
+        until: Option<NaiveDateTime>,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+        limit: usize,
+    ) -> Vec<NaiveDateTime> {
+        let mut result = Vec::new();
+        let mut count = 0;
+        for period in 0..MAX_PERIODS {
+            let Some((anchor, candidates)) = period
+                .checked_mul(self.interval)
+                .and_then(|n| self.period(start, n))
+            else {
+                break;
+            };
+            if anchor > to || until.is_some_and(|until| anchor > until) {
+                break;
+            }
+            for candidate in candidates {
+                if candidate < start {
+                    continue;
+                }
+                if candidate > to || until.is_some_and(|until| candidate > until) {
+                    return result;
+                }
+                count += 1;
+                if candidate >= from {
+                    result.push(candidate);
+                }
+                if result.len() >= limit || self.count.is_some_and(|max| count >= max) {
+                    return result;
+                }
+            }
+        }
+        result
+    }
+
+    /// The start of the `n`th period after the one containing `start`, and
+    /// the rule's candidates in it, in order
+    fn period(&self, start: NaiveDateTime, n: u32) -> Option<(NaiveDateTime, Vec<NaiveDateTime>)> {
+        let time = start.time();
+        let date = start.date();
+        let (anchor, mut days): (NaiveDate, Vec<NaiveDate>) = match self.freq {
+            Frequency::Secondly | Frequency::Minutely | Frequency::Hourly => {
+                let step = match self.freq {
+                    Frequency::Secondly => Duration::seconds(1),
+                    Frequency::Minutely => Duration::minutes(1),
+                    _ => Duration::hours(1),
+                };
+                let instant =
+                    start.checked_add_signed(step.checked_mul(i32::try_from(n).ok()?)?)?;
+                let keep = self.matches_filters(instant.date());
+                return Some((instant, if keep { vec![instant] } else { Vec::new() }));
+            }
+            Frequency::Daily => {
+                let day = date.checked_add_signed(Duration::try_days(n.into())?)?;
+                let days = if self.matches_filters(day) {
+                    vec![day]
+                } else {
+                    Vec::new()
+                };
+                (day, days)
+            }
+            Frequency::Weekly => {
+                let monday = date - Duration::days(date.weekday().num_days_from_monday().into());
+                let monday = monday.checked_add_signed(Duration::try_weeks(n.into())?)?;
+                let weekdays = if self.by_day.is_empty() {
+                    vec![date.weekday()]
+                } else {
+                    self.by_day.iter().map(|(_, day)| *day).collect()
+                };
+                let days = weekdays
+                    .into_iter()
+                    .filter_map(|day| {
+                        monday.checked_add_signed(Duration::days(day.num_days_from_monday().into()))
+                    })
+                    .filter(|d| self.by_month.is_empty() || self.by_month.contains(&d.month()))
+                    .collect();
+                (monday, days)
+            }
+            Frequency::Monthly => {
+                let first = date.with_day(1)?.checked_add_months(Months::new(n))?;
+                let days = if !self.by_month.is_empty() && !self.by_month.contains(&first.month()) {
+                    Vec::new()
+                } else {
+                    self.days_in_month(first, date.day())
+                };
+                (first, days)
+            }
+            Frequency::Yearly => {
+                let year = date.year().checked_add(i32::try_from(n).ok()?)?;
+                let first = NaiveDate::from_ymd_opt(year, 1, 1)?;
+                let days = if !self.by_day.is_empty() && self.by_month.is_empty() {
+                    // Weekdays of the whole year, ordinals counted in the year
+                    let last = NaiveDate::from_ymd_opt(year, 12, 31)?;
+                    weekdays_between(first, last, &self.by_day)
+                        .into_iter()
+                        .filter(|d| self.matches_month_day(*d))
+                        .collect()
+                } else if self.by_day.is_empty() && self.by_month_day.is_empty() {
+                    let months = if self.by_month.is_empty() {
+                        vec![date.month()]
+                    } else {
+                        self.by_month.clone()
+                    };
+                    months
+                        .into_iter()
+                        .filter_map(|m| NaiveDate::from_ymd_opt(year, m, date.day()))
+                        .collect()
+                } else {
+                    let months = if self.by_month.is_empty() {
+                        (1..=12).collect()
+                    } else {
+                        self.by_month.clone()
+                    };
+                    months
+                        .into_iter()
+                        .filter_map(|m| NaiveDate::from_ymd_opt(year, m, 1))
+                        .flat_map(|first| self.days_in_month(first, date.day()))
+                        .collect()
+                };
+                (first, days)
+            }
+        };
+
+        days.sort();
+        days.dedup();
+        if !self.by_set_pos.is_empty() {
+            let len = days.len() as i32;
+            days = self
+                .by_set_pos
+                .iter()
+                .filter_map(|pos| {
+                    let index = if *pos > 0 { pos - 1 } else { len + pos };
+                    (0..len).contains(&index).then(|| days[index as usize])
+                })
+                .collect();
+            days.sort();
+        }
+        Some((
+            anchor.and_time(time),
+            days.into_iter().map(|d| d.and_time(time)).collect(),
+        ))
+    }
+
+    /// Candidate days of the month starting at `first`
+    fn days_in_month(&self, first: NaiveDate, default_day: u32) -> Vec<NaiveDate> {
+        let last = last_day_of_month(first);
+        if !self.by_day.is_empty() {
+            weekdays_between(first, last, &self.by_day)
+                .into_iter()
+                .filter(|d| self.matches_month_day(*d))
+                .collect()
+        } else if !self.by_month_day.is_empty() {
+            let len = last.day() as i32;
+            self.by_month_day
+                .iter()
+                .filter_map(|n| {
+                    let day = if *n > 0 { *n } else { len + n + 1 };
+                    (1..=len)
+                        .contains(&day)
+                        .then(|| first.with_day(day as u32))
+                        .flatten()
+                })
+                .collect()
+        } else {
+            first.with_day(default_day).into_iter().collect()
+        }
+    }
+
+    fn matches_month_day(&self, day: NaiveDate) -> bool {
+        if self.by_month_day.is_empty() {
+            return true;
+        }
+        let len = last_day_of_month(day).day() as i32;
+        let d = day.day() as i32;
+        self.by_month_day
+            .iter()
+            .any(|n| *n == d || len + n + 1 == d)
+    }
+
+    /// `BYxxx` parts that limit daily and shorter frequencies
+    fn matches_filters(&self, day: NaiveDate) -> bool {
+        (self.by_month.is_empty() || self.by_month.contains(&day.month()))
+            && self.matches_month_day(day)
+            && (self.by_day.is_empty() || self.by_day.iter().any(|(_, wd)| *wd == day.weekday()))
+    }
+}
+
+fn parse_numbers<T: std::str::FromStr>(value: &str) -> Vec<T> {
+    value
+        .split(',')
+        .filter_map(|n| n.trim().parse().ok())
+        .collect()
+}
+
+fn parse_by_day(value: &str) -> Option<(Option<i32>, Weekday)> {
+    let value = value.trim().to_ascii_uppercase();
+    let split = value.len().checked_sub(2)?;
+    let day = match value.get(split..)? {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    };
+    let ordinal = match value.get(..split)?.trim_start_matches('+') {
+        "" => None,
+        n => Some(n.parse().ok()?),
+    };
+    Some((ordinal, day))
+}
+
+fn last_day_of_month(day: NaiveDate) -> NaiveDate {
+    day.with_day(1)
+        .and_then(|first| first.checked_add_months(Months::new(1)))
+        .and_then(|next| next.pred_opt())
+        .unwrap_or(day)
+}
+
+/// Days in `first..=last` matching `BYDAY` entries; an ordinal picks the
+/// nth (or nth-from-last) such weekday in the range
+fn weekdays_between(
+    first: NaiveDate,
+    last: NaiveDate,
+    by_day: &[(Option<i32>, Weekday)],
+) -> Vec<NaiveDate> {
+    let mut days = Vec::new();
+    for (ordinal, weekday) in by_day {
+        let offset = (7 + weekday.num_days_from_monday() as i64
+            - first.weekday().num_days_from_monday() as i64)
+            % 7;
+        let matching: Vec<NaiveDate> = first
+            .checked_add_signed(Duration::days(offset))
+            .into_iter()
+            .flat_map(|d| d.iter_weeks())
+            .take_while(|d| *d <= last)
+            .collect();
+        match ordinal {
+            None => days.extend(matching),
+            Some(n) => {
+                let index = if *n > 0 {
+                    n - 1
+                } else {
+                    matching.len() as i32 + n
+                };
+                if let Some(day) = usize::try_from(index).ok().and_then(|i| matching.get(i)) {
+                    days.push(*day);
+                }
+            }
+        }
+    }
+    days
+}
+
+// ============================================================================
+// Rendering
+// ============================================================================
+
+/// A `VEVENT` with its times resolved
+struct Event<'a> {
+    component: &'a Component,
+    summary: String,
+    start: Option<CalTime>,
+    end: Option<CalTime>,
+    rule: Option<Rule>,
+    /// Occurrences of a recurring event moved or changed by another
+    /// `VEVENT` with the same `UID`
+    overridden: Vec<NaiveDateTime>,
+}
+
+impl<'a> Event<'a> {
+    fn new(component: &'a Component) -> Self {
+        let start = component
+            .property("DTSTART")
+            .and_then(CalTime::from_property);
+        let end = component
+            .property("DTEND")
+            .and_then(CalTime::from_property)
+            .or_else(|| {
+                let start = start.as_ref()?;
+                let duration = parse_duration(&component.property("DURATION")?.value)?;
+                Some(start.at(start.local.checked_add_signed(duration)?))
+            });
+        Self {
+            component,
+            summary: component
+                .text("SUMMARY")
+                .unwrap_or_else(|| "Untitled event".to_string()),
+            start,
+            end,
+            rule: component
+                .property("RRULE")
+                .and_then(|p| Rule::parse(&p.value)),
+            overridden: Vec::new(),
+        }
+    }
+
+    fn exdates(&self) -> Vec<CalTime> {
+        self.component
+            .properties_named("EXDATE")
+            .flat_map(CalTime::list)
+            .collect()
+    }
+
+    fn rdates(&self) -> Vec<CalTime> {
+        self.component
+            .properties_named("RDATE")
+            .flat_map(CalTime::list)
+            .collect()
+    }
+
+    fn recurrence_id(&self) -> Option<CalTime> {
+        self.component
+            .property("RECURRENCE-ID")
+            .and_then(CalTime::from_property)
+    }
+
+    /// Start times of the event within `window`: every occurrence for
+    /// recurring events, minus exceptions and moved occurrences
+    fn occurrences_in(&self, zones: &TimeZones, window: &RecurrenceWindow) -> Vec<CalTime> {
+        let Some(start) = &self.start else {
+            return Vec::new();
+        };
+        let (from, to) = (window.start.naive_utc(), window.end.naive_utc());
+        let length = self
+            .end
+            .as_ref()
+            .map(|end| end.local - start.local)
+            .unwrap_or_else(Duration::zero);
+        let in_window = |time: &CalTime| {
+            let utc = zones.sort_key(time);
+            utc < to
+                && utc
+                    .checked_add_signed(length.max(Duration::zero()))
+                    .is_none_or(|end| end >= from)
+        };
+
+        let Some(rule) = &self.rule else {
+            return if in_window(start) {
+                vec![start.clone()]
+            } else {
+                Vec::new()
+            };
+        };
+
+        // Search with a day of slack on both sides for the zone offset
+        let slack = Duration::days(1)
+            .checked_add(&length.abs())
+            .unwrap_or(Duration::MAX);
+        let until = rule.until.as_ref().map(|u| zones.local_in(u, start));
+        let excluded: Vec<NaiveDateTime> = self
+            .exdates()
+            .iter()
+            .map(|t| {
+                if t.all_day {
+                    t.local
+                } else {
+                    zones.local_in(t, start)
+                }
+            })
+            .chain(self.overridden.iter().copied())
+            .collect();
+        let mut times: Vec<CalTime> = rule
+            .occurrences(
+                start.local,
+                until,
+                from.checked_sub_signed(slack).unwrap_or(NaiveDateTime::MIN),
+                to.checked_add_signed(slack).unwrap_or(NaiveDateTime::MAX),
+                usize::MAX,
+            )
+            .into_iter()
+            .filter(|local| !excluded.contains(local))
+            .map(|local| start.at(local))
+            .chain(self.rdates())
+            .filter(in_window)
+            .collect();
+        times.sort_by_key(|t| zones.sort_key(t));
+        times.dedup();
+        times.truncate(MAX_OCCURRENCES);
+        times
+    }
+
+    /// Details shown as list items below the event's heading
+    fn details(&self, zones: &TimeZones) -> Vec<String> {
+        let mut items = Vec::new();
+        if let Some(start) = &self.start {
+            let (start_text, end_text) = zones.format_span(start, self.end.as_ref());
+            items.push(format!("**Start:** {}", start_text));
+            if !end_text.is_empty() {
+                items.push(format!("**End:** {}", end_text));
+            }
+        }
+        if let Some(recurrence_id) = self.recurrence_id() {
+            items.push(format!(
+                "**Replaces occurrence:** {}",
+                zones.format(&recurrence_id)
+            ));
+        }
+        if let Some(location) = self.component.text("LOCATION") {
+            items.push(format!("**Location:** {}", location));
+        }
+        if let (Some(rule), Some(start)) = (&self.rule, &self.start) {
+            let until = rule.until.as_ref().map(|u| zones.local_in(u, start));
+            items.push(format!("**Repeats:** {}", rule.describe(until)));
+            let exdates = self.exdates();
+            if !exdates.is_empty() {
+                let dates: Vec<String> = exdates.iter().map(|t| zones.format(t)).collect();
+                items.push(format!("**Except:** {}", dates.join("; ")));
+            }
+        }
+        let rdates = self.rdates();
+        if !rdates.is_empty() {
+            let dates: Vec<String> = rdates.iter().map(|t| zones.format(t)).collect();
+            items.push(format!("**Also on:** {}", dates.join("; ")));
+        }
+        if let Some(status) = self.component.text("STATUS") {
+            items.push(format!("**Status:** {}", humanize_keyword(&status)));
+        }
+        if let Some(organizer) = self.component.property("ORGANIZER") {
+            items.push(format!("**Organizer:** {}", format_person(organizer)));
+        }
+        if let Some(url) = self.component.text("URL") {
+            items.push(format!("**URL:** {}", url));
+        }
+        let categories: Vec<String> = self
+            .component
+            .properties_named("CATEGORIES")
+            .flat_map(|p| p.list(','))
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty())
+            .collect();
+        if !categories.is_empty() {
+            items.push(format!("**Categories:** {}", categories.join(", ")));
+        }
+        items
+    }
+}
+
+/// "Ann Lee <ann@example.com>"
+fn format_person(property: &Property) -> String {
+    let value = property.text();
+    let value = value.trim();
+    let address = match value.get(..7) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("mailto:") => &value[7..],
+        _ => value,
+    };
+    match property
+        .param("CN")
+        .map(str::trim)
+        .filter(|cn| !cn.is_empty())
+    {
+        Some(name) if !address.is_empty() && name != address => {
+            format!("{} <{}>", name, address)
+        }
+        Some(name) => name.to_string(),
+        None => address.to_string(),
+    }
+}
+
+/// "Ann Lee <ann@example.com> (required, accepted)"
+fn format_attendee(property: &Property) -> String {
+    let mut notes = Vec::new();
+    match property
+        .param("CUTYPE")
+        .map(str::to_ascii_uppercase)
+        .as_deref()
+    {
+        None | Some("INDIVIDUAL") | Some("UNKNOWN") => {}
+        Some(kind) => notes.push(kind.to_lowercase()),
+    }
+    if let Some(role) = property.param("ROLE") {
+        notes.push(
+            match role.to_ascii_uppercase().as_str() {
+                "CHAIR" => "chair",
+                "REQ-PARTICIPANT" => "required",
+                "OPT-PARTICIPANT" => "optional",
+                "NON-PARTICIPANT" => "for information",
+                _ => return format_person(property),
+            }
+            .to_string(),
+        );
+    }
+    if let Some(status) = property.param("PARTSTAT") {
+        notes.push(match status.to_ascii_uppercase().as_str() {
+            "NEEDS-ACTION" => "no response yet".to_string(),
+            other => humanize_keyword(other).to_lowercase(),
+        });
+    }
+    if notes.is_empty() {
+        format_person(property)
+    } else {
+        format!("{} ({})", format_person(property), notes.join(", "))
+    }
+}
+
+/// "Notification 15 minutes before start: Standup"
+fn format_alarm(alarm: &Component, zones: &TimeZones) -> Option<String> {
+    let trigger = alarm.property("TRIGGER")?;
+    let when = if let Some(duration) = parse_duration(&trigger.value) {
+        let anchor = match trigger.param("RELATED") {
+            Some(related) if related.eq_ignore_ascii_case("END") => "end",
+            _ => "start",
+        };
+        if duration.is_zero() {
+            format!("at {}", anchor)
+        } else if duration < Duration::zero() {
+            format!("{} before {}", describe_duration(duration), anchor)
+        } else {
+            format!("{} after {}", describe_duration(duration), anchor)
+        }
+    } else {
+        format!("at {}", zones.format(&CalTime::from_property(trigger)?))
+    };
+
+    let action = match alarm
+        .text("ACTION")
+        .map(|a| a.to_ascii_uppercase())
+        .as_deref()
+    {
+        Some("AUDIO") => "Sound".to_string(),
+        Some("EMAIL") => "Email".to_string(),
+        Some("DISPLAY") | None => "Notification".to_string(),
+        Some(other) => humanize_keyword(other),
+    };
+    let mut text = format!("{} {}", action, when);
+
+    let repeat: i64 = alarm
+        .text("REPEAT")
+        .and_then(|r| r.parse().ok())
+        .unwrap_or(0);
+    if let (true, Some(interval)) = (
+        repeat > 0,
+        alarm
+            .property("DURATION")
+            .and_then(|p| parse_duration(&p.value)),
+    ) {
+        text.push_str(&format!(
+            ", repeated {} every {}",
+            plural(repeat, "time"),
+            describe_duration(interval)
+        ));
+    }
+    if let Some(message) = alarm.text("DESCRIPTION").or_else(|| alarm.text("SUMMARY")) {
+        text.push_str(&format!(": {}", message.replace('\n', " ")));
+    }
+    Some(text)
+}
+
+/// Attendees, reminders and description of an event or task
+fn push_participants_and_alarms(
+    blocks: &mut Vec<ContentBlock>,
+    component: &Component,
+    zones: &TimeZones,
+) {
+    let attendees: Vec<String> = component
+        .properties_named("ATTENDEE")
+        .map(format_attendee)
+        .collect();
+    if !attendees.is_empty() {
+        blocks.push(ContentBlock::Text("**Attendees:**".to_string()));
+        blocks.push(ContentBlock::List {
+            ordered: false,
+            items: attendees,
+        });
+    }
+    let alarms: Vec<String> = component
+        .children("VALARM")
+        .filter_map(|alarm| format_alarm(alarm, zones))
+        .collect();
+    if !alarms.is_empty() {
+        blocks.push(ContentBlock::Text("**Reminders:**".to_string()));
+        blocks.push(ContentBlock::List {
+            ordered: false,
+            items: alarms,
+        });
+    }
+    if let Some(description) = component.text("DESCRIPTION") {
+        blocks.push(ContentBlock::Text(description));
+    }
+}
+
+fn todo_details(todo: &Component, zones: &TimeZones) -> Vec<String> {
+    let mut items = Vec::new();
+    let time = |name: &str| todo.property(name).and_then(CalTime::from_property);
+    if let Some(start) = time("DTSTART") {
+        items.push(format!("**Start:** {}", zones.format(&start)));
+    }
+    if let Some(due) = time("DUE") {
+        items.push(format!("**Due:** {}", zones.format(&due)));
+    }
+    if let Some(status) = todo.text("STATUS") {
+        items.push(format!("**Status:** {}", humanize_keyword(&status)));
+    }
+    if let Some(priority) = todo.text("PRIORITY").and_then(|p| p.parse::<u8>().ok()) {
+        let level = match priority {
+            1..=4 => Some("high"),
+            5 => Some("medium"),
+            6..=9 => Some("low"),
+            _ => None,
+        };
+        if let Some(level) = level {
+            items.push(format!("**Priority:** {} ({})", priority, level));
+        }
+    }
+    if let Some(percent) = todo.text("PERCENT-COMPLETE") {
+        items.push(format!("**Progress:** {}%", percent));
+    }
+    if let Some(completed) = time("COMPLETED") {
+        items.push(format!("**Completed:** {}", zones.format(&completed)));
+    }
+    if let Some(organizer) = todo.property("ORGANIZER") {
+        items.push(format!("**Organizer:** {}", format_person(organizer)));
+    }
+    items
+}
+
 /// iCalendar (.ics) converter
 pub struct ICalendarConverter;
 
 impl ICalendarConverter {
-    fn convert_ics(bytes: &[u8]) -> Result<Document, MarkitdownError> {
+    fn convert_ics(
+        bytes: &[u8],
+        window: Option<&RecurrenceWindow>,
+    ) -> Result<Document, MarkitdownError> {
         let content = String::from_utf8_lossy(bytes);
-        let calendar: Calendar = content
-            .parse()
-            .map_err(|e| MarkitdownError::ParseError(format!("iCalendar parse error: {:?}", e)))?;
+        let calendars: Vec<Component> = parse_components(&content)
+            .map_err(|e| MarkitdownError::ParseError(format!("iCalendar parse error: {}", e)))?
+            .into_iter()
+            .filter(|c| c.name == "VCALENDAR")
+            .collect();
+        if calendars.is_empty() {
+            return Err(MarkitdownError::ParseError(
+                "iCalendar parse error: no VCALENDAR found".to_string(),
+            ));
+        }
 
         let mut document = Document::new();
-        let mut page = Page::new(1);
-        let mut markdown = String::new();
+        document.title = Some(
+            calendars
+                .iter()
+                .find_map(|c| c.text("X-WR-CALNAME").or_else(|| c.text("NAME")))
+                .unwrap_or_else(|| "Calendar".to_string()),
+        );
+        if let Some(timezone) = calendars.iter().find_map(|c| c.text("X-WR-TIMEZONE")) {
+            document.metadata.insert("timezone".to_string(), timezone);
+        }
 
-        // Get calendar name if available
-        if let Some(name) = calendar.get_name() {
-            markdown.push_str(&format!("# {}\n\n", name));
-        } else {
-            markdown.push_str("# Calendar\n\n");
+        // Times of all calendars in the file are resolved together
+        let mut zones = TimeZones::default();
+        for calendar in &calendars {
+            zones.0.extend(TimeZones::from_calendar(calendar).0);
         }
 
-        let mut event_count = 0;
-        let mut todo_count = 0;
+        let mut events: Vec<Event> = calendars
+            .iter()
+            .flat_map(|c| c.children("VEVENT"))
+            .map(Event::new)
+            .collect();
+        // Tell recurring events which of their occurrences were changed
+        let overrides: Vec<(String, NaiveDateTime)> = events
+            .iter()
+            .filter_map(|e| {
+                let recurrence_id = e.recurrence_id()?;
+                Some((e.component.text("UID")?, recurrence_id.local))
+            })
+            .collect();
+        for event in events.iter_mut().filter(|e| e.rule.is_some()) {
+            if let Some(uid) = event.component.text("UID") {
+                event.overridden = overrides
+                    .iter()
+                    .filter(|(id, _)| *id == uid)
+                    .map(|(_, local)| *local)
+                    .collect();
+            }
+        }
+        events.sort_by_key(|e| {
+            e.start
+                .as_ref()
+                .map_or(NaiveDateTime::MAX, |s| zones.sort_key(s))
+        });
 
-        for component in &calendar.components {
-            match component {
-                CalendarComponent::Event(event) => {
-                    event_count += 1;
-                    markdown.push_str("## 📅 Event\n\n");
+        let todos: Vec<&Component> = calendars.iter().flat_map(|c| c.children("VTODO")).collect();
 
-                    if let Some(summary) = event.get_summary() {
-                        markdown.push_str(&format!("**{}**\n\n", summary));
-                    }
+        document
+            .metadata
+            .insert("event_count".to_string(), events.len().to_string());
+        document
+            .metadata
+            .insert("todo_count".to_string(), todos.len().to_string());
 
-                    if let Some(start) = event.get_start() {
-                        markdown.push_str(&format!("- **Start:** {:?}\n", start));
-                    }
-                    if let Some(end) = event.get_end() {
-                        markdown.push_str(&format!("- **End:** {:?}\n", end));
-                    }
-                    if let Some(location) = event.get_location() {
-                        markdown.push_str(&format!("- **Location:** {}\n", location));
-                    }
-                    if let Some(description) = event.get_description() {
-                        markdown.push_str(&format!("\n{}\n", description));
-                    }
-                    markdown.push('\n');
-                }
-                CalendarComponent::Todo(todo) => {
-                    todo_count += 1;
-                    markdown.push_str("## ☑️ Task\n\n");
+        let mut page = Page::new(1);
+        if events.is_empty() && todos.is_empty() {
+            page.add_content(ContentBlock::Text(
+                "*No events or tasks found in calendar.*".to_string(),
+            ));
+            document.add_page(page);
+            return Ok(document);
+        }
 
-                    if let Some(summary) = todo.get_summary() {
-                        markdown.push_str(&format!("**{}**\n\n", summary));
-                    }
+        if !events.is_empty() {
+            page.add_content(ContentBlock::Heading {
+                level: 2,
+                text: "Events".to_string(),
+            });
+            page.add_content(Self::events_table(&events, &zones, window, &mut document));
 
-                    if let Some(due) = todo.get_due() {
-                        markdown.push_str(&format!("- **Due:** {:?}\n", due));
-                    }
-                    if let Some(description) = todo.get_description() {
-                        markdown.push_str(&format!("\n{}\n", description));
+            for event in &events {
+                page.add_content(ContentBlock::Heading {
+                    level: 3,
+                    text: event.summary.clone(),
+                });
+                let details = event.details(&zones);
+                if !details.is_empty() {
+                    page.add_content(ContentBlock::List {
+                        ordered: false,
+                        items: details,
+                    });
+                }
+                if let (Some(window), Some(_)) = (window, &event.rule) {
+                    let occurrences: Vec<String> = event
+                        .occurrences_in(&zones, window)
+                        .iter()
+                        .map(|t| zones.format(t))
+                        .collect();
+                    page.add_content(ContentBlock::Text(format!(
+                        "**Occurrences in window:** {}",
+                        occurrences.len()
+                    )));
+                    if !occurrences.is_empty() {
+                        page.add_content(ContentBlock::List {
+                            ordered: false,
+                            items: occurrences,
+                        });
                     }
-                    markdown.push('\n');
                 }
-                _ => {}
+                let mut blocks = Vec::new();
+                push_participants_and_alarms(&mut blocks, event.component, &zones);
+                for block in blocks {
+                    page.add_content(block);
+                }
             }
         }
 
-        if event_count == 0 && todo_count == 0 {
-            markdown.push_str("*No events or tasks found in calendar.*\n");
-        } else {
-            markdown.push_str(&format!(
-                "\n---\n*{} event(s), {} task(s)*\n",
-                event_count, todo_count
-            ));
+        if !todos.is_empty() {
+            page.add_content(ContentBlock::Heading {
+                level: 2,
+                text: "Tasks".to_string(),
+            });
+            for todo in todos {
+                page.add_content(ContentBlock::Heading {
+                    level: 3,
+                    text: todo
+                        .text("SUMMARY")
+                        .unwrap_or_else(|| "Untitled task".to_string()),
+                });
+                let details = todo_details(todo, &zones);
+                if !details.is_empty() {
+                    page.add_content(ContentBlock::List {
+                        ordered: false,
+                        items: details,
+                    });
+                }
+                let mut blocks = Vec::new();
+                push_participants_and_alarms(&mut blocks, todo, &zones);
+                for block in blocks {
+                    page.add_content(block);
+                }
+            }
         }
 
-        page.add_content(ContentBlock::Markdown(markdown));
         document.add_page(page);
         Ok(document)
     }
+
+    /// All events by start time. With a window, every occurrence in it gets
+    /// a row instead.
+    fn events_table(
+        events: &[Event],
+        zones: &TimeZones,
+        window: Option<&RecurrenceWindow>,
+        document: &mut Document,
+    ) -> ContentBlock {
+        let mut rows: Vec<(NaiveDateTime, Vec<String>)> = Vec::new();
+        for event in events {
+            let repeats = match (&event.rule, &event.start) {
+                (Some(rule), Some(start)) => {
+                    rule.describe(rule.until.as_ref().map(|u| zones.local_in(u, start)))
+                }
+                _ => String::new(),
+            };
+            let location = event.component.text("LOCATION").unwrap_or_default();
+            let starts = match (window, &event.start) {
+                (Some(window), Some(_)) => event.occurrences_in(zones, window),
+                _ => event.start.iter().cloned().collect(),
+            };
+            if event.start.is_none() && window.is_none() {
+                rows.push((
+                    NaiveDateTime::MAX,
+                    vec![
+                        String::new(),
+                        String::new(),
+                        table_cell(&event.summary),
+                        table_cell(&location),
+                        table_cell(&repeats),
+                    ],
+                ));
+            }
+            for start in starts {
+                let end = match (&event.start, &event.end) {
+                    (Some(first), Some(end)) => start
+                        .local
+                        .checked_add_signed(end.local - first.local)
+                        .map(|local| start.at(local)),
+                    _ => None,
+                };
+                let (start_text, end_text) = zones.format_span(&start, end.as_ref());
+                rows.push((
+                    zones.sort_key(&start),
+                    vec![
+                        start_text,
+                        end_text,
+                        table_cell(&event.summary),
+                        table_cell(&location),
+                        table_cell(&repeats),
+                    ],
+                ));
+            }
+        }
+        rows.sort_by_key(|(key, _)| *key);
+        if window.is_some() {
+            document
+                .metadata
+                .insert("occurrence_count".to_string(), rows.len().to_string());
+        }
+
+        ContentBlock::Table {
+            headers: ["Start", "End", "Event", "Location", "Repeats"]
+                .iter()
+                .map(|h| h.to_string())
+                .collect(),
+            rows: rows.into_iter().map(|(_, row)| row).collect(),
+        }
+    }
 }
 
 #[async_trait]
@@ -134,7 +1548,8 @@ impl DocumentConverter for ICalendarConverter {
             }
         }
 
-        Self::convert_ics(&bytes)
+        let window = options.as_ref().and_then(|o| o.recurrence_window.as_ref());
+        Self::convert_ics(&bytes, window)
     }
 
     fn supported_extensions(&self) -> &[&str] {
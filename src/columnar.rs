@@ -0,0 +1,404 @@
+//! Parquet and Arrow IPC (Feather) to Markdown converters.
+//!
+//! Both formats hold one table of typed columns. The schema, sampled rows and
+//! statistics over all rows are rendered like a SQLite table, using the
+//! sampling and BLOB settings of [`DatabaseOptions`](crate::DatabaseOptions).
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float64Type, Int64Type, UInt64Type};
+use arrow_array::{Array, ArrayRef, RecordBatch};
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_ipc::reader::{FileReader, StreamReader};
+use arrow_schema::{ArrowError, DataType, Schema};
+use async_trait::async_trait;
+use bytes::Bytes;
+use object_store::ObjectStore;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use crate::error::MarkitdownError;
+use crate::model::{ContentBlock, ConversionOptions, Document, DocumentConverter, Page};
+use crate::tabular::{ColumnStats, SchemaColumn, TableSummary, Value};
+
+/// Name used for the single table in image ids and alt text
+const TABLE_NAME: &str = "data";
+
+fn to_parse_error(e: impl std::fmt::Display) -> MarkitdownError {
+    MarkitdownError::ParseError(e.to_string())
+}
+
+/// All values of a column
+fn column_values(array: &ArrayRef) -> Result<Vec<Value>, ArrowError> {
+    let nulls = array.logical_nulls();
+    let is_null = |i: usize| nulls.as_ref().is_some_and(|n| n.is_null(i));
+    let collect = |value: &dyn Fn(usize) -> Value| {
+        (0..array.len())
+            .map(|i| if is_null(i) { Value::Null } else { value(i) })
+            .collect()
+    };
+
+    let data_type = array.data_type();
+    // Unsigned values above i64::MAX are kept exactly as text
+    if *data_type == DataType::UInt64 {
+        let integers = array.as_primitive::<UInt64Type>();
+        return Ok(collect(&|i| {
+            let value = integers.value(i);
+            i64::try_from(value).map_or_else(|_| Value::Text(value.to_string()), Value::Integer)
+        }));
+    }
+    if data_type.is_integer() {
+        let integers = arrow_cast::cast(array, &DataType::Int64)?;
+        let integers = integers.as_primitive::<Int64Type>();
+        return Ok(collect(&|i| Value::Integer(integers.value(i))));
+    }
+    if data_type.is_floating() {
+        let reals = arrow_cast::cast(array, &DataType::Float64)?;
+        let reals = reals.as_primitive::<Float64Type>();
+        return Ok(collect(&|i| Value::Real(reals.value(i))));
+    }
+    match data_type {
+        DataType::Binary => {
+            let binary = array.as_binary::<i32>();
+            Ok(collect(&|i| Value::Blob(binary.value(i).to_vec())))
+        }
+        DataType::LargeBinary => {
+            let binary = array.as_binary::<i64>();
+            Ok(collect(&|i| Value::Blob(binary.value(i).to_vec())))
+        }
+        DataType::FixedSizeBinary(_) => {
+            let binary = array.as_fixed_size_binary();
+            Ok(collect(&|i| Value::Blob(binary.value(i).to_vec())))
+        }
+        DataType::BinaryView => {
+            let binary = array.as_binary_view();
+            Ok(collect(&|i| Value::Blob(binary.value(i).to_vec())))
+        }
+        _ => {
+            let formatter = ArrayFormatter::try_new(array.as_ref(), &FormatOptions::default())?;
+            Ok(collect(&|i| Value::Text(formatter.value(i).to_string())))
+        }
+    }
+}
+
+/// Values of a column for its statistics when they differ from the values
+/// shown: decimals (shown exactly as text) and unsigned 64-bit integers count
+/// as approximate numbers
+fn stats_values(array: &ArrayRef) -> Result<Option<Vec<Value>>, ArrowError> {
+    if !matches!(
+        array.data_type(),
+        DataType::UInt64 | DataType::Decimal128(..) | DataType::Decimal256(..)
+    ) {
+        return Ok(None);
+    }
+    let nulls = array.logical_nulls();
+    let reals = arrow_cast::cast(array, &DataType::Float64)?;
+    let reals = reals.as_primitive::<Float64Type>();
+    Ok(Some(
+        (0..array.len())
+            .map(|i| {
+                if nulls.as_ref().is_some_and(|n| n.is_null(i)) {
+                    Value::Null
+                } else {
+                    Value::Real(reals.value(i))
+                }
+            })
+            .collect(),
+    ))
+}
+
+/// Schema, sampled rows and column statistics of a table read batch by batch.
+/// Returns the blocks and the number of rows and batches read.
+fn summarize_table(
+    schema: &Schema,
+    batches: impl Iterator<Item = Result<RecordBatch, ArrowError>>,
+    options: &ConversionOptions,
+) -> Result<(Vec<ContentBlock>, u64, usize), MarkitdownError> {
+    let summary = TableSummary {
+        options: &options.database,
+        extract_images: options.extract_images,
+        level: 2,
+    };
+    let columns: Vec<SchemaColumn> = schema
+        .fields()
+        .iter()
+        .map(|field| SchemaColumn {
+            name: field.name().clone(),
+            data_type: field.data_type().to_string(),
+            nullable: field.is_nullable(),
+            ..Default::default()
+        })
+        .collect();
+
+    let mut stats: Vec<ColumnStats> = columns.iter().map(|_| ColumnStats::default()).collect();
+    let mut sample: Vec<Vec<Value>> = Vec::new();
+    let mut total_rows = 0u64;
+    let mut batch_count = 0;
+    for batch in batches {
+        let batch = batch.map_err(to_parse_error)?;
+        let values = batch
+            .columns()
+            .iter()
+            .map(column_values)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(to_parse_error)?;
+
+        for row in 0..batch.num_rows() {
+            if summary
+                .row_limit()
+                .is_some_and(|limit| sample.len() >= limit)
+            {
+                break;
+            }
+            sample.push(values.iter().map(|column| column[row].clone()).collect());
+        }
+        for ((stats, column), array) in stats.iter_mut().zip(&values).zip(batch.columns()) {
+            match stats_values(array).map_err(to_parse_error)? {
+                Some(numbers) => numbers.iter().for_each(|value| stats.add(value)),
+                None => column.iter().for_each(|value| stats.add(value)),
+            }
+        }
+        total_rows += batch.num_rows() as u64;
+        batch_count += 1;
+    }
+
+    let headers: Vec<String> = columns.iter().map(|c| c.name.clone()).collect();
+    let names_and_types: Vec<(String, String)> = columns
+        .iter()
+        .map(|c| (c.name.clone(), c.data_type.clone()))
+        .collect();
+
+    let mut blocks = summary.schema_blocks(&columns, false);
+    blocks.extend(summary.rows_blocks(TABLE_NAME, &headers, sample, total_rows));
+    if total_rows > 0 {
+        blocks.extend(summary.statistics_blocks(&names_and_types, &stats));
+    }
+    Ok((blocks, total_rows, batch_count))
+}
+
+fn build_document(
+    title: &str,
+    summary_line: String,
+    blocks: Vec<ContentBlock>,
+    metadata: Vec<(&str, String)>,
+) -> Document {
+    let mut document = Document::new();
+    document.title = Some(title.to_string());
+    for (key, value) in metadata {
+        document.metadata.insert(key.to_string(), value);
+    }
+
+    let mut page = Page::new(1);
+    page.add_content(ContentBlock::Text(summary_line));
+    for block in blocks {
+        page.add_content(block);
+    }
+    document.add_page(page);
+    document
+}
+
+/// Parquet file converter
+pub struct ParquetConverter;
+
+impl ParquetConverter {
+    fn convert_parquet(
+        bytes: Bytes,
+        options: &ConversionOptions,
+    ) -> Result<Document, MarkitdownError> {
+        let builder = ParquetRecordBatchReaderBuilder::try_new(bytes).map_err(|e| {
+            MarkitdownError::ParseError(format!("Failed to read Parquet file: {}", e))
+        })?;
+        let metadata = builder.metadata().clone();
+        let schema = builder.schema().clone();
+        let reader = builder.build().map_err(to_parse_error)?;
+        let (blocks, total_rows, _) = summarize_table(&schema, reader, options)?;
+
+        let file_metadata = metadata.file_metadata();
+        let mut compression: Vec<String> = Vec::new();
+        for row_group in metadata.row_groups() {
+            for column in row_group.columns() {
+                let codec = column.compression().to_string();
+                if !compression.contains(&codec) {
+                    compression.push(codec);
+                }
+            }
+        }
+
+        let mut summary_line = format!(
+            "**Columns:** {} · **Row groups:** {}",
+            schema.fields().len(),
+            metadata.num_row_groups()
+        );
+        if !compression.is_empty() {
+            summary_line.push_str(&format!(" · **Compression:** {}", compression.join(", ")));
+        }
+        let mut fields = vec![
+            ("row_count", total_rows.to_string()),
+            ("column_count", schema.fields().len().to_string()),
+            ("row_group_count", metadata.num_row_groups().to_string()),
+        ];
+        if !compression.is_empty() {
+            fields.push(("compression", compression.join(", ")));
+        }
+        if let Some(created_by) = file_metadata.created_by() {
+            summary_line.push_str(&format!(" · **Created by:** {}", created_by));
+            fields.push(("created_by", created_by.to_string()));
+        }
+
+        Ok(build_document("Parquet File", summary_line, blocks, fields))
+    }
+}
+
+#[async_trait]
+impl DocumentConverter for ParquetConverter {
+    async fn convert(
+        &self,
+        store: Arc<dyn ObjectStore>,
+        path: &object_store::path::Path,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let result = store.get(path).await?;
+        let bytes = result.bytes().await?;
+        self.convert_bytes(bytes, options).await
+    }
+
+    async fn convert_bytes(
+        &self,
+        bytes: Bytes,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        if let Some(opts) = &options {
+            if let Some(ext) = &opts.file_extension {
+                if ext != ".parquet" {
+                    return Err(MarkitdownError::InvalidFile(format!(
+                        "Expected .parquet file, got {}",
+                        ext
+                    )));
+                }
+            }
+        }
+
+        let opts = options.unwrap_or_default();
+        let mut document = Self::convert_parquet(bytes, &opts)?;
+
+        // If LLM client is provided, get descriptions for all images
+        if let Some(ref llm_client) = opts.llm_client {
+            if let Some(path) = opts.image_context_path.as_deref() {
+                document.apply_image_context_path(path);
+            }
+            document = document
+                .with_image_descriptions(llm_client.as_ref())
+                .await?;
+        }
+
+        Ok(document)
+    }
+
+    fn supported_extensions(&self) -> &[&str] {
+        &[".parquet"]
+    }
+}
+
+/// Arrow IPC file and stream converter (including Feather v2)
+pub struct ArrowConverter;
+
+impl ArrowConverter {
+    fn convert_arrow(
+        bytes: Bytes,
+        options: &ConversionOptions,
+    ) -> Result<Document, MarkitdownError> {
+        if bytes.starts_with(b"FEA1") {
+            return Err(MarkitdownError::UnsupportedFormat(
+                "Feather v1 files are not supported; re-save them as Feather v2 (Arrow IPC)"
+                    .to_string(),
+            ));
+        }
+
+        let (format, schema, (blocks, total_rows, batch_count)) = if bytes.starts_with(b"ARROW1") {
+            let reader = FileReader::try_new(Cursor::new(bytes), None).map_err(|e| {
+                MarkitdownError::ParseError(format!("Failed to read Arrow file: {}", e))
+            })?;
+            let schema = reader.schema();
+            (
+                "file",
+                schema.clone(),
+                summarize_table(&schema, reader, options)?,
+            )
+        } else {
+            let reader = StreamReader::try_new(Cursor::new(bytes), None).map_err(|e| {
+                MarkitdownError::ParseError(format!("Failed to read Arrow stream: {}", e))
+            })?;
+            let schema = reader.schema();
+            (
+                "stream",
+                schema.clone(),
+                summarize_table(&schema, reader, options)?,
+            )
+        };
+
+        let summary_line = format!(
+            "**Columns:** {} · **Record batches:** {}",
+            schema.fields().len(),
+            batch_count
+        );
+        let fields = vec![
+            ("row_count", total_rows.to_string()),
+            ("column_count", schema.fields().len().to_string()),
+            ("batch_count", batch_count.to_string()),
+            ("format", format!("Arrow IPC {}", format)),
+        ];
+        Ok(build_document("Arrow File", summary_line, blocks, fields))
+    }
+}
+
+#[async_trait]
+impl DocumentConverter for ArrowConverter {
+    async fn convert(
+        &self,
+        store: Arc<dyn ObjectStore>,
+        path: &object_store::path::Path,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let result = store.get(path).await?;
+        let bytes = result.bytes().await?;
+        self.convert_bytes(bytes, options).await
+    }
+
+    async fn convert_bytes(
+        &self,
+        bytes: Bytes,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let valid_extensions = [".arrow", ".feather", ".ipc"];
+
+        if let Some(opts) = &options {
+            if let Some(ext) = &opts.file_extension {
+                if !valid_extensions.contains(&ext.as_str()) {
+                    return Err(MarkitdownError::InvalidFile(format!(
+                        "Expected Arrow file (.arrow, .feather, .ipc), got {}",
+                        ext
+                    )));
+                }
+            }
+        }
+
+        let opts = options.unwrap_or_default();
+        let mut document = Self::convert_arrow(bytes, &opts)?;
+
+        // If LLM client is provided, get descriptions for all images
+        if let Some(ref llm_client) = opts.llm_client {
+            if let Some(path) = opts.image_context_path.as_deref() {
+                document.apply_image_context_path(path);
+            }
+            document = document
+                .with_image_descriptions(llm_client.as_ref())
+                .await?;
+        }
+
+        Ok(document)
+    }
+
+    fn supported_extensions(&self) -> &[&str] {
+        &[".arrow", ".feather", ".ipc"]
+    }
+}
@@ -0,0 +1,271 @@
+//! Content lines shared by iCalendar (RFC 5545) and vCard (RFC 6350).
+//!
+//! Both formats are trees of `BEGIN:`/`END:` components holding
+//! `NAME;PARAM=value:value` properties, with long lines folded onto
+//! continuation lines that start with whitespace.
+
+use crate::error::MarkitdownError;
+
+/// A property such as `DTSTART;TZID=Europe/Berlin:20240304T090000`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Property {
+    /// vCard property group (`item1` in `item1.EMAIL`)
+    pub group: Option<String>,
+    /// Upper-cased property name
+    pub name: String,
+    /// Parameters with upper-cased names and unquoted values
+    pub params: Vec<(String, String)>,
+    /// Raw value, with escapes still in place
+    pub value: String,
+}
+
+impl Property {
+    /// Value of a parameter (case-insensitive name)
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// All values of a parameter, including repeated and comma-separated
+    /// ones (`TYPE=work,voice;TYPE=pref`), lower-cased
+    pub fn param_values(&self, name: &str) -> Vec<String> {
+        self.params
+            .iter()
+            .filter(|(n, _)| n.eq_ignore_ascii_case(name))
+            .flat_map(|(_, v)| v.split(','))
+            .map(|v| v.trim().to_lowercase())
+            .filter(|v| !v.is_empty())
+            .collect()
+    }
+
+    /// The value as text, with `\n`, `\,`, `\;` and `\\` escapes resolved
+    pub fn text(&self) -> String {
+        unescape(&self.value)
+    }
+
+    /// The value split at unescaped `sep`, each part unescaped
+    pub fn list(&self, sep: char) -> Vec<String> {
+        split_unescaped(&self.value, sep)
+            .into_iter()
+            .map(|part| unescape(&part))
+            .collect()
+    }
+}
+
+/// A `BEGIN:NAME` ... `END:NAME` block
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Component {
+    /// Upper-cased component name (`VEVENT`, `VCARD`, ...)
+    pub name: String,
+    pub properties: Vec<Property>,
+    pub components: Vec<Component>,
+}
+
+impl Component {
+    /// First property with this name
+    pub fn property(&self, name: &str) -> Option<&Property> {
+        self.properties
+            .iter()
+            .find(|p| p.name.eq_ignore_ascii_case(name))
+    }
+
+    /// All properties with this name
+    pub fn properties_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Property> {
+        self.properties
+            .iter()
+            .filter(move |p| p.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Unescaped, non-empty text of the first property with this name
+    pub fn text(&self, name: &str) -> Option<String> {
+        self.property(name)
+            .map(Property::text)
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+    }
+
+    /// Child components with this name
+    pub fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Component> {
+        self.components
+            .iter()
+            .filter(move |c| c.name.eq_ignore_ascii_case(name))
+    }
+}
+
+/// Whether a line continues on the next one as a quoted-printable soft
+/// line break (vCard 2.1)
+fn has_soft_line_break(line: &str) -> bool {
+    line.ends_with('=')
+        && line
+            .split_once(':')
+            .is_some_and(|(head, _)| head.to_ascii_uppercase().contains("QUOTED-PRINTABLE"))
+}
+
+/// Join folded lines
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut soft_break = false;
+    for line in text.lines() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        match (line.chars().next(), lines.last_mut()) {
+            (_, Some(last)) if soft_break => {
+                last.pop();
+                last.push_str(line.trim_start());
+            }
+            (Some(' ') | Some('\t'), Some(last)) => last.push_str(&line[1..]),
+            _ if line.trim().is_empty() => {}
+            _ => lines.push(line.to_string()),
+        }
+        soft_break = lines.last().is_some_and(|l| has_soft_line_break(l));
+    }
+    lines
+}
+
+/// Parse one unfolded content line
+fn parse_line(line: &str) -> Option<Property> {
+    // The value starts at the first colon outside a quoted parameter value
+    let mut in_quotes = false;
+    let colon = line.char_indices().find_map(|(i, c)| match c {
+        '"' => {
+            in_quotes = !in_quotes;
+            None
+        }
+        ':' if !in_quotes => Some(i),
+        _ => None,
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+
+    let mut parts = split_outside_quotes(head, ';').into_iter();
+    let full_name = parts.next()?;
+    let (group, name) = match full_name.rsplit_once('.') {
+        Some((group, name)) => (Some(group.to_string()), name),
+        None => (None, full_name.as_str()),
+    };
+    if name.is_empty() {
+        return None;
+    }
+
+    // vCard 2.1 writes bare type names (`TEL;WORK;VOICE:...`)
+    let params = parts
+        .map(|param| match param.split_once('=') {
+            Some((key, value)) => (key.trim().to_uppercase(), value.replace('"', "")),
+            None => ("TYPE".to_string(), param.trim().to_string()),
+        })
+        .collect();
+
+    Some(Property {
+        group,
+        name: name.trim().to_uppercase(),
+        params,
+        value: value.to_string(),
+    })
+}
+
+fn split_outside_quotes(text: &str, sep: char) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let mut in_quotes = false;
+    for c in text.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                parts.last_mut().unwrap().push(c);
+            }
+            c if c == sep && !in_quotes => parts.push(String::new()),
+            c => parts.last_mut().unwrap().push(c),
+        }
+    }
+    parts
+}
+
+/// Split at `sep` unless it is escaped with a backslash
+fn split_unescaped(text: &str, sep: char) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        let current = parts.last_mut().unwrap();
+        match c {
+            '\\' => {
+                current.push(c);
+                current.extend(chars.next());
+            }
+            c if c == sep => parts.push(String::new()),
+            c => current.push(c),
+        }
+    }
+    parts
+}
+
+/// Resolve text escapes
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Parse the top-level components of a file. Lines outside any component
+/// and unbalanced `END:` lines are ignored.
+pub(crate) fn parse_components(text: &str) -> Result<Vec<Component>, MarkitdownError> {
+    let mut roots = Vec::new();
+    let mut stack: Vec<Component> = Vec::new();
+
+    for line in unfold(text.trim_start_matches('\u{feff}')) {
+        let Some(property) = parse_line(&line) else {
+            continue;
+        };
+        match property.name.as_str() {
+            "BEGIN" => stack.push(Component {
+                name: property.value.trim().to_uppercase(),
+                ..Default::default()
+            }),
+            "END" => {
+                let name = property.value.trim().to_uppercase();
+                if !stack.iter().any(|c| c.name == name) {
+                    continue;
+                }
+                // Close components left open inside this one
+                while let Some(component) = stack.pop() {
+                    let done = component.name == name;
+                    match stack.last_mut() {
+                        Some(parent) => parent.components.push(component),
+                        None => roots.push(component),
+                    }
+                    if done {
+                        break;
+                    }
+                }
+            }
+            _ => {
+                if let Some(current) = stack.last_mut() {
+                    current.properties.push(property);
+                }
+            }
+        }
+    }
+    // Unterminated components at the end of the file
+    while let Some(component) = stack.pop() {
+        match stack.last_mut() {
+            Some(parent) => parent.components.push(component),
+            None => roots.push(component),
+        }
+    }
+
+    if roots.is_empty() {
+        return Err(MarkitdownError::ParseError(
+            "No BEGIN/END components found".to_string(),
+        ));
+    }
+    Ok(roots)
+}
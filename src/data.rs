@@ -8,7 +8,9 @@ use object_store::ObjectStore;
 use std::sync::Arc;
 
 use crate::error::MarkitdownError;
-use crate::model::{ContentBlock, ConversionOptions, Document, DocumentConverter, Page};
+use crate::model::{
+    ContentBlock, ConversionOptions, Document, DocumentConverter, Page, Provenance,
+};
 
 /// JSON to Markdown converter
 pub struct JsonConverter;
@@ -166,21 +168,18 @@ impl DocumentConverter for TomlConverter {
     }
 }
 
-/// Plain text and Markdown passthrough converter
+/// Plain text passthrough converter
 pub struct TextConverter;
 
 impl TextConverter {
-    fn convert_text(bytes: &[u8], is_markdown: bool) -> Result<Document, MarkitdownError> {
+    fn convert_text(bytes: &[u8]) -> Result<Document, MarkitdownError> {
         let content = String::from_utf8_lossy(bytes).to_string();
 
+        let lines = content.lines().count();
+
         let mut document = Document::new();
         let mut page = Page::new(1);
-
-        if is_markdown {
-            page.add_content(ContentBlock::Markdown(content));
-        } else {
-            page.add_content(ContentBlock::Text(content));
-        }
+        page.add_content_with_provenance(ContentBlock::Text(content), Provenance::lines(1, lines));
 
         document.add_page(page);
         Ok(document)
@@ -203,19 +202,13 @@ impl DocumentConverter for TextConverter {
     async fn convert_bytes(
         &self,
         bytes: Bytes,
-        options: Option<ConversionOptions>,
+        _options: Option<ConversionOptions>,
     ) -> Result<Document, MarkitdownError> {
-        let is_markdown = options
-            .as_ref()
-            .and_then(|o| o.file_extension.as_ref())
-            .map(|ext| ext == ".md" || ext == ".markdown")
-            .unwrap_or(false);
-
-        Self::convert_text(&bytes, is_markdown)
+        Self::convert_text(&bytes)
     }
 
     fn supported_extensions(&self) -> &[&str] {
-        &[".txt", ".text", ".md", ".markdown", ".log"]
+        &[".txt", ".text", ".log"]
     }
 }
 
@@ -278,7 +271,10 @@ impl CodeConverter {
 
         // Wrap in code block with language hint
         let markdown = format!("```{}\n{}\n```", language, content);
-        page.add_content(ContentBlock::Markdown(markdown));
+        page.add_content_with_provenance(
+            ContentBlock::Markdown(markdown),
+            Provenance::lines(1, content.lines().count()),
+        );
 
         document.add_page(page);
         Ok(document)
@@ -339,7 +335,6 @@ impl DocumentConverter for CodeConverter {
             ".bash",
             ".zsh",
             ".ps1",
-            ".sql",
             ".r",
             ".lua",
             ".pl",
@@ -5,6 +5,9 @@ use docx_rust::{
     DocxFile,
 };
 use object_store::ObjectStore;
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+use std::collections::HashMap;
 use std::io::{Cursor, Read};
 use std::sync::Arc;
 use zip::ZipArchive;
@@ -98,9 +101,48 @@ impl DocxConverter {
         }
 
         document.add_page(page);
+        crate::metadata::apply_ooxml_properties(&mut document, bytes);
+        if document.properties.language.is_none() {
+            document.properties.language = Self::declared_language(bytes);
+        }
         Ok(document)
     }
 
+    /// The `w:lang` most runs of the body declare, else the default from
+    /// the styles part
+    fn declared_language(bytes: &[u8]) -> Option<String> {
+        let mut archive = ZipArchive::new(Cursor::new(bytes)).ok()?;
+        let mut languages = |name: &str| {
+            let mut xml = String::new();
+            if let Ok(mut file) = archive.by_name(name) {
+                let _ = file.read_to_string(&mut xml);
+            }
+            let mut reader = Reader::from_str(&xml);
+            let mut counts: HashMap<String, usize> = HashMap::new();
+            loop {
+                match reader.read_event() {
+                    Ok(Event::Start(e)) | Ok(Event::Empty(e)) if e.name().as_ref() == b"w:lang" => {
+                        let value = e
+                            .attributes()
+                            .flatten()
+                            .find(|attr| attr.key.as_ref() == b"w:val")
+                            .and_then(|attr| attr.unescape_value().ok().map(|v| v.to_string()));
+                        if let Some(value) = value {
+                            *counts.entry(value).or_default() += 1;
+                        }
+                    }
+                    Ok(Event::Eof) | Err(_) => break,
+                    _ => {}
+                }
+            }
+            counts
+                .into_iter()
+                .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)))
+                .map(|(language, _)| language)
+        };
+        languages("word/document.xml").or_else(|| languages("word/styles.xml"))
+    }
+
     fn extract_images_from_docx(
         &self,
         bytes: &[u8],
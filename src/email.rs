@@ -1,129 +1,717 @@
 //! Email (EML/MSG) to Markdown converter.
 //!
-//! Supports conversion of email files to markdown,
-//! including headers, body text, and attachment listings.
+//! `.eml` files are parsed with `mail-parser`; Outlook `.msg` files are OLE
+//! compound files whose MAPI properties are read with `cfb`. Both are mapped
+//! onto one message model and rendered the same way: a header summary, the
+//! body (HTML bodies go through [`HtmlConverter`] with `cid:` images served
+//! from the message), and the attachments.
+//!
+//! Attachments are converted through `ConversionOptions::nested_converter`,
+//! so a PDF, DOCX or forwarded email attached to a message is rendered
+//! inline below it.
 
 use async_trait::async_trait;
 use bytes::Bytes;
-use mail_parser::{MessageParser, MimeHeaders};
+use cfb::CompoundFile;
+use futures::future::BoxFuture;
+use mail_parser::{Address, Message, MessageParser, MimeHeaders, PartType};
 use object_store::ObjectStore;
+use std::collections::{HashMap, HashSet};
+use std::io::{Cursor, Read, Seek};
 use std::sync::Arc;
 
 use crate::error::MarkitdownError;
-use crate::model::{ContentBlock, ConversionOptions, Document, DocumentConverter, Page};
+use crate::metadata::parse_date;
+use crate::model::{
+    ContentBlock, ConversionOptions, Document, DocumentConverter, ExtractedImage, Page,
+};
+use crate::nested::MAX_NESTING_DEPTH;
+use crate::quotes::{html_body_blocks, text_body_blocks};
+use crate::webarchive::{convert_bundled_html, resource_key, BundledResources};
 
-/// Email document converter
-pub struct EmailConverter;
+/// Signature of OLE compound files (Outlook .msg)
+const CFB_SIGNATURE: &[u8] = &[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
 
-impl EmailConverter {
-    /// Convert email content to markdown
-    fn convert_email(bytes: &[u8]) -> Result<Document, MarkitdownError> {
-        let message = MessageParser::default()
-            .parse(bytes)
-            .ok_or_else(|| MarkitdownError::ParseError("Failed to parse email".to_string()))?;
+/// A parsed email, independent of the file format it came from
+#[derive(Debug, Default)]
+pub(crate) struct EmailMessage {
+    pub(crate) from: Vec<String>,
+    to: Vec<String>,
+    cc: Vec<String>,
+    bcc: Vec<String>,
+    reply_to: Vec<String>,
+    pub(crate) subject: Option<String>,
+    /// RFC 3339 timestamp
+    pub(crate) date: Option<String>,
+    pub(crate) message_id: Option<String>,
+    pub(crate) in_reply_to: Option<String>,
+    pub(crate) references: Vec<String>,
+    text_body: Option<String>,
+    html_body: Option<String>,
+    attachments: Vec<EmailAttachment>,
+}
 
-        let mut document = Document::new();
-        let mut page = Page::new(1);
-        let mut markdown = String::new();
+/// A file attached to an email
+#[derive(Debug)]
+struct EmailAttachment {
+    name: Option<String>,
+    mime_type: Option<String>,
+    content_id: Option<String>,
+    /// Shown inside the body rather than offered as a download
+    inline: bool,
+    data: Bytes,
+    /// Outlook message embedded as an attachment
+    message: Option<Box<EmailMessage>>,
+}
 
-        // Email headers
-        markdown.push_str("# Email\n\n");
+impl EmailAttachment {
+    fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or("unnamed")
+    }
+
+    fn is_image(&self) -> bool {
+        self.mime_type
+            .as_deref()
+            .is_some_and(|m| m.starts_with("image/"))
+    }
+}
+
+/// Format a mailbox as `Name <address>`
+fn format_mailbox(name: Option<&str>, address: Option<&str>) -> Option<String> {
+    let name = name.map(str::trim).filter(|n| !n.is_empty());
+    let address = address.map(str::trim).filter(|a| !a.is_empty());
+    match (name, address) {
+        (Some(name), Some(address)) if name != address => Some(format!("{} <{}>", name, address)),
+        (_, Some(address)) => Some(address.to_string()),
+        (Some(name), None) => Some(name.to_string()),
+        (None, None) => None,
+    }
+}
 
-        // From
-        if let Some(from) = message.from() {
-            let from_str: Vec<String> = from
+/// Strip the angle brackets around a message id
+fn bare_message_id(id: &str) -> Option<String> {
+    let id = id.trim().trim_start_matches('<').trim_end_matches('>');
+    (!id.is_empty()).then(|| id.to_string())
+}
+
+// ============================================================================
+// EML
+// ============================================================================
+
+fn mime_addresses(address: Option<&Address>) -> Vec<String> {
+    address
+        .map(|address| {
+            address
                 .iter()
-                .map(|addr| {
-                    if let Some(name) = addr.name() {
-                        format!("{} <{}>", name, addr.address().unwrap_or(""))
-                    } else {
-                        addr.address().unwrap_or("").to_string()
-                    }
+                .filter_map(|addr| format_mailbox(addr.name(), addr.address()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Parse an RFC 822 message
+pub(crate) fn parse_eml(bytes: &[u8]) -> Result<EmailMessage, MarkitdownError> {
+    let message = MessageParser::default()
+        .parse(bytes)
+        .ok_or_else(|| MarkitdownError::ParseError("Failed to parse email".to_string()))?;
+    Ok(message_from_mime(&message))
+}
+
+/// Parse only the headers of an RFC 822 message (no body or attachments)
+pub(crate) fn parse_eml_headers(bytes: &[u8]) -> Option<EmailMessage> {
+    MessageParser::default()
+        .parse_headers(bytes)
+        .map(|message| message_from_mime(&message))
+}
+
+fn message_from_mime(message: &Message) -> EmailMessage {
+    let body_part = |ids: &[u32], html: bool| {
+        ids.iter()
+            .filter_map(|&id| message.parts.get(id as usize))
+            .find_map(|part| match (&part.body, html) {
+                (PartType::Text(text), false) | (PartType::Html(text), true) => {
+                    Some(text.to_string())
+                }
+                _ => None,
+            })
+    };
+
+    let attachments = message
+        .attachments()
+        .map(|part| {
+            let content_id = part.content_id().map(str::to_string);
+            let inline = part
+                .content_disposition()
+                .map_or(content_id.is_some(), |d| d.is_inline());
+            EmailAttachment {
+                // Forwarded messages are usually attached without a file name
+                name: part
+                    .attachment_name()
+                    .or_else(|| part.message().and_then(|m| m.subject()))
+                    .map(str::to_string),
+                mime_type: part.content_type().map(|ct| match ct.subtype() {
+                    Some(subtype) => format!("{}/{}", ct.ctype(), subtype),
+                    None => ct.ctype().to_string(),
+                }),
+                content_id,
+                inline,
+                data: Bytes::copy_from_slice(part.contents()),
+                message: None,
+            }
+        })
+        .collect();
+
+    EmailMessage {
+        from: mime_addresses(message.from()),
+        to: mime_addresses(message.to()),
+        cc: mime_addresses(message.cc()),
+        bcc: mime_addresses(message.bcc()),
+        reply_to: mime_addresses(message.reply_to()),
+        subject: message.subject().map(str::to_string),
+        date: message.date().map(|d| d.to_rfc3339()),
+        message_id: message.message_id().and_then(bare_message_id),
+        in_reply_to: message.in_reply_to().as_text().and_then(bare_message_id),
+        references: message
+            .references()
+            .as_text_list()
+            .map(|ids| ids.iter().filter_map(|id| bare_message_id(id)).collect())
+            .unwrap_or_default(),
+        text_body: body_part(&message.text_body, false),
+        html_body: body_part(&message.html_body, true),
+        attachments,
+    }
+}
+
+// ============================================================================
+// MSG
+// ============================================================================
+
+/// MAPI property ids used from Outlook messages
+mod prop {
+    pub const SUBJECT: u16 = 0x0037;
+    pub const CLIENT_SUBMIT_TIME: u16 = 0x0039;
+    pub const SENT_REPRESENTING_NAME: u16 = 0x0042;
+    pub const REPLY_RECIPIENT_NAMES: u16 = 0x0050;
+    pub const SENT_REPRESENTING_EMAIL: u16 = 0x0065;
+    pub const TRANSPORT_HEADERS: u16 = 0x007D;
+    pub const SENDER_NAME: u16 = 0x0C1A;
+    pub const SENDER_EMAIL: u16 = 0x0C1F;
+    pub const RECIPIENT_TYPE: u16 = 0x0C15;
+    pub const DISPLAY_CC: u16 = 0x0E03;
+    pub const DISPLAY_TO: u16 = 0x0E04;
+    pub const DELIVERY_TIME: u16 = 0x0E06;
+    pub const BODY: u16 = 0x1000;
+    pub const HTML_BODY: u16 = 0x1013;
+    pub const MESSAGE_ID: u16 = 0x1035;
+    pub const REFERENCES: u16 = 0x1039;
+    pub const IN_REPLY_TO: u16 = 0x1042;
+    pub const DISPLAY_NAME: u16 = 0x3001;
+    pub const EMAIL_ADDRESS: u16 = 0x3003;
+    pub const SMTP_ADDRESS: u16 = 0x39FE;
+    pub const ATTACH_DATA: u16 = 0x3701;
+    pub const ATTACH_FILENAME: u16 = 0x3704;
+    pub const ATTACH_METHOD: u16 = 0x3705;
+    pub const ATTACH_LONG_FILENAME: u16 = 0x3707;
+    pub const ATTACH_MIME_TAG: u16 = 0x370E;
+    pub const ATTACH_CONTENT_ID: u16 = 0x3712;
+    pub const SENDER_SMTP_ADDRESS: u16 = 0x5D01;
+    pub const ATTACHMENT_HIDDEN: u16 = 0x7FFE;
+}
+
+/// `PR_ATTACH_METHOD` value of an attached Outlook message
+const ATTACH_EMBEDDED_MSG: u32 = 5;
+
+/// Header sizes of `__properties_version1.0` streams
+const TOP_LEVEL_HEADER_LEN: usize = 32;
+const EMBEDDED_HEADER_LEN: usize = 24;
+const CHILD_HEADER_LEN: usize = 8;
+
+/// Fixed-size MAPI properties of one storage, keyed by property id
+struct FixedProperties(HashMap<u16, [u8; 8]>);
+
+impl FixedProperties {
+    fn long(&self, id: u16) -> Option<u32> {
+        self.0
+            .get(&id)
+            .map(|v| u32::from_le_bytes([v[0], v[1], v[2], v[3]]))
+    }
+
+    fn boolean(&self, id: u16) -> bool {
+        self.0.get(&id).is_some_and(|v| v[0] != 0)
+    }
+
+    /// FILETIME property as an RFC 3339 timestamp
+    fn time(&self, id: u16) -> Option<String> {
+        let ticks = u64::from_le_bytes(*self.0.get(&id)?);
+        // 100ns intervals since 1601-01-01
+        let secs = (ticks / 10_000_000) as i64 - 11_644_473_600;
+        let nanos = ((ticks % 10_000_000) * 100) as u32;
+        chrono::DateTime::from_timestamp(secs, nanos)
+            .map(|t| t.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+    }
+}
+
+/// Reads MAPI properties out of an Outlook .msg compound file
+struct MsgReader<R> {
+    file: CompoundFile<R>,
+}
+
+impl<R: Read + Seek> MsgReader<R> {
+    fn stream(&mut self, path: &str) -> Option<Vec<u8>> {
+        let mut stream = self.file.open_stream(path).ok()?;
+        let mut data = Vec::new();
+        stream.read_to_end(&mut data).ok()?;
+        Some(data)
+    }
+
+    /// String property, stored as UTF-16 (`001F`) or 8-bit (`001E`)
+    fn string(&mut self, storage: &str, id: u16) -> Option<String> {
+        let text =
+            if let Some(data) = self.stream(&format!("{}/__substg1.0_{:04X}001F", storage, id)) {
+                let units: Vec<u16> = data
+                    .chunks_exact(2)
+                    .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                    .collect();
+                String::from_utf16_lossy(&units)
+            } else {
+                let data = self.stream(&format!("{}/__substg1.0_{:04X}001E", storage, id))?;
+                String::from_utf8_lossy(&data).into_owned()
+            };
+        let text = text.trim_end_matches('\0').trim();
+        (!text.is_empty()).then(|| text.to_string())
+    }
+
+    fn binary(&mut self, storage: &str, id: u16) -> Option<Vec<u8>> {
+        self.stream(&format!("{}/__substg1.0_{:04X}0102", storage, id))
+            .filter(|data| !data.is_empty())
+    }
+
+    fn properties(&mut self, storage: &str, header_len: usize) -> FixedProperties {
+        let data = self
+            .stream(&format!("{}/__properties_version1.0", storage))
+            .unwrap_or_default();
+        FixedProperties(
+            data.get(header_len..)
+                .unwrap_or_default()
+                .chunks_exact(16)
+                .map(|entry| {
+                    let id = u16::from_le_bytes([entry[2], entry[3]]);
+                    let mut value = [0u8; 8];
+                    value.copy_from_slice(&entry[8..16]);
+                    (id, value)
                 })
-                .collect();
-            if !from_str.is_empty() {
-                markdown.push_str(&format!("**From:** {}\n\n", from_str.join(", ")));
+                .collect(),
+        )
+    }
+
+    /// Child storages whose name starts with `prefix`, in name order
+    fn storages(&mut self, storage: &str, prefix: &str) -> Vec<String> {
+        let parent = if storage.is_empty() { "/" } else { storage };
+        let mut storages: Vec<String> = self
+            .file
+            .read_storage(parent)
+            .map(|entries| {
+                entries
+                    .filter(|e| e.is_storage() && e.name().starts_with(prefix))
+                    .map(|e| e.path().to_string_lossy().into_owned())
+                    .collect()
+            })
+            .unwrap_or_default();
+        storages.sort();
+        storages
+    }
+
+    /// Read the message stored at `storage` ("" for the top level)
+    fn message(&mut self, storage: &str, header_len: usize, depth: usize) -> EmailMessage {
+        let props = self.properties(storage, header_len);
+        // Received messages keep their internet headers, which carry the
+        // fields Outlook does not always store as properties
+        let headers = self.string(storage, prop::TRANSPORT_HEADERS);
+        let headers = headers
+            .as_deref()
+            .and_then(|h| parse_eml_headers(h.as_bytes()))
+            .unwrap_or_default();
+
+        let sender_name = self
+            .string(storage, prop::SENDER_NAME)
+            .or_else(|| self.string(storage, prop::SENT_REPRESENTING_NAME));
+        let sender_email = self
+            .string(storage, prop::SENDER_SMTP_ADDRESS)
+            .or_else(|| self.string(storage, prop::SENDER_EMAIL))
+            .or_else(|| self.string(storage, prop::SENT_REPRESENTING_EMAIL))
+            .filter(|e| e.contains('@'));
+        let from: Vec<String> = format_mailbox(sender_name.as_deref(), sender_email.as_deref())
+            .into_iter()
+            .collect();
+
+        let mut message = EmailMessage {
+            from: if from.is_empty() { headers.from } else { from },
+            reply_to: headers.reply_to,
+            subject: self.string(storage, prop::SUBJECT).or(headers.subject),
+            date: props
+                .time(prop::CLIENT_SUBMIT_TIME)
+                .or_else(|| props.time(prop::DELIVERY_TIME))
+                .or(headers.date),
+            message_id: self
+                .string(storage, prop::MESSAGE_ID)
+                .and_then(|id| bare_message_id(&id))
+                .or(headers.message_id),
+            in_reply_to: self
+                .string(storage, prop::IN_REPLY_TO)
+                .and_then(|id| bare_message_id(&id))
+                .or(headers.in_reply_to),
+            text_body: self
+                .string(storage, prop::BODY)
+                .map(|body| body.replace("\r\n", "\n")),
+            html_body: self
+                .binary(storage, prop::HTML_BODY)
+                .map(|html| String::from_utf8_lossy(&html).into_owned())
+                .or_else(|| self.string(storage, prop::HTML_BODY)),
+            ..Default::default()
+        };
+        message.references = self
+            .string(storage, prop::REFERENCES)
+            .map(|refs| {
+                refs.split_whitespace()
+                    .filter_map(bare_message_id)
+                    .collect()
+            })
+            .unwrap_or(headers.references);
+        if message.reply_to.is_empty() {
+            message.reply_to = self
+                .string(storage, prop::REPLY_RECIPIENT_NAMES)
+                .map(|names| split_display_list(&names))
+                .unwrap_or_default();
+        }
+
+        for recipient in self.storages(storage, "__recip_version1.0_") {
+            let props = self.properties(&recipient, CHILD_HEADER_LEN);
+            let name = self.string(&recipient, prop::DISPLAY_NAME);
+            let email = self
+                .string(&recipient, prop::SMTP_ADDRESS)
+                .or_else(|| self.string(&recipient, prop::EMAIL_ADDRESS))
+                .filter(|e| e.contains('@'));
+            let Some(mailbox) = format_mailbox(name.as_deref(), email.as_deref()) else {
+                continue;
+            };
+            match props.long(prop::RECIPIENT_TYPE) {
+                Some(2) => message.cc.push(mailbox),
+                Some(3) => message.bcc.push(mailbox),
+                _ => message.to.push(mailbox),
             }
         }
+        if message.to.is_empty() && message.cc.is_empty() {
+            message.to = self
+                .string(storage, prop::DISPLAY_TO)
+                .map(|to| split_display_list(&to))
+                .unwrap_or(headers.to);
+            message.cc = self
+                .string(storage, prop::DISPLAY_CC)
+                .map(|cc| split_display_list(&cc))
+                .unwrap_or(headers.cc);
+        }
 
-        // To
-        if let Some(to) = message.to() {
-            let to_str: Vec<String> = to
-                .iter()
-                .map(|addr| {
-                    if let Some(name) = addr.name() {
-                        format!("{} <{}>", name, addr.address().unwrap_or(""))
-                    } else {
-                        addr.address().unwrap_or("").to_string()
+        for attachment in self.storages(storage, "__attach_version1.0_") {
+            let props = self.properties(&attachment, CHILD_HEADER_LEN);
+            let name = self
+                .string(&attachment, prop::ATTACH_LONG_FILENAME)
+                .or_else(|| self.string(&attachment, prop::ATTACH_FILENAME))
+                .or_else(|| self.string(&attachment, prop::DISPLAY_NAME));
+            let mime_type = self.string(&attachment, prop::ATTACH_MIME_TAG).or_else(|| {
+                name.as_deref()
+                    .and_then(|n| mime_guess::from_path(n).first())
+                    .map(|m| m.essence_str().to_string())
+            });
+            let content_id = self
+                .string(&attachment, prop::ATTACH_CONTENT_ID)
+                .and_then(|id| bare_message_id(&id));
+            let embedded = props.long(prop::ATTACH_METHOD) == Some(ATTACH_EMBEDDED_MSG);
+            let nested = (embedded && depth < MAX_NESTING_DEPTH).then(|| {
+                let path = format!("{}/__substg1.0_{:04X}000D", attachment, prop::ATTACH_DATA);
+                Box::new(self.message(&path, EMBEDDED_HEADER_LEN, depth + 1))
+            });
+
+            message.attachments.push(EmailAttachment {
+                name: name.or_else(|| nested.as_ref().and_then(|m| m.subject.clone())),
+                mime_type: if embedded {
+                    Some("application/vnd.ms-outlook".to_string())
+                } else {
+                    mime_type
+                },
+                inline: content_id.is_some() && props.boolean(prop::ATTACHMENT_HIDDEN),
+                content_id,
+                data: self
+                    .binary(&attachment, prop::ATTACH_DATA)
+                    .map(Bytes::from)
+                    .unwrap_or_default(),
+                message: nested,
+            });
+        }
+
+        message
+    }
+}
+
+/// Split Outlook's `;`-separated display lists
+fn split_display_list(list: &str) -> Vec<String> {
+    list.split(';')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Parse an Outlook .msg file
+fn parse_msg(bytes: &[u8]) -> Result<EmailMessage, MarkitdownError> {
+    let file = CompoundFile::open(Cursor::new(bytes))
+        .map_err(|e| MarkitdownError::ParseError(format!("Failed to open MSG file: {}", e)))?;
+    let mut reader = MsgReader { file };
+    Ok(reader.message("", TOP_LEVEL_HEADER_LEN, 0))
+}
+
+// ============================================================================
+// Rendering
+// ============================================================================
+
+/// Header summary shown above the body
+fn header_block(message: &EmailMessage) -> ContentBlock {
+    let mut markdown = String::new();
+    let fields = [
+        ("From", message.from.join(", ")),
+        ("To", message.to.join(", ")),
+        ("CC", message.cc.join(", ")),
+        ("BCC", message.bcc.join(", ")),
+        ("Reply-To", message.reply_to.join(", ")),
+        ("Subject", message.subject.clone().unwrap_or_default()),
+        ("Date", message.date.clone().unwrap_or_default()),
+    ];
+    for (label, value) in fields {
+        if !value.is_empty() {
+            markdown.push_str(&format!("**{}:** {}\n\n", label, value));
+        }
+    }
+    markdown.push_str("---");
+    ContentBlock::Markdown(markdown)
+}
+
+/// Prefix the ids of extracted images so attachments don't collide
+fn prefix_image_ids(blocks: &mut [ContentBlock], prefix: &str) {
+    for block in blocks {
+        if let ContentBlock::Image(image) = block {
+            image.id = format!("{}{}", prefix, image.id);
+        }
+    }
+}
+
+/// Render a message: header summary, body, inline images and attachments.
+/// Image ids are prefixed with `id_prefix`.
+pub(crate) fn render_message<'a>(
+    message: &'a EmailMessage,
+    options: &'a ConversionOptions,
+    id_prefix: &'a str,
+) -> BoxFuture<'a, Result<Vec<ContentBlock>, MarkitdownError>> {
+    Box::pin(async move {
+        let mut blocks = vec![header_block(message)];
+
+        // Body - prefer plain text, fallback to HTML
+        let mut shown_in_body = HashSet::new();
+        if let Some(text) = message
+            .text_body
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+        {
+            blocks.extend(text_body_blocks(text, options.quoted_replies));
+        } else if let Some(html) = message.html_body.as_deref() {
+            let mut resources = BundledResources::new(options.image_fetcher.clone());
+            for attachment in &message.attachments {
+                if let Some(id) = &attachment.content_id {
+                    resources.insert(&format!("cid:{}", id), attachment.data.clone());
+                }
+            }
+            let mut html_options = options.clone();
+            html_options.llm_client = None;
+            let document =
+                convert_bundled_html(html.as_bytes(), None, Arc::new(resources), &html_options)
+                    .await?;
+            let mut body: Vec<ContentBlock> =
+                document.pages.into_iter().flat_map(|p| p.content).collect();
+            for block in &body {
+                if let ContentBlock::Image(image) = block {
+                    if let Some(source) = image.source_path.as_deref() {
+                        shown_in_body.insert(resource_key(source));
                     }
-                })
-                .collect();
-            if !to_str.is_empty() {
-                markdown.push_str(&format!("**To:** {}\n\n", to_str.join(", ")));
+                }
             }
+            prefix_image_ids(&mut body, id_prefix);
+            blocks.extend(html_body_blocks(body, options.quoted_replies));
         }
 
-        // CC
-        if let Some(cc) = message.cc() {
-            let cc_str: Vec<String> = cc
-                .iter()
-                .map(|addr| {
-                    if let Some(name) = addr.name() {
-                        format!("{} <{}>", name, addr.address().unwrap_or(""))
-                    } else {
-                        addr.address().unwrap_or("").to_string()
-                    }
-                })
-                .collect();
-            if !cc_str.is_empty() {
-                markdown.push_str(&format!("**CC:** {}\n\n", cc_str.join(", ")));
+        // Inline images the body did not place
+        let mut image_count = 0;
+        for attachment in message.attachments.iter().filter(|a| a.inline) {
+            let cid = attachment
+                .content_id
+                .as_deref()
+                .map(|id| resource_key(&format!("cid:{}", id)));
+            if cid.is_some_and(|cid| shown_in_body.contains(&cid)) || !attachment.is_image() {
+                continue;
+            }
+            if options.extract_images && !attachment.data.is_empty() {
+                image_count += 1;
+                let mut image = ExtractedImage::new(
+                    format!("{}email_image_{}", id_prefix, image_count),
+                    attachment.data.clone(),
+                    attachment.mime_type.clone().unwrap_or_default(),
+                );
+                image.alt_text = attachment.name.clone();
+                blocks.push(ContentBlock::Image(image));
             }
         }
 
-        // Subject
-        if let Some(subject) = message.subject() {
-            markdown.push_str(&format!("**Subject:** {}\n\n", subject));
+        let attachments: Vec<&EmailAttachment> = message
+            .attachments
+            .iter()
+            .filter(|a| !a.inline || !a.is_image())
+            .collect();
+        if attachments.is_empty() {
+            return Ok(blocks);
         }
 
-        // Date
-        if let Some(date) = message.date() {
-            markdown.push_str(&format!("**Date:** {}\n\n", date));
+        blocks.push(ContentBlock::Heading {
+            level: 2,
+            text: "Attachments".to_string(),
+        });
+        blocks.push(ContentBlock::List {
+            ordered: false,
+            items: attachments
+                .iter()
+                .map(|a| {
+                    format!(
+                        "**{}** ({}, {} bytes)",
+                        a.display_name(),
+                        a.mime_type.as_deref().unwrap_or("unknown"),
+                        a.data.len()
+                    )
+                })
+                .collect(),
+        });
+
+        for (index, attachment) in attachments.into_iter().enumerate() {
+            let prefix = format!("{}attachment_{}_", id_prefix, index + 1);
+            let content = if let Some(embedded) = &attachment.message {
+                render_message(embedded, options, &prefix).await?
+            } else if attachment.is_image() {
+                if !options.extract_images || attachment.data.is_empty() {
+                    continue;
+                }
+                let mut image = ExtractedImage::new(
+                    format!("{}image", prefix),
+                    attachment.data.clone(),
+                    attachment.mime_type.clone().unwrap_or_default(),
+                );
+                image.alt_text = attachment.name.clone();
+                vec![ContentBlock::Image(image)]
+            } else if let Some(nested) = &options.nested_converter {
+                let mut nested_options = options.clone();
+                nested_options.llm_client = None;
+                // Attachments that fail to convert stay in the list above
+                match nested
+                    .convert_nested(
+                        attachment.data.clone(),
+                        attachment.name.as_deref(),
+                        attachment.mime_type.as_deref(),
+                        nested_options,
+                    )
+                    .await
+                {
+                    Ok(Some(document)) => {
+                        let mut content: Vec<ContentBlock> =
+                            document.pages.into_iter().flat_map(|p| p.content).collect();
+                        prefix_image_ids(&mut content, &prefix);
+                        content
+                    }
+                    _ => continue,
+                }
+            } else {
+                continue;
+            };
+
+            if !content.is_empty() {
+                blocks.push(ContentBlock::Heading {
+                    level: 3,
+                    text: attachment.display_name().to_string(),
+                });
+                blocks.extend(content);
+            }
         }
 
-        markdown.push_str("---\n\n");
+        Ok(blocks)
+    })
+}
 
-        // Body - prefer plain text, fallback to HTML
-        let body = message
-            .body_text(0)
-            .map(|s| s.to_string())
-            .or_else(|| message.body_html(0).map(|html| html2md::parse_html(&html)));
-
-        if let Some(body_text) = body {
-            markdown.push_str(&body_text);
-            markdown.push_str("\n\n");
-        }
-
-        // List attachments
-        let attachments: Vec<_> = message.attachments().collect();
-        if !attachments.is_empty() {
-            markdown.push_str("---\n\n## Attachments\n\n");
-            for attachment in attachments {
-                let name = attachment.attachment_name().unwrap_or("unnamed");
-                let size = attachment.len();
-                let content_type = attachment
-                    .content_type()
-                    .map(|ct| ct.c_type.as_ref())
-                    .unwrap_or("unknown");
-                markdown.push_str(&format!(
-                    "- **{}** ({}, {} bytes)\n",
-                    name, content_type, size
-                ));
+/// Email document converter
+pub struct EmailConverter;
+
+impl EmailConverter {
+    /// Convert a parsed message to a document
+    async fn convert_email(
+        message: &EmailMessage,
+        options: &ConversionOptions,
+    ) -> Result<Document, MarkitdownError> {
+        let mut document = Document::new();
+        document.title = message.subject.clone();
+
+        let metadata = [
+            ("from", message.from.join(", ")),
+            ("to", message.to.join(", ")),
+            ("cc", message.cc.join(", ")),
+            ("bcc", message.bcc.join(", ")),
+            ("reply_to", message.reply_to.join(", ")),
+            ("subject", message.subject.clone().unwrap_or_default()),
+            ("date", message.date.clone().unwrap_or_default()),
+            ("message_id", message.message_id.clone().unwrap_or_default()),
+            (
+                "in_reply_to",
+                message.in_reply_to.clone().unwrap_or_default(),
+            ),
+            ("references", message.references.join(" ")),
+        ];
+        for (key, value) in metadata {
+            if !value.is_empty() {
+                document.metadata.insert(key.to_string(), value);
             }
         }
+        document.properties.authors = message.from.clone();
+        document.properties.created = message.date.as_deref().and_then(parse_date);
 
-        page.add_content(ContentBlock::Markdown(markdown.trim().to_string()));
+        let attachment_count = message
+            .attachments
+            .iter()
+            .filter(|a| !a.inline || !a.is_image())
+            .count();
+        if attachment_count > 0 {
+            document
+                .metadata
+                .insert("attachment_count".to_string(), attachment_count.to_string());
+        }
+
+        let mut page = Page::new(1);
+        for block in render_message(message, options, "").await? {
+            page.add_content(block);
+        }
         document.add_page(page);
 
+        // If LLM client is provided, get descriptions for all images
+        if let Some(ref llm_client) = options.llm_client {
+            if let Some(path) = options.image_context_path.as_deref() {
+                document.apply_image_context_path(path);
+            }
+            document = document
+                .with_image_descriptions(llm_client.as_ref())
+                .await?;
+        }
+
         Ok(document)
     }
 }
@@ -170,7 +758,13 @@ impl DocumentConverter for EmailConverter {
             }
         }
 
-        Self::convert_email(&bytes)
+        let opts = options.unwrap_or_default();
+        let message = if bytes.starts_with(CFB_SIGNATURE) {
+            parse_msg(&bytes)?
+        } else {
+            parse_eml(&bytes)?
+        };
+        Self::convert_email(&message, &opts).await
     }
 
     fn supported_extensions(&self) -> &[&str] {
@@ -1,20 +1,25 @@
 //! EPUB to Markdown converter.
 //!
 //! Supports conversion of EPUB ebooks to markdown text,
-//! extracting chapters and content.
+//! extracting chapters and content. Relative links and images are resolved
+//! when a base URL is known (`<base href>` or `ConversionOptions::url`).
 //!
 //! Uses rbook (Apache-2.0 licensed) for EPUB parsing.
 
 use async_trait::async_trait;
 use bytes::Bytes;
 use object_store::ObjectStore;
+use quick_xml::events::Event;
 use rbook::prelude::*;
 use rbook::reader::Reader;
 use rbook::Epub;
-use std::io::Cursor;
+use std::io::{Cursor, Read};
 use std::sync::Arc;
+use zip::ZipArchive;
 
 use crate::error::MarkitdownError;
+use crate::links::{base_url, find_base_href, resolve_markdown_links};
+use crate::metadata::parse_date;
 use crate::model::{ContentBlock, ConversionOptions, Document, DocumentConverter, Page};
 
 /// EPUB document converter
@@ -22,7 +27,7 @@ pub struct EpubConverter;
 
 impl EpubConverter {
     /// Convert EPUB content to markdown
-    fn convert_epub(bytes: &[u8]) -> Result<Document, MarkitdownError> {
+    fn convert_epub(bytes: &[u8], url: Option<&str>) -> Result<Document, MarkitdownError> {
         let cursor = Cursor::new(bytes.to_vec());
         let epub = Epub::options()
             .strict(false)
@@ -31,24 +36,15 @@ impl EpubConverter {
 
         let mut document = Document::new();
 
-        // Extract metadata
+        // Extract metadata; the title is rendered as the document heading
         let metadata = epub.metadata();
-        let title = metadata.title().map(|t| t.value().to_string());
+        document.title = metadata.title().map(|t| t.value().to_string());
         let author = metadata.creators().next().map(|c| c.value().to_string());
 
-        // Add metadata as first page
-        if title.is_some() || author.is_some() {
+        // Add the author as first page
+        if let Some(a) = &author {
             let mut meta_page = Page::new(0);
-            let mut meta = String::new();
-
-            if let Some(t) = &title {
-                meta.push_str(&format!("# {}\n\n", t));
-            }
-            if let Some(a) = &author {
-                meta.push_str(&format!("**Author:** {}\n\n", a));
-            }
-
-            meta_page.add_content(ContentBlock::Markdown(meta));
+            meta_page.add_content(ContentBlock::Markdown(format!("**Author:** {}\n\n", a)));
             document.add_page(meta_page);
         }
 
@@ -61,7 +57,10 @@ impl EpubConverter {
                 let content = data.content();
                 // Content is XHTML, convert to markdown
                 let markdown = html2md::parse_html(content);
-                let cleaned = Self::clean_markdown(&markdown);
+                let mut cleaned = Self::clean_markdown(&markdown);
+                if let Some(base) = base_url(url, find_base_href(content).as_deref()) {
+                    cleaned = resolve_markdown_links(&cleaned, &base);
+                }
 
                 if !cleaned.is_empty() {
                     let mut page = Page::new(page_num);
@@ -81,9 +80,119 @@ impl EpubConverter {
             document.add_page(page);
         }
 
+        Self::apply_package_metadata(&mut document, bytes);
         Ok(document)
     }
 
+    /// Fill the document title and properties from the `<metadata>` of the
+    /// package document that `META-INF/container.xml` points to
+    fn apply_package_metadata(document: &mut Document, bytes: &[u8]) {
+        let Ok(mut archive) = ZipArchive::new(Cursor::new(bytes)) else {
+            return;
+        };
+        let mut read_part = |name: &str| {
+            let mut file = archive.by_name(name).ok()?;
+            let mut xml = String::new();
+            file.read_to_string(&mut xml).ok()?;
+            Some(xml)
+        };
+        let Some(package) = read_part("META-INF/container.xml")
+            .and_then(|container| Self::rootfile_path(&container))
+            .and_then(|path| read_part(&path))
+        else {
+            return;
+        };
+
+        for (name, property, value) in Self::package_fields(&package) {
+            let properties = &mut document.properties;
+            match (name.as_str(), property.as_deref()) {
+                ("title", _) => {
+                    document.title.get_or_insert(value);
+                }
+                ("creator", _) => properties.authors.push(value),
+                ("subject", _) => properties.keywords.push(value),
+                ("language", _) => {
+                    properties.language.get_or_insert(value);
+                }
+                ("date", _) if properties.created.is_none() => {
+                    properties.created = parse_date(&value);
+                }
+                ("meta", Some("dcterms:modified")) => properties.modified = parse_date(&value),
+                ("publisher" | "description" | "rights", _) => {
+                    document.metadata.insert(name, value);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Path of the first package document listed in `container.xml`
+    fn rootfile_path(container: &str) -> Option<String> {
+        let mut reader = quick_xml::Reader::from_str(container);
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(e)) | Ok(Event::Empty(e))
+                    if e.local_name().as_ref() == b"rootfile" =>
+                {
+                    return e
+                        .attributes()
+                        .flatten()
+                        .find(|attr| attr.key.as_ref() == b"full-path")
+                        .and_then(|attr| attr.unescape_value().ok().map(|v| v.to_string()));
+                }
+                Ok(Event::Eof) | Err(_) => return None,
+                _ => {}
+            }
+        }
+    }
+
+    /// Local name, `property` attribute and text of each element in the
+    /// package `<metadata>`
+    fn package_fields(package: &str) -> Vec<(String, Option<String>, String)> {
+        let mut reader = quick_xml::Reader::from_str(package);
+        let mut fields = Vec::new();
+        let mut in_metadata = false;
+        let mut field: Option<(String, Option<String>)> = None;
+        let mut text = String::new();
+
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(e)) if e.local_name().as_ref() == b"metadata" => in_metadata = true,
+                Ok(Event::Start(e)) if in_metadata => {
+                    let name = String::from_utf8_lossy(e.local_name().as_ref()).into_owned();
+                    let property = e
+                        .attributes()
+                        .flatten()
+                        .find(|attr| attr.key.as_ref() == b"property")
+                        .and_then(|attr| attr.unescape_value().ok().map(|v| v.to_string()));
+                    field = Some((name, property));
+                    text.clear();
+                }
+                Ok(Event::Text(e)) if field.is_some() => {
+                    if let Ok(decoded) = e.decode() {
+                        text.push_str(&decoded);
+                    }
+                }
+                Ok(Event::GeneralRef(e)) if field.is_some() => {
+                    if let Ok(name) = e.decode() {
+                        text.push_str(&crate::opendocument::entity_text(&name));
+                    }
+                }
+                Ok(Event::End(e)) if e.local_name().as_ref() == b"metadata" => break,
+                Ok(Event::End(_)) => {
+                    if let Some((name, property)) = field.take() {
+                        if !text.trim().is_empty() {
+                            fields.push((name, property, text.trim().to_string()));
+                        }
+                    }
+                }
+                Ok(Event::Eof) | Err(_) => break,
+                _ => {}
+            }
+        }
+        fields
+    }
+
     /// Clean up markdown output
     fn clean_markdown(md: &str) -> String {
         md.lines()
@@ -135,7 +244,8 @@ impl DocumentConverter for EpubConverter {
             }
         }
 
-        Self::convert_epub(&bytes)
+        let url = options.as_ref().and_then(|opts| opts.url.as_deref());
+        Self::convert_epub(&bytes, url)
     }
 
     fn supported_extensions(&self) -> &[&str] {
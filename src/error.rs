@@ -87,6 +87,12 @@ impl From<object_store::Error> for MarkitdownError {
     }
 }
 
+impl From<reqwest::Error> for MarkitdownError {
+    fn from(error: reqwest::Error) -> Self {
+        MarkitdownError::NetworkError(error.to_string())
+    }
+}
+
 impl From<String> for MarkitdownError {
     fn from(error: String) -> Self {
         MarkitdownError::Conversion(error)
@@ -6,10 +6,24 @@ use std::io::Cursor;
 use std::sync::Arc;
 
 use crate::error::MarkitdownError;
-use crate::model::{ContentBlock, ConversionOptions, Document, DocumentConverter, Page};
+use crate::model::{
+    ContentBlock, ConversionOptions, Document, DocumentConverter, Page, Provenance,
+};
 
 pub struct ExcelConverter;
 
+/// A1-style name of a zero-based (row, column) position
+fn cell_name((row, column): (u32, u32)) -> String {
+    let mut letters = Vec::new();
+    let mut n = column + 1;
+    while n > 0 {
+        n -= 1;
+        letters.push(char::from(b'A' + (n % 26) as u8));
+        n /= 26;
+    }
+    letters.iter().rev().collect::<String>() + &(row + 1).to_string()
+}
+
 impl ExcelConverter {
     fn convert_excel_bytes(&self, bytes: &[u8]) -> Result<Document, MarkitdownError> {
         let reader = Cursor::new(bytes);
@@ -24,10 +38,13 @@ impl ExcelConverter {
             let mut page = Page::new((sheet_idx + 1) as u32);
 
             // Add sheet name as heading
-            page.add_content(ContentBlock::Heading {
-                level: 2,
-                text: sheet_name.clone(),
-            });
+            page.add_content_with_provenance(
+                ContentBlock::Heading {
+                    level: 2,
+                    text: sheet_name.clone(),
+                },
+                Provenance::sheet(sheet_name, None),
+            );
 
             if let Ok(range) = workbook.worksheet_range(sheet_name) {
                 let rows: Vec<Vec<String>> = range
@@ -39,10 +56,17 @@ impl ExcelConverter {
                     let headers = rows[0].clone();
                     let data_rows: Vec<Vec<String>> = rows.into_iter().skip(1).collect();
 
-                    page.add_content(ContentBlock::Table {
-                        headers,
-                        rows: data_rows,
-                    });
+                    let cell_range = range
+                        .start()
+                        .zip(range.end())
+                        .map(|(start, end)| format!("{}:{}", cell_name(start), cell_name(end)));
+                    page.add_content_with_provenance(
+                        ContentBlock::Table {
+                            headers,
+                            rows: data_rows,
+                        },
+                        Provenance::sheet(sheet_name, cell_range),
+                    );
                 }
             }
 
@@ -54,6 +78,7 @@ impl ExcelConverter {
             document.add_page(Page::new(1));
         }
 
+        crate::metadata::apply_ooxml_properties(&mut document, bytes);
         Ok(document)
     }
 }
@@ -0,0 +1,333 @@
+//! Pluggable fetching of remote documents and referenced images.
+//!
+//! HTML pages usually link their images instead of embedding them. An
+//! [`ImageFetcher`] set via `ConversionOptions::with_image_fetcher` lets
+//! converters load those images so they can be extracted and described like
+//! embedded ones.
+//!
+//! URL conversion (`MarkItDown::convert_url`) downloads documents through an
+//! [`HttpFetcher`], so the HTTP client can be swapped out (e.g. in tests).
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use object_store::path::Path;
+use object_store::ObjectStore;
+use std::sync::Arc;
+use std::time::Duration;
+use url::Url;
+
+use crate::error::MarkitdownError;
+
+/// Default download size limit for URL conversion (50 MiB)
+pub const DEFAULT_MAX_BYTES: u64 = 50 * 1024 * 1024;
+
+/// Default request timeout for URL conversion
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Loads the bytes of an image referenced by URL or path
+#[async_trait]
+pub trait ImageFetcher: Send + Sync {
+    /// Fetch the image at `url`.
+    ///
+    /// `url` is the image reference after resolution against the document's
+    /// base URL, so it may still be relative when no base is known. Returns
+    /// `Ok(None)` when the image cannot be served by this fetcher. Errors do
+    /// not fail the conversion: the image stays a placeholder and the error
+    /// is listed in the document's `image_fetch_errors` metadata.
+    async fn fetch(&self, url: &str) -> Result<Option<Bytes>, MarkitdownError>;
+}
+
+/// Shared reference to an image fetcher
+pub type SharedImageFetcher = Arc<dyn ImageFetcher>;
+
+/// Fetches images from an [`ObjectStore`].
+///
+/// Relative references are resolved against an optional base directory and
+/// `file:` URLs map to their path. Other absolute URLs are not served.
+pub struct ObjectStoreImageFetcher {
+    store: Arc<dyn ObjectStore>,
+    base_dir: Option<String>,
+}
+
+impl ObjectStoreImageFetcher {
+    pub fn new(store: Arc<dyn ObjectStore>) -> Self {
+        Self {
+            store,
+            base_dir: None,
+        }
+    }
+
+    /// Resolve relative image references against this directory of the store
+    /// (typically the directory holding the converted document)
+    pub fn with_base_dir(mut self, dir: impl Into<String>) -> Self {
+        self.base_dir = Some(dir.into());
+        self
+    }
+
+    /// Map an image reference to a path in the store
+    fn store_path(&self, reference: &str) -> Option<Path> {
+        if let Ok(url) = Url::parse(reference) {
+            return match url.scheme() {
+                "file" => Path::from_url_path(url.path()).ok(),
+                _ => None,
+            };
+        }
+
+        // Use a file URL to normalise "." and ".." segments
+        let dir = self.base_dir.as_deref().unwrap_or("").trim_matches('/');
+        let root = if dir.is_empty() {
+            "file:///".to_string()
+        } else {
+            format!("file:///{}/", dir)
+        };
+        let url = Url::parse(&root).ok()?.join(reference).ok()?;
+        Path::from_url_path(url.path()).ok()
+    }
+}
+
+#[async_trait]
+impl ImageFetcher for ObjectStoreImageFetcher {
+    async fn fetch(&self, url: &str) -> Result<Option<Bytes>, MarkitdownError> {
+        let Some(path) = self.store_path(url) else {
+            return Ok(None);
+        };
+        match self.store.get(&path).await {
+            Ok(result) => Ok(Some(result.bytes().await?)),
+            Err(object_store::Error::NotFound { .. }) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Limits applied when fetching a document over HTTP
+#[derive(Debug, Clone)]
+pub struct FetchLimits {
+    /// Maximum response body size in bytes
+    pub max_bytes: Option<u64>,
+    /// Timeout for the whole request, including reading the body
+    pub timeout: Option<Duration>,
+}
+
+impl Default for FetchLimits {
+    fn default() -> Self {
+        Self {
+            max_bytes: Some(DEFAULT_MAX_BYTES),
+            timeout: Some(DEFAULT_TIMEOUT),
+        }
+    }
+}
+
+impl FetchLimits {
+    /// No size or time limit
+    pub fn unlimited() -> Self {
+        Self {
+            max_bytes: None,
+            timeout: None,
+        }
+    }
+
+    pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Fail if a body of `len` bytes exceeds the size limit
+    pub fn check_size(&self, len: u64, url: &str) -> Result<(), MarkitdownError> {
+        match self.max_bytes {
+            Some(max) if len > max => Err(MarkitdownError::NetworkError(format!(
+                "Response from {} exceeds the size limit of {} bytes",
+                url, max
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// An HTTP response as seen by the converters
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    /// URL after following redirects
+    pub final_url: String,
+    /// HTTP status code
+    pub status: u16,
+    /// Value of the `Content-Type` header
+    pub content_type: Option<String>,
+    /// Value of the `Content-Disposition` header
+    pub content_disposition: Option<String>,
+    /// Response body
+    pub body: Bytes,
+}
+
+impl HttpResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// MIME type from `Content-Type`, without parameters
+    pub fn mime_type(&self) -> Option<String> {
+        self.content_type
+            .as_deref()
+            .and_then(|ct| ct.split(';').next())
+            .map(|ct| ct.trim().to_lowercase())
+            .filter(|ct| !ct.is_empty())
+    }
+
+    /// File name from `Content-Disposition`, if any
+    pub fn file_name(&self) -> Option<String> {
+        self.content_disposition
+            .as_deref()
+            .and_then(content_disposition_filename)
+    }
+}
+
+/// Performs HTTP GET requests for URL conversion
+#[async_trait]
+pub trait HttpFetcher: Send + Sync {
+    /// Fetch `url`, following redirects. Implementations should stop reading
+    /// once the body exceeds `limits.max_bytes`.
+    async fn get(&self, url: &str, limits: &FetchLimits) -> Result<HttpResponse, MarkitdownError>;
+}
+
+/// Shared reference to an HTTP fetcher
+pub type SharedHttpFetcher = Arc<dyn HttpFetcher>;
+
+/// Default HTTP fetcher backed by `reqwest`
+pub struct ReqwestFetcher {
+    client: reqwest::Client,
+}
+
+impl ReqwestFetcher {
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .user_agent(concat!("markitdown-rs/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .unwrap_or_default();
+        Self { client }
+    }
+
+    /// Use a preconfigured client (proxies, certificates, headers)
+    pub fn with_client(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Default for ReqwestFetcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl HttpFetcher for ReqwestFetcher {
+    async fn get(&self, url: &str, limits: &FetchLimits) -> Result<HttpResponse, MarkitdownError> {
+        let mut request = self.client.get(url);
+        if let Some(timeout) = limits.timeout {
+            request = request.timeout(timeout);
+        }
+
+        let mut response = request.send().await?;
+        if let Some(len) = response.content_length() {
+            limits.check_size(len, url)?;
+        }
+
+        let header = |name: reqwest::header::HeaderName| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.to_string())
+        };
+        let content_type = header(reqwest::header::CONTENT_TYPE);
+        let content_disposition = header(reqwest::header::CONTENT_DISPOSITION);
+        let final_url = response.url().to_string();
+        let status = response.status().as_u16();
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            body.extend_from_slice(&chunk);
+            limits.check_size(body.len() as u64, url)?;
+        }
+
+        Ok(HttpResponse {
+            final_url,
+            status,
+            content_type,
+            content_disposition,
+            body: Bytes::from(body),
+        })
+    }
+}
+
+/// Loads `http(s)` images. Missing images (404, 410) stay placeholders; other
+/// HTTP statuses and network failures are returned as errors.
+#[async_trait]
+impl ImageFetcher for ReqwestFetcher {
+    async fn fetch(&self, url: &str) -> Result<Option<Bytes>, MarkitdownError> {
+        if !is_http_url(url) {
+            return Ok(None);
+        }
+        let response = self.get(url, &FetchLimits::default()).await?;
+        match response.status {
+            _ if response.is_success() => Ok(Some(response.body)),
+            404 | 410 => Ok(None),
+            status => Err(MarkitdownError::NetworkError(format!(
+                "HTTP {} fetching {}",
+                status, url
+            ))),
+        }
+    }
+}
+
+/// Whether `input` is an `http://` or `https://` URL
+pub fn is_http_url(input: &str) -> bool {
+    Url::parse(input.trim()).is_ok_and(|url| matches!(url.scheme(), "http" | "https"))
+}
+
+/// Extract the file name from a `Content-Disposition` header value.
+/// RFC 5987 `filename*` takes precedence over `filename`.
+pub fn content_disposition_filename(header: &str) -> Option<String> {
+    let mut plain = None;
+    for param in header.split(';').skip(1) {
+        let Some((key, value)) = param.split_once('=') else {
+            continue;
+        };
+        let value = value.trim().trim_matches('"');
+        match key.trim().to_ascii_lowercase().as_str() {
+            "filename*" => {
+                // charset'language'percent-encoded-name
+                let encoded = value.splitn(3, '\'').nth(2).unwrap_or(value);
+                let name = percent_decode(encoded);
+                if !name.is_empty() {
+                    return Some(name);
+                }
+            }
+            "filename" if !value.is_empty() => plain = Some(value.to_string()),
+            _ => {}
+        }
+    }
+    plain
+}
+
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = |b: u8| (b as char).to_digit(16);
+            if let (Some(hi), Some(lo)) = (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+                out.push((hi * 16 + lo) as u8);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
@@ -1,14 +1,77 @@
+//! HTML to Markdown converter.
+//!
+//! Walks the parsed DOM and produces structured content blocks (headings,
+//! paragraphs, lists, tables, code, quotes and images). Page metadata such as
+//! the description, OpenGraph properties and canonical URL is recorded in
+//! `Document::metadata`. An optional readability-style mode keeps only the
+//! main content of a page.
+//!
+//! Relative links and images are resolved against `<base href>` or
+//! `ConversionOptions::url`. Linked images can be loaded through the
+//! configured `ImageFetcher`.
+//!
+//! Well-known sites (Wikipedia, YouTube, search result pages) are recognised
+//! by URL or content and handled by the specializations in [`crate::web`].
+
 use async_trait::async_trait;
 use bytes::Bytes;
 use html2md::parse_html;
 use object_store::ObjectStore;
 use regex::Regex;
-use std::sync::Arc;
+use scraper::{ElementRef, Html, Node, Selector};
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock};
+use url::Url;
 
 use crate::error::MarkitdownError;
+use crate::fetch::ImageFetcher;
+use crate::links::{base_url, resolve_url};
 use crate::model::{
-    ContentBlock, ConversionOptions, Document, DocumentConverter, ExtractedImage, Page,
+    Alignment, ContentBlock, ConversionOptions, Definition, Document, DocumentConverter,
+    ExtractedImage, Inline, ListItem, Page, Table, TableCell, MAX_SPAN,
 };
+use crate::web::{convert_web_page, detect_web_source};
+
+/// Elements whose content is never converted
+const SKIPPED_TAGS: &[&str] = &[
+    "script", "style", "noscript", "template", "head", "iframe", "object", "embed", "svg",
+    "canvas", "button", "input", "select", "textarea", "option",
+];
+
+/// Elements that start a new block when encountered in running text
+const BLOCK_TAGS: &[&str] = &[
+    "address", "article", "aside", "body", "center", "details", "dialog", "div", "fieldset",
+    "footer", "form", "header", "hgroup", "html", "main", "nav", "section", "summary",
+];
+
+/// Class/id names that usually mark page chrome rather than content
+static NEGATIVE_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b(nav|navbar|navigation|menu|footer|cookies?|consent|gdpr|banner|sidebar|breadcrumbs?|share|sharing|social|advert|advertisement|ads?|sponsor|promo|related|popup|modal|newsletter|subscribe|comments?|skip-link|toolbar|masthead|dropdown|portlet|interlanguage)\b",
+    )
+    .unwrap()
+});
+
+/// Class/id names that usually mark the article body
+static POSITIVE_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(article|content|main|post|entry|body|story|text|blog)\b").unwrap()
+});
+
+static WHITESPACE_PATTERN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\s+").unwrap());
+
+static TITLE_SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("title").unwrap());
+static META_SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("meta").unwrap());
+static CANONICAL_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("link[rel~=canonical]").unwrap());
+static BASE_SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("base[href]").unwrap());
+static BODY_SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("body").unwrap());
+static H1_SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("h1").unwrap());
+static MAIN_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("main, [role=main]").unwrap());
+static ARTICLE_SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("article").unwrap());
+static PARAGRAPH_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("p, pre, td, blockquote").unwrap());
+static LINK_SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("a").unwrap());
 
 pub struct HtmlConverter;
 
@@ -16,28 +79,53 @@ impl HtmlConverter {
     fn convert_html_bytes(
         &self,
         bytes: &[u8],
-        extract_images: bool,
+        options: &ConversionOptions,
     ) -> Result<Document, MarkitdownError> {
         let content = String::from_utf8(bytes.to_vec())
             .map_err(|e| MarkitdownError::ParseError(format!("Invalid UTF-8 encoding: {}", e)))?;
 
-        let title = extract_title(&content);
+        let html = Html::parse_document(&content);
+
         let mut document = Document::new();
-        document.title = title;
+        document.metadata = extract_metadata(&html);
+        document.title = extract_title(&html, &document.metadata);
+        crate::metadata::fill_from_metadata(&mut document.properties, &document.metadata);
 
-        let mut page = Page::new(1);
+        let root = if options.main_content_only {
+            find_main_content(&html)
+        } else {
+            html.select(&BODY_SELECTOR).next()
+        }
+        .unwrap_or_else(|| html.root_element());
 
-        // Extract images if requested
-        if extract_images {
-            let images = extract_images_from_html(&content);
-            for img in images {
-                page.add_content(ContentBlock::Image(img));
+        let base_href = html
+            .select(&BASE_SELECTOR)
+            .next()
+            .and_then(|b| b.value().attr("href"));
+        let base = base_url(options.url.as_deref(), base_href);
+
+        if let Some(source) = detect_web_source(&html, base.as_ref(), &document.metadata) {
+            if convert_web_page(source, &html, options, base.clone(), &mut document) {
+                return Ok(document);
             }
         }
 
-        // Convert HTML to markdown
-        let markdown = parse_html(&content);
-        page.add_content(ContentBlock::Markdown(markdown));
+        let mut walker = HtmlWalker::new(options.extract_images, options.main_content_only, base);
+        walker.walk_element(root);
+        let blocks = walker.finish();
+
+        let mut page = Page::new(1);
+        if blocks.is_empty() && !content.trim().is_empty() {
+            // Nothing structured was found (e.g. a fragment without block
+            // elements); fall back to a plain conversion
+            let markdown = parse_html(&content);
+            if !markdown.trim().is_empty() {
+                page.add_content(ContentBlock::Markdown(markdown));
+            }
+        }
+        for block in blocks {
+            page.add_content(block);
+        }
 
         document.add_page(page);
         Ok(document)
@@ -63,25 +151,9 @@ impl DocumentConverter for HtmlConverter {
             }
         }
 
-        let extract_images = options.as_ref().map(|o| o.extract_images).unwrap_or(true);
-
         let result = store.get(path).await?;
         let bytes = result.bytes().await?;
-        let mut document = self.convert_html_bytes(&bytes, extract_images)?;
-
-        // If LLM client is provided, get descriptions for all images
-        if let Some(ref opts) = options {
-            if let Some(ref llm_client) = opts.llm_client {
-                if let Some(path) = opts.image_context_path.as_deref() {
-                    document.apply_image_context_path(path);
-                }
-                document = document
-                    .with_image_descriptions(llm_client.as_ref())
-                    .await?;
-            }
-        }
-
-        Ok(document)
+        self.convert_bytes(bytes, options).await
     }
 
     async fn convert_bytes(
@@ -100,19 +172,21 @@ impl DocumentConverter for HtmlConverter {
             }
         }
 
-        let extract_images = options.as_ref().map(|o| o.extract_images).unwrap_or(true);
-        let mut document = self.convert_html_bytes(&bytes, extract_images)?;
+        let opts = options.unwrap_or_default();
+        let mut document = self.convert_html_bytes(&bytes, &opts)?;
+
+        if let (true, Some(fetcher)) = (opts.extract_images, opts.image_fetcher.as_ref()) {
+            fetch_linked_images(&mut document, fetcher.as_ref()).await?;
+        }
 
         // If LLM client is provided, get descriptions for all images
-        if let Some(ref opts) = options {
-            if let Some(ref llm_client) = opts.llm_client {
-                if let Some(path) = opts.image_context_path.as_deref() {
-                    document.apply_image_context_path(path);
-                }
-                document = document
-                    .with_image_descriptions(llm_client.as_ref())
-                    .await?;
+        if let Some(ref llm_client) = opts.llm_client {
+            if let Some(path) = opts.image_context_path.as_deref() {
+                document.apply_image_context_path(path);
             }
+            document = document
+                .with_image_descriptions(llm_client.as_ref())
+                .await?;
         }
 
         Ok(document)
@@ -123,55 +197,1270 @@ impl DocumentConverter for HtmlConverter {
     }
 }
 
-fn extract_title(html: &str) -> Option<String> {
-    let re = Regex::new(r"(?i)<title(?:\s[^>]*)?>(.*?)</title>").ok()?;
-    if let Some(captures) = re.captures(html) {
-        return Some(captures[1].trim().to_string());
+/// Load the data of placeholder images (linked rather than embedded) through
+/// the image fetcher. Images the fetcher cannot serve, or that do not look like
+/// images, stay placeholders; fetch errors are listed in the
+/// `image_fetch_errors` metadata.
+pub(crate) async fn fetch_linked_images(
+    document: &mut Document,
+    fetcher: &dyn ImageFetcher,
+) -> Result<(), MarkitdownError> {
+    let mut errors = Vec::new();
+    for page in &mut document.pages {
+        for block in &mut page.content {
+            let ContentBlock::Image(image) = block else {
+                continue;
+            };
+            if !image.data.is_empty() {
+                continue;
+            }
+            let Some(source) = image.source_path.clone() else {
+                continue;
+            };
+            let data = match fetcher.fetch(&source).await {
+                Ok(Some(data)) => data,
+                Ok(None) => continue,
+                Err(e) => {
+                    errors.push(format!("{}: {}", source, e));
+                    continue;
+                }
+            };
+
+            let mime_type = infer::get(&data)
+                .map(|kind| kind.mime_type().to_string())
+                .or_else(|| {
+                    let path = source.split(['?', '#']).next().unwrap_or(&source);
+                    mime_guess::from_path(path)
+                        .first()
+                        .map(|m| m.essence_str().to_string())
+                });
+            if let Some(mime_type) = mime_type.filter(|m| m.starts_with("image/")) {
+                image.data = data;
+                image.mime_type = mime_type;
+            }
+        }
+    }
+    if !errors.is_empty() {
+        document
+            .metadata
+            .insert("image_fetch_errors".to_string(), errors.join("\n"));
     }
-    None
+    Ok(())
+}
+
+/// Extract the page title, falling back to OpenGraph and the first `<h1>`
+pub(crate) fn extract_title(html: &Html, metadata: &HashMap<String, String>) -> Option<String> {
+    html.select(&TITLE_SELECTOR)
+        .next()
+        .map(|t| collapse_whitespace(&t.text().collect::<String>()))
+        .filter(|t| !t.is_empty())
+        .or_else(|| metadata.get("og:title").cloned())
+        .or_else(|| {
+            html.select(&H1_SELECTOR)
+                .next()
+                .map(|h| collapse_whitespace(&h.text().collect::<String>()))
+                .filter(|t| !t.is_empty())
+        })
 }
 
-fn extract_images_from_html(html: &str) -> Vec<ExtractedImage> {
-    let mut images = Vec::new();
+/// Collect `<meta>` description/author/keywords/generator, OpenGraph and article
+/// properties, the canonical URL and the document language
+fn extract_metadata(html: &Html) -> HashMap<String, String> {
+    let mut metadata = HashMap::new();
+
+    for meta in html.select(&META_SELECTOR) {
+        let element = meta.value();
+        let Some(content) = element.attr("content").map(collapse_whitespace) else {
+            continue;
+        };
+        if content.is_empty() {
+            continue;
+        }
+
+        if let Some(name) = element.attr("name") {
+            let name = name.to_lowercase();
+            if matches!(
+                name.as_str(),
+                "description" | "author" | "keywords" | "generator"
+            ) {
+                metadata.entry(name).or_insert(content.clone());
+            }
+        }
+        if let Some(property) = element.attr("property") {
+            let property = property.to_lowercase();
+            if property.starts_with("og:") || property.starts_with("article:") {
+                metadata.entry(property).or_insert(content);
+            }
+        }
+    }
+
+    if let Some(href) = html
+        .select(&CANONICAL_SELECTOR)
+        .next()
+        .and_then(|link| link.value().attr("href"))
+    {
+        metadata.insert("canonical_url".to_string(), href.trim().to_string());
+    }
+
+    if let Some(lang) = html.root_element().value().attr("lang") {
+        if !lang.trim().is_empty() {
+            metadata.insert("language".to_string(), lang.trim().to_string());
+        }
+    }
+
+    if !metadata.contains_key("description") {
+        if let Some(description) = metadata.get("og:description").cloned() {
+            metadata.insert("description".to_string(), description);
+        }
+    }
+
+    metadata
+}
+
+/// Pick the element holding the main content of the page.
+///
+/// A single `<article>` or an explicit `<main>` wins. Otherwise paragraphs
+/// are scored by length and comma count and their score is propagated to the
+/// parent and grandparent, readability-style; the best candidate adjusted for
+/// link density is returned.
+fn find_main_content(html: &Html) -> Option<ElementRef<'_>> {
+    let articles: Vec<ElementRef> = html.select(&ARTICLE_SELECTOR).collect();
+    if articles.len() == 1 && text_length(articles[0]) > 200 {
+        return Some(articles[0]);
+    }
+    if let Some(main) = html.select(&MAIN_SELECTOR).next() {
+        if text_length(main) > 200 {
+            return Some(main);
+        }
+    }
+
+    let mut scores: HashMap<_, (ElementRef, f64)> = HashMap::new();
+    for paragraph in html.select(&PARAGRAPH_SELECTOR) {
+        if is_boilerplate(paragraph)
+            || paragraph
+                .ancestors()
+                .any(|a| ElementRef::wrap(a).is_some_and(is_boilerplate))
+        {
+            continue;
+        }
+
+        let text = collapse_whitespace(&paragraph.text().collect::<String>());
+        if text.chars().count() < 25 {
+            continue;
+        }
+        let score = 1.0 + text.matches(',').count() as f64 + (text.len() as f64 / 100.0).min(3.0);
+
+        let mut ancestors = paragraph.ancestors().filter_map(ElementRef::wrap);
+        if let Some(parent) = ancestors.next() {
+            scores
+                .entry(parent.id())
+                .or_insert_with(|| (parent, initial_score(parent)))
+                .1 += score;
+        }
+        if let Some(grandparent) = ancestors.next() {
+            scores
+                .entry(grandparent.id())
+                .or_insert_with(|| (grandparent, initial_score(grandparent)))
+                .1 += score / 2.0;
+        }
+    }
+
+    scores
+        .into_values()
+        .map(|(element, score)| (element, score * (1.0 - link_density(element))))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(element, _)| element)
+        .or_else(|| html.select(&BODY_SELECTOR).next())
+}
+
+/// Base score of a content candidate, from its tag and class/id names
+fn initial_score(element: ElementRef) -> f64 {
+    let mut score: f64 = match element.value().name() {
+        "article" | "main" => 10.0,
+        "div" | "section" => 5.0,
+        "pre" | "td" | "blockquote" => 3.0,
+        "form" | "ol" | "ul" | "dl" | "li" => -3.0,
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "th" => -5.0,
+        _ => 0.0,
+    };
+
+    let names = format!(
+        "{} {}",
+        element.value().attr("class").unwrap_or(""),
+        element.value().attr("id").unwrap_or("")
+    );
+    if NEGATIVE_PATTERN.is_match(&names) {
+        score -= 25.0;
+    }
+    if POSITIVE_PATTERN.is_match(&names) {
+        score += 25.0;
+    }
+    score
+}
+
+/// Share of an element's text that sits inside links
+fn link_density(element: ElementRef) -> f64 {
+    let total = text_length(element);
+    if total == 0 {
+        return 0.0;
+    }
+    let linked: usize = element.select(&LINK_SELECTOR).map(text_length).sum();
+    (linked as f64 / total as f64).min(1.0)
+}
+
+fn text_length(element: ElementRef) -> usize {
+    element
+        .text()
+        .map(|t| t.split_whitespace().map(|w| w.len() + 1).sum::<usize>())
+        .sum()
+}
+
+/// Whether an element is page chrome (navigation, banners, footers, hidden
+/// content) that main content extraction should drop
+fn is_boilerplate(element: ElementRef) -> bool {
+    let el = element.value();
+    match el.name() {
+        "html" | "body" | "main" | "article" => return false,
+        "nav" | "aside" | "footer" => return true,
+        // Article headers usually carry the title, page headers do not
+        "header" => return element.select(&H1_SELECTOR).next().is_none(),
+        _ => {}
+    }
+
+    if el.attr("hidden").is_some() || el.attr("aria-hidden") == Some("true") {
+        return true;
+    }
+    if let Some(role) = el.attr("role") {
+        if matches!(
+            role,
+            "navigation" | "banner" | "contentinfo" | "complementary" | "search" | "dialog"
+        ) {
+            return true;
+        }
+    }
+    if let Some(style) = el.attr("style") {
+        let style = style.replace(' ', "").to_lowercase();
+        if style.contains("display:none") || style.contains("visibility:hidden") {
+            return true;
+        }
+    }
+
+    let names = format!(
+        "{} {}",
+        el.attr("class").unwrap_or(""),
+        el.attr("id").unwrap_or("")
+    );
+    NEGATIVE_PATTERN.is_match(&names) && !POSITIVE_PATTERN.is_match(&names)
+}
+
+/// Collapse runs of whitespace into single spaces and trim
+pub(crate) fn collapse_whitespace(text: &str) -> String {
+    WHITESPACE_PATTERN.replace_all(text, " ").trim().to_string()
+}
+
+/// Whether a list has items with nested lists or task checkboxes
+fn is_nested_list(list: ElementRef) -> bool {
+    list.children()
+        .filter_map(ElementRef::wrap)
+        .filter(|item| item.value().name() == "li")
+        .flat_map(|item| item.children().filter_map(ElementRef::wrap))
+        .any(|child| matches!(child.value().name(), "ul" | "ol") || is_checkbox(child))
+}
+
+fn is_checkbox(element: ElementRef) -> bool {
+    element.value().name() == "input"
+        && element
+            .value()
+            .attr("type")
+            .is_some_and(|t| t.eq_ignore_ascii_case("checkbox"))
+}
+
+/// Collapse whitespace in spans as a browser would: runs become one space,
+/// and spaces at the ends and around line breaks are dropped
+fn normalize_spans(spans: Vec<Inline>) -> Vec<Inline> {
+    let mut spans = collapse_spans(spans);
+    trim_spans_start(&mut spans);
+    trim_spans_end(&mut spans);
+    spans
+}
+
+fn collapse_spans(spans: Vec<Inline>) -> Vec<Inline> {
+    let mut out: Vec<Inline> = Vec::new();
+    for span in spans {
+        let mut span = match span {
+            Inline::Text(text) => {
+                Inline::Text(WHITESPACE_PATTERN.replace_all(&text, " ").into_owned())
+            }
+            Inline::Emphasis(inner) => Inline::Emphasis(collapse_spans(inner)),
+            Inline::Strong(inner) => Inline::Strong(collapse_spans(inner)),
+            Inline::Strikethrough(inner) => Inline::Strikethrough(collapse_spans(inner)),
+            Inline::Link {
+                content,
+                url,
+                title,
+            } => Inline::Link {
+                content: collapse_spans(content),
+                url,
+                title,
+            },
+            other => other,
+        };
+        // Formatting around nothing but whitespace is dropped
+        if let Inline::Emphasis(inner) | Inline::Strong(inner) | Inline::Strikethrough(inner) =
+            &span
+        {
+            if Inline::plain_text(inner).trim().is_empty() {
+                span = Inline::Text(Inline::plain_text(inner));
+            }
+        }
+
+        let after_space = match out.last() {
+            None | Some(Inline::LineBreak) => true,
+            Some(Inline::Text(text)) => text.ends_with(' '),
+            Some(_) => false,
+        };
+        if after_space && !matches!(span, Inline::Text(_) | Inline::LineBreak) {
+            let mut trimmed = vec![span];
+            trim_spans_start(&mut trimmed);
+            let Some(trimmed) = trimmed.pop() else {
+                continue;
+            };
+            span = trimmed;
+        }
+        match &mut span {
+            Inline::Text(text) => {
+                if after_space {
+                    *text = text.trim_start_matches(' ').to_string();
+                }
+                if text.is_empty() {
+                    continue;
+                }
+                if let Some(Inline::Text(last)) = out.last_mut() {
+                    last.push_str(text);
+                    continue;
+                }
+            }
+            Inline::LineBreak => trim_spans_end(&mut out),
+            _ => {}
+        }
+        out.push(span);
+    }
+    out
+}
+
+/// Drop leading whitespace and line breaks
+fn trim_spans_start(spans: &mut Vec<Inline>) {
+    match spans.first_mut() {
+        Some(Inline::Text(text)) => {
+            *text = text.trim_start().to_string();
+            if text.is_empty() {
+                spans.remove(0);
+                trim_spans_start(spans);
+            }
+        }
+        Some(Inline::LineBreak) => {
+            spans.remove(0);
+            trim_spans_start(spans);
+        }
+        Some(
+            Inline::Emphasis(inner)
+            | Inline::Strong(inner)
+            | Inline::Strikethrough(inner)
+            | Inline::Link { content: inner, .. },
+        ) => trim_spans_start(inner),
+        _ => {}
+    }
+}
+
+/// Drop trailing whitespace and line breaks
+fn trim_spans_end(spans: &mut Vec<Inline>) {
+    match spans.last_mut() {
+        Some(Inline::Text(text)) => {
+            *text = text.trim_end().to_string();
+            if text.is_empty() {
+                spans.pop();
+                trim_spans_end(spans);
+            }
+        }
+        Some(Inline::LineBreak) => {
+            spans.pop();
+            trim_spans_end(spans);
+        }
+        Some(
+            Inline::Emphasis(inner)
+            | Inline::Strong(inner)
+            | Inline::Strikethrough(inner)
+            | Inline::Link { content: inner, .. },
+        ) => trim_spans_end(inner),
+        _ => {}
+    }
+}
+
+/// Language hint from a `language-x` / `lang-x` class
+fn code_language(element: ElementRef) -> Option<String> {
+    element.value().classes().find_map(|class| {
+        class
+            .strip_prefix("language-")
+            .or_else(|| class.strip_prefix("lang-"))
+            .filter(|lang| !lang.is_empty())
+            .map(|lang| lang.to_string())
+    })
+}
+
+/// Converts a DOM subtree into content blocks
+pub(crate) struct HtmlWalker {
+    blocks: Vec<ContentBlock>,
+    /// Inline text of the paragraph currently being built
+    paragraph: String,
+    /// Images found in running text, emitted after the enclosing block
+    pending_images: Vec<ExtractedImage>,
+    extract_images: bool,
+    main_content_only: bool,
+    /// Base URL for resolving relative links and images
+    base: Option<Url>,
+    /// Additional elements to drop (site-specific chrome)
+    skip: Option<Selector>,
+    image_count: usize,
+}
+
+impl HtmlWalker {
+    pub(crate) fn new(extract_images: bool, main_content_only: bool, base: Option<Url>) -> Self {
+        Self {
+            blocks: Vec::new(),
+            paragraph: String::new(),
+            pending_images: Vec::new(),
+            extract_images,
+            main_content_only,
+            base,
+            skip: None,
+            image_count: 0,
+        }
+    }
+
+    /// Drop elements matching `selector` in addition to the defaults
+    pub(crate) fn with_skip(mut self, selector: Selector) -> Self {
+        self.skip = Some(selector);
+        self
+    }
+
+    /// Continue image numbering after images produced by another walker
+    pub(crate) fn with_image_index(mut self, index: usize) -> Self {
+        self.image_count = index;
+        self
+    }
+
+    pub(crate) fn image_count(&self) -> usize {
+        self.image_count
+    }
+
+    pub(crate) fn finish(mut self) -> Vec<ContentBlock> {
+        self.flush();
+        self.blocks
+    }
+
+    /// Take the blocks produced so far, keeping the walker usable
+    pub(crate) fn take_blocks(&mut self) -> Vec<ContentBlock> {
+        self.flush();
+        std::mem::take(&mut self.blocks)
+    }
+
+    /// Emit the pending paragraph and any images collected from it
+    fn flush(&mut self) {
+        let text = self
+            .paragraph
+            .lines()
+            .map(collapse_whitespace)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.paragraph.clear();
+        if !text.is_empty() {
+            self.blocks.push(ContentBlock::Text(text));
+        }
+        for image in self.pending_images.drain(..) {
+            self.blocks.push(ContentBlock::Image(image));
+        }
+    }
+
+    fn push_block(&mut self, block: ContentBlock) {
+        self.flush();
+        self.blocks.push(block);
+    }
+
+    fn is_skipped(&self, element: ElementRef) -> bool {
+        SKIPPED_TAGS.contains(&element.value().name())
+            || (self.main_content_only && is_boilerplate(element))
+            || self.skip.as_ref().is_some_and(|s| s.matches(&element))
+    }
+
+    fn walk_children(&mut self, element: ElementRef) {
+        for child in element.children() {
+            match child.value() {
+                Node::Text(text) => self.paragraph.push_str(&text.replace('\n', " ")),
+                Node::Element(_) => {
+                    if let Some(child) = ElementRef::wrap(child) {
+                        self.walk_element(child);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn walk_element(&mut self, element: ElementRef) {
+        if self.is_skipped(element) {
+            return;
+        }
+
+        let name = element.value().name();
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.flush();
+                let level = name[1..].parse().unwrap_or(1);
+                let text = collapse_whitespace(&self.inline_text(element));
+                if !text.is_empty() {
+                    self.blocks.push(ContentBlock::Heading { level, text });
+                }
+                self.flush();
+            }
+            "p" => {
+                self.flush();
+                let spans = normalize_spans(self.inline_spans(element));
+                if !spans.is_empty() {
+                    self.blocks.push(ContentBlock::Paragraph(spans));
+                }
+                // Images collected from the paragraph follow it
+                self.flush();
+            }
+            "ul" | "ol" if is_nested_list(element) => {
+                self.flush();
+                let items = self.nested_items(element);
+                if !items.is_empty() {
+                    self.blocks.push(ContentBlock::NestedList {
+                        ordered: name == "ol",
+                        items,
+                    });
+                }
+                self.flush();
+            }
+            "ul" | "ol" => {
+                self.flush();
+                let items = self.list_items(element, 0);
+                if !items.is_empty() {
+                    self.blocks.push(ContentBlock::List {
+                        ordered: name == "ol",
+                        items,
+                    });
+                }
+                self.flush();
+            }
+            "table" => {
+                self.flush();
+                if let Some(table) = self.table(element) {
+                    self.blocks.push(table);
+                } else {
+                    // Layout table: treat the cells as ordinary containers
+                    self.walk_children(element);
+                }
+                self.flush();
+            }
+            "pre" => {
+                let language = element
+                    .children()
+                    .filter_map(ElementRef::wrap)
+                    .find(|c| c.value().name() == "code")
+                    .and_then(code_language)
+                    .or_else(|| code_language(element));
+                let code = element.text().collect::<String>();
+                let code = code.trim_start_matches('\n').trim_end().to_string();
+                if !code.is_empty() {
+                    self.push_block(ContentBlock::Code { language, code });
+                }
+            }
+            "blockquote" => {
+                self.flush();
+                let mut inner = HtmlWalker::new(
+                    self.extract_images,
+                    self.main_content_only,
+                    self.base.clone(),
+                );
+                inner.skip = self.skip.clone();
+                inner.image_count = self.image_count;
+                inner.walk_children(element);
+                self.image_count = inner.image_count;
 
-    // Match img tags and extract src and alt attributes
-    let img_re = Regex::new(r#"<img[^>]*\s+src=["']([^"']+)["'][^>]*>"#).ok();
-    let alt_re = Regex::new(r#"alt=["']([^"']*)["']"#).ok();
+                let mut quoted = Vec::new();
+                let mut images = Vec::new();
+                for block in inner.finish() {
+                    match block {
+                        ContentBlock::Image(img) => images.push(ContentBlock::Image(img)),
+                        other => quoted.push(other.to_markdown().trim_end().to_string()),
+                    }
+                }
+                if !quoted.is_empty() {
+                    self.blocks.push(ContentBlock::Quote(quoted.join("\n\n")));
+                }
+                self.blocks.extend(images);
+            }
+            "figure" => self.figure(element),
+            "img" => {
+                self.flush();
+                self.image(element, None);
+                self.flush();
+            }
+            "hr" => self.push_block(ContentBlock::Rule),
+            "br" => self.paragraph.push('\n'),
+            "dl" => self.definition_list(element),
+            _ if BLOCK_TAGS.contains(&name) || name == "li" => {
+                self.flush();
+                self.walk_children(element);
+                self.flush();
+            }
+            _ => {
+                let text = self.inline_element(element);
+                self.paragraph.push_str(&text);
+            }
+        }
+    }
 
-    if let Some(re) = img_re {
-        for (idx, cap) in re.captures_iter(html).enumerate() {
-            let src = cap.get(1).map(|m| m.as_str().to_string());
-            let alt = alt_re.as_ref().and_then(|r| {
-                r.captures(&cap[0])
-                    .and_then(|c| c.get(1).map(|m| m.as_str().to_string()))
-            });
+    /// Convert a `<dl>`; `<div>` groups of terms and definitions are read
+    /// through, other children end the list and become paragraphs
+    fn definition_list(&mut self, list: ElementRef) {
+        self.flush();
+        let entries: Vec<ElementRef> = list
+            .children()
+            .filter_map(ElementRef::wrap)
+            .flat_map(|child| {
+                if child.value().name() == "div" {
+                    child.children().filter_map(ElementRef::wrap).collect()
+                } else {
+                    vec![child]
+                }
+            })
+            .collect();
 
-            if let Some(src_url) = src {
-                // For data URLs, extract the actual image data
-                if src_url.starts_with("data:image/") {
-                    if let Some(extracted) = extract_data_url_image(&src_url, idx) {
-                        let mut img = extracted;
-                        img.alt_text = alt;
-                        images.push(img);
+        let mut definitions: Vec<Definition> = Vec::new();
+        for entry in entries {
+            if self.is_skipped(entry) {
+                continue;
+            }
+            let spans = normalize_spans(self.inline_spans(entry));
+            if spans.is_empty() {
+                continue;
+            }
+            match entry.value().name() {
+                "dt" => definitions.push(Definition {
+                    term: spans,
+                    definitions: Vec::new(),
+                }),
+                "dd" => match definitions.last_mut() {
+                    Some(definition) => definition.definitions.push(spans),
+                    None => definitions.push(Definition {
+                        term: Vec::new(),
+                        definitions: vec![spans],
+                    }),
+                },
+                _ => {
+                    if !definitions.is_empty() {
+                        self.blocks
+                            .push(ContentBlock::DefinitionList(std::mem::take(
+                                &mut definitions,
+                            )));
                     }
+                    self.blocks.push(ContentBlock::Paragraph(spans));
+                }
+            }
+        }
+        if !definitions.is_empty() {
+            self.blocks.push(ContentBlock::DefinitionList(definitions));
+        }
+        self.flush();
+    }
+
+    /// Render the children of an element as inline spans
+    fn inline_spans(&mut self, element: ElementRef) -> Vec<Inline> {
+        let mut spans = Vec::new();
+        for child in element.children() {
+            match child.value() {
+                Node::Text(text) => spans.push(Inline::text(text.replace('\n', " "))),
+                Node::Element(_) => {
+                    if let Some(child) = ElementRef::wrap(child) {
+                        spans.extend(self.element_spans(child));
+                    }
+                }
+                _ => {}
+            }
+        }
+        spans
+    }
+
+    /// Render a single element as inline spans; elements spans cannot
+    /// express (images, nested blocks) fall back to inline markdown
+    fn element_spans(&mut self, element: ElementRef) -> Vec<Inline> {
+        if self.is_skipped(element) {
+            return Vec::new();
+        }
+
+        let span = match element.value().name() {
+            "br" => Inline::LineBreak,
+            "strong" | "b" => Inline::Strong(self.inline_spans(element)),
+            "em" | "i" | "cite" => Inline::Emphasis(self.inline_spans(element)),
+            "del" | "s" | "strike" => Inline::Strikethrough(self.inline_spans(element)),
+            "code" | "kbd" | "samp" | "tt" => {
+                let code = collapse_whitespace(&element.text().collect::<String>());
+                if code.is_empty() {
+                    return Vec::new();
+                }
+                Inline::Code(code)
+            }
+            "a" => {
+                let content = self.inline_spans(element);
+                let href = element.value().attr("href").unwrap_or("").trim();
+                if Inline::plain_text(&content).trim().is_empty()
+                    || href.is_empty()
+                    || href.starts_with("javascript:")
+                {
+                    return content;
+                }
+                Inline::Link {
+                    content,
+                    url: resolve_url(self.base.as_ref(), href),
+                    title: None,
+                }
+            }
+            name if BLOCK_TAGS.contains(&name)
+                || matches!(
+                    name,
+                    "img" | "ul" | "ol" | "td" | "th" | "p" | "li" | "tr" | "table"
+                ) =>
+            {
+                Inline::Text(self.inline_element(element))
+            }
+            _ => return self.inline_spans(element),
+        };
+        vec![span]
+    }
+
+    /// Convert list items with their nested lists and task checkboxes
+    fn nested_items(&mut self, list: ElementRef) -> Vec<ListItem> {
+        let mut items = Vec::new();
+
+        for item in list.children().filter_map(ElementRef::wrap) {
+            if item.value().name() != "li" || self.is_skipped(item) {
+                continue;
+            }
+
+            let mut content = Vec::new();
+            let mut children = Vec::new();
+            let mut checked = None;
+            for child in item.children() {
+                match child.value() {
+                    Node::Text(text) => content.push(Inline::text(text.replace('\n', " "))),
+                    Node::Element(_) => {
+                        let Some(child) = ElementRef::wrap(child) else {
+                            continue;
+                        };
+                        match child.value().name() {
+                            name @ ("ul" | "ol") => {
+                                let nested = self.nested_items(child);
+                                if !nested.is_empty() {
+                                    children.push(ContentBlock::NestedList {
+                                        ordered: name == "ol",
+                                        items: nested,
+                                    });
+                                }
+                            }
+                            _ if checked.is_none() && is_checkbox(child) => {
+                                checked = Some(child.value().attr("checked").is_some());
+                            }
+                            _ => content.extend(self.element_spans(child)),
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            let content = normalize_spans(content);
+            if !content.is_empty() || !children.is_empty() {
+                let mut item = ListItem::new(content).with_children(children);
+                item.checked = checked;
+                items.push(item);
+            }
+        }
+
+        items
+    }
+
+    /// Render the children of an element as inline markdown
+    pub(crate) fn inline_text(&mut self, element: ElementRef) -> String {
+        let mut out = String::new();
+        for child in element.children() {
+            match child.value() {
+                Node::Text(text) => out.push_str(&text.replace('\n', " ")),
+                Node::Element(_) => {
+                    if let Some(child) = ElementRef::wrap(child) {
+                        out.push_str(&self.inline_element(child));
+                    }
+                }
+                _ => {}
+            }
+        }
+        out
+    }
+
+    /// Render a single element as inline markdown
+    fn inline_element(&mut self, element: ElementRef) -> String {
+        if self.is_skipped(element) {
+            return String::new();
+        }
+
+        let name = element.value().name();
+        match name {
+            "br" => "\n".to_string(),
+            "img" => {
+                if self.extract_images {
+                    self.image(element, None);
+                    String::new()
                 } else {
-                    // For external URLs, create a placeholder
-                    let mut img = ExtractedImage::new(
-                        format!("html_image_{}", idx),
-                        Bytes::new(),
-                        "image/unknown",
+                    let alt = element.value().attr("alt").unwrap_or("");
+                    let src = resolve_url(
+                        self.base.as_ref(),
+                        element.value().attr("src").unwrap_or(""),
                     );
-                    img.alt_text = alt.or(Some(src_url));
-                    images.push(img);
+                    format!("![{}]({})", alt, src)
                 }
             }
+            "strong" | "b" => wrap_inline(&self.inline_text(element), "**"),
+            "em" | "i" | "cite" => wrap_inline(&self.inline_text(element), "*"),
+            "del" | "s" | "strike" => wrap_inline(&self.inline_text(element), "~~"),
+            "code" | "kbd" | "samp" | "tt" => {
+                let code = collapse_whitespace(&element.text().collect::<String>());
+                if code.is_empty() {
+                    String::new()
+                } else {
+                    format!("`{}`", code)
+                }
+            }
+            "a" => {
+                let text = self.inline_text(element);
+                let href = element.value().attr("href").unwrap_or("").trim();
+                let label = collapse_whitespace(&text);
+                if label.is_empty() || href.is_empty() || href.starts_with("javascript:") {
+                    text
+                } else {
+                    format!("[{}]({})", label, resolve_url(self.base.as_ref(), href))
+                }
+            }
+            "ul" | "ol" => format!("\n{}\n", self.list_items(element, 0).join("\n")),
+            _ if BLOCK_TAGS.contains(&name) || matches!(name, "p" | "li" | "tr" | "table") => {
+                format!("\n{}\n", self.inline_text(element))
+            }
+            "td" | "th" => format!(" {} ", self.inline_text(element)),
+            _ => self.inline_text(element),
         }
     }
 
-    images
+    /// Render list items; nested lists become indented markdown inside the
+    /// parent item
+    fn list_items(&mut self, list: ElementRef, depth: usize) -> Vec<String> {
+        let mut items = Vec::new();
+
+        for item in list.children().filter_map(ElementRef::wrap) {
+            if item.value().name() != "li" || self.is_skipped(item) {
+                continue;
+            }
+
+            let mut text = String::new();
+            let mut nested = Vec::new();
+            for child in item.children() {
+                match child.value() {
+                    Node::Text(t) => text.push_str(&t.replace('\n', " ")),
+                    Node::Element(_) => {
+                        let Some(child) = ElementRef::wrap(child) else {
+                            continue;
+                        };
+                        let child_name = child.value().name();
+                        if child_name == "ul" || child_name == "ol" {
+                            let marker_ordered = child_name == "ol";
+                            let indent = "  ".repeat(depth + 1);
+                            for (i, sub) in
+                                self.list_items(child, depth + 1).into_iter().enumerate()
+                            {
+                                let marker = if marker_ordered {
+                                    format!("{}.", i + 1)
+                                } else {
+                                    "-".to_string()
+                                };
+                                nested.push(format!("{}{} {}", indent, marker, sub));
+                            }
+                        } else {
+                            text.push_str(&self.inline_element(child));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            let mut rendered = collapse_whitespace(&text);
+            if !nested.is_empty() {
+                rendered.push('\n');
+                rendered.push_str(&nested.join("\n"));
+            }
+            if !rendered.trim().is_empty() {
+                items.push(rendered);
+            }
+        }
+
+        items
+    }
+
+    /// Build a table block. Tables with merged cells, several header rows,
+    /// a caption, a footer or aligned columns become a `RichTable` that keeps
+    /// them; others a plain `Table`.
+    ///
+    /// For the plain grid, row-spanning cells repeat their value in every
+    /// covered row. Column spans repeat in header rows (so group headings
+    /// label every column) and leave the covered cells empty in body rows.
+    /// Returns `None` for layout tables (nested tables or a single cell).
+    fn table(&mut self, table: ElementRef) -> Option<ContentBlock> {
+        let mut rows: Vec<(ElementRef, bool)> = Vec::new();
+        let mut footer_rows = 0;
+        let mut caption = None;
+        for child in table.children().filter_map(ElementRef::wrap) {
+            match child.value().name() {
+                "tr" => rows.push((child, false)),
+                "thead" | "tbody" | "tfoot" => {
+                    let in_head = child.value().name() == "thead";
+                    for row in child.children().filter_map(ElementRef::wrap) {
+                        if row.value().name() == "tr" {
+                            rows.push((row, in_head));
+                        }
+                    }
+                    if child.value().name() == "tfoot" {
+                        footer_rows = child
+                            .children()
+                            .filter_map(ElementRef::wrap)
+                            .filter(|row| row.value().name() == "tr")
+                            .count();
+                    }
+                }
+                "caption" => {
+                    caption = Some(collapse_whitespace(&self.inline_text(child)))
+                        .filter(|c| !c.is_empty());
+                }
+                _ => {}
+            }
+        }
+        // Browsers show a `<tfoot>` last wherever it is written
+        if footer_rows > 0 {
+            let at = rows
+                .iter()
+                .position(|(row, _)| {
+                    row.parent()
+                        .and_then(ElementRef::wrap)
+                        .is_some_and(|p| p.value().name() == "tfoot")
+                })
+                .unwrap_or(rows.len());
+            let footer: Vec<_> = rows.drain(at..at + footer_rows).collect();
+            rows.extend(footer);
+        }
+
+        let nested_tables = table
+            .descendants()
+            .filter_map(ElementRef::wrap)
+            .skip(1)
+            .any(|e| e.value().name() == "table");
+        if rows.is_empty() || nested_tables {
+            return None;
+        }
+
+        let mut grid: Vec<Vec<Option<String>>> = Vec::new();
+        let mut header_rows = 0;
+        let mut in_header = true;
+        // The same rows as cells that keep their spans, for a rich table
+        let mut rich_rows: Vec<Vec<TableCell>> = Vec::new();
+        let mut merged = false;
+
+        for (r, (row, in_head)) in rows.iter().enumerate() {
+            let cells: Vec<ElementRef> = row
+                .children()
+                .filter_map(ElementRef::wrap)
+                .filter(|c| matches!(c.value().name(), "td" | "th"))
+                .collect();
+            let is_header =
+                *in_head || (!cells.is_empty() && cells.iter().all(|c| c.value().name() == "th"));
+            if in_header && is_header {
+                header_rows = r + 1;
+            } else {
+                in_header = false;
+            }
+
+            if grid.len() <= r {
+                grid.resize(r + 1, Vec::new());
+            }
+
+            let mut rich_row = Vec::with_capacity(cells.len());
+            let mut col = 0;
+            for cell in cells {
+                while grid[r].get(col).is_some_and(|c| c.is_some()) {
+                    col += 1;
+                }
+
+                let span = |attr: &str| {
+                    cell.value()
+                        .attr(attr)
+                        .and_then(|v| v.trim().parse::<usize>().ok())
+                        .filter(|v| *v > 0)
+                        .unwrap_or(1)
+                        .min(MAX_SPAN)
+                };
+                let colspan = span("colspan");
+                let rowspan = span("rowspan").min(rows.len() - r);
+                merged |= colspan > 1 || rowspan > 1;
+
+                let text = collapse_whitespace(&self.inline_text(cell));
+                rich_row.push(
+                    TableCell::new(text.clone())
+                        .with_colspan(colspan)
+                        .with_rowspan(rowspan),
+                );
+                for dr in 0..rowspan {
+                    let target = r + dr;
+                    if grid.len() <= target {
+                        grid.resize(target + 1, Vec::new());
+                    }
+                    for dc in 0..colspan {
+                        let c = col + dc;
+                        if grid[target].len() <= c {
+                            grid[target].resize(c + 1, None);
+                        }
+                        let value = if dc == 0 || (in_header && is_header) {
+                            text.clone()
+                        } else {
+                            String::new()
+                        };
+                        grid[target][c] = Some(value);
+                    }
+                }
+                col += colspan;
+            }
+            rich_rows.push(rich_row);
+        }
+
+        let column_count = grid.iter().map(|r| r.len()).max().unwrap_or(0);
+        let cell_count: usize = grid
+            .iter()
+            .map(|r| r.iter().filter(|c| c.is_some()).count())
+            .sum();
+        if column_count == 0 || cell_count <= 1 {
+            return None;
+        }
+
+        let alignments = Self::column_alignments(&rows, &rich_rows);
+        if merged
+            || header_rows > 1
+            || caption.is_some()
+            || footer_rows > 0
+            || alignments.iter().any(|a| *a != Alignment::Default)
+        {
+            let footer_start = rich_rows.len() - footer_rows.min(rich_rows.len() - header_rows);
+            let footer = rich_rows.split_off(footer_start);
+            let body = rich_rows.split_off(header_rows);
+            let mut rich = Table::new().with_alignments(alignments);
+            rich.caption = caption;
+            rich.header = rich_rows;
+            rich.body = body;
+            rich.footer = footer;
+            // Row spans stop at the end of their section
+            for section in [&mut rich.header, &mut rich.body, &mut rich.footer] {
+                let count = section.len();
+                for (r, row) in section.iter_mut().enumerate() {
+                    for cell in row {
+                        cell.rowspan = cell.rowspan.min(count - r);
+                    }
+                }
+            }
+            return Some(ContentBlock::RichTable(rich));
+        }
+
+        let mut grid: Vec<Vec<String>> = grid
+            .into_iter()
+            .map(|row| {
+                let mut row: Vec<String> = row.into_iter().map(|c| c.unwrap_or_default()).collect();
+                row.resize(column_count, String::new());
+                row
+            })
+            .filter(|row| row.iter().any(|c| !c.is_empty()))
+            .collect();
+        if grid.is_empty() {
+            return None;
+        }
+
+        // Multi-row headers are folded into one row per column ("Group / Sub")
+        let header_rows = header_rows.clamp(1, grid.len());
+        let headers: Vec<String> = (0..column_count)
+            .map(|c| {
+                let mut parts: Vec<&str> = Vec::new();
+                for row in &grid[..header_rows] {
+                    let cell = row[c].as_str();
+                    if !cell.is_empty() && !parts.contains(&cell) {
+                        parts.push(cell);
+                    }
+                }
+                parts.join(" / ")
+            })
+            .collect();
+        let rows = grid.split_off(header_rows);
+
+        Some(ContentBlock::Table { headers, rows })
+    }
+
+    /// Alignment of each column, from the `align` attribute or `text-align`
+    /// style of the first cell starting in it that sets one
+    fn column_alignments(rows: &[(ElementRef, bool)], cells: &[Vec<TableCell>]) -> Vec<Alignment> {
+        let (columns, width) = Table::cell_columns(cells);
+        let mut alignments = vec![Alignment::Default; width];
+        for ((row, _), starts) in rows.iter().zip(&columns) {
+            let elements = row
+                .children()
+                .filter_map(ElementRef::wrap)
+                .filter(|c| matches!(c.value().name(), "td" | "th"));
+            for (element, &column) in elements.zip(starts) {
+                if alignments[column] != Alignment::Default {
+                    continue;
+                }
+                let style = element.value().attr("style").unwrap_or_default();
+                let align = element.value().attr("align").or_else(|| {
+                    style.split(';').find_map(|declaration| {
+                        let (name, value) = declaration.split_once(':')?;
+                        name.trim()
+                            .eq_ignore_ascii_case("text-align")
+                            .then_some(value.trim())
+                    })
+                });
+                alignments[column] = match align.map(str::to_ascii_lowercase).as_deref() {
+                    Some("left") => Alignment::Left,
+                    Some("center") => Alignment::Center,
+                    Some("right") => Alignment::Right,
+                    _ => Alignment::Default,
+                };
+            }
+        }
+        alignments
+    }
+
+    /// Convert a `<figure>`; the `<figcaption>` becomes the image caption
+    fn figure(&mut self, figure: ElementRef) {
+        self.flush();
+
+        let caption = figure
+            .children()
+            .filter_map(ElementRef::wrap)
+            .find(|c| c.value().name() == "figcaption")
+            .map(|c| collapse_whitespace(&self.inline_text(c)))
+            .filter(|c| !c.is_empty());
+
+        let images: Vec<ElementRef> = figure
+            .descendants()
+            .filter_map(ElementRef::wrap)
+            .filter(|e| e.value().name() == "img")
+            .collect();
+
+        if images.len() == 1 && !self.is_skipped(images[0]) {
+            self.image(images[0], caption);
+        } else {
+            for child in figure.children() {
+                if let Some(child) = ElementRef::wrap(child) {
+                    if child.value().name() != "figcaption" {
+                        self.walk_element(child);
+                    }
+                } else if let Node::Text(text) = child.value() {
+                    self.paragraph.push_str(&text.replace('\n', " "));
+                }
+            }
+            self.flush();
+            if let Some(caption) = caption {
+                self.blocks
+                    .push(ContentBlock::Text(format!("*{}*", caption)));
+            }
+        }
+        self.flush();
+    }
+
+    /// Queue an image; data URLs are decoded, other sources become
+    /// placeholders carrying the URL
+    fn image(&mut self, img: ElementRef, caption: Option<String>) {
+        let Some(src) = img.value().attr("src").map(str::trim) else {
+            return;
+        };
+        if src.is_empty() {
+            return;
+        }
+        let src = resolve_url(self.base.as_ref(), src);
+        let alt = img
+            .value()
+            .attr("alt")
+            .map(collapse_whitespace)
+            .filter(|a| !a.is_empty());
+
+        if !self.extract_images {
+            let mut md = format!("![{}]({})", alt.as_deref().unwrap_or(""), src);
+            if let Some(caption) = caption {
+                md.push_str(&format!("\n\n*{}*", caption));
+            }
+            self.paragraph.push_str(&md);
+            self.flush();
+            return;
+        }
+
+        let idx = self.image_count;
+        let extracted = if src.starts_with("data:image/") {
+            extract_data_url_image(&src, format!("html_image_{}", idx)).map(|mut image| {
+                image.alt_text = alt;
+                image
+            })
+        } else {
+            // For external URLs, create a placeholder; the image fetcher may
+            // load it later
+            let mut image =
+                ExtractedImage::new(format!("html_image_{}", idx), Bytes::new(), "image/unknown");
+            image.alt_text = alt.or(Some(src.clone()));
+            image.source_path = Some(src);
+            Some(image)
+        };
+
+        if let Some(mut image) = extracted {
+            image.caption = caption;
+            image.width = img.value().attr("width").and_then(|w| w.parse().ok());
+            image.height = img.value().attr("height").and_then(|h| h.parse().ok());
+            self.image_count += 1;
+            self.pending_images.push(image);
+        }
+    }
+}
+
+/// Wrap inline text in a markdown emphasis marker, keeping surrounding spaces
+/// outside the markers
+fn wrap_inline(text: &str, marker: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return text.to_string();
+    }
+    let leading = if text.starts_with(char::is_whitespace) {
+        " "
+    } else {
+        ""
+    };
+    let trailing = if text.ends_with(char::is_whitespace) {
+        " "
+    } else {
+        ""
+    };
+    format!("{}{}{}{}{}", leading, marker, trimmed, marker, trailing)
 }
 
-fn extract_data_url_image(data_url: &str, idx: usize) -> Option<ExtractedImage> {
+/// Decode a base64 `data:image/...` URL into an image with the given id
+pub(crate) fn extract_data_url_image(data_url: &str, id: String) -> Option<ExtractedImage> {
     // Parse data URL format: data:image/type;base64,data
     let parts: Vec<&str> = data_url.splitn(2, ',').collect();
     if parts.len() != 2 {
@@ -188,9 +1477,5 @@ fn extract_data_url_image(data_url: &str, idx: usize) -> Option<ExtractedImage>
     use base64::prelude::*;
     let bytes = BASE64_STANDARD.decode(data).ok()?;
 
-    Some(ExtractedImage::new(
-        format!("html_image_{}", idx),
-        Bytes::from(bytes),
-        mime_type,
-    ))
+    Some(ExtractedImage::new(id, Bytes::from(bytes), mime_type))
 }
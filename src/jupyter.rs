@@ -1,15 +1,108 @@
 //! Jupyter Notebook to Markdown converter.
 //!
 //! Converts .ipynb files to markdown, preserving code cells and outputs.
+//! Outputs use the richest representation the notebook stored: plots become
+//! images, markdown and LaTeX are rendered, errors keep their traceback (without
+//! terminal colors) and long text outputs are cut to [`OutputLimits`].
 
 use async_trait::async_trait;
+use base64::prelude::*;
 use bytes::Bytes;
 use object_store::ObjectStore;
+use regex::Regex;
 use serde::Deserialize;
-use std::sync::Arc;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock};
 
 use crate::error::MarkitdownError;
-use crate::model::{ContentBlock, ConversionOptions, Document, DocumentConverter, Page};
+use crate::model::{
+    ContentBlock, ConversionOptions, Document, DocumentConverter, ExtractedImage, Page,
+};
+
+/// ANSI escape sequences (colors, cursor movement) in tracebacks and streams
+static ANSI_ESCAPE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\x1b\[[0-9;?]*[A-Za-z]|\x1b\][^\x07]*\x07").unwrap());
+
+/// Output representations in order of preference
+const MIME_PREFERENCE: &[&str] = &[
+    "image/png",
+    "image/jpeg",
+    "image/gif",
+    "image/webp",
+    "image/svg+xml",
+    "text/markdown",
+    "text/latex",
+    "text/html",
+    "application/json",
+    "text/plain",
+];
+
+/// How much of each text output (streams, results, tracebacks) is kept
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputLimits {
+    /// Lines kept per output; `None` keeps every line
+    pub max_lines: Option<usize>,
+    /// Characters kept per output; `None` keeps every character
+    pub max_chars: Option<usize>,
+}
+
+impl Default for OutputLimits {
+    fn default() -> Self {
+        Self {
+            max_lines: Some(100),
+            max_chars: Some(10_000),
+        }
+    }
+}
+
+impl OutputLimits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep outputs in full
+    pub fn unlimited() -> Self {
+        Self {
+            max_lines: None,
+            max_chars: None,
+        }
+    }
+
+    pub fn with_max_lines(mut self, lines: usize) -> Self {
+        self.max_lines = Some(lines);
+        self
+    }
+
+    pub fn with_max_chars(mut self, chars: usize) -> Self {
+        self.max_chars = Some(chars);
+        self
+    }
+
+    /// Cut text to the limits, noting how much was left out
+    pub(crate) fn apply(&self, text: &str) -> String {
+        let lines: Vec<&str> = text.lines().collect();
+        let mut kept = match self.max_lines {
+            Some(max) if lines.len() > max => lines[..max].join("\n"),
+            _ => text.trim_end_matches('\n').to_string(),
+        };
+        let mut truncated = false;
+        if let Some(max) = self.max_chars {
+            if let Some((index, _)) = kept.char_indices().nth(max) {
+                kept.truncate(index);
+                truncated = true;
+            }
+        }
+
+        let kept_lines = kept.lines().count();
+        if kept_lines < lines.len() {
+            kept.push_str(&format!("\n... ({} more lines)", lines.len() - kept_lines));
+        } else if truncated {
+            kept.push_str("\n... (truncated)");
+        }
+        kept
+    }
+}
 
 /// Jupyter Notebook to Markdown converter
 pub struct JupyterConverter;
@@ -23,6 +116,7 @@ struct Notebook {
 #[derive(Deserialize)]
 struct NotebookMetadata {
     kernelspec: Option<KernelSpec>,
+    language_info: Option<LanguageInfo>,
 }
 
 #[derive(Deserialize)]
@@ -30,14 +124,20 @@ struct KernelSpec {
     language: Option<String>,
 }
 
+#[derive(Deserialize)]
+struct LanguageInfo {
+    name: Option<String>,
+}
+
 #[derive(Deserialize)]
 struct Cell {
     cell_type: String,
     source: CellSource,
+    execution_count: Option<u64>,
     outputs: Option<Vec<Output>>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone)]
 #[serde(untagged)]
 enum CellSource {
     String(String),
@@ -45,7 +145,7 @@ enum CellSource {
 }
 
 impl CellSource {
-    fn to_string(&self) -> String {
+    fn text(&self) -> String {
         match self {
             CellSource::String(s) => s.clone(),
             CellSource::Array(arr) => arr.join(""),
@@ -53,23 +153,46 @@ impl CellSource {
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone)]
 struct Output {
     output_type: String,
+    /// Stream name (`stdout` or `stderr`)
+    name: Option<String>,
     text: Option<CellSource>,
-    data: Option<OutputData>,
+    /// Representations of a result or display, keyed by MIME type
+    data: Option<HashMap<String, Value>>,
+    metadata: Option<HashMap<String, Value>>,
+    execution_count: Option<u64>,
+    ename: Option<String>,
+    evalue: Option<String>,
+    traceback: Option<Vec<String>>,
 }
 
-#[derive(Deserialize)]
-struct OutputData {
-    #[serde(rename = "text/plain")]
-    text_plain: Option<CellSource>,
-    #[serde(rename = "text/html")]
-    text_html: Option<CellSource>,
+/// Text of a MIME bundle entry, stored as a string or a list of lines
+fn bundle_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Array(lines) => Some(
+            lines
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+                .join(""),
+        ),
+        _ => None,
+    }
+}
+
+fn strip_ansi(text: &str) -> String {
+    ANSI_ESCAPE.replace_all(text, "").to_string()
 }
 
 impl JupyterConverter {
-    fn convert_notebook(bytes: &[u8]) -> Result<Document, MarkitdownError> {
+    fn convert_notebook(
+        bytes: &[u8],
+        limits: &OutputLimits,
+        extract_images: bool,
+    ) -> Result<Document, MarkitdownError> {
         let notebook: Notebook = serde_json::from_slice(bytes)
             .map_err(|e| MarkitdownError::ParseError(format!("Invalid notebook: {}", e)))?;
 
@@ -79,95 +202,245 @@ impl JupyterConverter {
         let language = notebook
             .metadata
             .as_ref()
-            .and_then(|m| m.kernelspec.as_ref())
-            .and_then(|k| k.language.as_ref())
-            .map(|s| s.as_str())
-            .unwrap_or("python");
+            .and_then(|m| {
+                m.kernelspec
+                    .as_ref()
+                    .and_then(|k| k.language.clone())
+                    .or_else(|| m.language_info.as_ref().and_then(|l| l.name.clone()))
+            })
+            .unwrap_or_else(|| "python".to_string());
 
         let mut page = Page::new(1);
-        let mut markdown = String::new();
+        let mut images = 0;
 
-        for (i, cell) in notebook.cells.iter().enumerate() {
+        for cell in &notebook.cells {
             match cell.cell_type.as_str() {
                 "markdown" => {
                     // Markdown cells are passed through
-                    let source = cell.source.to_string();
-                    markdown.push_str(&source);
-                    markdown.push_str("\n\n");
+                    let source = cell.source.text();
+                    if !source.trim().is_empty() {
+                        page.add_content(ContentBlock::Markdown(format!(
+                            "{}\n",
+                            source.trim_end()
+                        )));
+                    }
                 }
                 "code" => {
-                    // Code cells are wrapped in code blocks
-                    let source = cell.source.to_string();
-
-                    // Add cell number comment
-                    markdown.push_str(&format!("**In [{}]:**\n\n", i + 1));
-                    markdown.push_str(&format!("```{}\n", language));
-                    markdown.push_str(&source);
-                    if !source.ends_with('\n') {
-                        markdown.push('\n');
-                    }
-                    markdown.push_str("```\n\n");
-
-                    // Add outputs if present
-                    if let Some(outputs) = &cell.outputs {
-                        for output in outputs {
-                            let output_text = Self::extract_output(output);
-                            if !output_text.is_empty() {
-                                markdown.push_str("**Out:**\n\n");
-                                markdown.push_str("```\n");
-                                markdown.push_str(&output_text);
-                                if !output_text.ends_with('\n') {
-                                    markdown.push('\n');
-                                }
-                                markdown.push_str("```\n\n");
+                    let count = cell
+                        .execution_count
+                        .map_or(" ".to_string(), |n| n.to_string());
+                    page.add_content(ContentBlock::Markdown(format!("**In [{}]:**\n", count)));
+                    page.add_content(ContentBlock::Code {
+                        language: Some(language.clone()),
+                        code: cell.source.text().trim_end_matches('\n').to_string(),
+                    });
+
+                    let outputs = cell.outputs.as_deref().unwrap_or_default();
+                    for output in Self::merge_streams(outputs) {
+                        let blocks =
+                            Self::output_blocks(&output, limits, extract_images, &mut images);
+                        if blocks.is_empty() {
+                            continue;
+                        }
+                        let label = match (output.output_type.as_str(), output.execution_count) {
+                            ("error", _) => "**Error:**".to_string(),
+                            ("stream", _) if output.name.as_deref() == Some("stderr") => {
+                                "**Out (stderr):**".to_string()
                             }
+                            ("execute_result", Some(n)) => format!("**Out [{}]:**", n),
+                            _ => "**Out:**".to_string(),
+                        };
+                        page.add_content(ContentBlock::Markdown(format!("{}\n", label)));
+                        for block in blocks {
+                            page.add_content(block);
                         }
                     }
                 }
                 "raw" => {
                     // Raw cells are wrapped in code blocks
-                    let source = cell.source.to_string();
-                    markdown.push_str("```\n");
-                    markdown.push_str(&source);
-                    if !source.ends_with('\n') {
-                        markdown.push('\n');
-                    }
-                    markdown.push_str("```\n\n");
+                    page.add_content(ContentBlock::Code {
+                        language: None,
+                        code: cell.source.text().trim_end_matches('\n').to_string(),
+                    });
                 }
                 _ => {}
             }
         }
 
-        page.add_content(ContentBlock::Markdown(markdown));
         document.add_page(page);
 
         Ok(document)
     }
 
-    fn extract_output(output: &Output) -> String {
-        match output.output_type.as_str() {
-            "stream" | "execute_result" | "display_data" => {
-                // Try text first
-                if let Some(text) = &output.text {
-                    return text.to_string();
-                }
-                // Try data
-                if let Some(data) = &output.data {
-                    if let Some(text) = &data.text_plain {
-                        return text.to_string();
-                    }
-                    if let Some(html) = &data.text_html {
-                        // Convert HTML to text (simple approach)
-                        return html2md::parse_html(&html.to_string());
+    /// Join consecutive chunks written to the same stream
+    fn merge_streams(outputs: &[Output]) -> Vec<Output> {
+        let mut merged: Vec<Output> = Vec::new();
+        for output in outputs {
+            if output.output_type == "stream" {
+                if let Some(last) = merged.last_mut() {
+                    if last.output_type == "stream" && last.name == output.name {
+                        let text = format!(
+                            "{}{}",
+                            last.text.as_ref().map(CellSource::text).unwrap_or_default(),
+                            output
+                                .text
+                                .as_ref()
+                                .map(CellSource::text)
+                                .unwrap_or_default()
+                        );
+                        last.text = Some(CellSource::String(text));
+                        continue;
                     }
                 }
             }
+            merged.push(output.clone());
+        }
+        merged
+    }
+
+    fn output_blocks(
+        output: &Output,
+        limits: &OutputLimits,
+        extract_images: bool,
+        images: &mut usize,
+    ) -> Vec<ContentBlock> {
+        let text_block = |text: &str| {
+            let text = limits.apply(&strip_ansi(text));
+            if text.trim().is_empty() {
+                Vec::new()
+            } else {
+                vec![ContentBlock::Code {
+                    language: None,
+                    code: text,
+                }]
+            }
+        };
+
+        match output.output_type.as_str() {
+            "stream" => text_block(
+                &output
+                    .text
+                    .as_ref()
+                    .map(CellSource::text)
+                    .unwrap_or_default(),
+            ),
             "error" => {
-                return "[Error output]".to_string();
+                let traceback = output
+                    .traceback
+                    .as_ref()
+                    .map(|lines| lines.join("\n"))
+                    .filter(|t| !t.trim().is_empty())
+                    .unwrap_or_else(|| {
+                        format!(
+                            "{}: {}",
+                            output.ename.as_deref().unwrap_or("Error"),
+                            output.evalue.as_deref().unwrap_or_default()
+                        )
+                    });
+                text_block(&traceback)
+            }
+            "execute_result" | "display_data" => {
+                // Older notebooks put plain text directly on the output
+                let Some(data) = &output.data else {
+                    return text_block(
+                        &output
+                            .text
+                            .as_ref()
+                            .map(CellSource::text)
+                            .unwrap_or_default(),
+                    );
+                };
+                for mime in MIME_PREFERENCE {
+                    let Some(value) = data.get(*mime) else {
+                        continue;
+                    };
+                    if mime.starts_with("image/") {
+                        if !extract_images {
+                            continue;
+                        }
+                        if let Some(image) =
+                            Self::output_image(output, mime, value, data, *images + 1)
+                        {
+                            *images += 1;
+                            return vec![ContentBlock::Image(image)];
+                        }
+                        continue;
+                    }
+                    let Some(text) = bundle_text(value).or_else(|| {
+                        // JSON bundles hold the value itself
+                        (*mime == "application/json")
+                            .then(|| serde_json::to_string_pretty(value).ok())
+                            .flatten()
+                    }) else {
+                        continue;
+                    };
+                    return match *mime {
+                        "text/markdown" => {
+                            vec![ContentBlock::Markdown(format!("{}\n", text.trim_end()))]
+                        }
+                        "text/latex" => {
+                            let latex = text.trim();
+                            let latex = if latex.starts_with('$') || latex.starts_with("\\[") {
+                                latex.to_string()
+                            } else {
+                                format!("$$\n{}\n$$", latex)
+                            };
+                            vec![ContentBlock::Markdown(format!("{}\n", latex))]
+                        }
+                        "text/html" => {
+                            let markdown = html2md::parse_html(&text);
+                            vec![ContentBlock::Markdown(format!("{}\n", markdown.trim_end()))]
+                        }
+                        "application/json" => vec![ContentBlock::Code {
+                            language: Some("json".to_string()),
+                            code: limits.apply(&text),
+                        }],
+                        _ => text_block(&text),
+                    };
+                }
+                Vec::new()
             }
-            _ => {}
+            _ => Vec::new(),
         }
-        String::new()
+    }
+
+    /// A plot or other image output; the plain-text representation becomes
+    /// the alt text and the output metadata its size
+    fn output_image(
+        output: &Output,
+        mime: &str,
+        value: &Value,
+        data: &HashMap<String, Value>,
+        number: usize,
+    ) -> Option<ExtractedImage> {
+        let text = bundle_text(value)?;
+        let bytes = if mime == "image/svg+xml" {
+            text.into_bytes()
+        } else {
+            let encoded: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+            BASE64_STANDARD.decode(encoded).ok()?
+        };
+
+        let mut image = ExtractedImage::new(
+            format!("notebook_image_{}", number),
+            Bytes::from(bytes),
+            mime,
+        );
+        image.alt_text = data
+            .get("text/plain")
+            .and_then(bundle_text)
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty());
+        image.page_number = Some(1);
+        let size = output.metadata.as_ref().and_then(|m| m.get(mime));
+        let dimension = |key: &str| {
+            size.and_then(|s| s.get(key))
+                .and_then(Value::as_u64)
+                .and_then(|v| u32::try_from(v).ok())
+        };
+        image.width = dimension("width");
+        image.height = dimension("height");
+        Some(image)
     }
 }
 
@@ -187,9 +460,23 @@ impl DocumentConverter for JupyterConverter {
     async fn convert_bytes(
         &self,
         bytes: Bytes,
-        _options: Option<ConversionOptions>,
+        options: Option<ConversionOptions>,
     ) -> Result<Document, MarkitdownError> {
-        Self::convert_notebook(&bytes)
+        let opts = options.clone().unwrap_or_default();
+        let mut document =
+            Self::convert_notebook(&bytes, &opts.notebook_outputs, opts.extract_images)?;
+
+        // If LLM client is provided, get descriptions for all images
+        if let Some(ref llm_client) = opts.llm_client {
+            if let Some(path) = opts.image_context_path.as_deref() {
+                document.apply_image_context_path(path);
+            }
+            document = document
+                .with_image_descriptions(llm_client.as_ref())
+                .await?;
+        }
+
+        Ok(document)
     }
 
     fn supported_extensions(&self) -> &[&str] {
@@ -0,0 +1,176 @@
+//! Offline language identification.
+//!
+//! Text is identified with whatlang's trigram profiles, so nothing leaves the
+//! process. Detected languages are reported as ISO 639-1 codes ("en", "de")
+//! where one exists, which makes them comparable with the BCP 47 tags
+//! documents declare (DOCX `w:lang`, HTML `lang`, EPUB `dc:language`, the PDF
+//! catalog `/Lang`).
+
+use crate::model::{Document, Page};
+use crate::render::{MarkdownFlavor, MarkdownRenderer};
+
+/// Texts with fewer letters than this are too short to identify
+const MIN_LETTERS: usize = 40;
+
+/// Only the start of long texts is read
+const MAX_SAMPLE_CHARS: usize = 20_000;
+
+/// How [`crate::MarkItDown`] tags documents and pages with their language.
+///
+/// Detection is off by default, as it renders and scans every page once more.
+/// When enabled, `Document::language` and `Page::language` hold comparable
+/// codes, with declared tags reduced to their primary subtag ("en-US" →
+/// "en"); the declared tag stays in `DocumentProperties::language`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LanguageDetection {
+    /// Leave languages to the converters
+    #[default]
+    Off,
+    /// Keep the language a document declares and detect the rest
+    PreferDeclared,
+    /// Detect every language, using declared ones only for text too short
+    /// to identify
+    PreferDetected,
+}
+
+/// Identify the language of a text; `None` when it is too short or the
+/// guess is unreliable
+pub fn detect_language(text: &str) -> Option<String> {
+    let sample: String = text.chars().take(MAX_SAMPLE_CHARS).collect();
+    if sample.chars().filter(|c| c.is_alphabetic()).count() < MIN_LETTERS {
+        return None;
+    }
+    let info = whatlang::detect(&sample)?;
+    if !info.is_reliable() {
+        return None;
+    }
+    Some(iso_639_1(info.lang().code()).to_string())
+}
+
+/// Primary subtag of a language tag, lower-cased ("en-US" → "en")
+pub fn primary_language(tag: &str) -> String {
+    tag.trim()
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+/// Tag the document and each of its pages with a language
+pub fn tag_languages(document: &mut Document, mode: LanguageDetection) {
+    if mode == LanguageDetection::Off {
+        return;
+    }
+
+    let declared = document
+        .properties
+        .language
+        .as_deref()
+        .and_then(declared_language);
+    let texts: Vec<String> = document.pages.iter().map(page_text).collect();
+    let detected = detect_language(&texts.join("\n\n"));
+    let language = match mode {
+        LanguageDetection::PreferDetected => detected.or(declared),
+        _ => declared.or(detected),
+    };
+
+    for (page, text) in document.pages.iter_mut().zip(&texts) {
+        let declared = page.language.take().as_deref().and_then(declared_language);
+        let detected = detect_language(text);
+        page.language = match mode {
+            LanguageDetection::PreferDetected => detected.or(declared),
+            _ => declared.or(detected),
+        }
+        .or_else(|| language.clone());
+    }
+    document.language = language;
+}
+
+/// Primary subtag of a declared tag, if it has one
+fn declared_language(tag: &str) -> Option<String> {
+    Some(primary_language(tag)).filter(|language| !language.is_empty())
+}
+
+/// Text of a page without markup
+fn page_text(page: &Page) -> String {
+    MarkdownRenderer::new()
+        .with_flavor(MarkdownFlavor::Plain)
+        .render_page(page)
+}
+
+/// Two-letter code for whatlang's ISO 639-3 codes; languages without one
+/// keep their three-letter code
+fn iso_639_1(code: &'static str) -> &'static str {
+    match code {
+        "afr" => "af",
+        "aka" => "ak",
+        "amh" => "am",
+        "ara" => "ar",
+        "aze" => "az",
+        "bel" => "be",
+        "ben" => "bn",
+        "bul" => "bg",
+        "cat" => "ca",
+        "ces" => "cs",
+        "cmn" => "zh",
+        "dan" => "da",
+        "deu" => "de",
+        "ell" => "el",
+        "eng" => "en",
+        "epo" => "eo",
+        "est" => "et",
+        "fin" => "fi",
+        "fra" => "fr",
+        "guj" => "gu",
+        "heb" => "he",
+        "hin" => "hi",
+        "hrv" => "hr",
+        "hun" => "hu",
+        "hye" => "hy",
+        "ind" => "id",
+        "ita" => "it",
+        "jav" => "jv",
+        "jpn" => "ja",
+        "kan" => "kn",
+        "kat" => "ka",
+        "khm" => "km",
+        "kor" => "ko",
+        "lat" => "la",
+        "lav" => "lv",
+        "lit" => "lt",
+        "mal" => "ml",
+        "mar" => "mr",
+        "mkd" => "mk",
+        "mya" => "my",
+        "nep" => "ne",
+        "nld" => "nl",
+        "nob" => "nb",
+        "ori" => "or",
+        "pan" => "pa",
+        "pes" => "fa",
+        "pol" => "pl",
+        "por" => "pt",
+        "ron" => "ro",
+        "rus" => "ru",
+        "sin" => "si",
+        "slk" => "sk",
+        "slv" => "sl",
+        "sna" => "sn",
+        "spa" => "es",
+        "srp" => "sr",
+        "swe" => "sv",
+        "tam" => "ta",
+        "tel" => "te",
+        "tgl" => "tl",
+        "tha" => "th",
+        "tuk" => "tk",
+        "tur" => "tr",
+        "ukr" => "uk",
+        "urd" => "ur",
+        "uzb" => "uz",
+        "vie" => "vi",
+        "yid" => "yi",
+        "zul" => "zu",
+        other => other,
+    }
+}
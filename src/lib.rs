@@ -1,6 +1,8 @@
 pub mod archive;
 pub mod bibtex;
 pub mod calendar;
+pub mod columnar;
+pub mod content_lines;
 pub mod csv;
 pub mod data;
 pub mod docbook;
@@ -9,35 +11,49 @@ pub mod email;
 pub mod epub;
 pub mod error;
 pub mod excel;
+pub mod fetch;
 pub mod fictionbook;
 pub mod html;
 pub mod image;
 pub mod iwork;
 pub mod jupyter;
+pub mod language;
 pub mod latex;
 pub mod legacy_office;
+pub mod links;
+pub mod literate;
 pub mod llm;
 pub mod log;
+pub mod mailbox;
 pub mod markdown;
+pub mod metadata;
 pub mod model;
+pub mod nested;
 pub mod opendocument;
 pub mod opml;
 pub mod orgmode;
 pub mod pdf;
 pub mod pptx;
 pub mod prompts;
+pub mod quotes;
+pub mod render;
 pub mod rss;
 pub mod rst;
 pub mod rtf;
+pub mod sqldump;
 pub mod sqlite;
 pub mod table_merge;
+pub mod tabular;
 pub mod typst;
 pub mod vcard;
+pub mod web;
+pub mod webarchive;
 
 use archive::ArchiveConverter;
 use bibtex::BibtexConverter;
 use bytes::Bytes;
 use calendar::ICalendarConverter;
+use columnar::{ArrowConverter, ParquetConverter};
 use csv::CsvConverter;
 use data::{CodeConverter, JsonConverter, TextConverter, TomlConverter, YamlConverter};
 use docbook::DocBookConverter;
@@ -46,6 +62,7 @@ use email::EmailConverter;
 use epub::EpubConverter;
 use error::MarkitdownError;
 use excel::ExcelConverter;
+use fetch::{is_http_url, FetchLimits, HttpResponse, ReqwestFetcher, SharedHttpFetcher};
 use fictionbook::FictionBookConverter;
 use html::HtmlConverter;
 use image::ImageConverter;
@@ -55,14 +72,17 @@ use latex::LatexConverter;
 use legacy_office::{
     DocConverter, DotxConverter, PotxConverter, PptConverter, XlsConverter, XltxConverter,
 };
+use literate::{JupytextConverter, QuartoConverter, RMarkdownConverter, ZeppelinConverter};
 use log::LogConverter;
+use mailbox::{MaildirConverter, MboxConverter};
 use markdown::MarkdownConverter;
 use mime_guess::MimeGuess;
 use model::{DocumentConverter, DocumentConverterResult};
+use nested::{ConverterRegistry, SharedNestedConverter};
 use object_store::local::LocalFileSystem;
 use object_store::memory::InMemory;
 use object_store::ObjectStore;
-use opendocument::{OdpConverter, OdsConverter, OdtConverter};
+use opendocument::{FlatOdfConverter, OdgConverter, OdpConverter, OdsConverter, OdtConverter};
 use opml::OpmlConverter;
 use orgmode::OrgModeConverter;
 use pdf::PdfConverter;
@@ -70,6 +90,7 @@ use pptx::PptxConverter;
 use rss::RssConverter;
 use rst::RstConverter;
 use rtf::RtfConverter;
+use sqldump::SqlDumpConverter;
 use sqlite::SqliteConverter;
 use std::io::Cursor;
 use std::io::Read;
@@ -78,22 +99,41 @@ use std::sync::Arc;
 use std::{collections::HashMap, fs};
 use typst::TypstConverter;
 use vcard::VCardConverter;
+use webarchive::{MhtmlConverter, WarcConverter, WebArchiveConverter};
 use zip::ZipArchive;
 
 // Re-export key types
+pub use calendar::RecurrenceWindow;
+pub use fetch::{HttpFetcher, ImageFetcher, ObjectStoreImageFetcher, SharedImageFetcher};
+pub use jupyter::OutputLimits;
+pub use language::LanguageDetection;
 pub use llm::{
     create_llm_client, create_llm_client_with_config, LlmClient, LlmConfig, LlmWrapper,
     MockLlmClient, SharedLlmClient,
 };
-pub use model::{ContentBlock, ConversionOptions, Document, ExtractedImage, Page};
+pub use log::FullLog;
+pub use mailbox::MailboxFilter;
+pub use model::{
+    Alignment, BoundingBox, ContentBlock, ConversionOptions, Definition, Document,
+    DocumentProperties, ExtractedImage, Inline, LineRange, ListItem, Page, Provenance, Table,
+    TableCell,
+};
+pub use nested::NestedConverter;
 pub use prompts::{
     DEFAULT_BATCH_IMAGE_PROMPT, DEFAULT_IMAGE_DESCRIPTION_PROMPT, DEFAULT_PAGE_CONVERSION_PROMPT,
 };
+pub use quotes::QuotedReplyMode;
+pub use render::{MarkdownFlavor, MarkdownRenderer};
+pub use sqlite::{BlobMode, DatabaseOptions};
+pub use table_merge::TableSpan;
 
 /// Main interface for converting documents to markdown
 pub struct MarkItDown {
-    converters: Vec<Box<dyn DocumentConverter>>,
+    converters: Vec<Arc<dyn DocumentConverter>>,
     store: Arc<dyn ObjectStore>,
+    http_fetcher: SharedHttpFetcher,
+    fetch_limits: FetchLimits,
+    language_detection: LanguageDetection,
 }
 
 impl MarkItDown {
@@ -107,6 +147,9 @@ impl MarkItDown {
         let mut md = MarkItDown {
             converters: Vec::new(),
             store,
+            http_fetcher: Arc::new(ReqwestFetcher::new()),
+            fetch_limits: FetchLimits::default(),
+            language_detection: LanguageDetection::default(),
         };
 
         // Document formats
@@ -123,14 +166,23 @@ impl MarkItDown {
         md.register_converter(Box::new(RtfConverter));
         md.register_converter(Box::new(EpubConverter));
         md.register_converter(Box::new(EmailConverter));
+        md.register_converter(Box::new(MboxConverter));
         md.register_converter(Box::new(MarkdownConverter));
 
+        // Saved web page formats
+        md.register_converter(Box::new(MhtmlConverter));
+        md.register_converter(Box::new(WebArchiveConverter));
+        md.register_converter(Box::new(WarcConverter));
+
         // Calendar and contact formats
         md.register_converter(Box::new(ICalendarConverter));
         md.register_converter(Box::new(VCardConverter));
 
         // Database formats
         md.register_converter(Box::new(SqliteConverter));
+        md.register_converter(Box::new(SqlDumpConverter));
+        md.register_converter(Box::new(ParquetConverter));
+        md.register_converter(Box::new(ArrowConverter));
 
         // Data formats
         md.register_converter(Box::new(JsonConverter));
@@ -158,6 +210,8 @@ impl MarkItDown {
         md.register_converter(Box::new(OdtConverter));
         md.register_converter(Box::new(OdsConverter));
         md.register_converter(Box::new(OdpConverter));
+        md.register_converter(Box::new(OdgConverter));
+        md.register_converter(Box::new(FlatOdfConverter));
 
         // Apple iWork formats
         md.register_converter(Box::new(PagesConverter));
@@ -177,6 +231,10 @@ impl MarkItDown {
 
         // Notebook formats
         md.register_converter(Box::new(JupyterConverter));
+        md.register_converter(Box::new(RMarkdownConverter));
+        md.register_converter(Box::new(QuartoConverter));
+        md.register_converter(Box::new(JupytextConverter));
+        md.register_converter(Box::new(ZeppelinConverter));
 
         md
     }
@@ -187,7 +245,13 @@ impl MarkItDown {
     }
 
     pub fn register_converter(&mut self, converter: Box<dyn DocumentConverter>) {
-        self.converters.insert(0, converter);
+        self.converters.insert(0, Arc::from(converter));
+    }
+
+    /// A converter for embedded files (e.g. email attachments) backed by the
+    /// registered converters
+    pub fn nested_converter(&self) -> SharedNestedConverter {
+        Arc::new(ConverterRegistry::new(self.converters.clone()))
     }
 
     /// Get the object store
@@ -195,6 +259,22 @@ impl MarkItDown {
         self.store.clone()
     }
 
+    /// Replace the HTTP client used for URL conversion
+    pub fn set_http_fetcher(&mut self, fetcher: SharedHttpFetcher) {
+        self.http_fetcher = fetcher;
+    }
+
+    /// Set the size and time limits for URL conversion
+    pub fn set_fetch_limits(&mut self, limits: FetchLimits) {
+        self.fetch_limits = limits;
+    }
+
+    /// Choose how converted documents and their pages are tagged with a
+    /// language. Detection is off by default ([`LanguageDetection::Off`]).
+    pub fn set_language_detection(&mut self, mode: LanguageDetection) {
+        self.language_detection = mode;
+    }
+
     fn get_file_type_map() -> HashMap<&'static str, Vec<&'static str>> {
         let mut map = HashMap::new();
         map.insert("application/pdf", vec![".pdf"]);
@@ -216,9 +296,90 @@ impl MarkItDown {
         map.insert("audio/mpeg", vec![".mp3"]);
         map.insert("audio/wav", vec![".wav"]);
         map.insert("application/xml", vec![".xml", ".rss", ".atom"]);
+        map.insert("text/xml", vec![".xml"]);
+        map.insert("application/xhtml+xml", vec![".html"]);
+        map.insert("application/rss+xml", vec![".rss"]);
+        map.insert("application/atom+xml", vec![".atom"]);
+        map.insert("application/json", vec![".json"]);
+        map.insert("text/plain", vec![".txt"]);
+        map.insert("text/markdown", vec![".md"]);
+        map.insert("text/csv", vec![".csv"]);
+        map.insert("application/epub+zip", vec![".epub"]);
+        map.insert("multipart/related", vec![".mht", ".mhtml"]);
+        map.insert("application/x-mimearchive", vec![".mht", ".mhtml"]);
+        map.insert("application/x-webarchive", vec![".webarchive"]);
+        map.insert("application/warc", vec![".warc"]);
+        map.insert("application/mbox", vec![".mbox"]);
+        map.insert("application/sql", vec![".sql"]);
+        map.insert("application/vnd.apache.parquet", vec![".parquet"]);
+        map.insert("application/vnd.apache.arrow.file", vec![".arrow"]);
+        map.insert("application/vnd.apache.arrow.stream", vec![".arrow"]);
+        map.insert(
+            "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+            vec![".pptx"],
+        );
         map
     }
 
+    /// Detect the file type of an HTTP response.
+    /// Priority: 1) `Content-Disposition` file name, 2) `Content-Type`,
+    /// 3) URL path extension, 4) magic bytes. Generic content types such as
+    /// `text/plain` only win when the URL has no known extension.
+    pub fn detect_response_type(&self, response: &HttpResponse) -> Option<String> {
+        let known = |ext: String| self.find_converter(&ext).is_some().then_some(ext);
+        let extension_of = |name: &str| {
+            Path::new(name)
+                .extension()
+                .and_then(|e| e.to_str())
+                .map(|e| format!(".{}", e.to_lowercase()))
+        };
+
+        if let Some(ext) = response
+            .file_name()
+            .and_then(|name| extension_of(&name))
+            .and_then(known)
+        {
+            return Some(ext);
+        }
+
+        let mime = response.mime_type();
+        let generic = mime.as_deref().is_none_or(|m| {
+            matches!(
+                m,
+                "application/octet-stream" | "binary/octet-stream" | "text/plain"
+            )
+        });
+        let from_mime = |mime: &str| {
+            if let Some(extensions) = Self::get_file_type_map().get(mime) {
+                return extensions.first().map(|&ext| ext.to_string());
+            }
+            mime_guess::get_mime_extensions_str(mime)
+                .into_iter()
+                .flatten()
+                .find_map(|ext| known(format!(".{}", ext)))
+        };
+
+        if !generic {
+            if let Some(ext) = mime.as_deref().and_then(from_mime) {
+                return Some(ext);
+            }
+        }
+
+        if let Some(ext) = url::Url::parse(&response.final_url)
+            .ok()
+            .and_then(|url| extension_of(url.path()))
+            .and_then(known)
+        {
+            return Some(ext);
+        }
+
+        self.detect_bytes(&response.body).or_else(|| {
+            mime.as_deref()
+                .filter(|m| *m == "text/plain")
+                .and_then(from_mime)
+        })
+    }
+
     /// Detect file type from path
     /// Priority: 1) File extension (if valid), 2) infer magic bytes, 3) MIME guess
     pub fn detect_file_type(&self, file_path: &str) -> Option<String> {
@@ -279,12 +440,17 @@ impl MarkItDown {
         None
     }
 
-    /// Convert a file from the object store to a Document
+    /// Convert a file from the object store to a Document.
+    /// `http://` and `https://` URLs are fetched via [`MarkItDown::convert_url`].
     pub async fn convert(
         &self,
         path: &str,
         mut options: Option<ConversionOptions>,
     ) -> Result<Document, MarkitdownError> {
+        if is_http_url(path) {
+            return self.convert_url(path, options).await;
+        }
+
         // Detect extension if not provided
         let extension = if let Some(ref opts) = options {
             opts.file_extension.clone()
@@ -300,36 +466,62 @@ impl MarkItDown {
             if opts.image_context_path.is_none() {
                 opts.image_context_path = Some(path.to_string());
             }
+            if opts.nested_converter.is_none() {
+                opts.nested_converter = Some(self.nested_converter());
+            }
         } else {
             options = Some(
                 ConversionOptions::default()
                     .with_extension(extension.clone().unwrap_or_default())
-                    .with_image_context_path(path.to_string()),
+                    .with_image_context_path(path.to_string())
+                    .with_nested_converter(self.nested_converter()),
             );
         }
 
         let ext = extension.as_deref().unwrap_or("");
+        let local_path = Path::new(path);
+
+        // Mailboxes can be larger than memory, so they are read as they are
+        // converted
+        if MaildirConverter::is_maildir(local_path) {
+            return MaildirConverter
+                .convert_dir(local_path, options)
+                .await
+                .map(|document| self.finish(document, ext));
+        }
+        if (ext == ".mbox" || ext == ".mbx") && local_path.is_file() {
+            return MboxConverter
+                .convert_file(local_path, options)
+                .await
+                .map(|document| self.finish(document, ext));
+        }
 
         // Handle ZIP files specially
         if ext == ".zip" || ext == "zip" {
-            return self.convert_zip_file(path, options).await;
+            return self
+                .convert_zip_file(path, options)
+                .await
+                .map(|document| self.finish(document, ext));
         }
 
         // Find converter
         if let Some(converter) = self.find_converter(ext) {
             // Check if the path is a local file - if so, read it and use convert_bytes
             // This makes local file handling work seamlessly with any ObjectStore
-            let local_path = Path::new(path);
             if local_path.exists() {
                 let bytes = fs::read(path)?;
-                return converter.convert_bytes(Bytes::from(bytes), options).await;
+                return converter
+                    .convert_bytes(Bytes::from(bytes), options)
+                    .await
+                    .map(|document| self.finish(document, ext));
             }
 
             // Otherwise, use the object store
             let obj_path = object_store::path::Path::from(path);
             return converter
                 .convert(self.store.clone(), &obj_path, options)
-                .await;
+                .await
+                .map(|document| self.finish(document, ext));
         }
 
         Err(MarkitdownError::UnsupportedFormat(format!(
@@ -356,9 +548,14 @@ impl MarkItDown {
             if opts.file_extension.is_none() {
                 opts.file_extension = extension.clone();
             }
+            if opts.nested_converter.is_none() {
+                opts.nested_converter = Some(self.nested_converter());
+            }
         } else {
             options = Some(
-                ConversionOptions::default().with_extension(extension.clone().unwrap_or_default()),
+                ConversionOptions::default()
+                    .with_extension(extension.clone().unwrap_or_default())
+                    .with_nested_converter(self.nested_converter()),
             );
         }
 
@@ -366,12 +563,18 @@ impl MarkItDown {
 
         // Handle ZIP files specially
         if ext == ".zip" || ext == "zip" {
-            return self.convert_zip_bytes(&bytes, options).await;
+            return self
+                .convert_zip_bytes(&bytes, options)
+                .await
+                .map(|document| self.finish(document, ext));
         }
 
         // Find converter
         if let Some(converter) = self.find_converter(ext) {
-            return converter.convert_bytes(bytes, options).await;
+            return converter
+                .convert_bytes(bytes, options)
+                .await
+                .map(|document| self.finish(document, ext));
         }
 
         Err(MarkitdownError::UnsupportedFormat(format!(
@@ -380,15 +583,93 @@ impl MarkItDown {
         )))
     }
 
+    /// Fetch a document over HTTP(S) and convert it.
+    ///
+    /// The format comes from the options, then the response headers, the URL
+    /// and finally the content. Relative links resolve against the final URL
+    /// (after redirects). The requested URL, final URL, content type and fetch
+    /// time are recorded in the document metadata.
+    pub async fn convert_url(
+        &self,
+        url: &str,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let request = self.http_fetcher.get(url, &self.fetch_limits);
+        let response = match self.fetch_limits.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, request).await.map_err(|_| {
+                MarkitdownError::NetworkError(format!(
+                    "Timed out after {:?} fetching {}",
+                    timeout, url
+                ))
+            })??,
+            None => request.await?,
+        };
+        let fetched_at = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+
+        if !response.is_success() {
+            return Err(MarkitdownError::NetworkError(format!(
+                "HTTP {} fetching {}",
+                response.status, url
+            )));
+        }
+        self.fetch_limits
+            .check_size(response.body.len() as u64, url)?;
+
+        let mut options = options.unwrap_or_default();
+        if options.file_extension.is_none() {
+            options.file_extension = self.detect_response_type(&response);
+        }
+        if options.url.is_none() {
+            options.url = Some(response.final_url.clone());
+        }
+        if options.image_context_path.is_none() {
+            options.image_context_path = Some(response.final_url.clone());
+        }
+
+        let mut document = self
+            .convert_bytes(response.body.clone(), Some(options))
+            .await?;
+
+        document
+            .metadata
+            .insert("source_url".to_string(), url.to_string());
+        document
+            .metadata
+            .insert("final_url".to_string(), response.final_url.clone());
+        document
+            .metadata
+            .insert("fetched_at".to_string(), fetched_at);
+        if let Some(content_type) = &response.content_type {
+            document
+                .metadata
+                .insert("content_type".to_string(), content_type.clone());
+        }
+        if let Some(file_name) = response.file_name() {
+            document.metadata.insert("file_name".to_string(), file_name);
+        }
+
+        Ok(document)
+    }
+
     /// Convert a local file to markdown (convenience method)
     pub async fn convert_file(&self, file_path: &str) -> Result<String, MarkitdownError> {
+        self.convert_file_with(file_path, &MarkdownRenderer::default())
+            .await
+    }
+
+    /// Convert a local file to markdown rendered with `renderer`
+    pub async fn convert_file_with(
+        &self,
+        file_path: &str,
+        renderer: &MarkdownRenderer,
+    ) -> Result<String, MarkitdownError> {
         // Read file and convert
         let bytes = fs::read(file_path)?;
         let extension = self.detect_file_type(file_path);
         let options = extension.map(|ext| ConversionOptions::default().with_extension(ext));
 
         let document = self.convert_bytes(Bytes::from(bytes), options).await?;
-        Ok(document.to_markdown())
+        Ok(document.to_markdown_with(renderer))
     }
 
     /// Convert bytes to a legacy DocumentConverterResult
@@ -443,6 +724,10 @@ impl MarkItDown {
         bytes: &[u8],
         options: Option<ConversionOptions>,
     ) -> Result<Document, MarkitdownError> {
+        if MaildirConverter::is_maildir_zip(bytes) {
+            return MaildirConverter.convert_zip(bytes, options).await;
+        }
+
         let cursor = Cursor::new(bytes);
         let mut archive = ZipArchive::new(cursor)?;
 
@@ -516,6 +801,17 @@ impl MarkItDown {
 
         Ok(document)
     }
+
+    /// Record the format a document was converted from, unless its converter
+    /// did, and tag it with its languages
+    fn finish(&self, mut document: Document, extension: &str) -> Document {
+        let format = extension.trim_start_matches('.');
+        if document.properties.source_format.is_none() && !format.is_empty() {
+            document.properties.source_format = Some(format.to_lowercase());
+        }
+        language::tag_languages(&mut document, self.language_detection);
+        document
+    }
 }
 
 impl Default for MarkItDown {
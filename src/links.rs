@@ -0,0 +1,107 @@
+//! Link and image URL resolution.
+//!
+//! Converters that know where a document came from (via
+//! `ConversionOptions::url` or an HTML `<base href>`) use these helpers to turn
+//! relative link and image targets into absolute URLs.
+
+use regex::{Captures, Regex};
+use std::sync::LazyLock;
+use url::Url;
+
+/// `<base href="...">` inside raw HTML
+static BASE_HREF_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?is)<base\b[^>]*?\bhref\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s>]+))"#).unwrap()
+});
+
+/// Inline markdown links and images: `[text](target "title")`
+static INLINE_LINK_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(!?\[[^\]]*\]\()(\s*)(<[^>\n]*>|[^)\s]+)").unwrap());
+
+/// Reference definitions: `[id]: target "title"`
+static REFERENCE_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\s{0,3}\[[^\]]+\]:\s*)(<[^>\n]*>|\S+)").unwrap());
+
+/// Determine the base URL for a document.
+///
+/// A `<base href>` wins over the document URL; a relative `<base href>` is
+/// resolved against the document URL. Returns `None` when neither yields an
+/// absolute URL.
+pub fn base_url(document_url: Option<&str>, base_href: Option<&str>) -> Option<Url> {
+    let document_url = document_url.and_then(|u| Url::parse(u.trim()).ok());
+    let base_href = base_href.map(str::trim).filter(|h| !h.is_empty());
+
+    match (document_url, base_href) {
+        (Some(url), Some(href)) => url.join(href).ok().or(Some(url)),
+        (None, Some(href)) => Url::parse(href).ok(),
+        (url, None) => url,
+    }
+}
+
+/// Find the `href` of the first `<base>` element in raw HTML
+pub fn find_base_href(html: &str) -> Option<String> {
+    let caps = BASE_HREF_PATTERN.captures(html)?;
+    caps.get(1)
+        .or_else(|| caps.get(2))
+        .or_else(|| caps.get(3))
+        .map(|m| m.as_str().trim().to_string())
+        .filter(|h| !h.is_empty())
+}
+
+/// Resolve a link or image target against a base URL.
+///
+/// Absolute URLs (any scheme, including `mailto:` and `data:`), fragment-only
+/// links and empty targets are returned unchanged, as is everything when no
+/// base is known.
+pub fn resolve_url(base: Option<&Url>, target: &str) -> String {
+    let target = target.trim();
+    let Some(base) = base else {
+        return target.to_string();
+    };
+    if target.is_empty() || target.starts_with('#') || Url::parse(target).is_ok() {
+        return target.to_string();
+    }
+    base.join(target)
+        .map(|u| u.to_string())
+        .unwrap_or_else(|_| target.to_string())
+}
+
+/// Rewrite relative inline links, images and reference definitions in
+/// markdown to absolute URLs. Fenced code blocks are left untouched.
+pub fn resolve_markdown_links(markdown: &str, base: &Url) -> String {
+    let mut out = Vec::new();
+    let mut fence: Option<&str> = None;
+
+    for line in markdown.split('\n') {
+        let trimmed = line.trim_start();
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            out.push(line.to_string());
+            continue;
+        }
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            fence = Some(&trimmed[..3]);
+            out.push(line.to_string());
+            continue;
+        }
+
+        let line = REFERENCE_PATTERN.replace(line, |caps: &Captures| {
+            format!("{}{}", &caps[1], resolve_target(base, &caps[2]))
+        });
+        let line = INLINE_LINK_PATTERN.replace_all(&line, |caps: &Captures| {
+            format!("{}{}{}", &caps[1], &caps[2], resolve_target(base, &caps[3]))
+        });
+        out.push(line.into_owned());
+    }
+
+    out.join("\n")
+}
+
+/// Resolve a markdown link target, keeping angle-bracket syntax
+fn resolve_target(base: &Url, target: &str) -> String {
+    match target.strip_prefix('<').and_then(|t| t.strip_suffix('>')) {
+        Some(inner) => format!("<{}>", resolve_url(Some(base), inner)),
+        None => resolve_url(Some(base), target),
+    }
+}
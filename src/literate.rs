@@ -0,0 +1,574 @@
+//! R Markdown, Quarto, Jupytext and Zeppelin notebook converters.
+//!
+//! These formats mix prose with code. Like the Jupyter converter, prose
+//! becomes markdown blocks and code chunks fenced code blocks. Chunk options
+//! are kept in the info string after the language (`{r setup, include=FALSE}`
+//! becomes `r setup, include=FALSE`, and Quarto's `#| echo: false` lines
+//! become `echo=false`). YAML headers fill the document title and metadata.
+
+use async_trait::async_trait;
+use base64::prelude::*;
+use bytes::Bytes;
+use object_store::ObjectStore;
+use pulldown_cmark::MetadataBlockKind;
+use regex::Regex;
+use serde::Deserialize;
+use std::sync::{Arc, LazyLock};
+
+use crate::data::CodeConverter;
+use crate::error::MarkitdownError;
+use crate::jupyter::OutputLimits;
+use crate::markdown::front_matter_metadata;
+use crate::model::{
+    ContentBlock, ConversionOptions, Document, DocumentConverter, ExtractedImage, Page,
+};
+
+/// Opening line of an executable chunk: ```` ```{r name, echo=FALSE} ````
+static CHUNK_START: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*(`{3,})\s*\{([A-Za-z][\w.-]*)[\s,]*(.*?)\s*\}\s*$").unwrap());
+
+/// Opening line of any other fenced block, whose contents are left alone
+static FENCE_START: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\s*(`{3,}|~{3,})").unwrap());
+
+/// Quarto chunk option comment: `#| echo: false`
+static CHUNK_OPTION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^#\|\s*([\w.-]+)\s*:\s*(.*?)\s*$").unwrap());
+
+/// Jupytext percent-format cell marker: `# %% Title [markdown] key="value"`
+static PERCENT_CELL: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^# %%(?:\s+(.*))?$").unwrap());
+
+/// Cell type in a percent-format cell marker
+static CELL_TYPE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\[(\w+)\]").unwrap());
+
+/// Split a leading `---` YAML header from the rest of the text
+fn split_yaml_header(text: &str) -> (Option<String>, String) {
+    let mut lines = text.lines();
+    if lines.next().map(str::trim_end) != Some("---") {
+        return (None, text.to_string());
+    }
+    let mut header = Vec::new();
+    for line in lines.by_ref() {
+        if matches!(line.trim_end(), "---" | "...") {
+            return (
+                Some(header.join("\n")),
+                lines.collect::<Vec<_>>().join("\n"),
+            );
+        }
+        header.push(line);
+    }
+    (None, text.to_string())
+}
+
+/// Document title and metadata from a YAML header
+fn apply_yaml_header(document: &mut Document, header: &str) {
+    document.metadata = front_matter_metadata(MetadataBlockKind::YamlStyle, header);
+    document.title = document.metadata.remove("title");
+    crate::metadata::fill_from_metadata(&mut document.properties, &document.metadata);
+}
+
+/// Code block info string: the language followed by the chunk options
+fn info_string(language: &str, options: &[String]) -> String {
+    let options: Vec<&str> = options
+        .iter()
+        .map(|o| o.trim())
+        .filter(|o| !o.is_empty())
+        .collect();
+    if options.is_empty() {
+        language.to_string()
+    } else {
+        format!("{} {}", language, options.join(", "))
+    }
+}
+
+/// Drop blank lines around a cell body
+fn trim_blank_lines<'a, 'b>(lines: &'b [&'a str]) -> &'b [&'a str] {
+    let start = lines.iter().position(|l| !l.trim().is_empty());
+    let end = lines.iter().rposition(|l| !l.trim().is_empty());
+    match (start, end) {
+        (Some(start), Some(end)) => &lines[start..=end],
+        _ => &[],
+    }
+}
+
+fn push_markdown(page: &mut Page, lines: &[&str]) {
+    let lines = trim_blank_lines(lines);
+    if !lines.is_empty() {
+        page.add_content(ContentBlock::Markdown(format!("{}\n", lines.join("\n"))));
+    }
+}
+
+/// Whether a line closes a fence opened with `fence`
+fn closes_fence(line: &str, fence: &str) -> bool {
+    let line = line.trim();
+    let marker = fence.chars().next().unwrap_or('`');
+    line.len() >= fence.len() && line.chars().all(|c| c == marker)
+}
+
+/// Prose and executable chunks of an R Markdown or Quarto document
+fn literate_document(bytes: &[u8]) -> Document {
+    let text = String::from_utf8_lossy(bytes).replace("\r\n", "\n");
+    let (header, body) = split_yaml_header(&text);
+
+    let mut document = Document::new();
+    if let Some(header) = header {
+        apply_yaml_header(&mut document, &header);
+    }
+
+    let mut page = Page::new(1);
+    let mut prose: Vec<&str> = Vec::new();
+    let mut open_fence: Option<String> = None;
+    let mut lines = body.lines();
+    while let Some(line) = lines.next() {
+        if let Some(fence) = &open_fence {
+            if closes_fence(line, fence) {
+                open_fence = None;
+            }
+            prose.push(line);
+            continue;
+        }
+
+        if let Some(caps) = CHUNK_START.captures(line) {
+            push_markdown(&mut page, &prose);
+            prose.clear();
+
+            let mut code = Vec::new();
+            for line in lines.by_ref() {
+                if closes_fence(line, &caps[1]) {
+                    break;
+                }
+                code.push(line);
+            }
+
+            // Quarto keeps options in `#|` comments at the top of the chunk
+            let mut options = vec![caps[3].to_string()];
+            let mut code = trim_blank_lines(&code);
+            while let Some(option) = code.first().and_then(|l| CHUNK_OPTION.captures(l)) {
+                options.push(format!("{}={}", &option[1], &option[2]));
+                code = &code[1..];
+            }
+
+            page.add_content(ContentBlock::Code {
+                language: Some(info_string(&caps[2], &options)),
+                code: trim_blank_lines(code).join("\n"),
+            });
+            continue;
+        }
+
+        if let Some(caps) = FENCE_START.captures(line) {
+            open_fence = Some(caps[1].to_string());
+        }
+        prose.push(line);
+    }
+    push_markdown(&mut page, &prose);
+
+    document.add_page(page);
+    document
+}
+
+/// R Markdown (.Rmd) converter
+pub struct RMarkdownConverter;
+
+#[async_trait]
+impl DocumentConverter for RMarkdownConverter {
+    async fn convert(
+        &self,
+        store: Arc<dyn ObjectStore>,
+        path: &object_store::path::Path,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let result = store.get(path).await?;
+        let bytes = result.bytes().await?;
+        self.convert_bytes(bytes, options).await
+    }
+
+    async fn convert_bytes(
+        &self,
+        bytes: Bytes,
+        _options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        Ok(literate_document(&bytes))
+    }
+
+    fn supported_extensions(&self) -> &[&str] {
+        &[".rmd"]
+    }
+}
+
+/// Quarto (.qmd) converter
+pub struct QuartoConverter;
+
+#[async_trait]
+impl DocumentConverter for QuartoConverter {
+    async fn convert(
+        &self,
+        store: Arc<dyn ObjectStore>,
+        path: &object_store::path::Path,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let result = store.get(path).await?;
+        let bytes = result.bytes().await?;
+        self.convert_bytes(bytes, options).await
+    }
+
+    async fn convert_bytes(
+        &self,
+        bytes: Bytes,
+        _options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        Ok(literate_document(&bytes))
+    }
+
+    fn supported_extensions(&self) -> &[&str] {
+        &[".qmd"]
+    }
+}
+
+/// Jupytext percent-format (.py) converter. Scripts without `# %%` cell
+/// markers are converted as plain source code.
+pub struct JupytextConverter;
+
+impl JupytextConverter {
+    fn is_percent_script(text: &str) -> bool {
+        text.lines().any(|line| PERCENT_CELL.is_match(line))
+    }
+
+    fn convert_script(text: &str) -> Document {
+        let text = text.replace("\r\n", "\n");
+        let mut lines: Vec<&str> = text.lines().collect();
+
+        // The notebook metadata is a commented YAML header
+        let mut document = Document::new();
+        if lines.first().map(|l| l.trim_end()) == Some("# ---") {
+            if let Some(end) = lines.iter().skip(1).position(|l| l.trim_end() == "# ---") {
+                let header: Vec<&str> = lines[1..=end]
+                    .iter()
+                    .map(|l| l.strip_prefix("# ").unwrap_or(l.trim_start_matches('#')))
+                    .collect();
+                apply_yaml_header(&mut document, &header.join("\n"));
+                lines.drain(..end + 2);
+            }
+        }
+        let language = document
+            .metadata
+            .get("jupyter.kernelspec.language")
+            .cloned()
+            .unwrap_or_else(|| "python".to_string());
+
+        let mut page = Page::new(1);
+        let mut kind = "code".to_string();
+        let mut options = String::new();
+        let mut cell: Vec<&str> = Vec::new();
+        for line in lines.iter().copied().chain(std::iter::once("# %%")) {
+            let Some(caps) = PERCENT_CELL.captures(line) else {
+                cell.push(line);
+                continue;
+            };
+
+            match kind.as_str() {
+                "markdown" | "md" => {
+                    let uncommented: Vec<&str> = cell
+                        .iter()
+                        .map(|l| l.strip_prefix("# ").unwrap_or(l.trim_start_matches('#')))
+                        .collect();
+                    push_markdown(&mut page, &uncommented);
+                }
+                _ => {
+                    let code = trim_blank_lines(&cell);
+                    if !code.is_empty() {
+                        page.add_content(ContentBlock::Code {
+                            language: (kind != "raw")
+                                .then(|| info_string(&language, &[options.clone()])),
+                            code: code.join("\n"),
+                        });
+                    }
+                }
+            }
+
+            let marker = caps.get(1).map_or("", |m| m.as_str());
+            kind = CELL_TYPE
+                .captures(marker)
+                .map_or("code".to_string(), |c| c[1].to_lowercase());
+            options = CELL_TYPE.replace(marker, "").trim().to_string();
+            cell.clear();
+        }
+
+        document.add_page(page);
+        document
+    }
+}
+
+#[async_trait]
+impl DocumentConverter for JupytextConverter {
+    async fn convert(
+        &self,
+        store: Arc<dyn ObjectStore>,
+        path: &object_store::path::Path,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let result = store.get(path).await?;
+        let bytes = result.bytes().await?;
+        self.convert_bytes(bytes, options).await
+    }
+
+    async fn convert_bytes(
+        &self,
+        bytes: Bytes,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let text = String::from_utf8_lossy(&bytes);
+        if Self::is_percent_script(&text) {
+            return Ok(Self::convert_script(&text));
+        }
+
+        let mut options = options.unwrap_or_default();
+        options
+            .file_extension
+            .get_or_insert_with(|| ".py".to_string());
+        CodeConverter.convert_bytes(bytes, Some(options)).await
+    }
+
+    fn supported_extensions(&self) -> &[&str] {
+        &[".py"]
+    }
+}
+
+/// Apache Zeppelin notebook (.zpln) converter
+pub struct ZeppelinConverter;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ZeppelinNote {
+    name: Option<String>,
+    id: Option<String>,
+    default_interpreter_group: Option<String>,
+    #[serde(default)]
+    paragraphs: Vec<Paragraph>,
+}
+
+#[derive(Deserialize)]
+struct Paragraph {
+    title: Option<String>,
+    text: Option<String>,
+    config: Option<ParagraphConfig>,
+    results: Option<ParagraphResults>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ParagraphConfig {
+    editor_setting: Option<EditorSetting>,
+}
+
+#[derive(Deserialize)]
+struct EditorSetting {
+    language: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ParagraphResults {
+    code: Option<String>,
+    #[serde(default)]
+    msg: Vec<ResultMessage>,
+}
+
+#[derive(Deserialize)]
+struct ResultMessage {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    data: String,
+}
+
+impl ZeppelinConverter {
+    /// Language of an interpreter such as `spark.pyspark` or `jdbc(postgres)`
+    fn interpreter_language(interpreter: &str) -> String {
+        let name = interpreter.split('(').next().unwrap_or(interpreter);
+        let name = name.rsplit('.').next().unwrap_or(name).to_lowercase();
+        match name.as_str() {
+            "md" | "markdown" => "markdown",
+            "python" | "pyspark" | "ipython" | "ipyspark" => "python",
+            "sql" | "jdbc" | "hive" | "bigquery" | "postgresql" => "sql",
+            "sh" | "shell" => "bash",
+            "spark" | "scala" => "scala",
+            "r" | "sparkr" | "ir" => "r",
+            other => other,
+        }
+        .to_string()
+    }
+
+    fn convert_note(
+        bytes: &[u8],
+        limits: &OutputLimits,
+        extract_images: bool,
+    ) -> Result<Document, MarkitdownError> {
+        let note: ZeppelinNote = serde_json::from_slice(bytes)
+            .map_err(|e| MarkitdownError::ParseError(format!("Invalid Zeppelin note: {}", e)))?;
+
+        let mut document = Document::new();
+        document.title = note.name.clone();
+        if let Some(id) = &note.id {
+            document.metadata.insert("id".to_string(), id.clone());
+        }
+        if let Some(group) = &note.default_interpreter_group {
+            document
+                .metadata
+                .insert("interpreter".to_string(), group.clone());
+        }
+
+        let mut page = Page::new(1);
+        let mut images = 0;
+        for paragraph in &note.paragraphs {
+            let text = paragraph.text.as_deref().unwrap_or_default();
+            let (interpreter, source) = match text.strip_prefix('%') {
+                Some(rest) => {
+                    let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+                    (Some(&rest[..end]), &rest[end..])
+                }
+                None => (None, text),
+            };
+            let language = paragraph
+                .config
+                .as_ref()
+                .and_then(|c| c.editor_setting.as_ref())
+                .and_then(|e| e.language.clone())
+                .or_else(|| interpreter.map(Self::interpreter_language))
+                .or_else(|| {
+                    note.default_interpreter_group
+                        .as_deref()
+                        .map(Self::interpreter_language)
+                })
+                .unwrap_or_default();
+            let source = source.trim_matches('\n');
+
+            if let Some(title) = &paragraph.title {
+                if !title.trim().is_empty() {
+                    page.add_content(ContentBlock::Heading {
+                        level: 2,
+                        text: title.trim().to_string(),
+                    });
+                }
+            }
+
+            // Markdown paragraphs are shown as their source, not the rendered HTML
+            if language == "markdown" {
+                let lines: Vec<&str> = source.lines().collect();
+                push_markdown(&mut page, &lines);
+                continue;
+            }
+            if !source.trim().is_empty() {
+                let options: Vec<String> = interpreter
+                    .filter(|i| *i != language)
+                    .map(|i| format!("interpreter={}", i))
+                    .into_iter()
+                    .collect();
+                page.add_content(ContentBlock::Code {
+                    language: Some(info_string(&language, &options)),
+                    code: source.to_string(),
+                });
+            }
+
+            let Some(results) = &paragraph.results else {
+                continue;
+            };
+            let blocks: Vec<ContentBlock> = results
+                .msg
+                .iter()
+                .filter_map(|msg| Self::result_block(msg, limits, extract_images, &mut images))
+                .collect();
+            if blocks.is_empty() {
+                continue;
+            }
+            let label = match results.code.as_deref() {
+                Some("ERROR") => "**Error:**",
+                _ => "**Out:**",
+            };
+            page.add_content(ContentBlock::Markdown(format!("{}\n", label)));
+            for block in blocks {
+                page.add_content(block);
+            }
+        }
+
+        document.add_page(page);
+        Ok(document)
+    }
+
+    fn result_block(
+        msg: &ResultMessage,
+        limits: &OutputLimits,
+        extract_images: bool,
+        images: &mut usize,
+    ) -> Option<ContentBlock> {
+        match msg.kind.as_str() {
+            "TEXT" if !msg.data.trim().is_empty() => Some(ContentBlock::Code {
+                language: None,
+                code: limits.apply(&msg.data),
+            }),
+            "HTML" => {
+                let markdown = html2md::parse_html(&msg.data);
+                let markdown = markdown.trim();
+                (!markdown.is_empty()).then(|| ContentBlock::Markdown(format!("{}\n", markdown)))
+            }
+            // Tab-separated rows with a header line
+            "TABLE" => {
+                let mut lines = msg.data.lines().filter(|l| !l.is_empty());
+                let headers: Vec<String> = lines.next()?.split('\t').map(String::from).collect();
+                let rows = lines
+                    .map(|l| l.split('\t').map(String::from).collect())
+                    .collect();
+                Some(ContentBlock::Table { headers, rows })
+            }
+            "IMG" if extract_images => {
+                let encoded: String = msg.data.chars().filter(|c| !c.is_whitespace()).collect();
+                let data = BASE64_STANDARD.decode(encoded).ok()?;
+                *images += 1;
+                let mut image = ExtractedImage::new(
+                    format!("notebook_image_{}", images),
+                    Bytes::from(data),
+                    "image/png",
+                );
+                image.page_number = Some(1);
+                Some(ContentBlock::Image(image))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[async_trait]
+impl DocumentConverter for ZeppelinConverter {
+    async fn convert(
+        &self,
+        store: Arc<dyn ObjectStore>,
+        path: &object_store::path::Path,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let result = store.get(path).await?;
+        let bytes = result.bytes().await?;
+        self.convert_bytes(bytes, options).await
+    }
+
+    async fn convert_bytes(
+        &self,
+        bytes: Bytes,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let opts = options.clone().unwrap_or_default();
+        let mut document = Self::convert_note(&bytes, &opts.notebook_outputs, opts.extract_images)?;
+
+        // If LLM client is provided, get descriptions for all images
+        if let Some(ref llm_client) = opts.llm_client {
+            if let Some(path) = opts.image_context_path.as_deref() {
+                document.apply_image_context_path(path);
+            }
+            document = document
+                .with_image_descriptions(llm_client.as_ref())
+                .await?;
+        }
+
+        Ok(document)
+    }
+
+    fn supported_extensions(&self) -> &[&str] {
+        &[".zpln"]
+    }
+}
@@ -27,10 +27,12 @@ use rig::{
     message::{ImageDetail, ImageMediaType, Message, UserContent},
     OneOrMany,
 };
+use std::collections::HashMap;
 use std::io::Cursor;
 use std::sync::Arc;
 
 use crate::error::MarkitdownError;
+use crate::language::primary_language;
 use crate::model::ExtractedImage;
 use crate::prompts::{
     DEFAULT_BATCH_IMAGE_PROMPT, DEFAULT_IMAGE_DESCRIPTION_PROMPT, DEFAULT_PAGE_CONVERSION_PROMPT,
@@ -43,6 +45,10 @@ pub struct LlmConfig {
     pub image_description_prompt: String,
     /// System prompt for full page conversion (PDF pages rendered as images)
     pub page_conversion_prompt: String,
+    /// Page conversion prompts for pages in a known language, keyed by
+    /// primary language subtag ("de", "ja"); other pages use
+    /// `page_conversion_prompt`
+    pub page_conversion_prompts: HashMap<String, String>,
     /// System prompt for batch image description (when images_per_message > 1)
     pub batch_image_prompt: String,
     /// Temperature for LLM responses (0.0 = deterministic, 1.0 = creative)
@@ -75,6 +81,7 @@ impl Default for LlmConfig {
         Self {
             image_description_prompt: DEFAULT_IMAGE_DESCRIPTION_PROMPT.to_string(),
             page_conversion_prompt: DEFAULT_PAGE_CONVERSION_PROMPT.to_string(),
+            page_conversion_prompts: HashMap::new(),
             batch_image_prompt: DEFAULT_BATCH_IMAGE_PROMPT.to_string(),
             temperature: 0.1, // Low temperature for accurate extraction with minimal repetition
             images_per_message: 1,
@@ -102,6 +109,28 @@ impl LlmConfig {
         self
     }
 
+    /// Set the page conversion prompt for pages in a language (a BCP 47 tag
+    /// such as "de" or "pt-BR"; only the primary subtag is matched)
+    pub fn with_page_prompt_for(
+        mut self,
+        language: impl AsRef<str>,
+        prompt: impl Into<String>,
+    ) -> Self {
+        self.page_conversion_prompts
+            .insert(primary_language(language.as_ref()), prompt.into());
+        self
+    }
+
+    /// The page conversion prompt for a page in `language`
+    pub fn page_prompt(&self, language: Option<&str>) -> &str {
+        language
+            .and_then(|language| {
+                self.page_conversion_prompts
+                    .get(&primary_language(language))
+            })
+            .unwrap_or(&self.page_conversion_prompt)
+    }
+
     /// Set the batch image prompt
     pub fn with_batch_prompt(mut self, prompt: impl Into<String>) -> Self {
         self.batch_image_prompt = prompt.into();
@@ -192,6 +221,28 @@ pub trait LlmClient: Send + Sync {
         pages: &[(&[u8], &str)], // (image_data, mime_type) pairs
     ) -> Vec<Option<String>>;
 
+    /// Convert a page image whose text is in `language` (a BCP 47 tag).
+    /// Clients without language-specific prompts convert it like any page.
+    async fn convert_page_image_in_language(
+        &self,
+        image_data: &[u8],
+        mime_type: &str,
+        language: Option<&str>,
+    ) -> Result<String, MarkitdownError> {
+        let _ = language;
+        self.convert_page_image(image_data, mime_type).await
+    }
+
+    /// Convert multiple page images in `language` to markdown in parallel
+    async fn convert_page_images_batch_in_language(
+        &self,
+        pages: &[(&[u8], &str)],
+        language: Option<&str>,
+    ) -> Vec<Option<String>> {
+        let _ = language;
+        self.convert_page_images_batch(pages).await
+    }
+
     /// Generate a text completion
     async fn complete(&self, prompt: &str) -> Result<String, MarkitdownError>;
 
@@ -906,6 +957,16 @@ impl<M: CompletionModel + Send + Sync + 'static> LlmClient for LlmWrapper<M> {
         &self,
         image_data: &[u8],
         mime_type: &str,
+    ) -> Result<String, MarkitdownError> {
+        self.convert_page_image_in_language(image_data, mime_type, None)
+            .await
+    }
+
+    async fn convert_page_image_in_language(
+        &self,
+        image_data: &[u8],
+        mime_type: &str,
+        language: Option<&str>,
     ) -> Result<String, MarkitdownError> {
         // Prepare the image, converting if necessary
         let (base64_data, image_type) = match prepare_image_for_llm(image_data, mime_type) {
@@ -926,20 +987,32 @@ impl<M: CompletionModel + Send + Sync + 'static> LlmClient for LlmWrapper<M> {
             Some(image_type),
             Some(ImageDetail::High), // Use high detail for page conversion
         ));
-        content.push(UserContent::text(
-            "Convert this page to markdown. Output only the content, no commentary.",
-        ));
+        let mut instruction =
+            "Convert this page to markdown. Output only the content, no commentary.".to_string();
+        if let Some(language) = language {
+            instruction.push_str(&format!(
+                " The page is written in language \"{}\".",
+                language
+            ));
+        }
+        content.push(UserContent::text(instruction));
 
         let request = self
-            .build_request(&self.config.page_conversion_prompt, content)
+            .build_request(self.config.page_prompt(language), content)
             .build();
 
         self.send_request(request).await
     }
 
-    async fn convert_page_images_batch(
+    async fn convert_page_images_batch(&self, pages: &[(&[u8], &str)]) -> Vec<Option<String>> {
+        self.convert_page_images_batch_in_language(pages, None)
+            .await
+    }
+
+    async fn convert_page_images_batch_in_language(
         &self,
         pages: &[(&[u8], &str)],
+        language: Option<&str>,
     ) -> Vec<Option<String>> {
         if pages.is_empty() {
             return Vec::new();
@@ -953,7 +1026,10 @@ impl<M: CompletionModel + Send + Sync + 'static> LlmClient for LlmWrapper<M> {
             let futures: Vec<_> = chunk
                 .iter()
                 .map(|(data, mime)| async move {
-                    match self.convert_page_image(data, mime).await {
+                    match self
+                        .convert_page_image_in_language(data, mime, language)
+                        .await
+                    {
                         Ok(markdown) if !markdown.trim().is_empty() => Some(markdown),
                         _ => None,
                     }
@@ -1139,10 +1215,7 @@ impl LlmClient for MockLlmClient {
         Ok(self.text_response.clone())
     }
 
-    async fn convert_page_images_batch(
-        &self,
-        pages: &[(&[u8], &str)],
-    ) -> Vec<Option<String>> {
+    async fn convert_page_images_batch(&self, pages: &[(&[u8], &str)]) -> Vec<Option<String>> {
         vec![Some(self.text_response.clone()); pages.len()]
     }
 
@@ -1293,6 +1366,18 @@ mod tests {
         assert!(result4.contains("👋"));
     }
 
+    #[test]
+    fn test_page_prompt_by_language() {
+        let config = LlmConfig::default().with_page_prompt_for("de-DE", "Seite umwandeln");
+        assert_eq!(config.page_prompt(Some("de")), "Seite umwandeln");
+        assert_eq!(config.page_prompt(Some("DE-AT")), "Seite umwandeln");
+        assert_eq!(
+            config.page_prompt(Some("fr")),
+            DEFAULT_PAGE_CONVERSION_PROMPT
+        );
+        assert_eq!(config.page_prompt(None), DEFAULT_PAGE_CONVERSION_PROMPT);
+    }
+
     #[test]
     fn test_detect_and_truncate_repetition_short_text() {
         // Short text should be returned as-is
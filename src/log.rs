@@ -1,111 +1,795 @@
 //! Log file to Markdown converter.
 //!
-//! Parses common log file formats and converts to markdown with syntax highlighting.
+//! Detects the format of a log (syslog, JSON lines, Apache/Nginx access logs,
+//! logfmt or timestamped text such as log4j output) and parses each event's
+//! timestamp, level, source and message into an events table. Lines that do
+//! not start an event, such as Java and Python stack traces, are grouped with
+//! the event above them. The summary covers the time range, event rate and
+//! level counts; errors and warnings are listed with their full text.
 
 use async_trait::async_trait;
 use bytes::Bytes;
+use chrono::{DateTime, Datelike, NaiveDateTime, Timelike};
 use object_store::ObjectStore;
 use regex::Regex;
-use std::sync::Arc;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, LazyLock};
 
 use crate::error::MarkitdownError;
 use crate::model::{ContentBlock, ConversionOptions, Document, DocumentConverter, Page};
 
+/// Events shown in the events table
+const MAX_EVENT_ROWS: usize = 200;
+
+/// Errors and warnings listed with their full text
+const MAX_LISTED_EVENTS: usize = 50;
+
+/// Lines inspected to detect the format
+const DETECTION_LINES: usize = 50;
+
+/// Whether the full log text is included below the analysis
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FullLog {
+    /// Always include the full log
+    #[default]
+    Always,
+    /// Include the full log only when it has at most this many lines
+    UpToLines(usize),
+    /// Only show the analysis
+    Never,
+}
+
+impl FullLog {
+    fn includes(&self, lines: usize) -> bool {
+        match self {
+            FullLog::Always => true,
+            FullLog::UpToLines(limit) => lines <= *limit,
+            FullLog::Never => false,
+        }
+    }
+}
+
+/// Log formats recognized by [`LogConverter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LogFormat {
+    Syslog,
+    JsonLines,
+    AccessLog,
+    Logfmt,
+    Timestamped,
+    Plain,
+}
+
+impl LogFormat {
+    fn name(&self) -> &'static str {
+        match self {
+            LogFormat::Syslog => "Syslog",
+            LogFormat::JsonLines => "JSON lines",
+            LogFormat::AccessLog => "Apache/Nginx access log",
+            LogFormat::Logfmt => "logfmt",
+            LogFormat::Timestamped => "Timestamped text",
+            LogFormat::Plain => "Plain text",
+        }
+    }
+}
+
+static SYSLOG_RFC3164: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(?:<(\d{1,3})>)?([A-Z][a-z]{2} [ \d]\d \d{2}:\d{2}:\d{2}) (\S+) ([^:\[\s]+)(?:\[(\d+)\])?: ?(.*)$",
+    )
+    .unwrap()
+});
+
+static SYSLOG_RFC5424: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^<(\d{1,3})>1 (\S+) (\S+) (\S+) (\S+) \S+ (?:-|(?:\[[^\]]*\])+) ?(.*)$").unwrap()
+});
+
+static ACCESS_LOG: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^(\S+) \S+ (\S+) \[([^\]]+)\] "([^"]*)" (\d{3}) (\S+)(?: "([^"]*)" "([^"]*)")?"#)
+        .unwrap()
+});
+
+static LOGFMT_PAIR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"([\w.@-]+)=("(?:[^"\\]|\\.)*"|\S*)"#).unwrap());
+
+/// Timestamp, thread, level, thread, logger and message of log4j, logback,
+/// Python logging and similar lines
+static TIMESTAMPED: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)^\[?(\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}:\d{2}(?:[.,]\d+)?(?:Z|[+-]\d{2}:?\d{2})?)\]?\s+(?:-\s+)?(?:\[([^\]]+)\]\s+)?(?:\[?(TRACE|DEBUG|INFO|NOTICE|WARN|WARNING|ERROR|ERR|SEVERE|FATAL|CRITICAL)\]?:?\s+(?:-\s+)?)?(?:\[([^\]]+)\]\s+)?(?:([\w.$/]+)\s+-\s+)?(.*)$",
+    )
+    .unwrap()
+});
+
+/// Level keywords of unstructured lines, as matched by earlier versions
+static ERROR_WORDS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(error|fatal|critical|exception|panic|fail(ed)?)\b").unwrap()
+});
+static WARN_WORDS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(warn(ing)?|caution)\b").unwrap());
+static INFO_WORDS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(info|notice|debug|trace)\b").unwrap());
+
+/// Lines that continue a stack trace or exception
+static STACK_LINE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"^(?:\s+at |\s+\.\.\. \d+ (?:more|common frames omitted)|Caused by: |Suppressed: |Traceback \(most recent call last\):|\s+File ".*", line \d+|[\w.$]+(?:Exception|Error)(?::|$))"#,
+    )
+    .unwrap()
+});
+
+/// One parsed log event; multi-line events keep every line in `text`
+#[derive(Debug, Clone, Default)]
+struct LogEvent {
+    line: usize,
+    timestamp: Option<String>,
+    time: Option<NaiveDateTime>,
+    level: Option<&'static str>,
+    source: Option<String>,
+    message: String,
+    /// Access log fields: client, request, status and size
+    access: Option<[String; 4]>,
+    /// Stack trace stored in a field of a structured line
+    trace: Option<String>,
+    text: String,
+    stack_trace: bool,
+}
+
+/// Canonical name of a level word
+fn normalize_level(level: &str) -> Option<&'static str> {
+    match level.trim().to_ascii_lowercase().as_str() {
+        "fatal" | "critical" | "crit" | "alert" | "emerg" | "emergency" | "panic" => Some("FATAL"),
+        "error" | "err" | "severe" => Some("ERROR"),
+        "warn" | "warning" => Some("WARN"),
+        "info" | "information" | "notice" => Some("INFO"),
+        "debug" | "fine" | "config" => Some("DEBUG"),
+        "trace" | "finer" | "finest" | "verbose" => Some("TRACE"),
+        _ => None,
+    }
+}
+
+/// Level of a syslog priority value
+fn syslog_level(priority: &str) -> Option<&'static str> {
+    let severity = priority.parse::<u32>().ok()? % 8;
+    Some(match severity {
+        0..=2 => "FATAL",
+        3 => "ERROR",
+        4 => "WARN",
+        5 | 6 => "INFO",
+        _ => "DEBUG",
+    })
+}
+
+/// Level of an access log status code
+fn status_level(status: &str) -> &'static str {
+    match status.as_bytes().first() {
+        Some(b'5') => "ERROR",
+        Some(b'4') => "WARN",
+        _ => "INFO",
+    }
+}
+
+/// Level of a line without a level field, from its keywords
+fn keyword_level(text: &str) -> Option<&'static str> {
+    if ERROR_WORDS.is_match(text) {
+        Some("ERROR")
+    } else if WARN_WORDS.is_match(text) {
+        Some("WARN")
+    } else if INFO_WORDS.is_match(text) {
+        Some("INFO")
+    } else {
+        None
+    }
+}
+
+/// Parse the common log timestamp styles. Syslog timestamps have no year and
+/// are placed in 1970, which keeps their durations correct within a year.
+fn parse_timestamp(text: &str) -> Option<NaiveDateTime> {
+    let text = text.trim().trim_matches(|c| c == '[' || c == ']');
+    if let Ok(time) = DateTime::parse_from_rfc3339(text) {
+        return Some(time.naive_utc());
+    }
+    if let Ok(time) = DateTime::parse_from_str(text, "%d/%b/%Y:%H:%M:%S %z") {
+        return Some(time.naive_utc());
+    }
+    let normalized = text.replace(',', ".").replace('T', " ");
+    for format in ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%d %H:%M:%S"] {
+        if let Ok(time) = NaiveDateTime::parse_from_str(&normalized, format) {
+            return Some(time);
+        }
+    }
+    if let Ok(time) = DateTime::parse_from_str(&normalized, "%Y-%m-%d %H:%M:%S%.f%z") {
+        return Some(time.naive_utc());
+    }
+    let syslog = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if let Ok(time) =
+        NaiveDateTime::parse_from_str(&format!("1970 {}", syslog), "%Y %b %d %H:%M:%S")
+    {
+        return Some(time);
+    }
+    // Unix timestamps in seconds or milliseconds
+    let seconds = text.parse::<f64>().ok()?;
+    let seconds = if seconds > 1e11 {
+        seconds / 1000.0
+    } else {
+        seconds
+    };
+    if seconds < 1e8 {
+        return None;
+    }
+    DateTime::from_timestamp(seconds as i64, (seconds.fract() * 1e9) as u32)
+        .map(|time| time.naive_utc())
+}
+
+/// First value of `object` under one of `keys`, as text
+fn json_field(
+    object: &serde_json::Map<String, serde_json::Value>,
+    keys: &[&str],
+) -> Option<String> {
+    keys.iter().find_map(|key| match object.get(*key)? {
+        serde_json::Value::String(text) => Some(text.clone()),
+        serde_json::Value::Null => None,
+        other => Some(other.to_string()),
+    })
+}
+
+const TIME_KEYS: &[&str] = &[
+    "timestamp",
+    "@timestamp",
+    "time",
+    "ts",
+    "datetime",
+    "date",
+    "t",
+];
+const LEVEL_KEYS: &[&str] = &[
+    "level",
+    "severity",
+    "lvl",
+    "log.level",
+    "levelname",
+    "loglevel",
+];
+const MESSAGE_KEYS: &[&str] = &["message", "msg", "text", "event", "error"];
+const SOURCE_KEYS: &[&str] = &[
+    "logger",
+    "logger_name",
+    "source",
+    "module",
+    "component",
+    "service",
+    "caller",
+    "name",
+];
+const STACK_KEYS: &[&str] = &[
+    "stack_trace",
+    "stacktrace",
+    "stack",
+    "exception",
+    "exc_info",
+];
+
+impl LogFormat {
+    /// Parse a line that starts an event in this format
+    fn parse(&self, line: &str) -> Option<LogEvent> {
+        match self {
+            LogFormat::Syslog => {
+                if let Some(caps) = SYSLOG_RFC5424.captures(line) {
+                    let mut source = format!("{} {}", &caps[3], &caps[4]);
+                    if &caps[5] != "-" {
+                        source.push_str(&format!("[{}]", &caps[5]));
+                    }
+                    return Some(LogEvent {
+                        timestamp: Some(caps[2].to_string()),
+                        level: syslog_level(&caps[1]),
+                        source: Some(source),
+                        message: caps[6].to_string(),
+                        ..Default::default()
+                    });
+                }
+                let caps = SYSLOG_RFC3164.captures(line)?;
+                let message = caps[6].to_string();
+                let mut source = format!("{} {}", &caps[3], &caps[4]);
+                if let Some(pid) = caps.get(5) {
+                    source.push_str(&format!("[{}]", pid.as_str()));
+                }
+                Some(LogEvent {
+                    timestamp: Some(caps[2].to_string()),
+                    level: caps
+                        .get(1)
+                        .and_then(|p| syslog_level(p.as_str()))
+                        .or_else(|| keyword_level(&message)),
+                    source: Some(source),
+                    message,
+                    ..Default::default()
+                })
+            }
+            LogFormat::JsonLines => {
+                let serde_json::Value::Object(object) =
+                    serde_json::from_str::<serde_json::Value>(line.trim()).ok()?
+                else {
+                    return None;
+                };
+                let message = json_field(&object, MESSAGE_KEYS).unwrap_or_else(|| {
+                    // Fields other than time, level and source
+                    object
+                        .iter()
+                        .filter(|(key, _)| {
+                            ![TIME_KEYS, LEVEL_KEYS, SOURCE_KEYS]
+                                .iter()
+                                .any(|keys| keys.contains(&key.as_str()))
+                        })
+                        .map(|(key, value)| format!("{}={}", key, value))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                });
+                let mut event = LogEvent {
+                    timestamp: json_field(&object, TIME_KEYS),
+                    level: json_field(&object, LEVEL_KEYS)
+                        .and_then(|level| normalize_level(&level)),
+                    source: json_field(&object, SOURCE_KEYS),
+                    message,
+                    ..Default::default()
+                };
+                // Stack traces logged as a field are shown below the line
+                if let Some(trace) = json_field(&object, STACK_KEYS) {
+                    event.stack_trace = true;
+                    event.trace = Some(trace);
+                }
+                Some(event)
+            }
+            LogFormat::AccessLog => {
+                let caps = ACCESS_LOG.captures(line)?;
+                let status = caps[5].to_string();
+                let mut source = caps[1].to_string();
+                if &caps[2] != "-" {
+                    source.push_str(&format!(" ({})", &caps[2]));
+                }
+                Some(LogEvent {
+                    timestamp: Some(caps[3].to_string()),
+                    level: Some(status_level(&status)),
+                    source: Some(source),
+                    message: format!("{} → {}", &caps[4], status),
+                    access: Some([
+                        caps[1].to_string(),
+                        caps[4].to_string(),
+                        status,
+                        caps[6].to_string(),
+                    ]),
+                    ..Default::default()
+                })
+            }
+            LogFormat::Logfmt => {
+                let pairs: HashMap<String, String> = LOGFMT_PAIR
+                    .captures_iter(line)
+                    .map(|caps| {
+                        let value = caps[2].trim_matches('"').replace("\\\"", "\"");
+                        (caps[1].to_string(), value)
+                    })
+                    .collect();
+                let field = |keys: &[&str]| keys.iter().find_map(|key| pairs.get(*key).cloned());
+                let known = [TIME_KEYS, LEVEL_KEYS, MESSAGE_KEYS]
+                    .iter()
+                    .any(|keys| field(keys).is_some());
+                if pairs.len() < 2 || !known {
+                    return None;
+                }
+                Some(LogEvent {
+                    timestamp: field(TIME_KEYS),
+                    level: field(LEVEL_KEYS).and_then(|level| normalize_level(&level)),
+                    source: field(SOURCE_KEYS),
+                    message: field(MESSAGE_KEYS).unwrap_or_else(|| line.to_string()),
+                    ..Default::default()
+                })
+            }
+            LogFormat::Timestamped => {
+                let caps = TIMESTAMPED.captures(line)?;
+                let message = caps[6].to_string();
+                let mut level = caps.get(3).and_then(|l| normalize_level(l.as_str()));
+                let mut thread = caps.get(2).or(caps.get(4)).map(|t| t.as_str());
+                // "[INFO]" before the level position is the level, not a thread
+                if level.is_none() {
+                    if let Some(bracketed) = thread.and_then(normalize_level) {
+                        level = Some(bracketed);
+                        thread = None;
+                    }
+                }
+                let source = match (caps.get(5), thread) {
+                    (Some(logger), Some(thread)) => {
+                        Some(format!("{} [{}]", logger.as_str(), thread))
+                    }
+                    (logger, thread) => logger.map(|l| l.as_str()).or(thread).map(String::from),
+                };
+                Some(LogEvent {
+                    timestamp: Some(caps[1].to_string()),
+                    level: level.or_else(|| keyword_level(&message)),
+                    source,
+                    message,
+                    ..Default::default()
+                })
+            }
+            LogFormat::Plain => Some(LogEvent {
+                level: keyword_level(line),
+                message: line.trim().to_string(),
+                ..Default::default()
+            }),
+        }
+    }
+}
+
+/// The format that parses most of the first non-empty lines
+fn detect_format(lines: &[&str]) -> LogFormat {
+    let sample: Vec<&str> = lines
+        .iter()
+        .filter(|line| {
+            !line.trim().is_empty()
+                && !line.starts_with(char::is_whitespace)
+                && !STACK_LINE.is_match(line)
+        })
+        .take(DETECTION_LINES)
+        .copied()
+        .collect();
+    let candidates = [
+        LogFormat::JsonLines,
+        LogFormat::AccessLog,
+        LogFormat::Syslog,
+        LogFormat::Timestamped,
+        LogFormat::Logfmt,
+    ];
+    let mut best = (LogFormat::Plain, 0);
+    for format in candidates {
+        let matches = sample
+            .iter()
+            .filter(|line| format.parse(line).is_some())
+            .count();
+        if matches > best.1 {
+            best = (format, matches);
+        }
+    }
+    // Wrapped messages may make up the rest
+    if best.1 * 2 >= sample.len() && best.1 > 0 {
+        best.0
+    } else {
+        LogFormat::Plain
+    }
+}
+
+/// Split a log into events, grouping continuation lines with the event above
+fn parse_events(lines: &[&str], format: LogFormat) -> Vec<LogEvent> {
+    let mut events: Vec<LogEvent> = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let continues = STACK_LINE.is_match(line)
+            || (format != LogFormat::Plain && line.starts_with(char::is_whitespace));
+        let parsed = if continues && !events.is_empty() {
+            None
+        } else {
+            format.parse(line)
+        };
+        match (parsed, events.last_mut()) {
+            (Some(mut event), _) => {
+                event.line = index + 1;
+                event.time = event.timestamp.as_deref().and_then(parse_timestamp);
+                event.text = line.to_string();
+                if let Some(trace) = &event.trace {
+                    event.text.push('\n');
+                    event.text.push_str(trace.trim_end());
+                }
+                events.push(event);
+            }
+            (None, Some(event)) => {
+                event.text.push('\n');
+                event.text.push_str(line);
+                if STACK_LINE.is_match(line) {
+                    event.stack_trace = true;
+                }
+            }
+            (None, None) => events.push(LogEvent {
+                line: index + 1,
+                level: keyword_level(line),
+                message: line.trim().to_string(),
+                text: line.to_string(),
+                ..Default::default()
+            }),
+        }
+    }
+
+    // Exceptions raise the level of events that did not state one
+    for event in &mut events {
+        if event.stack_trace && event.level.is_none() {
+            event.level = Some("ERROR");
+        }
+    }
+    events
+}
+
+/// Put a value on one line for a table cell; the renderer escapes pipes
+fn table_cell(text: &str) -> String {
+    text.replace('\n', " ")
+}
+
+fn format_duration(seconds: i64) -> String {
+    let (days, rest) = (seconds / 86_400, seconds % 86_400);
+    let (hours, rest) = (rest / 3_600, rest % 3_600);
+    let (minutes, seconds) = (rest / 60, rest % 60);
+    let mut parts = Vec::new();
+    for (value, unit) in [(days, "d"), (hours, "h"), (minutes, "m"), (seconds, "s")] {
+        if value > 0 {
+            parts.push(format!("{}{}", value, unit));
+        }
+    }
+    if parts.is_empty() {
+        "0s".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+/// Timestamp of an event as written, or formatted when it is a Unix time
+fn display_time(event: &LogEvent) -> Option<String> {
+    let text = event.timestamp.as_ref()?;
+    match (text.parse::<f64>(), event.time) {
+        (Ok(_), Some(time)) => Some(time.format("%Y-%m-%d %H:%M:%S").to_string()),
+        _ => Some(text.clone()),
+    }
+}
+
+/// Time range and rate statistics of the events with a timestamp
+struct TimeStats {
+    first: String,
+    last: String,
+    seconds: i64,
+    per_minute: f64,
+    /// Busiest minute and its event count
+    peak: (NaiveDateTime, usize),
+}
+
+impl TimeStats {
+    fn new(events: &[LogEvent]) -> Option<Self> {
+        let timed: Vec<&LogEvent> = events.iter().filter(|e| e.time.is_some()).collect();
+        let first = timed.iter().min_by_key(|e| e.time)?;
+        let last = timed.iter().max_by_key(|e| e.time)?;
+        let seconds = (last.time? - first.time?).num_seconds();
+
+        let mut minutes: BTreeMap<NaiveDateTime, usize> = BTreeMap::new();
+        for time in timed.iter().filter_map(|e| e.time) {
+            let minute = time.with_second(0)?.with_nanosecond(0)?;
+            *minutes.entry(minute).or_default() += 1;
+        }
+        let peak = minutes
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))
+            .map(|(minute, count)| (*minute, *count))?;
+
+        Some(Self {
+            first: display_time(first)?,
+            last: display_time(last)?,
+            seconds,
+            per_minute: timed.len() as f64 / (seconds.max(60) as f64 / 60.0),
+            peak,
+        })
+    }
+
+    fn items(&self) -> Vec<String> {
+        let peak = if self.peak.0.year() == 1970 {
+            // Syslog timestamps have no year
+            self.peak.0.format("%b %d %H:%M").to_string()
+        } else {
+            self.peak.0.format("%Y-%m-%d %H:%M").to_string()
+        };
+        vec![
+            format!("**Time range:** {} – {}", self.first, self.last),
+            format!("**Duration:** {}", format_duration(self.seconds)),
+            format!("**Rate:** {:.1} events/min", self.per_minute),
+            format!("**Peak minute:** {} ({} events)", peak, self.peak.1),
+        ]
+    }
+}
+
 /// Log file converter
 pub struct LogConverter;
 
 impl LogConverter {
-    fn convert_log(bytes: &[u8]) -> Result<Document, MarkitdownError> {
+    fn convert_log(bytes: &[u8], options: &ConversionOptions) -> Result<Document, MarkitdownError> {
         let content = String::from_utf8_lossy(bytes);
-        let analysis = Self::analyze_log(&content);
+        let lines: Vec<&str> = content.lines().collect();
+        let format = detect_format(&lines);
+        let events = parse_events(&lines, format);
+        let time_stats = TimeStats::new(&events);
 
         let mut document = Document::new();
+        document.title = Some("Log File Analysis".to_string());
         let mut page = Page::new(1);
-        let mut markdown = String::new();
 
-        markdown.push_str("# Log File Analysis\n\n");
+        let mut level_counts: BTreeMap<usize, (&str, usize)> = BTreeMap::new();
+        for event in &events {
+            if let Some(level) = event.level {
+                let order = ["FATAL", "ERROR", "WARN", "INFO", "DEBUG", "TRACE"]
+                    .iter()
+                    .position(|l| *l == level)
+                    .unwrap_or(usize::MAX);
+                level_counts.entry(order).or_insert((level, 0)).1 += 1;
+            }
+        }
+        let count = |levels: &[&str]| {
+            events
+                .iter()
+                .filter(|e| e.level.is_some_and(|l| levels.contains(&l)))
+                .count()
+        };
+        let error_count = count(&["FATAL", "ERROR"]);
+        let warning_count = count(&["WARN"]);
 
         // Summary
-        markdown.push_str("## Summary\n\n");
-        markdown.push_str(&format!("- **Total lines:** {}\n", analysis.total_lines));
-        markdown.push_str(&format!("- **Errors:** {} 🔴\n", analysis.error_count));
-        markdown.push_str(&format!("- **Warnings:** {} 🟡\n", analysis.warning_count));
-        markdown.push_str(&format!("- **Info:** {} 🔵\n", analysis.info_count));
-        markdown.push('\n');
-
-        // Errors section
-        if !analysis.errors.is_empty() {
-            markdown.push_str("## 🔴 Errors\n\n");
-            for (line_num, line) in &analysis.errors {
-                markdown.push_str(&format!("**Line {}:**\n```\n{}\n```\n\n", line_num, line));
-            }
+        page.add_content(ContentBlock::Heading {
+            level: 2,
+            text: "Summary".to_string(),
+        });
+        let mut summary = vec![
+            format!("**Format:** {}", format.name()),
+            format!("**Total lines:** {}", lines.len()),
+            format!("**Events:** {}", events.len()),
+            format!("**Errors:** {} 🔴", error_count),
+            format!("**Warnings:** {} 🟡", warning_count),
+        ];
+        let stack_traces = events.iter().filter(|e| e.stack_trace).count();
+        if stack_traces > 0 {
+            summary.push(format!("**Stack traces:** {}", stack_traces));
+        }
+        if let Some(stats) = &time_stats {
+            summary.extend(stats.items());
+        }
+        page.add_content(ContentBlock::List {
+            ordered: false,
+            items: summary,
+        });
+
+        if !level_counts.is_empty() {
+            page.add_content(ContentBlock::Heading {
+                level: 2,
+                text: "Levels".to_string(),
+            });
+            page.add_content(ContentBlock::Table {
+                headers: vec!["Level".to_string(), "Events".to_string()],
+                rows: level_counts
+                    .values()
+                    .map(|(level, count)| vec![level.to_string(), count.to_string()])
+                    .collect(),
+            });
         }
 
-        // Warnings section
-        if !analysis.warnings.is_empty() {
-            markdown.push_str("## 🟡 Warnings\n\n");
-            for (line_num, line) in &analysis.warnings {
-                markdown.push_str(&format!("**Line {}:**\n```\n{}\n```\n\n", line_num, line));
+        Self::add_events_table(&mut page, &events, format);
+
+        for (title, levels) in [
+            ("🔴 Errors", &["FATAL", "ERROR"][..]),
+            ("🟡 Warnings", &["WARN"][..]),
+        ] {
+            let listed: Vec<&LogEvent> = events
+                .iter()
+                .filter(|e| e.level.is_some_and(|l| levels.contains(&l)))
+                .take(MAX_LISTED_EVENTS)
+                .collect();
+            if listed.is_empty() {
+                continue;
+            }
+            page.add_content(ContentBlock::Heading {
+                level: 2,
+                text: title.to_string(),
+            });
+            for event in listed {
+                page.add_content(ContentBlock::Text(format!("**Line {}:**", event.line)));
+                page.add_content(ContentBlock::Code {
+                    language: None,
+                    code: event.text.clone(),
+                });
             }
         }
 
         // Full log
-        markdown.push_str("## Full Log\n\n");
-        markdown.push_str("```log\n");
-        markdown.push_str(&content);
-        if !content.ends_with('\n') {
-            markdown.push('\n');
+        page.add_content(ContentBlock::Heading {
+            level: 2,
+            text: "Full Log".to_string(),
+        });
+        if options.full_log.includes(lines.len()) {
+            page.add_content(ContentBlock::Code {
+                language: Some("log".to_string()),
+                code: content.trim_end_matches('\n').to_string(),
+            });
+        } else {
+            page.add_content(ContentBlock::Text(format!(
+                "*Full log omitted ({} lines).*",
+                lines.len()
+            )));
+        }
+
+        for (key, value) in [
+            ("format", format.name().to_string()),
+            ("line_count", lines.len().to_string()),
+            ("event_count", events.len().to_string()),
+            ("error_count", error_count.to_string()),
+            ("warning_count", warning_count.to_string()),
+        ] {
+            document.metadata.insert(key.to_string(), value);
+        }
+        if let Some(stats) = &time_stats {
+            document
+                .metadata
+                .insert("first_timestamp".to_string(), stats.first.clone());
+            document
+                .metadata
+                .insert("last_timestamp".to_string(), stats.last.clone());
         }
-        markdown.push_str("```\n");
 
-        page.add_content(ContentBlock::Markdown(markdown));
         document.add_page(page);
         Ok(document)
     }
 
-    fn analyze_log(content: &str) -> LogAnalysis {
-        let mut analysis = LogAnalysis::default();
-
-        // Common log level patterns
-        let error_re =
-            Regex::new(r"(?i)\b(error|fatal|critical|exception|panic|fail(ed)?)\b").unwrap();
-        let warn_re = Regex::new(r"(?i)\b(warn(ing)?|caution)\b").unwrap();
-        let info_re = Regex::new(r"(?i)\b(info|notice|debug|trace)\b").unwrap();
-
-        for (idx, line) in content.lines().enumerate() {
-            let line_num = idx + 1;
-            analysis.total_lines += 1;
-
-            if error_re.is_match(line) {
-                analysis.error_count += 1;
-                if analysis.errors.len() < 50 {
-                    // Limit to first 50
-                    analysis.errors.push((line_num, line.to_string()));
-                }
-            } else if warn_re.is_match(line) {
-                analysis.warning_count += 1;
-                if analysis.warnings.len() < 50 {
-                    analysis.warnings.push((line_num, line.to_string()));
-                }
-            } else if info_re.is_match(line) {
-                analysis.info_count += 1;
-            }
+    /// "Events" table with the parsed fields of the first events
+    fn add_events_table(page: &mut Page, events: &[LogEvent], format: LogFormat) {
+        if events.is_empty() || format == LogFormat::Plain {
+            return;
         }
+        let shown = &events[..events.len().min(MAX_EVENT_ROWS)];
+        let (headers, rows): (Vec<&str>, Vec<Vec<String>>) = if format == LogFormat::AccessLog {
+            (
+                vec!["Line", "Time", "Client", "Request", "Status", "Size"],
+                shown
+                    .iter()
+                    .map(|event| {
+                        let mut row = vec![
+                            event.line.to_string(),
+                            table_cell(event.timestamp.as_deref().unwrap_or("")),
+                        ];
+                        if let Some(fields) = &event.access {
+                            row.extend(fields.iter().map(|f| table_cell(f)));
+                        }
+                        row
+                    })
+                    .collect(),
+            )
+        } else {
+            (
+                vec!["Line", "Time", "Level", "Source", "Message"],
+                shown
+                    .iter()
+                    .map(|event| {
+                        let mut message = table_cell(&event.message);
+                        let more = event.text.lines().count() - 1;
+                        if more > 0 {
+                            message.push_str(&format!(" *(+{} more lines)*", more));
+                        }
+                        vec![
+                            event.line.to_string(),
+                            table_cell(event.timestamp.as_deref().unwrap_or("")),
+                            event.level.unwrap_or("").to_string(),
+                            table_cell(event.source.as_deref().unwrap_or("")),
+                            message,
+                        ]
+                    })
+                    .collect(),
+            )
+        };
 
-        analysis
+        page.add_content(ContentBlock::Heading {
+            level: 2,
+            text: "Events".to_string(),
+        });
+        page.add_content(ContentBlock::Table {
+            headers: headers.into_iter().map(String::from).collect(),
+            rows,
+        });
+        if events.len() > shown.len() {
+            page.add_content(ContentBlock::Text(format!(
+                "*Showing the first {} of {} events.*",
+                shown.len(),
+                events.len()
+            )));
+        }
     }
 }
 
-#[derive(Default)]
-struct LogAnalysis {
-    total_lines: usize,
-    error_count: usize,
-    warning_count: usize,
-    info_count: usize,
-    errors: Vec<(usize, String)>,
-    warnings: Vec<(usize, String)>,
-}
-
 #[async_trait]
 impl DocumentConverter for LogConverter {
     async fn convert(
@@ -122,9 +806,9 @@ impl DocumentConverter for LogConverter {
     async fn convert_bytes(
         &self,
         bytes: Bytes,
-        _options: Option<ConversionOptions>,
+        options: Option<ConversionOptions>,
     ) -> Result<Document, MarkitdownError> {
-        Self::convert_log(&bytes)
+        Self::convert_log(&bytes, &options.unwrap_or_default())
     }
 
     fn supported_extensions(&self) -> &[&str] {
@@ -0,0 +1,521 @@
+//! Mailbox converters (mbox files and Maildir folders).
+//!
+//! Every message becomes one page, rendered like a single `.eml` by the
+//! email converter. Pages are grouped by thread (`Message-ID`,
+//! `In-Reply-To` and `References`) and ordered by date within a thread.
+//!
+//! Mailboxes can be very large, so messages are split off and converted one
+//! at a time; only the rendered pages are kept. A [`MailboxFilter`] drops
+//! messages by date or sender before their bodies are parsed.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use object_store::ObjectStore;
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::io::AsyncReadExt;
+
+use crate::email::{parse_eml, parse_eml_headers, render_message, EmailMessage};
+use crate::error::MarkitdownError;
+use crate::model::{ContentBlock, ConversionOptions, Document, DocumentConverter, Page};
+
+/// Selects which messages of a mailbox are converted
+#[derive(Debug, Clone, Default)]
+pub struct MailboxFilter {
+    /// Keep messages sent at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Keep messages sent before this time
+    pub until: Option<DateTime<Utc>>,
+    /// Keep messages whose sender (name or address) contains this text,
+    /// ignoring case
+    pub sender: Option<String>,
+}
+
+impl MailboxFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn with_until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    pub fn with_sender(mut self, sender: impl Into<String>) -> Self {
+        self.sender = Some(sender.into());
+        self
+    }
+
+    /// Whether a message with these headers passes the filter. Messages
+    /// without a date never match a date range.
+    fn matches(&self, message: &EmailMessage) -> bool {
+        if self.since.is_some() || self.until.is_some() {
+            let Some(date) = message
+                .date
+                .as_deref()
+                .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+            else {
+                return false;
+            };
+            if self.since.is_some_and(|since| date < since)
+                || self.until.is_some_and(|until| date >= until)
+            {
+                return false;
+            }
+        }
+        if let Some(sender) = &self.sender {
+            let sender = sender.to_lowercase();
+            if !message
+                .from
+                .iter()
+                .any(|from| from.to_lowercase().contains(&sender))
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// A converted message waiting to be placed in its thread
+struct ConvertedMessage {
+    message_id: Option<String>,
+    parents: Vec<String>,
+    subject: Option<String>,
+    date: Option<DateTime<Utc>>,
+    blocks: Vec<ContentBlock>,
+}
+
+/// Converts messages one at a time and assembles the mailbox document
+struct MailboxBuilder<'a> {
+    options: &'a ConversionOptions,
+    messages: Vec<ConvertedMessage>,
+    scanned: usize,
+    /// Messages that could not be parsed or rendered
+    failed: usize,
+}
+
+impl<'a> MailboxBuilder<'a> {
+    fn new(options: &'a ConversionOptions) -> Self {
+        Self {
+            options,
+            messages: Vec::new(),
+            scanned: 0,
+            failed: 0,
+        }
+    }
+
+    /// Convert one raw message, unless the filter rejects it. Messages that
+    /// cannot be parsed or rendered are skipped and counted.
+    async fn add(&mut self, raw: &[u8]) -> Result<(), MarkitdownError> {
+        if raw.iter().all(u8::is_ascii_whitespace) {
+            return Ok(());
+        }
+        self.scanned += 1;
+
+        if let Some(filter) = &self.options.mailbox_filter {
+            match parse_eml_headers(raw) {
+                Some(headers) if filter.matches(&headers) => {}
+                _ => return Ok(()),
+            }
+        }
+        let Ok(message) = parse_eml(raw) else {
+            self.failed += 1;
+            return Ok(());
+        };
+
+        let prefix = format!("message_{}_", self.scanned);
+        let Ok(blocks) = render_message(&message, self.options, &prefix).await else {
+            self.failed += 1;
+            return Ok(());
+        };
+        let mut parents = message.references.clone();
+        parents.extend(message.in_reply_to.clone());
+        self.messages.push(ConvertedMessage {
+            message_id: message.message_id.clone(),
+            parents,
+            subject: message.subject.clone(),
+            date: message
+                .date
+                .as_deref()
+                .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+                .map(|d| d.with_timezone(&Utc)),
+            blocks,
+        });
+        Ok(())
+    }
+
+    /// Group messages into threads and build one page per message
+    async fn finish(self) -> Result<Document, MarkitdownError> {
+        if self.scanned == 0 {
+            return Err(MarkitdownError::ParseError(
+                "No messages found in mailbox".to_string(),
+            ));
+        }
+
+        let threads = group_threads(&self.messages);
+        let mut document = Document::new();
+        document
+            .metadata
+            .insert("message_count".to_string(), self.messages.len().to_string());
+        document
+            .metadata
+            .insert("thread_count".to_string(), threads.len().to_string());
+        let filtered = self.scanned - self.messages.len() - self.failed;
+        if filtered > 0 {
+            document
+                .metadata
+                .insert("messages_filtered".to_string(), filtered.to_string());
+        }
+        if self.failed > 0 {
+            document
+                .metadata
+                .insert("messages_failed".to_string(), self.failed.to_string());
+        }
+
+        let mut messages: Vec<Option<ConvertedMessage>> =
+            self.messages.into_iter().map(Some).collect();
+        for thread in threads {
+            let thread_subject = messages[thread[0]]
+                .as_ref()
+                .and_then(|m| m.subject.clone())
+                .unwrap_or_else(|| "(no subject)".to_string());
+            let thread_len = thread.len();
+            for (position, index) in thread.into_iter().enumerate() {
+                let Some(message) = messages[index].take() else {
+                    continue;
+                };
+                let mut page = Page::new(document.pages.len() as u32 + 1);
+                page.add_content(ContentBlock::Heading {
+                    level: 2,
+                    text: message
+                        .subject
+                        .unwrap_or_else(|| "(no subject)".to_string()),
+                });
+                if thread_len > 1 {
+                    page.add_content(ContentBlock::Text(format!(
+                        "**Thread:** {} (message {} of {})",
+                        thread_subject,
+                        position + 1,
+                        thread_len
+                    )));
+                }
+                for block in message.blocks {
+                    page.add_content(block);
+                }
+                document.add_page(page);
+            }
+        }
+
+        // If LLM client is provided, get descriptions for all images
+        if let Some(ref llm_client) = self.options.llm_client {
+            if let Some(path) = self.options.image_context_path.as_deref() {
+                document.apply_image_context_path(path);
+            }
+            document = document
+                .with_image_descriptions(llm_client.as_ref())
+                .await?;
+        }
+
+        Ok(document)
+    }
+}
+
+/// Group messages into threads by the ids they reference. Threads are
+/// ordered by their first message, messages within a thread by date (then
+/// mailbox order). Returns indices into `messages`.
+fn group_threads(messages: &[ConvertedMessage]) -> Vec<Vec<usize>> {
+    fn find(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+
+    let mut ids: HashMap<&str, usize> = HashMap::new();
+    let mut parent: Vec<usize> = (0..messages.len()).collect();
+    for (index, message) in messages.iter().enumerate() {
+        let linked = message.message_id.iter().chain(message.parents.iter());
+        for id in linked {
+            let node = *ids.entry(id.as_str()).or_insert_with(|| {
+                parent.push(parent.len());
+                parent.len() - 1
+            });
+            let (a, b) = (find(&mut parent, index), find(&mut parent, node));
+            parent[a] = b;
+        }
+    }
+
+    let mut threads: Vec<Vec<usize>> = Vec::new();
+    let mut thread_of_root: HashMap<usize, usize> = HashMap::new();
+    for index in 0..messages.len() {
+        let root = find(&mut parent, index);
+        let thread = *thread_of_root.entry(root).or_insert_with(|| {
+            threads.push(Vec::new());
+            threads.len() - 1
+        });
+        threads[thread].push(index);
+    }
+    for thread in &mut threads {
+        thread.sort_by_key(|&i| (messages[i].date, i));
+    }
+    threads.sort_by_key(|thread| (messages[thread[0]].date, thread[0]));
+    threads
+}
+
+/// Splits an mbox stream into messages at `From ` separator lines and
+/// undoes mboxrd `>From ` quoting
+#[derive(Default)]
+struct MboxSplitter {
+    line: Vec<u8>,
+    message: Vec<u8>,
+    in_message: bool,
+}
+
+impl MboxSplitter {
+    /// Feed the next chunk of the file, returning the messages it completes
+    fn feed(&mut self, chunk: &[u8]) -> Vec<Vec<u8>> {
+        let mut completed = Vec::new();
+        for piece in chunk.split_inclusive(|&b| b == b'\n') {
+            self.line.extend_from_slice(piece);
+            if piece.ends_with(b"\n") {
+                let line = std::mem::take(&mut self.line);
+                completed.extend(self.push_line(&line));
+            }
+        }
+        completed
+    }
+
+    /// Flush the final message
+    fn finish(mut self) -> Option<Vec<u8>> {
+        let line = std::mem::take(&mut self.line);
+        // A trailing separator completes the message before it
+        if let Some(message) = self.push_line(&line) {
+            return Some(message);
+        }
+        self.in_message.then(|| std::mem::take(&mut self.message))
+    }
+
+    fn push_line(&mut self, line: &[u8]) -> Option<Vec<u8>> {
+        if line.starts_with(b"From ") {
+            let completed = self.in_message.then(|| std::mem::take(&mut self.message));
+            self.in_message = true;
+            return completed;
+        }
+        if !self.in_message {
+            return None;
+        }
+        let quotes = line.iter().take_while(|&&b| b == b'>').count();
+        if quotes > 0 && line[quotes..].starts_with(b"From ") {
+            self.message.extend_from_slice(&line[1..]);
+        } else {
+            self.message.extend_from_slice(line);
+        }
+        None
+    }
+}
+
+/// mbox mailbox converter
+pub struct MboxConverter;
+
+impl MboxConverter {
+    /// Convert a local mbox file without loading it into memory
+    pub async fn convert_file(
+        &self,
+        path: impl AsRef<Path>,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        check_extension(&options)?;
+        let options = options.unwrap_or_default();
+        let mut builder = MailboxBuilder::new(&options);
+        let mut splitter = MboxSplitter::default();
+        let mut file = tokio::fs::File::open(path).await?;
+        let mut buffer = vec![0u8; 64 * 1024];
+        loop {
+            let read = file.read(&mut buffer).await?;
+            if read == 0 {
+                break;
+            }
+            for message in splitter.feed(&buffer[..read]) {
+                builder.add(&message).await?;
+            }
+        }
+        if let Some(message) = splitter.finish() {
+            builder.add(&message).await?;
+        }
+        builder.finish().await
+    }
+}
+
+fn check_extension(options: &Option<ConversionOptions>) -> Result<(), MarkitdownError> {
+    if let Some(ext) = options.as_ref().and_then(|o| o.file_extension.as_ref()) {
+        let ext_lower = ext.to_lowercase();
+        if ext_lower != ".mbox" && ext_lower != ".mbx" {
+            return Err(MarkitdownError::InvalidFile(format!(
+                "Expected .mbox file, got {}",
+                ext
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl DocumentConverter for MboxConverter {
+    async fn convert(
+        &self,
+        store: Arc<dyn ObjectStore>,
+        path: &object_store::path::Path,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        check_extension(&options)?;
+        let options = options.unwrap_or_default();
+        let mut builder = MailboxBuilder::new(&options);
+        let mut splitter = MboxSplitter::default();
+
+        // Stream the object instead of downloading it in one piece
+        let mut stream = store.get(path).await?.into_stream();
+        while let Some(chunk) = stream.next().await {
+            for message in splitter.feed(&chunk?) {
+                builder.add(&message).await?;
+            }
+        }
+        if let Some(message) = splitter.finish() {
+            builder.add(&message).await?;
+        }
+        builder.finish().await
+    }
+
+    async fn convert_bytes(
+        &self,
+        bytes: Bytes,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        check_extension(&options)?;
+        let options = options.unwrap_or_default();
+        let mut builder = MailboxBuilder::new(&options);
+        let mut splitter = MboxSplitter::default();
+        for message in splitter.feed(&bytes) {
+            builder.add(&message).await?;
+        }
+        if let Some(message) = splitter.finish() {
+            builder.add(&message).await?;
+        }
+        builder.finish().await
+    }
+
+    fn supported_extensions(&self) -> &[&str] {
+        &[".mbox", ".mbx"]
+    }
+}
+
+/// Maildir mailbox converter, for folders and ZIP archives of folders.
+///
+/// Messages are the files in the `cur/` and `new/` folders of the Maildir
+/// and of its Maildir++ subfolders; `tmp/` is ignored.
+pub struct MaildirConverter;
+
+/// Whether a path inside a Maildir holds a delivered message
+fn is_message_path(path: &Path) -> bool {
+    let in_mail_folder = path
+        .parent()
+        .and_then(|p| p.file_name())
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n == "cur" || n == "new");
+    let hidden = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.starts_with('.'));
+    in_mail_folder && !hidden
+}
+
+impl MaildirConverter {
+    /// Whether `path` is a Maildir folder
+    pub fn is_maildir(path: impl AsRef<Path>) -> bool {
+        let path = path.as_ref();
+        path.join("cur").is_dir() && path.join("new").is_dir()
+    }
+
+    /// Whether a ZIP archive holds a Maildir: most of its files are in
+    /// `cur/` or `new/` folders
+    pub fn is_maildir_zip(bytes: &[u8]) -> bool {
+        let Ok(archive) = zip::ZipArchive::new(Cursor::new(bytes)) else {
+            return false;
+        };
+        let files: Vec<&str> = archive.file_names().filter(|n| !n.ends_with('/')).collect();
+        let messages = files
+            .iter()
+            .filter(|n| is_message_path(Path::new(n)))
+            .count();
+        messages > 0 && messages * 2 >= files.len()
+    }
+
+    /// Convert a Maildir folder, reading one message at a time
+    pub async fn convert_dir(
+        &self,
+        path: impl AsRef<Path>,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        fn collect(dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+            for entry in std::fs::read_dir(dir)? {
+                let path = entry?.path();
+                if path.is_dir() {
+                    collect(&path, files)?;
+                } else if is_message_path(&path) {
+                    files.push(path);
+                }
+            }
+            Ok(())
+        }
+
+        let mut files = Vec::new();
+        collect(path.as_ref(), &mut files)?;
+        // Maildir file names start with the delivery time
+        files.sort();
+
+        let options = options.unwrap_or_default();
+        let mut builder = MailboxBuilder::new(&options);
+        for file in files {
+            builder.add(&std::fs::read(file)?).await?;
+        }
+        builder.finish().await
+    }
+
+    /// Convert a ZIP archive of a Maildir folder
+    pub async fn convert_zip(
+        &self,
+        bytes: &[u8],
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes))
+            .map_err(|e| MarkitdownError::ParseError(format!("ZIP parse error: {}", e)))?;
+        let mut names: Vec<String> = archive
+            .file_names()
+            .filter(|n| is_message_path(Path::new(n)))
+            .map(str::to_string)
+            .collect();
+        names.sort();
+
+        let options = options.unwrap_or_default();
+        let mut builder = MailboxBuilder::new(&options);
+        for name in names {
+            let mut file = archive
+                .by_name(&name)
+                .map_err(|e| MarkitdownError::ParseError(format!("ZIP entry error: {}", e)))?;
+            let mut contents = Vec::new();
+            file.read_to_end(&mut contents)?;
+            builder.add(&contents).await?;
+        }
+        builder.finish().await
+    }
+}
@@ -1,11 +1,14 @@
 use clap::Parser;
 use std::fs;
 
-use markitdown::{model::ConversionOptions, MarkItDown};
+use markitdown::{
+    fetch::is_http_url, model::ConversionOptions, MarkItDown, MarkdownFlavor, MarkdownRenderer,
+};
 
 #[derive(Parser, Debug)]
 #[command(name = "markitdown")]
 struct Cli {
+    /// File path or http(s) URL to convert
     #[arg(value_name = "FILE", index = 1)]
     input: String,
 
@@ -14,11 +17,51 @@ struct Cli {
 
     #[arg(short, long)]
     format: Option<String>,
+
+    /// Markdown flavor of the output: commonmark, gfm, obsidian or plain
+    #[arg(long, default_value = "gfm")]
+    flavor: String,
+
+    /// Shift every heading down by this many levels
+    #[arg(long, default_value_t = 0)]
+    heading_offset: u8,
+
+    /// Remove emoji from the output
+    #[arg(long)]
+    no_emoji: bool,
+
+    /// Wrap paragraphs at this many characters
+    #[arg(long)]
+    wrap: Option<usize>,
+}
+
+fn renderer(cli: &Cli) -> MarkdownRenderer {
+    let flavor = match cli.flavor.to_lowercase().as_str() {
+        "commonmark" => MarkdownFlavor::CommonMark,
+        "obsidian" => MarkdownFlavor::Obsidian,
+        "plain" => MarkdownFlavor::Plain,
+        "gfm" => MarkdownFlavor::Gfm,
+        other => {
+            eprintln!("Warning: Unsupported flavor '{}'. Using gfm.", other);
+            MarkdownFlavor::Gfm
+        }
+    };
+    let mut renderer = MarkdownRenderer::new()
+        .with_flavor(flavor)
+        .with_heading_offset(cli.heading_offset);
+    if cli.no_emoji {
+        renderer = renderer.without_emoji();
+    }
+    if let Some(width) = cli.wrap {
+        renderer = renderer.with_line_width(width);
+    }
+    renderer
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
+    let renderer = renderer(&cli);
 
     let output = match cli.output {
         Some(file) => file,
@@ -42,10 +85,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let input_file = cli.input.trim().to_string();
 
-    if !std::path::Path::new(&input_file).exists() {
-        return Err(format!("Error: File '{}' not found", input_file).into());
-    }
-
     let markitdown = MarkItDown::new();
 
     let options = if format.is_empty() {
@@ -54,8 +93,32 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Some(ConversionOptions::default().with_extension(format!(".{}", format)))
     };
 
+    if is_http_url(&input_file) {
+        match markitdown.convert_url(&input_file, options).await {
+            Ok(doc) => {
+                let markdown = doc.to_markdown_with(&renderer);
+                if output == "console" {
+                    println!("{}", &markdown);
+                } else {
+                    fs::write(&output, &markdown)
+                        .map_err(|e| format!("Failed to write to '{}': {}", output, e))?;
+                    eprintln!("Successfully converted to: {}", output);
+                }
+            }
+            Err(e) => {
+                eprintln!("Error: Unable to convert URL '{}'. {}", input_file, e);
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    if !std::path::Path::new(&input_file).exists() {
+        return Err(format!("Error: File '{}' not found", input_file).into());
+    }
+
     // Use the async convert_file method for simplicity
-    match markitdown.convert_file(&input_file).await {
+    match markitdown.convert_file_with(&input_file, &renderer).await {
         Ok(markdown) => {
             if output == "console" {
                 println!("{}", &markdown);
@@ -74,7 +137,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     .await
                 {
                     Ok(doc) => {
-                        let markdown = doc.to_markdown();
+                        let markdown = doc.to_markdown_with(&renderer);
                         if output == "console" {
                             println!("{}", &markdown);
                         } else {
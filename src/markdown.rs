@@ -1,33 +1,717 @@
-//! Markdown pass-through converter.
+//! Markdown converter.
 //!
-//! Handles markdown files, optionally normalizing them.
+//! Parses CommonMark with the GitHub extensions (tables, task lists,
+//! strikethrough, footnotes) into content blocks, so tables, headings and
+//! lists can be processed like those of any other format. YAML (`---`) and
+//! TOML (`+++`) front matter fills the document title and metadata. Relative
+//! links and images are resolved when `ConversionOptions::url` is set.
 
 use async_trait::async_trait;
 use bytes::Bytes;
 use object_store::ObjectStore;
+use pulldown_cmark::{CodeBlockKind, Event, LinkType, MetadataBlockKind, Options, Parser, Tag};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::ops::Range;
 use std::sync::Arc;
+use url::Url;
 
 use crate::error::MarkitdownError;
-use crate::model::{ContentBlock, ConversionOptions, Document, DocumentConverter, Page};
+use crate::html::{extract_data_url_image, fetch_linked_images};
+use crate::links::{base_url, resolve_markdown_links};
+use crate::model::{
+    ContentBlock, ConversionOptions, Document, DocumentConverter, ExtractedImage, Inline, ListItem,
+    Page, Provenance,
+};
+use crate::render::indent_continuation;
 
-/// Markdown file converter (pass-through)
+/// Builds content blocks from the parser events of one document
+struct BlockBuilder<'a> {
+    source: &'a str,
+    events: Vec<(Event<'a>, Range<usize>)>,
+    position: usize,
+    extract_images: bool,
+    image_count: usize,
+    in_table: bool,
+    front_matter: Option<(MetadataBlockKind, String)>,
+    /// Nesting of `blocks` calls; 1 while reading top-level blocks
+    depth: usize,
+    /// Index and source byte range of each top-level block
+    spans: Vec<(usize, Range<usize>)>,
+}
+
+impl<'a> BlockBuilder<'a> {
+    fn next(&mut self) -> Option<(Event<'a>, Range<usize>)> {
+        let event = self.events.get(self.position).cloned();
+        self.position += 1;
+        event
+    }
+
+    /// Index of the `End` event closing the tag opened just before `position`
+    fn closing_index(&self) -> usize {
+        let mut depth = 0;
+        for (i, (event, _)) in self.events.iter().enumerate().skip(self.position) {
+            match event {
+                Event::Start(_) => depth += 1,
+                Event::End(_) if depth == 0 => return i,
+                Event::End(_) => depth -= 1,
+                _ => {}
+            }
+        }
+        self.events.len()
+    }
+
+    /// Block content up to the end of the enclosing container (or document)
+    fn blocks(&mut self) -> Vec<ContentBlock> {
+        let mut blocks = Vec::new();
+        // Tight list items hold their text without a paragraph
+        let mut text = String::new();
+        self.depth += 1;
+
+        while let Some((event, range)) = self.next() {
+            let is_inline = !matches!(event, Event::Start(_) | Event::End(_) | Event::Rule)
+                || matches!(event, Event::Start(ref tag) if is_inline_tag(tag));
+            if !is_inline && !text.trim().is_empty() {
+                blocks.push(ContentBlock::Text(
+                    std::mem::take(&mut text).trim().to_string(),
+                ));
+            }
+
+            // The range of a start event covers the whole element
+            let first = blocks.len();
+            let span = range.clone();
+            match event {
+                Event::End(_) => break,
+                Event::Start(Tag::Paragraph) => blocks.extend(self.paragraph()),
+                Event::Start(Tag::Heading { level, .. }) => {
+                    let text = self.inlines();
+                    blocks.push(ContentBlock::Heading {
+                        level: level as u8,
+                        text: text.trim().to_string(),
+                    });
+                }
+                Event::Start(Tag::BlockQuote(_)) => {
+                    let inner = self.blocks();
+                    blocks.push(ContentBlock::Quote(render_blocks(&inner)));
+                }
+                Event::Start(Tag::CodeBlock(kind)) => {
+                    let language = match kind {
+                        CodeBlockKind::Fenced(info) => info
+                            .split_whitespace()
+                            .next()
+                            .map(|language| language.to_string()),
+                        CodeBlockKind::Indented => None,
+                    };
+                    let code = self.raw_text();
+                    let code = code.strip_suffix('\n').unwrap_or(&code).to_string();
+                    blocks.push(ContentBlock::Code { language, code });
+                }
+                Event::Start(Tag::HtmlBlock) => {
+                    let html = self.raw_text();
+                    blocks.push(ContentBlock::Markdown(format!("{}\n", html.trim_end())));
+                }
+                Event::Start(Tag::List(start)) => blocks.push(self.list(start.is_some())),
+                Event::Start(Tag::Table(_)) => blocks.push(self.table()),
+                Event::Start(Tag::FootnoteDefinition(label)) => {
+                    blocks.push(ContentBlock::FootnoteDefinition {
+                        label: label.to_string(),
+                        content: self.blocks(),
+                    });
+                }
+                Event::Start(Tag::MetadataBlock(kind)) => {
+                    self.front_matter = Some((kind, self.raw_text()));
+                }
+                Event::Start(_) => {
+                    if is_inline {
+                        self.inline(event, range, &mut text);
+                    } else {
+                        // Other containers (e.g. definition lists) keep their text
+                        blocks.extend(self.blocks());
+                    }
+                }
+                Event::Rule => blocks.push(ContentBlock::Rule),
+                event => self.inline(event, range, &mut text),
+            }
+            if self.depth == 1 {
+                self.spans
+                    .extend((first..blocks.len()).map(|i| (i, span.clone())));
+            }
+        }
+
+        if !text.trim().is_empty() {
+            blocks.push(ContentBlock::Text(text.trim().to_string()));
+        }
+        self.depth -= 1;
+        blocks
+    }
+
+    /// A paragraph: text, or one image block per image when it holds only images
+    fn paragraph(&mut self) -> Vec<ContentBlock> {
+        if self.extract_images {
+            if let Some(images) = self.image_paragraph() {
+                return images;
+            }
+        }
+        let text = self.inlines();
+        vec![ContentBlock::Text(text.trim().to_string())]
+    }
+
+    fn image_paragraph(&mut self) -> Option<Vec<ContentBlock>> {
+        let end = self.closing_index();
+        let mut images = Vec::new();
+        let mut depth = 0;
+        for (event, _) in &self.events[self.position..end] {
+            match event {
+                Event::Start(Tag::Image {
+                    dest_url, title, ..
+                }) if depth == 0 => {
+                    images.push((dest_url.to_string(), title.to_string(), String::new()));
+                    depth += 1;
+                }
+                Event::Start(_) if depth > 0 => depth += 1,
+                Event::End(_) if depth > 0 => depth -= 1,
+                Event::Text(text) | Event::Code(text) if depth > 0 => {
+                    if let Some((_, _, alt)) = images.last_mut() {
+                        alt.push_str(text);
+                    }
+                }
+                Event::Text(text) if text.trim().is_empty() => {}
+                Event::SoftBreak | Event::HardBreak => {}
+                _ if depth > 0 => {}
+                _ => return None,
+            }
+        }
+        if images.is_empty() {
+            return None;
+        }
+
+        self.position = end + 1;
+        let blocks = images
+            .into_iter()
+            .map(|(src, title, alt)| {
+                let id = format!("markdown_image_{}", self.image_count);
+                self.image_count += 1;
+                let mut image = if src.starts_with("data:image/") {
+                    extract_data_url_image(&src, id.clone())
+                } else {
+                    None
+                }
+                .unwrap_or_else(|| {
+                    // Linked images stay placeholders unless the image fetcher loads them
+                    let mut image = ExtractedImage::new(id, Bytes::new(), "image/unknown");
+                    image.source_path = Some(src);
+                    image
+                });
+                image.alt_text = Some(alt).filter(|alt| !alt.is_empty());
+                image.caption = Some(title).filter(|title| !title.is_empty());
+                ContentBlock::Image(image)
+            })
+            .collect();
+        Some(blocks)
+    }
+
+    fn list(&mut self, ordered: bool) -> ContentBlock {
+        let end = self.closing_index();
+        let nested = self.events[self.position..end].iter().any(|(event, _)| {
+            matches!(event, Event::TaskListMarker(_) | Event::Start(Tag::List(_)))
+        });
+        if nested {
+            return ContentBlock::NestedList {
+                ordered,
+                items: self.nested_items(),
+            };
+        }
+
+        let mut items = Vec::new();
+        while let Some((event, _)) = self.next() {
+            let Event::Start(Tag::Item) = event else {
+                break;
+            };
+            let marker_width = if ordered {
+                format!("{}. ", items.len() + 1).len()
+            } else {
+                2
+            };
+            let blocks = self.blocks();
+            items.push(indent_continuation(
+                &render_blocks_tight(&blocks),
+                marker_width,
+            ));
+        }
+        ContentBlock::List { ordered, items }
+    }
+
+    /// Items of a list with nested lists or task checkboxes. The first
+    /// paragraph of an item is its text; later blocks are its children.
+    fn nested_items(&mut self) -> Vec<ListItem> {
+        let mut items = Vec::new();
+        while let Some((event, _)) = self.next() {
+            let Event::Start(Tag::Item) = event else {
+                break;
+            };
+            // Loose items wrap their text, and the checkbox, in a paragraph
+            let loose = matches!(
+                self.events.get(self.position),
+                Some((Event::Start(Tag::Paragraph), _))
+            );
+            let marker = self.position + usize::from(loose);
+            let checked = match self.events.get(marker) {
+                Some((Event::TaskListMarker(checked), _)) => {
+                    let checked = *checked;
+                    self.events.remove(marker);
+                    Some(checked)
+                }
+                _ => None,
+            };
+
+            let content = if loose {
+                self.position += 1;
+                self.spans()
+            } else {
+                let mut spans = Vec::new();
+                while let Some((event, _)) = self.events.get(self.position) {
+                    let is_inline = !matches!(event, Event::Start(_) | Event::End(_) | Event::Rule)
+                        || matches!(event, Event::Start(tag) if is_inline_tag(tag));
+                    if !is_inline {
+                        break;
+                    }
+                    let (event, range) = self.events[self.position].clone();
+                    self.position += 1;
+                    self.span(event, range, &mut spans);
+                }
+                spans
+            };
+            let mut item = ListItem::new(trim_spans(content)).with_children(self.blocks());
+            item.checked = checked;
+            items.push(item);
+        }
+        items
+    }
+
+    fn table(&mut self) -> ContentBlock {
+        let mut headers = Vec::new();
+        let mut rows = Vec::new();
+        self.in_table = true;
+        while let Some((event, _)) = self.next() {
+            match event {
+                Event::Start(Tag::TableHead) => headers = self.cells(),
+                Event::Start(Tag::TableRow) => rows.push(self.cells()),
+                _ => break,
+            }
+        }
+        self.in_table = false;
+        ContentBlock::Table { headers, rows }
+    }
+
+    fn cells(&mut self) -> Vec<String> {
+        let mut cells = Vec::new();
+        while let Some((event, _)) = self.next() {
+            let Event::Start(Tag::TableCell) = event else {
+                break;
+            };
+            cells.push(self.inlines().trim().to_string());
+        }
+        cells
+    }
+
+    /// Text of a code, HTML or metadata block
+    fn raw_text(&mut self) -> String {
+        let mut text = String::new();
+        while let Some((event, _)) = self.next() {
+            match event {
+                Event::Text(t) | Event::Html(t) => text.push_str(&t),
+                _ => break,
+            }
+        }
+        text
+    }
+
+    /// Inline content up to the end of the enclosing tag, as markdown
+    fn inlines(&mut self) -> String {
+        let mut out = String::new();
+        while let Some((event, range)) = self.next() {
+            if let Event::End(_) = event {
+                break;
+            }
+            self.inline(event, range, &mut out);
+        }
+        out
+    }
+
+    /// Inline content up to the end of the enclosing tag, as spans
+    fn spans(&mut self) -> Vec<Inline> {
+        let mut spans = Vec::new();
+        while let Some((event, range)) = self.next() {
+            if let Event::End(_) = event {
+                break;
+            }
+            self.span(event, range, &mut spans);
+        }
+        spans
+    }
+
+    /// Formatting, inline links, code and breaks become spans; everything
+    /// else keeps its markdown as text
+    fn span(&mut self, event: Event<'a>, range: Range<usize>, spans: &mut Vec<Inline>) {
+        let span = match event {
+            Event::Code(code) => Inline::Code(code.to_string()),
+            Event::InlineMath(math) => Inline::Math(math.to_string()),
+            Event::FootnoteReference(label) => Inline::FootnoteReference(label.to_string()),
+            Event::HardBreak => Inline::LineBreak,
+            Event::Start(Tag::Emphasis) => Inline::Emphasis(self.spans()),
+            Event::Start(Tag::Strong) => Inline::Strong(self.spans()),
+            Event::Start(Tag::Strikethrough) => Inline::Strikethrough(self.spans()),
+            Event::Start(Tag::Link {
+                link_type: LinkType::Inline,
+                dest_url,
+                title,
+                ..
+            }) => Inline::Link {
+                content: self.spans(),
+                url: dest_url.to_string(),
+                title: Some(title.to_string()).filter(|title| !title.is_empty()),
+            },
+            event => {
+                let mut text = String::new();
+                self.inline(event, range, &mut text);
+                match spans.last_mut() {
+                    Some(Inline::Text(last)) => last.push_str(&text),
+                    _ => spans.push(Inline::Text(text)),
+                }
+                return;
+            }
+        };
+        spans.push(span);
+    }
+
+    fn inline(&mut self, event: Event<'a>, range: Range<usize>, out: &mut String) {
+        let source = &self.source[range.clone()];
+        // Text after a backslash escape starts at the escaped character
+        if let Event::Text(text) = &event {
+            if range.start > 0
+                && self.source.as_bytes()[range.start - 1] == b'\\'
+                && text.starts_with(|c: char| c.is_ascii_punctuation())
+                && !(self.in_table && text.starts_with('|'))
+            {
+                out.push('\\');
+            }
+        }
+        match event {
+            // Cells keep escaped pipes unescaped; the renderer escapes them
+            Event::Text(text) if self.in_table && text.contains('|') => out.push_str(&text),
+            // The source keeps backslash escapes and entities intact
+            Event::Text(_) | Event::Code(_) | Event::InlineHtml(_) | Event::Html(_) => {
+                out.push_str(source)
+            }
+            Event::InlineMath(math) => out.push_str(&format!("${}$", math)),
+            Event::DisplayMath(math) => out.push_str(&format!("$${}$$", math)),
+            Event::FootnoteReference(label) => out.push_str(&format!("[^{}]", label)),
+            Event::SoftBreak => out.push('\n'),
+            Event::HardBreak => out.push_str("\\\n"),
+            Event::TaskListMarker(checked) => out.push_str(if checked { "[x] " } else { "[ ] " }),
+            Event::Start(Tag::Emphasis) => {
+                let marker = &source[..1];
+                out.push_str(&format!("{}{}{}", marker, self.inlines(), marker));
+            }
+            Event::Start(Tag::Strong) => {
+                let marker = &source[..2];
+                out.push_str(&format!("{}{}{}", marker, self.inlines(), marker));
+            }
+            Event::Start(Tag::Strikethrough) => {
+                let marker = &source[..source.len() - source.trim_start_matches('~').len()];
+                out.push_str(&format!("{}{}{}", marker, self.inlines(), marker));
+            }
+            Event::Start(Tag::Superscript) => out.push_str(&format!("^{}^", self.inlines())),
+            Event::Start(Tag::Subscript) => out.push_str(&format!("~{}~", self.inlines())),
+            Event::Start(Tag::Link {
+                link_type,
+                dest_url,
+                title,
+                id,
+            }) => {
+                let text = self.inlines();
+                out.push_str(&link_markdown("", &text, link_type, &dest_url, &title, &id));
+            }
+            Event::Start(Tag::Image {
+                link_type,
+                dest_url,
+                title,
+                id,
+            }) => {
+                let text = self.inlines();
+                out.push_str(&link_markdown(
+                    "!", &text, link_type, &dest_url, &title, &id,
+                ));
+            }
+            Event::Start(_) => out.push_str(&self.inlines()),
+            Event::End(_) | Event::Rule => {}
+        }
+    }
+}
+
+/// Spans without the whitespace at their ends
+fn trim_spans(mut spans: Vec<Inline>) -> Vec<Inline> {
+    if let Some(Inline::Text(text)) = spans.first_mut() {
+        *text = text.trim_start().to_string();
+    }
+    if let Some(Inline::Text(text)) = spans.last_mut() {
+        *text = text.trim_end().to_string();
+    }
+    spans.retain(|span| !matches!(span, Inline::Text(text) if text.is_empty()));
+    spans
+}
+
+fn is_inline_tag(tag: &Tag) -> bool {
+    matches!(
+        tag,
+        Tag::Emphasis
+            | Tag::Strong
+            | Tag::Strikethrough
+            | Tag::Superscript
+            | Tag::Subscript
+            | Tag::Link { .. }
+            | Tag::Image { .. }
+    )
+}
+
+/// A link or image (`prefix` = "!") in the style it was written
+fn link_markdown(
+    prefix: &str,
+    text: &str,
+    link_type: LinkType,
+    dest: &str,
+    title: &str,
+    id: &str,
+) -> String {
+    match link_type {
+        LinkType::Reference => format!("{}[{}][{}]", prefix, text, id),
+        LinkType::Collapsed => format!("{}[{}][]", prefix, text),
+        LinkType::Shortcut => format!("{}[{}]", prefix, text),
+        LinkType::Autolink | LinkType::Email => format!("<{}>", text),
+        _ => {
+            let dest = if dest.contains([' ', '(', ')']) || dest.is_empty() {
+                format!("<{}>", dest)
+            } else {
+                dest.to_string()
+            };
+            if title.is_empty() {
+                format!("{}[{}]({})", prefix, text, dest)
+            } else {
+                format!(
+                    "{}[{}]({} \"{}\")",
+                    prefix,
+                    text,
+                    dest,
+                    title.replace('"', "\\\"")
+                )
+            }
+        }
+    }
+}
+
+/// Blocks as markdown, separated by blank lines
+pub(crate) fn render_blocks(blocks: &[ContentBlock]) -> String {
+    blocks
+        .iter()
+        .map(|block| block.to_markdown())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
+
+/// Blocks of a list item: nested lists and code follow the text directly,
+/// further paragraphs after a blank line
+pub(crate) fn render_blocks_tight(blocks: &[ContentBlock]) -> String {
+    let mut md = String::new();
+    for (i, block) in blocks.iter().enumerate() {
+        if i > 0 {
+            md.push_str(match block {
+                ContentBlock::Text(_) => "\n\n",
+                _ => "\n",
+            });
+        }
+        md.push_str(block.to_markdown().trim_end());
+    }
+    md
+}
+
+/// Parse YAML or TOML front matter into flat metadata entries
+pub(crate) fn front_matter_metadata(
+    kind: MetadataBlockKind,
+    text: &str,
+) -> HashMap<String, String> {
+    let value = match kind {
+        MetadataBlockKind::YamlStyle => serde_yaml::from_str::<serde_yaml::Value>(text)
+            .ok()
+            .and_then(|value| serde_json::to_value(value).ok()),
+        MetadataBlockKind::PlusesStyle => toml::from_str::<toml::Table>(text)
+            .ok()
+            .and_then(|table| serde_json::to_value(table).ok()),
+    };
+
+    let mut metadata = HashMap::new();
+    if let Some(Value::Object(map)) = value {
+        for (key, value) in map {
+            flatten_value(&key, &value, &mut metadata);
+        }
+    }
+    metadata
+}
+
+/// Nested tables become dotted keys and lists of scalars comma-separated values
+fn flatten_value(key: &str, value: &Value, metadata: &mut HashMap<String, String>) {
+    match value {
+        Value::Null => {}
+        Value::String(s) => {
+            metadata.insert(key.to_string(), s.clone());
+        }
+        Value::Object(map) => {
+            for (child, value) in map {
+                flatten_value(&format!("{}.{}", key, child), value, metadata);
+            }
+        }
+        Value::Array(items)
+            if items
+                .iter()
+                .all(|item| !item.is_object() && !item.is_array()) =>
+        {
+            let items: Vec<String> = items
+                .iter()
+                .map(|item| match item {
+                    Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                })
+                .collect();
+            metadata.insert(key.to_string(), items.join(", "));
+        }
+        other => {
+            metadata.insert(key.to_string(), other.to_string());
+        }
+    }
+}
+
+/// Put back linked images the image fetcher did not load as markdown text,
+/// so their links are kept
+fn restore_linked_images(document: &mut Document) {
+    for page in &mut document.pages {
+        for block in &mut page.content {
+            let ContentBlock::Image(image) = block else {
+                continue;
+            };
+            if !image.data.is_empty() {
+                continue;
+            }
+            let Some(src) = image.source_path.as_deref() else {
+                continue;
+            };
+            let alt = image.alt_text.as_deref().unwrap_or("");
+            let text = link_markdown(
+                "!",
+                alt,
+                LinkType::Inline,
+                src,
+                image.caption.as_deref().unwrap_or(""),
+                "",
+            );
+            *block = ContentBlock::Text(text);
+        }
+    }
+}
+
+/// Markdown file converter
 pub struct MarkdownConverter;
 
 impl MarkdownConverter {
-    fn convert_markdown(bytes: &[u8]) -> Result<Document, MarkitdownError> {
+    fn convert_markdown(
+        bytes: &[u8],
+        base: Option<&Url>,
+        extract_images: bool,
+    ) -> Result<Document, MarkitdownError> {
         let content = String::from_utf8_lossy(bytes);
 
+        // Normalize line endings and resolve relative links before parsing
+        let cleaned = content.replace("\r\n", "\n").replace('\r', "\n");
+        let cleaned = match base {
+            Some(base) => resolve_markdown_links(&cleaned, base),
+            None => cleaned,
+        };
+
+        let options = Options::ENABLE_TABLES
+            | Options::ENABLE_FOOTNOTES
+            | Options::ENABLE_STRIKETHROUGH
+            | Options::ENABLE_TASKLISTS
+            | Options::ENABLE_YAML_STYLE_METADATA_BLOCKS
+            | Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS;
+        let mut parser = Parser::new_ext(&cleaned, options).into_offset_iter();
+        let events: Vec<_> = parser.by_ref().collect();
+
+        // Link reference definitions produce no events; keep them in source order
+        let mut definitions: Vec<(usize, String)> = parser
+            .reference_definitions()
+            .iter()
+            .map(|(label, def)| {
+                let md = match &def.title {
+                    Some(title) => format!(
+                        "[{}]: {} \"{}\"",
+                        label,
+                        def.dest,
+                        title.replace('"', "\\\"")
+                    ),
+                    None => format!("[{}]: {}", label, def.dest),
+                };
+                (def.span.start, md)
+            })
+            .collect();
+        definitions.sort();
+
+        let mut builder = BlockBuilder {
+            source: &cleaned,
+            events,
+            position: 0,
+            extract_images,
+            image_count: 0,
+            in_table: false,
+            front_matter: None,
+            depth: 0,
+            spans: Vec::new(),
+        };
+        let blocks = builder.blocks();
+
         let mut document = Document::new();
-        let mut page = Page::new(1);
+        if let Some((kind, text)) = &builder.front_matter {
+            document.metadata = front_matter_metadata(*kind, text);
+            document.title = document.metadata.remove("title");
+            crate::metadata::fill_from_metadata(&mut document.properties, &document.metadata);
+        }
 
-        // Clean up the markdown (normalize line endings, trim)
-        let cleaned = content
-            .replace("\r\n", "\n")
-            .replace('\r', "\n")
-            .trim()
-            .to_string();
+        // Top-level blocks record the source lines they were read from
+        let line_starts: Vec<usize> = std::iter::once(0)
+            .chain(cleaned.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        let line = |offset: usize| line_starts.partition_point(|start| *start <= offset);
+        let mut spans = std::mem::take(&mut builder.spans).into_iter().peekable();
 
-        page.add_content(ContentBlock::Markdown(cleaned));
+        let mut page = Page::new(1);
+        for (index, block) in blocks.into_iter().enumerate() {
+            match spans.next_if(|(i, _)| *i == index) {
+                Some((_, span)) => page.add_content_with_provenance(
+                    block,
+                    Provenance::lines(
+                        line(span.start),
+                        line(span.end.saturating_sub(1).max(span.start)),
+                    ),
+                ),
+                None => page.add_content(block),
+            }
+        }
+        if !definitions.is_empty() {
+            let definitions: Vec<String> = definitions.into_iter().map(|(_, md)| md).collect();
+            page.add_content(ContentBlock::Markdown(format!(
+                "{}\n",
+                definitions.join("\n")
+            )));
+        }
         document.add_page(page);
         Ok(document)
     }
@@ -77,7 +761,26 @@ impl DocumentConverter for MarkdownConverter {
             }
         }
 
-        Self::convert_markdown(&bytes)
+        let opts = options.unwrap_or_default();
+        let base = base_url(opts.url.as_deref(), None);
+        let mut document = Self::convert_markdown(&bytes, base.as_ref(), opts.extract_images)?;
+
+        if let Some(fetcher) = opts.image_fetcher.as_ref() {
+            fetch_linked_images(&mut document, fetcher.as_ref()).await?;
+        }
+        restore_linked_images(&mut document);
+
+        // If LLM client is provided, get descriptions for all images
+        if let Some(ref llm_client) = opts.llm_client {
+            if let Some(path) = opts.image_context_path.as_deref() {
+                document.apply_image_context_path(path);
+            }
+            document = document
+                .with_image_descriptions(llm_client.as_ref())
+                .await?;
+        }
+
+        Ok(document)
     }
 
     fn supported_extensions(&self) -> &[&str] {
@@ -0,0 +1,253 @@
+//! Document properties shared by the converters.
+//!
+//! Dates in the notations formats use (ISO 8601, PDF `D:` dates, RFC 2822
+//! email dates) are parsed for [`DocumentProperties`], the free-form metadata
+//! some converters collect (HTML meta tags, ODF `meta.xml`, front matter) is
+//! mapped onto the common properties, and the `docProps` parts of Word, Excel
+//! and PowerPoint packages are read.
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use zip::ZipArchive;
+
+use crate::model::{Document, DocumentProperties};
+use crate::opendocument::entity_text;
+
+/// Parse a date and time; dates without an offset are taken as UTC
+pub(crate) fn parse_date(text: &str) -> Option<DateTime<FixedOffset>> {
+    let text = text.trim();
+    if let Some(pdf) = text.strip_prefix("D:") {
+        return pdf_date(pdf);
+    }
+    if let Ok(date) = DateTime::parse_from_rfc3339(text) {
+        return Some(date);
+    }
+    if let Ok(date) = DateTime::parse_from_rfc2822(text) {
+        return Some(date);
+    }
+    for format in [
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M",
+    ] {
+        if let Ok(date) = NaiveDateTime::parse_from_str(text, format) {
+            return Some(date.and_utc().fixed_offset());
+        }
+    }
+    NaiveDate::parse_from_str(text, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date| date.and_utc().fixed_offset())
+}
+
+/// A PDF date after its `D:` prefix: `YYYYMMDDHHmmSS` (all but the year
+/// optional) followed by `Z` or an offset such as `+01'00'`
+fn pdf_date(text: &str) -> Option<DateTime<FixedOffset>> {
+    let digits: String = text.chars().take_while(char::is_ascii_digit).collect();
+    let field = |start: usize, default: u32| {
+        digits
+            .get(start..start + 2)
+            .and_then(|d| d.parse().ok())
+            .unwrap_or(default)
+    };
+    let year: i32 = digits.get(..4)?.parse().ok()?;
+    let date = NaiveDate::from_ymd_opt(year, field(4, 1), field(6, 1))?.and_hms_opt(
+        field(8, 0),
+        field(10, 0),
+        field(12, 0),
+    )?;
+
+    let rest = &text[digits.len()..];
+    let seconds = match rest.chars().next() {
+        Some(sign @ ('+' | '-')) => {
+            let offset: String = rest[1..].chars().filter(char::is_ascii_digit).collect();
+            let part = |range: std::ops::Range<usize>| {
+                offset
+                    .get(range)
+                    .and_then(|p| p.parse::<i32>().ok())
+                    .unwrap_or(0)
+            };
+            let seconds = (part(0..2) * 60 + part(2..4)) * 60;
+            if sign == '-' {
+                -seconds
+            } else {
+                seconds
+            }
+        }
+        _ => 0,
+    };
+    date.and_local_timezone(FixedOffset::east_opt(seconds)?)
+        .single()
+}
+
+/// Split a keyword or name list on commas and semicolons
+pub(crate) fn split_list(text: &str) -> Vec<String> {
+    text.split([',', ';'])
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Split a list of names on semicolons; a single name may be written
+/// "Last, First"
+pub(crate) fn split_names(text: &str) -> Vec<String> {
+    text.split(';')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Fill the properties a converter left empty from well-known entries of its
+/// free-form metadata
+pub(crate) fn fill_from_metadata(
+    properties: &mut DocumentProperties,
+    metadata: &HashMap<String, String>,
+) {
+    let get = |keys: &[&str]| {
+        keys.iter()
+            .find_map(|key| metadata.get(*key))
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    };
+
+    if properties.authors.is_empty() {
+        if let Some(authors) = get(&["authors"]) {
+            properties.authors = split_list(authors);
+        } else if let Some(author) = get(&["author", "creator", "article:author"]) {
+            properties.authors = split_names(author);
+        }
+    }
+    if properties.created.is_none() {
+        properties.created =
+            get(&["created", "date", "article:published_time"]).and_then(parse_date);
+    }
+    if properties.modified.is_none() {
+        properties.modified = get(&["modified", "article:modified_time"]).and_then(parse_date);
+    }
+    if properties.language.is_none() {
+        properties.language = get(&["language", "lang"]).map(str::to_string);
+    }
+    if properties.page_count.is_none() {
+        properties.page_count = get(&["page_count"]).and_then(|n| n.parse().ok());
+    }
+    if properties.word_count.is_none() {
+        properties.word_count = get(&["word_count"]).and_then(|n| n.parse().ok());
+    }
+    if properties.producer.is_none() {
+        properties.producer = get(&["generator", "producer"]).map(str::to_string);
+    }
+    if properties.keywords.is_empty() {
+        if let Some(keywords) = get(&["keywords", "tags"]) {
+            properties.keywords = split_list(keywords);
+        }
+    }
+}
+
+/// Read the `docProps/core.xml` and `docProps/app.xml` parts of a Word,
+/// Excel or PowerPoint package into the document: the title (unless it has
+/// one), the common properties, and the subject, description, category,
+/// company and last editor as metadata entries
+pub(crate) fn apply_ooxml_properties(document: &mut Document, bytes: &[u8]) {
+    let Ok(mut archive) = ZipArchive::new(Cursor::new(bytes)) else {
+        return;
+    };
+    let mut read_part = |name: &str| {
+        let mut file = archive.by_name(name).ok()?;
+        let mut xml = String::new();
+        file.read_to_string(&mut xml).ok()?;
+        Some(xml)
+    };
+    let core = read_part("docProps/core.xml");
+    let app = read_part("docProps/app.xml");
+
+    let properties = &mut document.properties;
+    for (name, value) in core.as_deref().map(part_fields).unwrap_or_default() {
+        let extra = match name.as_str() {
+            "dc:title" => {
+                document.title.get_or_insert(value);
+                continue;
+            }
+            "dc:creator" => {
+                properties.authors = split_names(&value);
+                continue;
+            }
+            "cp:keywords" => {
+                properties.keywords = split_list(&value);
+                continue;
+            }
+            "dcterms:created" => {
+                properties.created = parse_date(&value);
+                continue;
+            }
+            "dcterms:modified" => {
+                properties.modified = parse_date(&value);
+                continue;
+            }
+            "dc:language" => {
+                properties.language = Some(value);
+                continue;
+            }
+            "dc:subject" => "subject",
+            "dc:description" => "description",
+            "cp:category" => "category",
+            "cp:lastModifiedBy" => "last_modified_by",
+            "cp:revision" => "revision",
+            _ => continue,
+        };
+        document.metadata.insert(extra.to_string(), value);
+    }
+    for (name, value) in app.as_deref().map(part_fields).unwrap_or_default() {
+        match name.as_str() {
+            "Application" => properties.producer = Some(value),
+            "Pages" | "Slides" => properties.page_count = value.parse().ok(),
+            "Words" => properties.word_count = value.parse().ok(),
+            "Company" => {
+                document.metadata.insert("company".to_string(), value);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Name and text of the elements directly below the root of a properties part
+fn part_fields(xml: &str) -> Vec<(String, String)> {
+    let mut reader = Reader::from_str(xml);
+    let mut fields = Vec::new();
+    let mut depth = 0;
+    let mut text = String::new();
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(_)) => {
+                depth += 1;
+                text.clear();
+            }
+            Ok(Event::Text(e)) if depth == 2 => {
+                if let Ok(decoded) = e.decode() {
+                    text.push_str(&decoded);
+                }
+            }
+            Ok(Event::GeneralRef(e)) if depth == 2 => {
+                if let Ok(name) = e.decode() {
+                    text.push_str(&entity_text(&name));
+                }
+            }
+            Ok(Event::End(e)) => {
+                if depth == 2 && !text.trim().is_empty() {
+                    let name = String::from_utf8_lossy(e.name().as_ref()).into_owned();
+                    fields.push((name, text.trim().to_string()));
+                }
+                depth -= 1;
+                text.clear();
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+    }
+    fields
+}
@@ -1,12 +1,22 @@
 use async_trait::async_trait;
 use bytes::Bytes;
+use chrono::{DateTime, FixedOffset};
 use object_store::ObjectStore;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+use crate::calendar::RecurrenceWindow;
 use crate::error::MarkitdownError;
+use crate::fetch::SharedImageFetcher;
+use crate::jupyter::OutputLimits;
 use crate::llm::{LlmClient, SharedLlmClient};
-use crate::table_merge;
+use crate::log::FullLog;
+use crate::mailbox::MailboxFilter;
+use crate::nested::SharedNestedConverter;
+use crate::quotes::QuotedReplyMode;
+use crate::render::MarkdownRenderer;
+use crate::sqlite::DatabaseOptions;
+use crate::table_merge::{self, TableSpan};
 
 /// Represents an extracted image from a document
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,6 +40,8 @@ pub struct ExtractedImage {
     pub page_number: Option<u32>,
     /// Optional source path or context hint for this image
     pub source_path: Option<String>,
+    /// Optional caption from the source (e.g., an HTML `<figcaption>`)
+    pub caption: Option<String>,
 }
 
 impl ExtractedImage {
@@ -44,6 +56,7 @@ impl ExtractedImage {
             height: None,
             page_number: None,
             source_path: None,
+            caption: None,
         }
     }
 
@@ -89,69 +102,398 @@ pub enum ContentBlock {
     Quote(String),
     /// Raw markdown (already formatted)
     Markdown(String),
+    /// A paragraph of text with inline formatting, links and footnote references
+    Paragraph(Vec<Inline>),
+    /// A list whose items can hold checkboxes and nested lists
+    NestedList { ordered: bool, items: Vec<ListItem> },
+    /// The text of a footnote, referenced by `Inline::FootnoteReference`
+    FootnoteDefinition {
+        label: String,
+        content: Vec<ContentBlock>,
+    },
+    /// Display math in LaTeX notation
+    Math(String),
+    /// A horizontal rule between sections
+    Rule,
+    /// A page break from the source (e.g. a Word page break)
+    PageBreak,
+    /// Terms with their definitions
+    DefinitionList(Vec<Definition>),
+    /// A table with merged cells, column alignment, a caption or several
+    /// header rows; rendered as HTML when a pipe table cannot express it
+    RichTable(Table),
 }
 
-impl ContentBlock {
-    /// Convert this content block to markdown
-    pub fn to_markdown(&self) -> String {
-        match self {
-            ContentBlock::Text(text) => format!("{}\n", text),
-            ContentBlock::Heading { level, text } => {
-                format!("{} {}\n", "#".repeat(*level as usize), text)
-            }
-            ContentBlock::Image(img) => {
-                if let Some(desc) = img.get_display_text() {
-                    format!("![{}]({})\n\n*{}*\n", img.id, img.id, desc)
-                } else {
-                    format!("![{}]({})\n", img.id, img.id)
+/// A span of text within a `ContentBlock::Paragraph`, list item or definition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Inline {
+    /// Plain text
+    Text(String),
+    /// Emphasized (italic) text
+    Emphasis(Vec<Inline>),
+    /// Strongly emphasized (bold) text
+    Strong(Vec<Inline>),
+    /// Struck-through text
+    Strikethrough(Vec<Inline>),
+    /// Inline code
+    Code(String),
+    /// A hyperlink
+    Link {
+        content: Vec<Inline>,
+        url: String,
+        title: Option<String>,
+    },
+    /// Inline math in LaTeX notation
+    Math(String),
+    /// A reference to a `ContentBlock::FootnoteDefinition` with the same label
+    FootnoteReference(String),
+    /// A hard line break
+    LineBreak,
+}
+
+impl Inline {
+    pub fn text(text: impl Into<String>) -> Self {
+        Inline::Text(text.into())
+    }
+
+    /// The text of spans without any formatting
+    pub fn plain_text(spans: &[Inline]) -> String {
+        spans
+            .iter()
+            .map(|span| match span {
+                Inline::Text(text) | Inline::Code(text) | Inline::Math(text) => text.clone(),
+                Inline::Emphasis(spans) | Inline::Strong(spans) | Inline::Strikethrough(spans) => {
+                    Inline::plain_text(spans)
                 }
-            }
-            ContentBlock::Table { headers, rows } => {
-                let mut md = String::new();
-                md.push_str("| ");
-                md.push_str(&headers.join(" | "));
-                md.push_str(" |\n| ");
-                md.push_str(
-                    &headers
-                        .iter()
-                        .map(|_| "---")
-                        .collect::<Vec<_>>()
-                        .join(" | "),
-                );
-                md.push_str(" |\n");
-                for row in rows {
-                    md.push_str("| ");
-                    md.push_str(&row.join(" | "));
-                    md.push_str(" |\n");
+                Inline::Link { content, .. } => Inline::plain_text(content),
+                Inline::FootnoteReference(label) => format!("[{}]", label),
+                Inline::LineBreak => "\n".to_string(),
+            })
+            .collect()
+    }
+}
+
+/// An item of a `ContentBlock::NestedList`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListItem {
+    /// The item text
+    pub content: Vec<Inline>,
+    /// `Some` for task list items: whether the box is checked
+    pub checked: Option<bool>,
+    /// Blocks below the item text, such as nested lists
+    pub children: Vec<ContentBlock>,
+}
+
+impl ListItem {
+    pub fn new(content: Vec<Inline>) -> Self {
+        Self {
+            content,
+            checked: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Make this a task list item with a checked or unchecked box
+    pub fn with_checkbox(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<ContentBlock>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+/// Horizontal alignment of a table column
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Alignment {
+    #[default]
+    Default,
+    Left,
+    Center,
+    Right,
+}
+
+/// Upper bound for colspan/rowspan values (matches the HTML spec limit for colspan)
+pub(crate) const MAX_SPAN: usize = 1000;
+
+/// A cell of a `Table`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableCell {
+    pub text: String,
+    /// Number of columns the cell covers (at least 1, read as at most 1000)
+    pub colspan: usize,
+    /// Number of rows the cell covers (at least 1, read as at most 1000)
+    pub rowspan: usize,
+}
+
+impl TableCell {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            colspan: 1,
+            rowspan: 1,
+        }
+    }
+
+    pub fn with_colspan(mut self, colspan: usize) -> Self {
+        self.colspan = colspan.clamp(1, MAX_SPAN);
+        self
+    }
+
+    pub fn with_rowspan(mut self, rowspan: usize) -> Self {
+        self.rowspan = rowspan.clamp(1, MAX_SPAN);
+        self
+    }
+
+    /// Columns covered, within `1..=MAX_SPAN` whatever the field holds
+    pub(crate) fn columns(&self) -> usize {
+        self.colspan.clamp(1, MAX_SPAN)
+    }
+
+    /// Rows covered, within `1..=MAX_SPAN` whatever the field holds
+    pub(crate) fn rows(&self) -> usize {
+        self.rowspan.clamp(1, MAX_SPAN)
+    }
+}
+
+impl From<&str> for TableCell {
+    fn from(text: &str) -> Self {
+        TableCell::new(text)
+    }
+}
+
+impl From<String> for TableCell {
+    fn from(text: String) -> Self {
+        TableCell::new(text)
+    }
+}
+
+/// A table with cell spans, column alignment, a caption and header and footer
+/// rows. Row spans do not cross from one section into the next.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Table {
+    pub caption: Option<String>,
+    /// Alignment of each column; columns without an entry use the default
+    pub alignments: Vec<Alignment>,
+    /// Header rows; empty for a table without a header
+    pub header: Vec<Vec<TableCell>>,
+    pub body: Vec<Vec<TableCell>>,
+    /// Footer rows such as totals
+    pub footer: Vec<Vec<TableCell>>,
+}
+
+impl Table {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A table of plain rows with one header row (none when `headers` is empty)
+    pub fn from_rows(headers: Vec<String>, rows: Vec<Vec<String>>) -> Self {
+        let row = |cells: Vec<String>| cells.into_iter().map(TableCell::new).collect();
+        Self {
+            header: if headers.is_empty() {
+                Vec::new()
+            } else {
+                vec![row(headers)]
+            },
+            body: rows.into_iter().map(row).collect(),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn with_alignments(mut self, alignments: Vec<Alignment>) -> Self {
+        self.alignments = alignments;
+        self
+    }
+
+    pub fn with_header_row(mut self, row: Vec<TableCell>) -> Self {
+        self.header.push(row);
+        self
+    }
+
+    pub fn with_row(mut self, row: Vec<TableCell>) -> Self {
+        self.body.push(row);
+        self
+    }
+
+    pub fn with_footer_row(mut self, row: Vec<TableCell>) -> Self {
+        self.footer.push(row);
+        self
+    }
+
+    /// Alignment of a column
+    pub fn alignment(&self, column: usize) -> Alignment {
+        self.alignments.get(column).copied().unwrap_or_default()
+    }
+
+    /// Number of columns, counting the columns spanned cells cover
+    pub fn column_count(&self) -> usize {
+        [&self.header, &self.body, &self.footer]
+            .into_iter()
+            .map(|rows| Self::cell_columns(rows).1)
+            .max()
+            .unwrap_or(0)
+            .max(self.alignments.len())
+    }
+
+    /// Whether a pipe table can show this table: no merged cells and at most
+    /// one header row
+    pub fn is_simple(&self) -> bool {
+        self.header.len() <= 1
+            && [&self.header, &self.body, &self.footer]
+                .into_iter()
+                .flatten()
+                .flatten()
+                .all(|cell| cell.colspan <= 1 && cell.rowspan <= 1)
+    }
+
+    /// The column each cell of `rows` starts in, skipping positions covered
+    /// by row spans from above, and the number of columns used
+    pub(crate) fn cell_columns(rows: &[Vec<TableCell>]) -> (Vec<Vec<usize>>, usize) {
+        // Rows still covered by a row span, per column
+        let mut covered: Vec<usize> = Vec::new();
+        let mut width = 0;
+        let mut columns = Vec::with_capacity(rows.len());
+        for row in rows {
+            let mut starts = Vec::with_capacity(row.len());
+            let mut column = 0;
+            let mut next_covered = covered
+                .iter()
+                .map(|n| n.saturating_sub(1))
+                .collect::<Vec<_>>();
+            for cell in row {
+                while covered.get(column).is_some_and(|n| *n > 0) {
+                    column += 1;
                 }
-                md
-            }
-            ContentBlock::List { ordered, items } => {
-                let mut md = String::new();
-                for (i, item) in items.iter().enumerate() {
-                    if *ordered {
-                        md.push_str(&format!("{}. {}\n", i + 1, item));
-                    } else {
-                        md.push_str(&format!("- {}\n", item));
-                    }
+                starts.push(column);
+                let span = cell.columns();
+                if next_covered.len() < column + span {
+                    next_covered.resize(column + span, 0);
                 }
-                md
-            }
-            ContentBlock::Code { language, code } => {
-                format!("```{}\n{}\n```\n", language.as_deref().unwrap_or(""), code)
-            }
-            ContentBlock::Quote(text) => {
-                text.lines()
-                    .map(|line| format!("> {}", line))
-                    .collect::<Vec<_>>()
-                    .join("\n")
-                    + "\n"
+                for rows_left in &mut next_covered[column..column + span] {
+                    *rows_left = cell.rows() - 1;
+                }
+                column += span;
             }
-            ContentBlock::Markdown(md) => md.clone(),
+            width = width.max(column).max(
+                covered
+                    .iter()
+                    .rposition(|n| *n > 0)
+                    .map_or(0, |last| last + 1),
+            );
+            covered = next_covered;
+            columns.push(starts);
+        }
+        (columns, width)
+    }
+}
+
+/// A term of a `ContentBlock::DefinitionList` with its definitions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Definition {
+    pub term: Vec<Inline>,
+    pub definitions: Vec<Vec<Inline>>,
+}
+
+/// Where a content block came from in the source file, so search hits and
+/// citations can point back to the original
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Provenance {
+    /// Index of the block in `Page::content`
+    pub block_index: usize,
+    /// PDF page (1-indexed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    /// Area of the PDF page the block was read from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bounding_box: Option<BoundingBox>,
+    /// Spreadsheet sheet name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sheet: Option<String>,
+    /// Spreadsheet cell range in A1 notation (e.g. "A1:D20")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cell_range: Option<String>,
+    /// Presentation slide (1-indexed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slide: Option<u32>,
+    /// Name of the slide shape holding the block (e.g. "Title 1")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shape: Option<String>,
+    /// Source lines of text formats
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lines: Option<LineRange>,
+}
+
+impl Provenance {
+    /// A PDF page, optionally narrowed to an area of it
+    pub fn pdf_page(page: u32, bounding_box: Option<BoundingBox>) -> Self {
+        Self {
+            page: Some(page),
+            bounding_box,
+            ..Self::default()
+        }
+    }
+
+    /// A spreadsheet sheet, optionally narrowed to a cell range
+    pub fn sheet(sheet: impl Into<String>, cell_range: Option<String>) -> Self {
+        Self {
+            sheet: Some(sheet.into()),
+            cell_range,
+            ..Self::default()
+        }
+    }
+
+    /// A presentation slide, optionally narrowed to one shape
+    pub fn slide(slide: u32, shape: Option<String>) -> Self {
+        Self {
+            slide: Some(slide),
+            shape,
+            ..Self::default()
+        }
+    }
+
+    /// Lines `start..=end` (1-indexed) of a text file
+    pub fn lines(start: usize, end: usize) -> Self {
+        Self {
+            lines: Some(LineRange {
+                start,
+                end: end.max(start),
+            }),
+            ..Self::default()
         }
     }
 }
 
+/// A rectangle on a PDF page in points, with the origin at the bottom left
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BoundingBox {
+    pub x0: f64,
+    pub y0: f64,
+    pub x1: f64,
+    pub y1: f64,
+}
+
+/// Lines of a text file, 1-indexed and inclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl ContentBlock {
+    /// Convert this content block to markdown
+    pub fn to_markdown(&self) -> String {
+        MarkdownRenderer::default().render_block(self)
+    }
+}
+
 /// Represents a single page of content extracted from a document
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Page {
@@ -161,6 +503,13 @@ pub struct Page {
     pub content: Vec<ContentBlock>,
     /// Optional rendered image of the entire page (for scanned PDFs, slides, complex layouts)
     pub rendered_image: Option<ExtractedImage>,
+    /// Where content blocks came from in the source, for the blocks that
+    /// record it
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub provenance: Vec<Provenance>,
+    /// Language of the page text as a BCP 47 tag (e.g. "en")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
 }
 
 impl Page {
@@ -169,6 +518,8 @@ impl Page {
             page_number,
             content: Vec::new(),
             rendered_image: None,
+            provenance: Vec::new(),
+            language: None,
         }
     }
 
@@ -183,6 +534,20 @@ impl Page {
         self.content.push(block);
     }
 
+    /// Add a content block along with where it came from in the source
+    pub fn add_content_with_provenance(&mut self, block: ContentBlock, provenance: Provenance) {
+        self.provenance.push(Provenance {
+            block_index: self.content.len(),
+            ..provenance
+        });
+        self.content.push(block);
+    }
+
+    /// Where the block at `index` came from, if recorded
+    pub fn provenance_of(&self, index: usize) -> Option<&Provenance> {
+        self.provenance.iter().find(|p| p.block_index == index)
+    }
+
     /// Apply a source path hint to all images on this page (if not already set)
     pub fn apply_image_context_path(&mut self, path: &str) {
         for block in &mut self.content {
@@ -216,11 +581,7 @@ impl Page {
 
     /// Get all text content (excluding images) as markdown
     pub fn to_markdown(&self) -> String {
-        self.content
-            .iter()
-            .map(|block| block.to_markdown())
-            .collect::<Vec<_>>()
-            .join("\n")
+        MarkdownRenderer::default().render_page(self)
     }
 
     /// Create a new page with images replaced by their LLM descriptions
@@ -258,6 +619,8 @@ impl Page {
 
         // Build the new page with descriptions
         let mut new_page = Page::new(self.page_number);
+        new_page.provenance = self.provenance.clone();
+        new_page.language = self.language.clone();
         let mut desc_iter = descriptions.into_iter();
         let mut image_indices: std::collections::HashSet<usize> =
             images_to_describe.iter().map(|(i, _)| *i).collect();
@@ -281,6 +644,8 @@ impl Page {
     /// Convert this page to a text-only page, replacing images with their descriptions
     pub fn to_text_only(&self) -> Page {
         let mut new_page = Page::new(self.page_number);
+        new_page.provenance = self.provenance.clone();
+        new_page.language = self.language.clone();
 
         for block in &self.content {
             match block {
@@ -299,6 +664,41 @@ impl Page {
     }
 }
 
+/// Metadata every format can have. The title is `Document::title`;
+/// format-specific entries stay in `Document::metadata`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DocumentProperties {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<DateTime<FixedOffset>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified: Option<DateTime<FixedOffset>>,
+    /// Language tag the document declares (e.g. "en-US")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Pages (or slides) according to the source file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_count: Option<u32>,
+    /// Words according to the source file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub word_count: Option<u64>,
+    /// File format without the dot (e.g. "docx")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_format: Option<String>,
+    /// Application that produced the file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub producer: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+}
+
+impl DocumentProperties {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
 /// Represents a complete document with multiple pages
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Document {
@@ -308,6 +708,16 @@ pub struct Document {
     pub pages: Vec<Page>,
     /// Document-level metadata
     pub metadata: std::collections::HashMap<String, String>,
+    /// Authors, dates, language and the other metadata common to all formats
+    #[serde(default, skip_serializing_if = "DocumentProperties::is_empty")]
+    pub properties: DocumentProperties,
+    /// Language of the text as an ISO 639-1 code (e.g. "en"), comparable with
+    /// `Page::language`; set when language detection is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Tables merged across pages by [`Document::with_merged_tables`]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub table_spans: Vec<TableSpan>,
 }
 
 impl Document {
@@ -316,6 +726,9 @@ impl Document {
             title: None,
             pages: Vec::new(),
             metadata: std::collections::HashMap::new(),
+            properties: DocumentProperties::default(),
+            language: None,
+            table_spans: Vec::new(),
         }
     }
 
@@ -325,6 +738,9 @@ impl Document {
             title: None,
             pages: vec![page],
             metadata: std::collections::HashMap::new(),
+            properties: DocumentProperties::default(),
+            language: None,
+            table_spans: Vec::new(),
         }
     }
 
@@ -347,20 +763,13 @@ impl Document {
 
     /// Convert the entire document to markdown
     pub fn to_markdown(&self) -> String {
-        let mut md = String::new();
-
-        if let Some(title) = &self.title {
-            md.push_str(&format!("# {}\n\n", title));
-        }
-
-        for page in &self.pages {
-            if self.pages.len() > 1 {
-                md.push_str(&format!("\n---\n## Page {}\n\n", page.page_number));
-            }
-            md.push_str(&page.to_markdown());
-        }
+        MarkdownRenderer::default().render(self)
+    }
 
-        md
+    /// Convert the entire document to markdown with a custom renderer
+    /// (flavor, heading offset, emoji, line width)
+    pub fn to_markdown_with(&self, renderer: &MarkdownRenderer) -> String {
+        renderer.render(self)
     }
 
     /// Create a new document with all images replaced by LLM descriptions
@@ -371,6 +780,8 @@ impl Document {
         let mut new_doc = Document::new();
         new_doc.title = self.title.clone();
         new_doc.metadata = self.metadata.clone();
+        new_doc.properties = self.properties.clone();
+        new_doc.table_spans = self.table_spans.clone();
 
         for page in &self.pages {
             new_doc.add_page(page.with_image_descriptions(llm_client).await?);
@@ -384,6 +795,8 @@ impl Document {
         let mut new_doc = Document::new();
         new_doc.title = self.title.clone();
         new_doc.metadata = self.metadata.clone();
+        new_doc.properties = self.properties.clone();
+        new_doc.table_spans = self.table_spans.clone();
 
         for page in &self.pages {
             new_doc.add_page(page.to_text_only());
@@ -396,37 +809,19 @@ impl Document {
     ///
     /// This method detects tables at page boundaries and merges them when:
     /// - A table ends at the bottom of a page
-    /// - The next page starts with table content (with or without header)
+    /// - The next page starts with table content (with a repeated header, no
+    ///   header, or after a "continued" caption)
     /// - The column counts match
     ///
-    /// Merged tables are placed on the first page where they start.
+    /// Merged tables are placed on the first page where they start; all other
+    /// content blocks are kept. Markdown blocks that end or start with a pipe
+    /// table (e.g. LLM-converted PDF pages) have that table split out into a
+    /// table block when it is merged. `table_spans` records the pages each
+    /// merged table came from.
     pub fn with_merged_tables(&self) -> Document {
-        if self.pages.len() <= 1 {
-            return self.clone();
-        }
-
-        // Extract markdown content from each page
-        let page_contents: Vec<(u32, String)> = self
-            .pages
-            .iter()
-            .map(|p| (p.page_number, p.to_markdown()))
-            .collect();
-
-        // Merge tables across pages
-        let merged_contents = table_merge::merge_tables_across_pages(&page_contents);
-
-        // Rebuild the document with merged content
-        let mut new_doc = Document::new();
-        new_doc.title = self.title.clone();
-        new_doc.metadata = self.metadata.clone();
-
-        for merged in merged_contents {
-            let mut page = Page::new(merged.page_number);
-            // Use the merged markdown content
-            page.add_content(ContentBlock::Markdown(merged.content));
-            new_doc.add_page(page);
-        }
-
+        let mut new_doc = self.clone();
+        let spans = table_merge::merge_page_tables(&mut new_doc.pages);
+        new_doc.table_spans.extend(spans);
         new_doc
     }
 }
@@ -470,6 +865,27 @@ pub struct ConversionOptions {
     pub force_llm_ocr: bool,
     /// Merge tables that span multiple pages into a single table
     pub merge_multipage_tables: bool,
+    /// Only keep the main content of web pages (drops navigation, footers, banners)
+    pub main_content_only: bool,
+    /// Optional fetcher for images referenced (not embedded) by the document
+    pub image_fetcher: Option<SharedImageFetcher>,
+    /// Converter for files embedded in the document (e.g. email attachments).
+    /// `MarkItDown` sets this to its converter registry when unset.
+    pub nested_converter: Option<SharedNestedConverter>,
+    /// Only convert the mailbox messages (mbox, Maildir) matching this filter
+    pub mailbox_filter: Option<MailboxFilter>,
+    /// What to do with quoted replies and signatures in email bodies
+    pub quoted_replies: QuotedReplyMode,
+    /// Expand recurring calendar events into their occurrences in this range
+    pub recurrence_window: Option<RecurrenceWindow>,
+    /// Render vCard address books as one compact table row per contact
+    pub contact_table: bool,
+    /// Sampling, schema objects and BLOB rendering for SQLite databases
+    pub database: DatabaseOptions,
+    /// When log files include their full text below the analysis
+    pub full_log: FullLog,
+    /// How much of each Jupyter notebook cell output is kept
+    pub notebook_outputs: OutputLimits,
 }
 
 impl std::fmt::Debug for ConversionOptions {
@@ -484,6 +900,23 @@ impl std::fmt::Debug for ConversionOptions {
             .field("image_context_path", &self.image_context_path)
             .field("extract_images", &self.extract_images)
             .field("force_llm_ocr", &self.force_llm_ocr)
+            .field("merge_multipage_tables", &self.merge_multipage_tables)
+            .field("main_content_only", &self.main_content_only)
+            .field(
+                "image_fetcher",
+                &self.image_fetcher.as_ref().map(|_| "<ImageFetcher>"),
+            )
+            .field(
+                "nested_converter",
+                &self.nested_converter.as_ref().map(|_| "<NestedConverter>"),
+            )
+            .field("mailbox_filter", &self.mailbox_filter)
+            .field("quoted_replies", &self.quoted_replies)
+            .field("recurrence_window", &self.recurrence_window)
+            .field("contact_table", &self.contact_table)
+            .field("database", &self.database)
+            .field("full_log", &self.full_log)
+            .field("notebook_outputs", &self.notebook_outputs)
             .finish()
     }
 }
@@ -498,6 +931,16 @@ impl Default for ConversionOptions {
             extract_images: true,
             force_llm_ocr: false,
             merge_multipage_tables: false,
+            main_content_only: false,
+            image_fetcher: None,
+            nested_converter: None,
+            mailbox_filter: None,
+            quoted_replies: QuotedReplyMode::Keep,
+            recurrence_window: None,
+            contact_table: false,
+            database: DatabaseOptions::default(),
+            full_log: FullLog::Always,
+            notebook_outputs: OutputLimits::default(),
         }
     }
 }
@@ -512,6 +955,12 @@ impl ConversionOptions {
         self
     }
 
+    /// Set the source URL; relative links and images are resolved against it
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
     pub fn with_llm(mut self, client: SharedLlmClient) -> Self {
         self.llm_client = Some(client);
         self
@@ -542,6 +991,71 @@ impl ConversionOptions {
         self.merge_multipage_tables = merge;
         self
     }
+
+    /// Enable readability-style main content extraction for HTML input.
+    /// Navigation menus, cookie banners, sidebars and footers are dropped and
+    /// only the article body is converted.
+    pub fn with_main_content_only(mut self, main_only: bool) -> Self {
+        self.main_content_only = main_only;
+        self
+    }
+
+    /// Load images that HTML documents link to instead of embedding, so they
+    /// can be extracted and described like embedded images.
+    pub fn with_image_fetcher(mut self, fetcher: SharedImageFetcher) -> Self {
+        self.image_fetcher = Some(fetcher);
+        self
+    }
+
+    /// Convert embedded files (e.g. email attachments) with this converter
+    /// instead of the `MarkItDown` registry
+    pub fn with_nested_converter(mut self, converter: SharedNestedConverter) -> Self {
+        self.nested_converter = Some(converter);
+        self
+    }
+
+    /// Only convert mailbox messages matching `filter` (by date or sender)
+    pub fn with_mailbox_filter(mut self, filter: MailboxFilter) -> Self {
+        self.mailbox_filter = Some(filter);
+        self
+    }
+
+    /// Remove or collapse quoted replies and signatures in email bodies
+    pub fn with_quoted_replies(mut self, mode: QuotedReplyMode) -> Self {
+        self.quoted_replies = mode;
+        self
+    }
+
+    /// List the occurrences of recurring calendar events within `window`
+    pub fn with_recurrence_window(mut self, window: RecurrenceWindow) -> Self {
+        self.recurrence_window = Some(window);
+        self
+    }
+
+    /// Render vCard contacts as a table instead of one section each
+    pub fn with_contact_table(mut self, table: bool) -> Self {
+        self.contact_table = table;
+        self
+    }
+
+    /// Control how SQLite databases are summarized (sample size, views,
+    /// indexes, triggers, foreign keys and BLOBs)
+    pub fn with_database_options(mut self, database: DatabaseOptions) -> Self {
+        self.database = database;
+        self
+    }
+
+    /// Include, limit or omit the full text of log files
+    pub fn with_full_log(mut self, full_log: FullLog) -> Self {
+        self.full_log = full_log;
+        self
+    }
+
+    /// Limit the lines and characters kept of each notebook cell output
+    pub fn with_notebook_output_limits(mut self, limits: OutputLimits) -> Self {
+        self.notebook_outputs = limits;
+        self
+    }
 }
 
 /// Trait for document converters that work with ObjectStore
@@ -0,0 +1,127 @@
+//! Conversion of files embedded in other documents.
+//!
+//! Containers such as emails carry files (PDFs, Office documents, other
+//! emails) that have converters of their own. `MarkItDown` passes its
+//! converter registry to converters as `ConversionOptions::nested_converter`,
+//! so embedded files are converted exactly like top-level ones.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::error::MarkitdownError;
+use crate::model::{ConversionOptions, Document, DocumentConverter};
+
+/// How deep embedded files are followed (an email attached to an email
+/// attached to an email...)
+pub const MAX_NESTING_DEPTH: usize = 4;
+
+/// Converts files embedded in a document
+#[async_trait]
+pub trait NestedConverter: Send + Sync {
+    /// Convert an embedded file. The format is taken from `file_name`, then
+    /// `mime_type`, then the content. Returns `Ok(None)` when no converter
+    /// handles the file or the nesting limit is reached.
+    async fn convert_nested(
+        &self,
+        bytes: Bytes,
+        file_name: Option<&str>,
+        mime_type: Option<&str>,
+        options: ConversionOptions,
+    ) -> Result<Option<Document>, MarkitdownError>;
+}
+
+/// Shared reference to a nested converter
+pub type SharedNestedConverter = Arc<dyn NestedConverter>;
+
+/// Nested converter backed by the converters registered with `MarkItDown`
+pub struct ConverterRegistry {
+    converters: Vec<Arc<dyn DocumentConverter>>,
+    depth: usize,
+}
+
+impl ConverterRegistry {
+    pub fn new(converters: Vec<Arc<dyn DocumentConverter>>) -> Self {
+        Self {
+            converters,
+            depth: 0,
+        }
+    }
+
+    fn find_converter(&self, extension: &str) -> Option<&Arc<dyn DocumentConverter>> {
+        self.converters.iter().find(|c| c.can_handle(extension))
+    }
+
+    /// Pick the extension of an embedded file
+    fn detect_extension(
+        &self,
+        bytes: &[u8],
+        file_name: Option<&str>,
+        mime_type: Option<&str>,
+    ) -> Option<String> {
+        let known = |ext: String| self.find_converter(&ext).is_some().then_some(ext);
+
+        if let Some(ext) = file_name
+            .and_then(|name| Path::new(name).extension())
+            .and_then(|ext| ext.to_str())
+            .map(|ext| format!(".{}", ext.to_lowercase()))
+            .and_then(known)
+        {
+            return Some(ext);
+        }
+
+        let mime = mime_type
+            .and_then(|m| m.split(';').next())
+            .map(|m| m.trim().to_lowercase())
+            .filter(|m| m != "application/octet-stream");
+        if let Some(ext) = mime.as_deref().and_then(|mime| {
+            mime_guess::get_mime_extensions_str(mime)
+                .into_iter()
+                .flatten()
+                .find_map(|ext| known(format!(".{}", ext)))
+        }) {
+            return Some(ext);
+        }
+        // Attached emails are often named without an extension
+        if mime.as_deref() == Some("message/rfc822") {
+            return known(".eml".to_string());
+        }
+
+        infer::get(bytes).and_then(|kind| known(format!(".{}", kind.extension())))
+    }
+}
+
+#[async_trait]
+impl NestedConverter for ConverterRegistry {
+    async fn convert_nested(
+        &self,
+        bytes: Bytes,
+        file_name: Option<&str>,
+        mime_type: Option<&str>,
+        mut options: ConversionOptions,
+    ) -> Result<Option<Document>, MarkitdownError> {
+        if self.depth >= MAX_NESTING_DEPTH {
+            return Ok(None);
+        }
+        let Some(extension) = self.detect_extension(&bytes, file_name, mime_type) else {
+            return Ok(None);
+        };
+        let Some(converter) = self.find_converter(&extension) else {
+            return Ok(None);
+        };
+
+        options.file_extension = Some(extension);
+        if let Some(name) = file_name {
+            options.image_context_path = Some(name.to_string());
+        }
+        options.nested_converter = Some(Arc::new(ConverterRegistry {
+            converters: self.converters.clone(),
+            depth: self.depth + 1,
+        }));
+        converter
+            .convert_bytes(bytes, Some(options))
+            .await
+            .map(Some)
+    }
+}
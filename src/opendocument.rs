@@ -1,151 +1,996 @@
-//! OpenDocument format converters (.odt, .ods, .odp).
+//! OpenDocument format converters (.odt, .ods, .odp, .odg and the flat .fod* variants).
 //!
 //! OpenDocument formats are ZIP-based XML formats used by LibreOffice, OpenOffice, etc.
+//! Text documents are walked element by element so outline headings, nested
+//! lists, tables, notes, links and pictures map onto content blocks, and
+//! `meta.xml` fills the document metadata.
 
 use async_trait::async_trait;
+use base64::prelude::*;
 use bytes::Bytes;
 use object_store::ObjectStore;
-use quick_xml::events::Event;
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::{BytesStart, Event};
 use quick_xml::reader::Reader;
+use std::collections::HashMap;
 use std::io::{Cursor, Read};
 use std::sync::Arc;
 use zip::ZipArchive;
 
 use crate::error::MarkitdownError;
-use crate::model::{ContentBlock, ConversionOptions, Document, DocumentConverter, Page};
+use crate::markdown::render_blocks_tight;
+use crate::model::{
+    ContentBlock, ConversionOptions, Document, DocumentConverter, ExtractedImage, Page,
+};
+use crate::render::indent_continuation;
+
+/// Upper bound for `number-columns-repeated` / `number-rows-repeated`, which
+/// office suites use to pad tables out to the sheet size
+const MAX_REPEAT: usize = 256;
 
 /// OpenDocument Text (.odt) converter
 pub struct OdtConverter;
 
 impl OdtConverter {
-    fn convert_odt(bytes: &[u8]) -> Result<Document, MarkitdownError> {
+    fn convert_odt(bytes: &[u8], extract_images: bool) -> Result<Document, MarkitdownError> {
         let cursor = Cursor::new(bytes);
         let mut archive = ZipArchive::new(cursor)
             .map_err(|e| MarkitdownError::ParseError(format!("ODT parse error: {}", e)))?;
 
+        let meta = read_entry(&mut archive, "meta.xml")?;
+        let styles = read_entry(&mut archive, "styles.xml")?;
+        let content = read_entry(&mut archive, "content.xml")?;
+        let pictures = if extract_images {
+            read_pictures(&mut archive)?
+        } else {
+            HashMap::new()
+        };
+        Self::text_document(
+            meta.as_deref(),
+            styles.as_deref(),
+            content.as_deref(),
+            &pictures,
+            extract_images,
+        )
+    }
+
+    /// Build a text document from the parts of a package; a flat document
+    /// serves as all of them
+    fn text_document(
+        meta: Option<&str>,
+        styles: Option<&str>,
+        content: Option<&str>,
+        pictures: &HashMap<String, Vec<u8>>,
+        extract_images: bool,
+    ) -> Result<Document, MarkitdownError> {
         let mut document = Document::new();
         let mut page = Page::new(1);
-        let mut markdown = String::new();
 
-        markdown.push_str("# OpenDocument Text\n\n");
+        if let Some(meta) = meta {
+            document.metadata = odf_metadata(meta)?;
+            document.title = document.metadata.remove("title");
+            crate::metadata::fill_from_metadata(&mut document.properties, &document.metadata);
+        }
 
-        // Read content.xml
-        if let Ok(mut content_file) = archive.by_name("content.xml") {
-            let mut content = String::new();
-            content_file
-                .read_to_string(&mut content)
-                .map_err(|e| MarkitdownError::ParseError(format!("Read error: {}", e)))?;
+        let mut odf_styles = OdfStyles::default();
+        if let Some(xml) = styles {
+            odf_styles.read(xml)?;
+        }
 
-            let text = Self::extract_text_from_xml(&content)?;
-            markdown.push_str(&text);
+        if let Some(content) = content {
+            odf_styles.read(content)?;
+            let mut builder =
+                OdfBuilder::new(content, &odf_styles, pictures, extract_images, "odt");
+            for block in builder.blocks()? {
+                page.add_content(block);
+            }
+            for note in builder.notes {
+                page.add_content(note);
+            }
         } else {
-            markdown.push_str("*Unable to read content from ODT file.*\n");
+            page.add_content(ContentBlock::Text(
+                "*Unable to read content from ODT file.*".to_string(),
+            ));
         }
 
-        page.add_content(ContentBlock::Markdown(markdown));
         document.add_page(page);
         Ok(document)
     }
+}
+
+#[async_trait]
+impl DocumentConverter for OdtConverter {
+    async fn convert(
+        &self,
+        store: Arc<dyn ObjectStore>,
+        path: &object_store::path::Path,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let result = store.get(path).await?;
+        let bytes = result.bytes().await?;
+        self.convert_bytes(bytes, options).await
+    }
 
-    fn extract_text_from_xml(xml: &str) -> Result<String, MarkitdownError> {
+    async fn convert_bytes(
+        &self,
+        bytes: Bytes,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let extract_images = options.as_ref().map(|o| o.extract_images).unwrap_or(true);
+        let document = Self::convert_odt(&bytes, extract_images)?;
+        with_image_descriptions(document, options).await
+    }
+
+    fn supported_extensions(&self) -> &[&str] {
+        &[".odt", ".ott"] // .ott is template
+    }
+}
+
+/// Read a ZIP entry as text, or `None` when the package does not contain it
+fn read_entry(
+    archive: &mut ZipArchive<Cursor<&[u8]>>,
+    name: &str,
+) -> Result<Option<String>, MarkitdownError> {
+    let Ok(mut file) = archive.by_name(name) else {
+        return Ok(None);
+    };
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .map_err(|e| MarkitdownError::ParseError(format!("Read error: {}", e)))?;
+    Ok(Some(content))
+}
+
+/// Pictures stored in the package, keyed by their path (`Pictures/...`)
+fn read_pictures(
+    archive: &mut ZipArchive<Cursor<&[u8]>>,
+) -> Result<HashMap<String, Vec<u8>>, MarkitdownError> {
+    let mut pictures = HashMap::new();
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i)?;
+        let name = file.name().to_string();
+        if name.starts_with("Pictures/") && !file.is_dir() {
+            let mut data = Vec::new();
+            file.read_to_end(&mut data)?;
+            pictures.insert(name, data);
+        }
+    }
+    Ok(pictures)
+}
+
+fn xml_error(e: impl std::fmt::Display) -> MarkitdownError {
+    MarkitdownError::ParseError(format!("XML error: {}", e))
+}
+
+/// Unescaped value of an attribute
+fn attribute(element: &BytesStart, name: &[u8]) -> Option<String> {
+    element
+        .attributes()
+        .flatten()
+        .find(|attr| attr.key.as_ref() == name)
+        .and_then(|attr| attr.unescape_value().ok().map(|v| v.to_string()))
+}
+
+/// Text of an entity or character reference such as `&apos;`
+pub(crate) fn entity_text(name: &str) -> String {
+    if let Some(code) = name.strip_prefix('#') {
+        let code = match code.strip_prefix('x') {
+            Some(hex) => u32::from_str_radix(hex, 16).ok(),
+            None => code.parse().ok(),
+        };
+        return code
+            .and_then(char::from_u32)
+            .map(String::from)
+            .unwrap_or_default();
+    }
+    resolve_predefined_entity(name)
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Read `office:meta` (from `meta.xml` or a flat document) into metadata entries
+fn odf_metadata(xml: &str) -> Result<HashMap<String, String>, MarkitdownError> {
+    let mut reader = Reader::from_str(xml);
+    let mut metadata = HashMap::new();
+    let mut keywords = Vec::new();
+    let mut in_meta = false;
+    let mut field: Option<String> = None;
+    let mut text = String::new();
+
+    loop {
+        match reader.read_event().map_err(xml_error)? {
+            Event::Start(e) if e.name().as_ref() == b"office:meta" => in_meta = true,
+            Event::Start(e) if in_meta => {
+                text.clear();
+                field = match e.name().as_ref() {
+                    b"dc:title" => Some("title".to_string()),
+                    b"dc:subject" => Some("subject".to_string()),
+                    b"dc:description" => Some("description".to_string()),
+                    b"dc:language" => Some("language".to_string()),
+                    b"meta:keyword" => Some("keywords".to_string()),
+                    b"meta:initial-creator" => Some("author".to_string()),
+                    b"dc:creator" => Some("last_modified_by".to_string()),
+                    b"meta:creation-date" => Some("created".to_string()),
+                    b"dc:date" => Some("modified".to_string()),
+                    b"meta:generator" => Some("generator".to_string()),
+                    b"meta:user-defined" => attribute(&e, b"meta:name"),
+                    _ => None,
+                };
+            }
+            Event::Empty(e) if in_meta && e.name().as_ref() == b"meta:document-statistic" => {
+                for (attr, key) in [
+                    (&b"meta:page-count"[..], "page_count"),
+                    (b"meta:word-count", "word_count"),
+                    (b"meta:table-count", "table_count"),
+                    (b"meta:image-count", "image_count"),
+                ] {
+                    if let Some(value) = attribute(&e, attr) {
+                        metadata.insert(key.to_string(), value);
+                    }
+                }
+            }
+            Event::Text(e) if field.is_some() => text.push_str(&e.decode().map_err(xml_error)?),
+            Event::GeneralRef(e) if field.is_some() => {
+                text.push_str(&entity_text(&e.decode().map_err(xml_error)?))
+            }
+            Event::End(e) if e.name().as_ref() == b"office:meta" => break,
+            Event::End(_) => {
+                let value = text.trim().to_string();
+                match field.take() {
+                    Some(key) if key == "keywords" && !value.is_empty() => keywords.push(value),
+                    Some(key) if !value.is_empty() => {
+                        metadata.insert(key, value);
+                    }
+                    _ => {}
+                }
+                text.clear();
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if !keywords.is_empty() {
+        metadata.insert("keywords".to_string(), keywords.join(", "));
+    }
+    // The initial creator is the author; `dc:creator` names whoever saved last
+    if let Some(creator) = metadata.remove("last_modified_by") {
+        if metadata.get("author") != Some(&creator) {
+            metadata.entry("author".to_string()).or_insert(creator);
+        }
+    }
+    Ok(metadata)
+}
+
+/// Character formatting of a text style; `None` inherits from the parent style
+#[derive(Debug, Default, Clone, Copy)]
+struct TextProperties {
+    bold: Option<bool>,
+    italic: Option<bool>,
+    monospace: Option<bool>,
+    strikethrough: Option<bool>,
+}
+
+/// How a paragraph style renders
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ParagraphKind {
+    Normal,
+    Quote,
+    Code,
+    Rule,
+}
+
+/// Named and automatic styles of an OpenDocument package
+#[derive(Debug, Default)]
+struct OdfStyles {
+    parents: HashMap<String, String>,
+    text: HashMap<String, TextProperties>,
+    /// Whether a list style numbers its items, per `(style, level)`
+    lists: HashMap<(String, usize), bool>,
+}
+
+impl OdfStyles {
+    /// Add the `style:style` and `text:list-style` definitions of a document
+    fn read(&mut self, xml: &str) -> Result<(), MarkitdownError> {
         let mut reader = Reader::from_str(xml);
-        reader.config_mut().trim_text(true);
+        let mut style: Option<String> = None;
+        let mut list: Option<String> = None;
 
-        let mut text = String::new();
-        let mut in_text = false;
-        let mut in_heading = false;
-        let mut heading_level = 1;
-        let mut current_text = String::new();
+        loop {
+            match reader.read_event().map_err(xml_error)? {
+                Event::Start(e) | Event::Empty(e) => match e.name().as_ref() {
+                    b"style:style" => {
+                        style = attribute(&e, b"style:name");
+                        if let (Some(name), Some(parent)) =
+                            (&style, attribute(&e, b"style:parent-style-name"))
+                        {
+                            self.parents.insert(name.clone(), parent);
+                        }
+                    }
+                    b"style:text-properties" => {
+                        if let Some(name) = &style {
+                            self.text.insert(name.clone(), text_properties(&e));
+                        }
+                    }
+                    b"text:list-style" => list = attribute(&e, b"style:name"),
+                    name @ (b"text:list-level-style-number" | b"text:list-level-style-bullet") => {
+                        let level = attribute(&e, b"text:level")
+                            .and_then(|l| l.parse().ok())
+                            .unwrap_or(1);
+                        if let Some(list) = &list {
+                            self.lists.insert(
+                                (list.clone(), level),
+                                name == b"text:list-level-style-number",
+                            );
+                        }
+                    }
+                    _ => {}
+                },
+                Event::End(e) => match e.name().as_ref() {
+                    b"style:style" => style = None,
+                    b"text:list-style" => list = None,
+                    _ => {}
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// A style and its ancestors, nearest first
+    fn chain<'s>(&'s self, name: &'s str) -> impl Iterator<Item = &'s str> {
+        std::iter::successors(Some(name), |name| {
+            self.parents.get(*name).map(String::as_str)
+        })
+        .take(16)
+    }
+
+    fn text_properties(&self, name: &str) -> TextProperties {
+        let mut resolved = TextProperties::default();
+        for props in self.chain(name).filter_map(|n| self.text.get(n)) {
+            resolved.bold = resolved.bold.or(props.bold);
+            resolved.italic = resolved.italic.or(props.italic);
+            resolved.monospace = resolved.monospace.or(props.monospace);
+            resolved.strikethrough = resolved.strikethrough.or(props.strikethrough);
+        }
+        resolved
+    }
+
+    fn paragraph_kind(&self, name: &str) -> ParagraphKind {
+        for style in self.chain(name) {
+            match style {
+                "Quotations" => return ParagraphKind::Quote,
+                "Preformatted_20_Text" | "Source_20_Code" => return ParagraphKind::Code,
+                "Horizontal_20_Line" => return ParagraphKind::Rule,
+                _ => {}
+            }
+        }
+        ParagraphKind::Normal
+    }
+
+    fn list_ordered(&self, name: &str, level: usize) -> bool {
+        self.lists
+            .get(&(name.to_string(), level))
+            .copied()
+            .unwrap_or(false)
+    }
+}
+
+fn text_properties(element: &BytesStart) -> TextProperties {
+    let weight = attribute(element, b"fo:font-weight");
+    let style = attribute(element, b"fo:font-style");
+    let font =
+        attribute(element, b"style:font-name").or_else(|| attribute(element, b"fo:font-family"));
+    let strike = attribute(element, b"style:text-line-through-style");
+    TextProperties {
+        bold: weight.map(|w| w == "bold" || w.parse::<u32>().is_ok_and(|w| w >= 600)),
+        italic: style.map(|s| s == "italic" || s == "oblique"),
+        monospace: font.map(|f| {
+            ["Mono", "Courier", "Consolas", "Code"]
+                .iter()
+                .any(|name| f.contains(name))
+        }),
+        strikethrough: strike.map(|s| s != "none"),
+    }
+}
 
+/// Wrap text in an inline marker, keeping surrounding spaces outside it
+fn emphasize(text: &str, marker: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return text.to_string();
+    }
+    let leading = &text[..text.len() - text.trim_start().len()];
+    let trailing = &text[text.trim_end().len()..];
+    format!("{}{}{}{}{}", leading, marker, trimmed, marker, trailing)
+}
+
+/// Walks the body of an OpenDocument file, turning elements into content blocks
+struct OdfBuilder<'a> {
+    reader: Reader<&'a [u8]>,
+    styles: &'a OdfStyles,
+    pictures: &'a HashMap<String, Vec<u8>>,
+    extract_images: bool,
+    /// Prefix of extracted image ids, e.g. `odt` for `odt_image_1`
+    image_prefix: &'static str,
+    /// Page (drawing page or sheet) currently being read
+    page: u32,
+    /// List styles of the enclosing `text:list` elements
+    lists: Vec<Option<String>>,
+    images: usize,
+    /// Footnote and endnote definitions in citation order
+    notes: Vec<ContentBlock>,
+}
+
+impl<'a> OdfBuilder<'a> {
+    fn new(
+        xml: &'a str,
+        styles: &'a OdfStyles,
+        pictures: &'a HashMap<String, Vec<u8>>,
+        extract_images: bool,
+        image_prefix: &'static str,
+    ) -> Self {
+        Self {
+            reader: Reader::from_str(xml),
+            styles,
+            pictures,
+            extract_images,
+            image_prefix,
+            page: 1,
+            lists: Vec::new(),
+            images: 0,
+            notes: Vec::new(),
+        }
+    }
+
+    fn next(&mut self) -> Result<Event<'a>, MarkitdownError> {
+        self.reader.read_event().map_err(xml_error)
+    }
+
+    /// Skip an element and everything inside it
+    fn skip(&mut self, element: &BytesStart) -> Result<(), MarkitdownError> {
+        let end = element.to_end().into_owned();
+        self.reader.read_to_end(end.name()).map_err(xml_error)?;
+        Ok(())
+    }
+
+    /// Block content up to the end of the enclosing element
+    fn blocks(&mut self) -> Result<Vec<ContentBlock>, MarkitdownError> {
+        let mut blocks = Vec::new();
         loop {
-            match reader.read_event() {
-                Ok(Event::Start(e)) => {
-                    let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
-                    match name.as_str() {
-                        "text:p" => {
-                            in_text = true;
-                            current_text.clear();
+            match self.next()? {
+                Event::Start(e) => match e.name().as_ref() {
+                    b"text:h" => {
+                        let level = attribute(&e, b"text:outline-level")
+                            .and_then(|l| l.parse::<u8>().ok())
+                            .unwrap_or(1)
+                            .clamp(1, 6);
+                        let (text, extra) = self.inlines()?;
+                        if !text.trim().is_empty() {
+                            blocks.push(ContentBlock::Heading {
+                                level,
+                                text: text.trim().to_string(),
+                            });
                         }
-                        "text:h" => {
-                            in_heading = true;
-                            current_text.clear();
-                            // Try to get outline level
-                            for attr in e.attributes().flatten() {
-                                if attr.key.as_ref() == b"text:outline-level" {
-                                    if let Ok(level) =
-                                        String::from_utf8_lossy(&attr.value).parse::<u8>()
-                                    {
-                                        heading_level = level.min(6);
-                                    }
-                                }
-                            }
+                        blocks.extend(extra);
+                    }
+                    b"text:p" => self.paragraph(&e, &mut blocks)?,
+                    b"text:list" => {
+                        let style = attribute(&e, b"text:style-name")
+                            .or_else(|| self.lists.last().cloned().flatten());
+                        let ordered = style
+                            .as_deref()
+                            .is_some_and(|s| self.styles.list_ordered(s, self.lists.len() + 1));
+                        self.lists.push(style);
+                        let list = self.list(ordered);
+                        self.lists.pop();
+                        blocks.extend(list?);
+                    }
+                    b"table:table" => blocks.push(self.table()?),
+                    // Frames anchored to the page rather than a paragraph
+                    b"draw:frame" => blocks.extend(self.frame()?),
+                    name if is_metadata(name) => self.skip(&e)?,
+                    // Sections, index bodies and other containers keep their content
+                    _ => blocks.extend(self.blocks()?),
+                },
+                Event::Empty(e) if e.name().as_ref() == b"text:p" => {
+                    let style = attribute(&e, b"text:style-name").unwrap_or_default();
+                    if self.styles.paragraph_kind(&style) == ParagraphKind::Rule {
+                        blocks.push(ContentBlock::Rule);
+                    }
+                }
+                Event::End(_) | Event::Eof => break,
+                _ => {}
+            }
+        }
+        Ok(blocks)
+    }
+
+    /// Named sheets (`table:table`) or drawing pages (`draw:page`) of a body,
+    /// with their content blocks
+    fn parts(
+        &mut self,
+        element: &[u8],
+    ) -> Result<Vec<(String, Vec<ContentBlock>)>, MarkitdownError> {
+        let mut parts = Vec::new();
+        loop {
+            match self.next()? {
+                Event::Start(e) if e.name().as_ref() == element => {
+                    let name = attribute(&e, b"table:name")
+                        .or_else(|| attribute(&e, b"draw:name"))
+                        .unwrap_or_default();
+                    let blocks = if element == b"table:table" {
+                        vec![self.table()?]
+                    } else {
+                        self.blocks()?
+                    };
+                    parts.push((name, blocks));
+                    self.page += 1;
+                }
+                Event::Start(e) if is_metadata(e.name().as_ref()) => self.skip(&e)?,
+                Event::Start(_) => parts.extend(self.parts(element)?),
+                Event::End(_) | Event::Eof => break,
+                _ => {}
+            }
+        }
+        Ok(parts)
+    }
+
+    fn paragraph(
+        &mut self,
+        element: &BytesStart,
+        blocks: &mut Vec<ContentBlock>,
+    ) -> Result<(), MarkitdownError> {
+        let style = attribute(element, b"text:style-name").unwrap_or_default();
+        let kind = self.styles.paragraph_kind(&style);
+        let (text, extra) = self.inlines()?;
+        let text = text.trim();
+
+        match kind {
+            ParagraphKind::Rule => blocks.push(ContentBlock::Rule),
+            // Consecutive code and quote paragraphs form a single block
+            ParagraphKind::Code => match blocks.last_mut() {
+                Some(ContentBlock::Code { code, .. }) => {
+                    code.push('\n');
+                    code.push_str(text);
+                }
+                _ => blocks.push(ContentBlock::Code {
+                    language: None,
+                    code: text.to_string(),
+                }),
+            },
+            ParagraphKind::Quote if !text.is_empty() => match blocks.last_mut() {
+                Some(ContentBlock::Quote(quote)) => {
+                    quote.push_str("\n\n");
+                    quote.push_str(text);
+                }
+                _ => blocks.push(ContentBlock::Quote(text.to_string())),
+            },
+            _ if !text.is_empty() => blocks.push(ContentBlock::Text(text.to_string())),
+            _ => {}
+        }
+        blocks.extend(extra);
+        Ok(())
+    }
+
+    /// List items, preceded by the blocks of any unnumbered `text:list-header`
+    fn list(&mut self, ordered: bool) -> Result<Vec<ContentBlock>, MarkitdownError> {
+        let mut header = Vec::new();
+        let mut items = Vec::new();
+        loop {
+            match self.next()? {
+                Event::Start(e) if e.name().as_ref() == b"text:list-item" => {
+                    let marker_width = if ordered {
+                        format!("{}. ", items.len() + 1).len()
+                    } else {
+                        2
+                    };
+                    let blocks = self.blocks()?;
+                    if blocks.is_empty() {
+                        continue;
+                    }
+                    items.push(indent_continuation(
+                        &render_blocks_tight(&blocks),
+                        marker_width,
+                    ));
+                }
+                Event::Start(e) if e.name().as_ref() == b"text:list-header" => {
+                    header.extend(self.blocks()?);
+                }
+                Event::Start(e) => self.skip(&e)?,
+                Event::End(_) | Event::Eof => break,
+                _ => {}
+            }
+        }
+        if !items.is_empty() {
+            header.push(ContentBlock::List { ordered, items });
+        }
+        Ok(header)
+    }
+
+    fn table(&mut self) -> Result<ContentBlock, MarkitdownError> {
+        let mut header_rows = Vec::new();
+        let mut rows = Vec::new();
+        self.table_rows(&mut header_rows, &mut rows, false)?;
+
+        // Without declared header rows the first row heads the table
+        let mut headers = if header_rows.is_empty() {
+            if rows.is_empty() {
+                Vec::new()
+            } else {
+                rows.remove(0)
+            }
+        } else {
+            header_rows.remove(0)
+        };
+        rows.splice(0..0, header_rows);
+
+        // Drop trailing columns that are empty throughout (padding from repeats)
+        let width = std::iter::once(&headers)
+            .chain(rows.iter())
+            .filter_map(|row| row.iter().rposition(|cell| !cell.is_empty()))
+            .max()
+            .map_or(0, |last| last + 1);
+        for row in std::iter::once(&mut headers).chain(rows.iter_mut()) {
+            row.resize(width, String::new());
+        }
+
+        Ok(ContentBlock::Table { headers, rows })
+    }
+
+    fn table_rows(
+        &mut self,
+        header_rows: &mut Vec<Vec<String>>,
+        rows: &mut Vec<Vec<String>>,
+        in_header: bool,
+    ) -> Result<(), MarkitdownError> {
+        loop {
+            match self.next()? {
+                Event::Start(e) => match e.name().as_ref() {
+                    b"table:table-header-rows" => self.table_rows(header_rows, rows, true)?,
+                    b"table:table-rows" | b"table:table-row-group" => {
+                        self.table_rows(header_rows, rows, in_header)?
+                    }
+                    b"table:table-row" => {
+                        let repeat = attribute(&e, b"table:number-rows-repeated")
+                            .and_then(|n| n.parse::<usize>().ok())
+                            .unwrap_or(1);
+                        let row = self.table_row()?;
+                        // Repeated empty rows only pad the table out
+                        if row.iter().all(|cell| cell.is_empty()) && repeat > 1 {
+                            continue;
                         }
-                        "text:list-item" => {
-                            current_text.push_str("- ");
+                        let target = if in_header {
+                            &mut *header_rows
+                        } else {
+                            &mut *rows
+                        };
+                        for _ in 0..repeat.min(MAX_REPEAT) {
+                            target.push(row.clone());
                         }
-                        _ => {}
                     }
+                    _ => self.skip(&e)?,
+                },
+                Event::End(_) | Event::Eof => break,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn table_row(&mut self) -> Result<Vec<String>, MarkitdownError> {
+        let mut cells = Vec::new();
+        loop {
+            let (element, content) = match self.next()? {
+                Event::Start(e) => (e, true),
+                Event::Empty(e) => (e, false),
+                Event::End(_) | Event::Eof => break,
+                _ => continue,
+            };
+            let name = element.name();
+            if name.as_ref() != b"table:table-cell" && name.as_ref() != b"table:covered-table-cell"
+            {
+                if content {
+                    self.skip(&element)?;
                 }
-                Ok(Event::End(e)) => {
-                    let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
-                    match name.as_str() {
-                        "text:p" => {
-                            if in_text {
-                                text.push_str(&current_text);
-                                text.push_str("\n\n");
-                                in_text = false;
-                            }
+                continue;
+            }
+
+            let repeat = attribute(&element, b"table:number-columns-repeated")
+                .and_then(|n| n.parse::<usize>().ok())
+                .unwrap_or(1)
+                .min(MAX_REPEAT);
+            let text = if content {
+                self.blocks()?
+                    .iter()
+                    .map(|block| block.to_markdown().trim().to_string())
+                    .filter(|text| !text.is_empty())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            } else {
+                String::new()
+            };
+            cells.extend(std::iter::repeat_n(text, repeat));
+        }
+        Ok(cells)
+    }
+
+    /// Inline content up to the end of the enclosing element, as markdown, plus
+    /// any blocks anchored in it (frames with pictures or text boxes)
+    fn inlines(&mut self) -> Result<(String, Vec<ContentBlock>), MarkitdownError> {
+        let mut text = String::new();
+        let mut extra = Vec::new();
+        loop {
+            match self.next()? {
+                Event::Text(e) => push_collapsed(&mut text, &e.decode().map_err(xml_error)?),
+                Event::GeneralRef(e) => {
+                    text.push_str(&entity_text(&e.decode().map_err(xml_error)?))
+                }
+                Event::Empty(e) => match e.name().as_ref() {
+                    b"text:s" => {
+                        let count = attribute(&e, b"text:c")
+                            .and_then(|c| c.parse::<usize>().ok())
+                            .unwrap_or(1);
+                        text.push_str(&" ".repeat(count.min(MAX_REPEAT)));
+                    }
+                    b"text:tab" => text.push('\t'),
+                    b"text:line-break" => text.push('\n'),
+                    _ => {}
+                },
+                Event::Start(e) => match e.name().as_ref() {
+                    b"text:span" => {
+                        let (inner, blocks) = self.inlines()?;
+                        let props = attribute(&e, b"text:style-name")
+                            .map(|style| self.styles.text_properties(&style))
+                            .unwrap_or_default();
+                        let mut inner = inner;
+                        if props.monospace == Some(true) {
+                            inner = emphasize(&inner, "`");
                         }
-                        "text:h" => {
-                            if in_heading {
-                                text.push_str(&"#".repeat(heading_level as usize));
-                                text.push(' ');
-                                text.push_str(&current_text);
-                                text.push_str("\n\n");
-                                in_heading = false;
-                            }
+                        if props.strikethrough == Some(true) {
+                            inner = emphasize(&inner, "~~");
                         }
-                        _ => {}
+                        if props.italic == Some(true) {
+                            inner = emphasize(&inner, "*");
+                        }
+                        if props.bold == Some(true) {
+                            inner = emphasize(&inner, "**");
+                        }
+                        text.push_str(&inner);
+                        extra.extend(blocks);
+                    }
+                    b"text:a" => {
+                        let href = attribute(&e, b"xlink:href").unwrap_or_default();
+                        let (inner, blocks) = self.inlines()?;
+                        let label = inner.trim();
+                        if href.is_empty() {
+                            text.push_str(&inner);
+                        } else if label.is_empty() {
+                            text.push_str(&format!("<{}>", href));
+                        } else {
+                            text.push_str(&format!("[{}]({})", label, href));
+                        }
+                        extra.extend(blocks);
+                    }
+                    b"text:note" => {
+                        let label = self.note()?;
+                        text.push_str(&format!("[^{}]", label));
+                    }
+                    b"draw:frame" => extra.extend(self.frame()?),
+                    b"office:annotation" | b"text:tracked-changes" | b"draw:object" => {
+                        self.skip(&e)?
                     }
+                    _ if e.name().as_ref() == b"text:hidden-text"
+                        && attribute(&e, b"text:is-hidden").as_deref() == Some("true") =>
+                    {
+                        self.skip(&e)?
+                    }
+                    _ => {
+                        let (inner, blocks) = self.inlines()?;
+                        text.push_str(&inner);
+                        extra.extend(blocks);
+                    }
+                },
+                Event::End(_) | Event::Eof => break,
+                _ => {}
+            }
+        }
+        Ok((text, extra))
+    }
+
+    /// Record a footnote or endnote and return its label
+    fn note(&mut self) -> Result<usize, MarkitdownError> {
+        let mut body = Vec::new();
+        loop {
+            match self.next()? {
+                Event::Start(e) if e.name().as_ref() == b"text:note-body" => {
+                    body = self.blocks()?;
                 }
-                Ok(Event::Text(e)) => {
-                    // Use into_inner to get the escaped bytes, then decode
-                    let escaped_text = String::from_utf8_lossy(e.as_ref()).to_string();
-                    current_text.push_str(&escaped_text);
+                Event::Start(e) => self.skip(&e)?,
+                Event::End(_) | Event::Eof => break,
+                _ => {}
+            }
+        }
+        let label = self.notes.len() + 1;
+        self.notes.push(ContentBlock::FootnoteDefinition {
+            label: label.to_string(),
+            content: body,
+        });
+        Ok(label)
+    }
+
+    /// A `draw:frame`: a picture, or a text box whose paragraphs may caption one
+    fn frame(&mut self) -> Result<Vec<ContentBlock>, MarkitdownError> {
+        let mut blocks = Vec::new();
+        let mut title = None;
+        let mut description = None;
+        loop {
+            let (element, content) = match self.next()? {
+                Event::Start(e) => (e, true),
+                Event::Empty(e) => (e, false),
+                Event::End(_) | Event::Eof => break,
+                _ => continue,
+            };
+            match element.name().as_ref() {
+                b"draw:image" => {
+                    let data = if content { self.binary_data()? } else { None };
+                    // Later images are fallbacks (e.g. a PNG for an SVG)
+                    if !blocks.iter().any(|b| matches!(b, ContentBlock::Image(_))) {
+                        let href = attribute(&element, b"xlink:href").unwrap_or_default();
+                        blocks.extend(self.image(&href, data));
+                    }
+                }
+                b"draw:text-box" if content => blocks.extend(self.blocks()?),
+                b"svg:title" if content => title = Some(self.inlines()?.0),
+                b"svg:desc" if content => description = Some(self.inlines()?.0),
+                _ if content => self.skip(&element)?,
+                _ => {}
+            }
+        }
+
+        let alt_text = title.or(description).filter(|t| !t.trim().is_empty());
+        for block in blocks.iter_mut() {
+            match block {
+                ContentBlock::Image(image) if image.alt_text.is_none() => {
+                    image.alt_text = alt_text.clone()
+                }
+                ContentBlock::Markdown(link) if link.starts_with("![](") => {
+                    if let Some(alt) = &alt_text {
+                        *link = link.replacen("![]", &format!("![{}]", alt.trim()), 1);
+                    }
                 }
-                Ok(Event::Eof) => break,
-                Err(e) => return Err(MarkitdownError::ParseError(format!("XML error: {}", e))),
                 _ => {}
             }
         }
 
-        Ok(text.trim().to_string())
+        // A text box holding one picture and text is a captioned figure
+        let pictures = blocks
+            .iter()
+            .filter(|b| matches!(b, ContentBlock::Image(_)))
+            .count();
+        if pictures == 1
+            && blocks
+                .iter()
+                .all(|b| matches!(b, ContentBlock::Image(_) | ContentBlock::Text(_)))
+        {
+            let caption = blocks
+                .iter()
+                .filter_map(|b| match b {
+                    ContentBlock::Text(text) => Some(text.as_str()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            let mut image = blocks
+                .into_iter()
+                .find_map(|b| match b {
+                    ContentBlock::Image(image) => Some(image),
+                    _ => None,
+                })
+                .expect("one picture");
+            if !caption.is_empty() {
+                image.caption = Some(caption);
+            }
+            return Ok(vec![ContentBlock::Image(image)]);
+        }
+        Ok(blocks)
     }
-}
 
-#[async_trait]
-impl DocumentConverter for OdtConverter {
-    async fn convert(
-        &self,
-        store: Arc<dyn ObjectStore>,
-        path: &object_store::path::Path,
-        options: Option<ConversionOptions>,
-    ) -> Result<Document, MarkitdownError> {
-        let result = store.get(path).await?;
-        let bytes = result.bytes().await?;
-        self.convert_bytes(bytes, options).await
+    /// Base64 picture data embedded in a flat document's `draw:image`
+    fn binary_data(&mut self) -> Result<Option<Vec<u8>>, MarkitdownError> {
+        let mut data = None;
+        loop {
+            match self.next()? {
+                Event::Start(e) if e.name().as_ref() == b"office:binary-data" => {
+                    let mut encoded = String::new();
+                    loop {
+                        match self.next()? {
+                            Event::Text(t) => encoded.push_str(&t.decode().map_err(xml_error)?),
+                            Event::End(_) | Event::Eof => break,
+                            _ => {}
+                        }
+                    }
+                    encoded.retain(|c| !c.is_whitespace());
+                    data = BASE64_STANDARD.decode(encoded).ok();
+                }
+                Event::Start(e) => self.skip(&e)?,
+                Event::End(_) | Event::Eof => break,
+                _ => {}
+            }
+        }
+        Ok(data)
     }
 
-    async fn convert_bytes(
-        &self,
-        bytes: Bytes,
-        _options: Option<ConversionOptions>,
-    ) -> Result<Document, MarkitdownError> {
-        Self::convert_odt(&bytes)
+    /// A picture embedded in or stored with the document, or a link to an
+    /// external one
+    fn image(&mut self, href: &str, data: Option<Vec<u8>>) -> Option<ContentBlock> {
+        let path = href.strip_prefix("./").unwrap_or(href);
+        let data = data.or_else(|| self.pictures.get(path).cloned());
+        if let (true, Some(data)) = (self.extract_images, data) {
+            let mime_type = infer::get(&data)
+                .map(|kind| kind.mime_type().to_string())
+                .or_else(|| {
+                    mime_guess::from_path(path)
+                        .first()
+                        .map(|m| m.essence_str().to_string())
+                })
+                .unwrap_or_else(|| "application/octet-stream".to_string());
+            self.images += 1;
+            let mut image = ExtractedImage::new(
+                format!("{}_image_{}", self.image_prefix, self.images),
+                Bytes::from(data),
+                mime_type,
+            );
+            if !path.is_empty() {
+                image.source_path = Some(path.to_string());
+            }
+            image.page_number = Some(self.page);
+            return Some(ContentBlock::Image(image));
+        }
+        if !self.extract_images
+            || path.is_empty()
+            || path.starts_with("Pictures/")
+            || path.starts_with("Object")
+        {
+            return None;
+        }
+        Some(ContentBlock::Markdown(format!("![]({})\n", href)))
     }
+}
 
-    fn supported_extensions(&self) -> &[&str] {
-        &[".odt", ".ott"] // .ott is template
+/// Elements outside the document body, or that hold no readable content
+fn is_metadata(name: &[u8]) -> bool {
+    matches!(
+        name,
+        b"office:meta"
+            | b"office:settings"
+            | b"office:scripts"
+            | b"office:font-face-decls"
+            | b"office:styles"
+            | b"office:automatic-styles"
+            | b"office:master-styles"
+            | b"office:forms"
+            | b"office:annotation"
+            | b"text:tracked-changes"
+            | b"text:sequence-decls"
+            | b"text:variable-decls"
+            | b"text:user-field-decls"
+            | b"text:index-title-template"
+            | b"table:named-expressions"
+            | b"presentation:notes"
+    ) || name.ends_with(b"-source")
+}
+
+/// Append text with whitespace collapsed the way ODF paragraphs render it
+fn push_collapsed(out: &mut String, text: &str) {
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !out.ends_with(' ') {
+                out.push(' ');
+            }
+        } else {
+            out.push(c);
+        }
     }
 }
 
@@ -191,10 +1036,7 @@ impl OdsConverter {
 
                 // Header row
                 let headers = if let Some(first_row) = rows.first() {
-                    first_row
-                        .iter()
-                        .map(|c| format!("{}", c).replace('|', "\\|"))
-                        .collect()
+                    first_row.iter().map(|c| c.to_string()).collect()
                 } else {
                     vec![]
                 };
@@ -204,11 +1046,7 @@ impl OdsConverter {
                     .iter()
                     .skip(1)
                     .take(100)
-                    .map(|row| {
-                        row.iter()
-                            .map(|c| format!("{}", c).replace('|', "\\|"))
-                            .collect()
-                    })
+                    .map(|row| row.iter().map(|c| c.to_string()).collect())
                     .collect();
 
                 page.add_content(ContentBlock::Table {
@@ -234,6 +1072,53 @@ impl OdsConverter {
 
         Ok(document)
     }
+
+    /// Sheets of a flat spreadsheet, laid out like those read through calamine
+    fn flat_document(xml: &str) -> Result<Document, MarkitdownError> {
+        let mut styles = OdfStyles::default();
+        styles.read(xml)?;
+        let pictures = HashMap::new();
+        let mut builder = OdfBuilder::new(xml, &styles, &pictures, false, "ods");
+
+        let mut document = Document::new();
+        for (sheet_idx, (sheet_name, blocks)) in
+            builder.parts(b"table:table")?.into_iter().enumerate()
+        {
+            let mut page = Page::new((sheet_idx + 1) as u32);
+            page.add_content(ContentBlock::Heading {
+                level: 2,
+                text: format!("Sheet: {}", sheet_name),
+            });
+
+            for block in blocks {
+                let ContentBlock::Table { headers, mut rows } = block else {
+                    continue;
+                };
+                if headers.is_empty() && rows.is_empty() {
+                    page.add_content(ContentBlock::Text("*Empty sheet*".to_string()));
+                    continue;
+                }
+                let total = rows.len();
+                rows.truncate(100);
+                page.add_content(ContentBlock::Table { headers, rows });
+                if total > 100 {
+                    page.add_content(ContentBlock::Text(format!(
+                        "*... and {} more rows*",
+                        total - 100
+                    )));
+                }
+            }
+            document.add_page(page);
+        }
+
+        if document.pages.is_empty() {
+            document.add_page(Page::new(1));
+        }
+        document.metadata = odf_metadata(xml)?;
+        document.title = document.metadata.remove("title");
+        crate::metadata::fill_from_metadata(&mut document.properties, &document.metadata);
+        Ok(document)
+    }
 }
 
 #[async_trait]
@@ -271,16 +1156,16 @@ impl OdpConverter {
         let mut archive = ZipArchive::new(cursor)
             .map_err(|e| MarkitdownError::ParseError(format!("ODP parse error: {}", e)))?;
 
-        let mut document = Document::new();
+        let content = read_entry(&mut archive, "content.xml")?;
+        Self::slides_document(content.as_deref())
+    }
 
-        // Read content.xml
-        if let Ok(mut content_file) = archive.by_name("content.xml") {
-            let mut content = String::new();
-            content_file
-                .read_to_string(&mut content)
-                .map_err(|e| MarkitdownError::ParseError(format!("Read error: {}", e)))?;
+    /// One page per slide of a presentation's content (or flat document)
+    fn slides_document(content: Option<&str>) -> Result<Document, MarkitdownError> {
+        let mut document = Document::new();
 
-            let slides = Self::extract_slides_from_xml(&content)?;
+        if let Some(content) = content {
+            let slides = Self::extract_slides_from_xml(content)?;
 
             for (idx, slide_text) in slides.iter().enumerate() {
                 let mut page = Page::new((idx + 1) as u32);
@@ -388,3 +1273,219 @@ impl DocumentConverter for OdpConverter {
         &[".odp", ".otp"] // .otp is template
     }
 }
+
+/// OpenDocument Drawing (.odg) converter
+pub struct OdgConverter;
+
+impl OdgConverter {
+    fn convert_odg(bytes: &[u8], extract_images: bool) -> Result<Document, MarkitdownError> {
+        let cursor = Cursor::new(bytes);
+        let mut archive = ZipArchive::new(cursor)
+            .map_err(|e| MarkitdownError::ParseError(format!("ODG parse error: {}", e)))?;
+
+        let meta = read_entry(&mut archive, "meta.xml")?;
+        let styles = read_entry(&mut archive, "styles.xml")?;
+        let content = read_entry(&mut archive, "content.xml")?;
+        let pictures = if extract_images {
+            read_pictures(&mut archive)?
+        } else {
+            HashMap::new()
+        };
+        Self::drawing_document(
+            meta.as_deref(),
+            styles.as_deref(),
+            content.as_deref(),
+            &pictures,
+            extract_images,
+        )
+    }
+
+    /// One page per drawing page, with the text of its frames and shapes and
+    /// its pictures
+    fn drawing_document(
+        meta: Option<&str>,
+        styles: Option<&str>,
+        content: Option<&str>,
+        pictures: &HashMap<String, Vec<u8>>,
+        extract_images: bool,
+    ) -> Result<Document, MarkitdownError> {
+        let mut document = Document::new();
+        if let Some(meta) = meta {
+            document.metadata = odf_metadata(meta)?;
+            document.title = document.metadata.remove("title");
+            crate::metadata::fill_from_metadata(&mut document.properties, &document.metadata);
+        }
+
+        let mut odf_styles = OdfStyles::default();
+        if let Some(xml) = styles {
+            odf_styles.read(xml)?;
+        }
+
+        if let Some(content) = content {
+            odf_styles.read(content)?;
+            let mut builder =
+                OdfBuilder::new(content, &odf_styles, pictures, extract_images, "odg");
+            for (idx, (name, blocks)) in builder.parts(b"draw:page")?.into_iter().enumerate() {
+                let mut page = Page::new((idx + 1) as u32);
+                page.add_content(ContentBlock::Heading {
+                    level: 2,
+                    text: if name.is_empty() {
+                        format!("Page {}", idx + 1)
+                    } else {
+                        name
+                    },
+                });
+                for block in blocks {
+                    page.add_content(block);
+                }
+                document.add_page(page);
+            }
+            if let Some(page) = document.pages.last_mut() {
+                for note in builder.notes {
+                    page.add_content(note);
+                }
+            }
+        }
+
+        if document.pages.is_empty() {
+            let mut page = Page::new(1);
+            page.add_content(ContentBlock::Text(
+                "*Unable to read content from ODG file.*".to_string(),
+            ));
+            document.add_page(page);
+        }
+
+        Ok(document)
+    }
+}
+
+#[async_trait]
+impl DocumentConverter for OdgConverter {
+    async fn convert(
+        &self,
+        store: Arc<dyn ObjectStore>,
+        path: &object_store::path::Path,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let result = store.get(path).await?;
+        let bytes = result.bytes().await?;
+        self.convert_bytes(bytes, options).await
+    }
+
+    async fn convert_bytes(
+        &self,
+        bytes: Bytes,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let extract_images = options.as_ref().map(|o| o.extract_images).unwrap_or(true);
+        let document = Self::convert_odg(&bytes, extract_images)?;
+        with_image_descriptions(document, options).await
+    }
+
+    fn supported_extensions(&self) -> &[&str] {
+        &[".odg", ".otg"] // .otg is template
+    }
+}
+
+/// Flat OpenDocument converter (.fodt, .fods, .fodp, .fodg)
+///
+/// Flat files hold the metadata, styles and content of a package in a single
+/// XML document, with pictures inlined as base64. The body element decides
+/// which of the packaged converters' parsers reads it.
+pub struct FlatOdfConverter;
+
+impl FlatOdfConverter {
+    fn convert_flat(bytes: &[u8], extract_images: bool) -> Result<Document, MarkitdownError> {
+        let xml = String::from_utf8_lossy(bytes);
+        let pictures = HashMap::new();
+        match Self::body_kind(&xml)?.as_deref() {
+            Some("office:text") => {
+                OdtConverter::text_document(Some(&xml), None, Some(&xml), &pictures, extract_images)
+            }
+            Some("office:spreadsheet") => OdsConverter::flat_document(&xml),
+            Some("office:presentation") => {
+                let mut document = OdpConverter::slides_document(Some(&xml))?;
+                document.metadata = odf_metadata(&xml)?;
+                document.title = document.metadata.remove("title");
+                crate::metadata::fill_from_metadata(&mut document.properties, &document.metadata);
+                Ok(document)
+            }
+            Some("office:drawing") => OdgConverter::drawing_document(
+                Some(&xml),
+                None,
+                Some(&xml),
+                &pictures,
+                extract_images,
+            ),
+            _ => Err(MarkitdownError::InvalidFile(
+                "Not a flat OpenDocument file".to_string(),
+            )),
+        }
+    }
+
+    /// Name of the first element inside `office:body`
+    fn body_kind(xml: &str) -> Result<Option<String>, MarkitdownError> {
+        let mut reader = Reader::from_str(xml);
+        let mut in_body = false;
+        loop {
+            match reader.read_event().map_err(xml_error)? {
+                Event::Start(e) if in_body => {
+                    return Ok(Some(String::from_utf8_lossy(e.name().as_ref()).to_string()))
+                }
+                Event::Start(e) if e.name().as_ref() == b"office:body" => in_body = true,
+                // Skip the (possibly large) metadata and style sections
+                Event::Start(e) if is_metadata(e.name().as_ref()) => {
+                    reader.read_to_end(e.name()).map_err(xml_error)?;
+                }
+                Event::Eof => return Ok(None),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl DocumentConverter for FlatOdfConverter {
+    async fn convert(
+        &self,
+        store: Arc<dyn ObjectStore>,
+        path: &object_store::path::Path,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let result = store.get(path).await?;
+        let bytes = result.bytes().await?;
+        self.convert_bytes(bytes, options).await
+    }
+
+    async fn convert_bytes(
+        &self,
+        bytes: Bytes,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let extract_images = options.as_ref().map(|o| o.extract_images).unwrap_or(true);
+        let document = Self::convert_flat(&bytes, extract_images)?;
+        with_image_descriptions(document, options).await
+    }
+
+    fn supported_extensions(&self) -> &[&str] {
+        &[".fodt", ".fods", ".fodp", ".fodg"]
+    }
+}
+
+/// Describe extracted images when an LLM client is configured
+async fn with_image_descriptions(
+    mut document: Document,
+    options: Option<ConversionOptions>,
+) -> Result<Document, MarkitdownError> {
+    if let Some(ref opts) = options {
+        if let Some(ref llm_client) = opts.llm_client {
+            if let Some(path) = opts.image_context_path.as_deref() {
+                document.apply_image_context_path(path);
+            }
+            document = document
+                .with_image_descriptions(llm_client.as_ref())
+                .await?;
+        }
+    }
+    Ok(document)
+}
@@ -14,13 +14,33 @@ use bytes::Bytes;
 use hayro::{render, InterpreterSettings, Pdf, RenderSettings};
 use hayro_syntax::object::dict::keys::{HEIGHT, SUBTYPE, WIDTH};
 use hayro_syntax::object::{Name, Stream};
+use lopdf::Object;
 use object_store::ObjectStore;
 use pdf_extract;
 use std::sync::Arc;
 
 use crate::error::MarkitdownError;
+use crate::language::detect_language;
 use crate::llm::LlmClient;
-use crate::model::{ContentBlock, ConversionOptions, Document, DocumentConverter, Page};
+use crate::metadata::{parse_date, split_list, split_names};
+use crate::model::{
+    BoundingBox, ContentBlock, ConversionOptions, Document, DocumentConverter, Page, Provenance,
+};
+
+/// Decode a PDF text string: UTF-16BE when it starts with a byte order
+/// mark, otherwise PDFDocEncoding (read as Latin-1)
+fn pdf_text_string(bytes: &[u8]) -> String {
+    match bytes.strip_prefix(&[0xFE, 0xFF]) {
+        Some(utf16) => {
+            let units: Vec<u16> = utf16
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect();
+            String::from_utf16_lossy(&units)
+        }
+        None => bytes.iter().map(|&b| b as char).collect(),
+    }
+}
 
 /// Threshold for "low text" - if page has significant images and text below this, render whole page
 /// This is set high because pages with good text extraction don't need LLM even with images
@@ -266,6 +286,88 @@ impl PdfConverter {
         Ok(png_data)
     }
 
+    /// Provenance of a page: its number and, when the structure parsed, its
+    /// media box
+    fn page_provenance(pdf: Option<&Pdf>, page_index: usize) -> Provenance {
+        let bounding_box = pdf.and_then(|pdf| {
+            let pages = pdf.pages();
+            let media_box = pages.get(page_index)?.media_box();
+            Some(BoundingBox {
+                x0: media_box.x0,
+                y0: media_box.y0,
+                x1: media_box.x1,
+                y1: media_box.y1,
+            })
+        });
+        Provenance::pdf_page((page_index + 1) as u32, bounding_box)
+    }
+
+    /// Fill the title and properties from the document information
+    /// dictionary (`/Info` in the trailer) and the catalog's `/Lang`
+    fn apply_info_dictionary(
+        document: &mut Document,
+        pdf: Option<&lopdf::Document>,
+        page_count: usize,
+    ) {
+        if page_count > 0 {
+            document.properties.page_count = Some(page_count as u32);
+        }
+        let Some(pdf) = pdf else {
+            return;
+        };
+        if document.properties.language.is_none() {
+            document.properties.language = Self::catalog_language(pdf);
+        }
+        let info = match pdf.trailer.get(b"Info") {
+            Ok(Object::Reference(id)) => pdf.get_dictionary(*id).ok(),
+            Ok(Object::Dictionary(dict)) => Some(dict),
+            _ => None,
+        };
+        let Some(info) = info else {
+            return;
+        };
+        let text = |key: &[u8]| match info.get(key) {
+            Ok(Object::String(bytes, _)) => {
+                Some(pdf_text_string(bytes)).filter(|text| !text.trim().is_empty())
+            }
+            _ => None,
+        };
+
+        if document.title.is_none() {
+            document.title = text(b"Title").map(|title| title.trim().to_string());
+        }
+        let properties = &mut document.properties;
+        if let Some(author) = text(b"Author") {
+            properties.authors = split_names(&author);
+        }
+        if let Some(keywords) = text(b"Keywords") {
+            properties.keywords = split_list(&keywords);
+        }
+        properties.created = text(b"CreationDate").as_deref().and_then(parse_date);
+        properties.modified = text(b"ModDate").as_deref().and_then(parse_date);
+        properties.producer = text(b"Producer").or_else(|| text(b"Creator"));
+        for (key, name) in [(&b"Subject"[..], "subject"), (b"Creator", "creator")] {
+            if let Some(value) = text(key) {
+                document.metadata.insert(name.to_string(), value);
+            }
+        }
+    }
+
+    /// Language the document catalog declares (`/Lang`)
+    fn catalog_language(pdf: &lopdf::Document) -> Option<String> {
+        let catalog = match pdf.trailer.get(b"Root") {
+            Ok(Object::Reference(id)) => pdf.get_dictionary(*id).ok()?,
+            Ok(Object::Dictionary(dict)) => dict,
+            _ => return None,
+        };
+        match catalog.get(b"Lang") {
+            Ok(Object::String(bytes, _)) => {
+                Some(pdf_text_string(bytes).trim().to_string()).filter(|lang| !lang.is_empty())
+            }
+            _ => None,
+        }
+    }
+
     /// Parse PDF using hayro
     fn parse_pdf(bytes: &[u8]) -> Result<Pdf, MarkitdownError> {
         let data: Arc<dyn AsRef<[u8]> + Send + Sync> = Arc::new(bytes.to_vec());
@@ -456,6 +558,14 @@ impl PdfConverter {
             page_texts.len()
         };
 
+        // Language of the text layer (or the one the catalog declares) picks
+        // the OCR prompt
+        let info = lopdf::Document::load_mem(bytes).ok();
+        let language = info
+            .as_ref()
+            .and_then(Self::catalog_language)
+            .or_else(|| detect_language(&page_texts.join("\n\n")));
+
         // Analyze which pages need LLM processing
         let mut page_metrics: Vec<(usize, PageMetrics)> = Vec::with_capacity(page_count);
         let mut pages_needing_llm: Vec<usize> = Vec::new();
@@ -468,9 +578,8 @@ impl PdfConverter {
                 .unwrap_or(0);
 
             let metrics = PageMetrics::from_text_with_xobjects(page_text, xobject_count);
-            let needs_llm = (force_llm || metrics.should_use_llm())
-                && llm_client.is_some()
-                && pdf.is_some();
+            let needs_llm =
+                (force_llm || metrics.should_use_llm()) && llm_client.is_some() && pdf.is_some();
 
             if needs_llm {
                 pages_needing_llm.push(idx);
@@ -481,7 +590,7 @@ impl PdfConverter {
         // Batch render and process pages that need LLM
         let llm_results = if !pages_needing_llm.is_empty() {
             if let (Some(llm), Some(ref pdf_ref)) = (llm_client, &pdf) {
-                Self::batch_llm_convert(&pages_needing_llm, pdf_ref, llm).await
+                Self::batch_llm_convert(&pages_needing_llm, pdf_ref, llm, language.as_deref()).await
             } else {
                 std::collections::HashMap::new()
             }
@@ -496,17 +605,27 @@ impl PdfConverter {
             let mut page = Page::new((idx + 1) as u32);
 
             // Check if we have LLM result for this page
+            let provenance = Self::page_provenance(pdf.as_ref(), idx);
             if let Some(Some(markdown)) = llm_results.get(&idx) {
-                page.add_content(ContentBlock::Markdown(markdown.clone()));
+                page.add_content_with_provenance(
+                    ContentBlock::Markdown(markdown.clone()),
+                    provenance,
+                );
             } else if !metrics.text.is_empty() {
                 // Use extracted text
                 if !metrics.is_high_quality() && llm_client.is_none() {
-                    page.add_content(ContentBlock::Text(format!(
-                        "[Note: Text extraction quality may be poor]\n\n{}",
-                        metrics.text
-                    )));
+                    page.add_content_with_provenance(
+                        ContentBlock::Text(format!(
+                            "[Note: Text extraction quality may be poor]\n\n{}",
+                            metrics.text
+                        )),
+                        provenance,
+                    );
                 } else {
-                    page.add_content(ContentBlock::Text(metrics.text.clone()));
+                    page.add_content_with_provenance(
+                        ContentBlock::Text(metrics.text.clone()),
+                        provenance,
+                    );
                 }
             }
 
@@ -517,7 +636,14 @@ impl PdfConverter {
 
         // Handle empty document case
         if document.pages.is_empty() && page_count > 0 {
-            Self::try_llm_fallback_for_all_pages(&mut document, page_count, llm_client, &pdf).await;
+            Self::try_llm_fallback_for_all_pages(
+                &mut document,
+                page_count,
+                llm_client,
+                &pdf,
+                language.as_deref(),
+            )
+            .await;
         }
 
         // Final fallback
@@ -525,6 +651,7 @@ impl PdfConverter {
             Self::add_fallback_page(&mut document);
         }
 
+        Self::apply_info_dictionary(&mut document, info.as_ref(), actual_page_count);
         Ok(document)
     }
 
@@ -533,6 +660,7 @@ impl PdfConverter {
         pages_needing_llm: &[usize],
         pdf: &Pdf,
         llm: &dyn LlmClient,
+        language: Option<&str>,
     ) -> std::collections::HashMap<usize, Option<String>> {
         // Render all pages that need LLM processing
         let rendered_pages: Vec<(usize, Vec<u8>)> = pages_needing_llm
@@ -555,7 +683,9 @@ impl PdfConverter {
             .collect();
 
         // Process in parallel batches
-        let results = llm.convert_page_images_batch(&page_data).await;
+        let results = llm
+            .convert_page_images_batch_in_language(&page_data, language)
+            .await;
 
         // Map results back to page indices
         rendered_pages
@@ -571,17 +701,21 @@ impl PdfConverter {
         page_count: usize,
         llm_client: Option<&dyn LlmClient>,
         pdf: &Option<Pdf>,
+        language: Option<&str>,
     ) {
         if let (Some(llm), Some(ref pdf_ref)) = (llm_client, pdf) {
             // Use batch processing for all pages
             let all_pages: Vec<usize> = (0..page_count).collect();
-            let results = Self::batch_llm_convert(&all_pages, pdf_ref, llm).await;
+            let results = Self::batch_llm_convert(&all_pages, pdf_ref, llm, language).await;
 
             for idx in 0..page_count {
                 let mut page = Page::new((idx + 1) as u32);
 
                 if let Some(Some(markdown)) = results.get(&idx) {
-                    page.add_content(ContentBlock::Markdown(markdown.clone()));
+                    page.add_content_with_provenance(
+                        ContentBlock::Markdown(markdown.clone()),
+                        Self::page_provenance(Some(pdf_ref), idx),
+                    );
                 }
 
                 if !page.content.is_empty() {
@@ -623,7 +757,10 @@ impl PdfConverter {
             let trimmed = page_text.trim();
             if !trimmed.is_empty() {
                 let mut page = Page::new((idx + 1) as u32);
-                page.add_content(ContentBlock::Text(trimmed.to_string()));
+                page.add_content_with_provenance(
+                    ContentBlock::Text(trimmed.to_string()),
+                    Self::page_provenance(pdf.as_ref(), idx),
+                );
                 document.add_page(page);
             }
         }
@@ -653,6 +790,8 @@ impl PdfConverter {
             document.add_page(page);
         }
 
+        let info = lopdf::Document::load_mem(bytes).ok();
+        Self::apply_info_dictionary(&mut document, info.as_ref(), actual_page_count);
         Ok(document)
     }
 }
@@ -1,6 +1,6 @@
 use crate::error::MarkitdownError;
 use crate::model::{
-    ContentBlock, ConversionOptions, Document, DocumentConverter, ExtractedImage, Page,
+    ContentBlock, ConversionOptions, Document, DocumentConverter, ExtractedImage, Page, Provenance,
 };
 use async_trait::async_trait;
 use bytes::Bytes;
@@ -13,6 +13,9 @@ use zip::ZipArchive;
 
 pub struct PptxConverter;
 
+/// Content blocks of a slide with the name of the shape holding each
+type ShapeBlocks = Vec<(ContentBlock, Option<String>)>;
+
 /// Represents an image reference found in a slide
 #[derive(Debug, Clone)]
 struct SlideImageRef {
@@ -168,10 +171,13 @@ impl PptxConverter {
         image_refs
     }
 
-    /// Extract text content from slide XML
-    fn extract_text_content(content: &str) -> Result<(String, Vec<ContentBlock>), MarkitdownError> {
+    /// Extract text content from slide XML, with the name of the shape each
+    /// block was read from
+    fn extract_text_content(content: &str) -> Result<(String, ShapeBlocks), MarkitdownError> {
         let mut markdown = String::new();
         let mut blocks: Vec<ContentBlock> = Vec::new();
+        let mut shapes: Vec<Option<String>> = Vec::new();
+        let mut shape: Option<String> = None;
         let mut reader = Reader::from_str(content);
         reader.config_mut().trim_text(true);
 
@@ -183,7 +189,13 @@ impl PptxConverter {
             match reader.read_event_into(&mut buf).map_err(|e| {
                 MarkitdownError::ParseError(format!("Failed to read XML event: {}", e))
             })? {
+                Event::Empty(element) if element.name().as_ref() == b"p:cNvPr" => {
+                    shape = Self::shape_name(&element);
+                }
                 Event::Start(element) => {
+                    if element.name().as_ref() == b"p:cNvPr" {
+                        shape = Self::shape_name(&element);
+                    }
                     Self::process_element(
                         &element,
                         &mut reader,
@@ -191,13 +203,24 @@ impl PptxConverter {
                         &mut blocks,
                         &mut count,
                     )?;
+                    shapes.resize(blocks.len(), shape.clone());
                 }
                 Event::Eof => break,
                 _ => {}
             }
         }
 
-        Ok((markdown, blocks))
+        Ok((markdown, blocks.into_iter().zip(shapes).collect()))
+    }
+
+    /// The `name` of a shape's `p:cNvPr` properties
+    fn shape_name(element: &quick_xml::events::BytesStart) -> Option<String> {
+        element
+            .attributes()
+            .flatten()
+            .find(|attr| attr.key.as_ref() == b"name")
+            .and_then(|attr| String::from_utf8(attr.value.to_vec()).ok())
+            .filter(|name| !name.is_empty())
     }
 
     /// Process a start element and extract content
@@ -373,7 +396,7 @@ impl PptxConverter {
     /// Parse a single slide XML and extract text content and image references
     fn parse_slide_content(
         content: &str,
-    ) -> Result<(String, Vec<ContentBlock>, Vec<SlideImageRef>), MarkitdownError> {
+    ) -> Result<(String, ShapeBlocks, Vec<SlideImageRef>), MarkitdownError> {
         let (markdown, blocks) = Self::extract_text_content(content)?;
         let image_refs = Self::extract_image_refs(content);
         Ok((markdown, blocks, image_refs))
@@ -408,6 +431,7 @@ impl PptxConverter {
             document.add_page(page);
         }
 
+        crate::metadata::apply_ooxml_properties(&mut document, bytes);
         Ok(document)
     }
 
@@ -458,14 +482,14 @@ impl PptxConverter {
         let mut page = Page::new(slide_num);
 
         // Add slide header
-        page.add_content(ContentBlock::Markdown(format!(
-            "<!-- Slide {} -->\n",
-            slide_num
-        )));
+        page.add_content_with_provenance(
+            ContentBlock::Markdown(format!("<!-- Slide {} -->\n", slide_num)),
+            Provenance::slide(slide_num, None),
+        );
 
         // Add parsed content blocks
-        for block in blocks {
-            page.add_content(block);
+        for (block, shape) in blocks {
+            page.add_content_with_provenance(block, Provenance::slide(slide_num, shape));
         }
 
         // Add images that belong to this slide
@@ -483,7 +507,10 @@ impl PptxConverter {
                     if let Some(desc) = &img_ref.description {
                         image.description = Some(desc.clone());
                     }
-                    page.add_content(ContentBlock::Image(image));
+                    page.add_content_with_provenance(
+                        ContentBlock::Image(image),
+                        Provenance::slide(slide_num, None),
+                    );
                 }
             }
         }
@@ -0,0 +1,263 @@
+//! Detection of quoted replies and signatures in email bodies.
+//!
+//! Replies usually carry the whole previous conversation below an
+//! attribution line ("On ... wrote:") or an Outlook header block
+//! ("From:/Sent:"), and end with a signature or legal footer. These parts
+//! can be removed, or collapsed into [`ContentBlock::Quote`]s so the new
+//! text of each message stands out.
+
+use crate::model::ContentBlock;
+
+/// What to do with quoted replies and signatures in email bodies
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QuotedReplyMode {
+    /// Keep the body as written
+    #[default]
+    Keep,
+    /// Render quoted replies and signatures as quote blocks
+    Collapse,
+    /// Remove quoted replies and signatures
+    Strip,
+}
+
+/// Part of an email body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Part {
+    Body,
+    Quoted,
+    Signature,
+}
+
+/// Phrases that end an attribution line ("On Monday, Ann wrote:")
+const ATTRIBUTION_ENDINGS: &[&str] = &["wrote:", "schrieb:", "a écrit :", "a écrit:", "escribió:"];
+
+/// Openings of legal footers and disclaimers (compared case-insensitively)
+const FOOTER_OPENINGS: &[&str] = &[
+    "confidentiality notice",
+    "disclaimer:",
+    "this email and any attachments",
+    "this e-mail and any attachments",
+    "this message and any attachments",
+    "this email is confidential",
+    "this e-mail is confidential",
+    "the information contained in this",
+    "this message is intended only for",
+];
+
+/// Sign-offs added by mail clients
+const CLIENT_SIGNATURES: &[&str] = &["sent from my ", "get outlook for ", "sent from mail for "];
+
+fn starts_with_ignore_case(line: &str, prefix: &str) -> bool {
+    line.len() >= prefix.len()
+        && line.is_char_boundary(prefix.len())
+        && line[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
+
+/// Whether a line is an email header field such as `From:`, also when
+/// rendered bold (`**From:**`)
+fn is_header_field(line: &str, name: &str) -> bool {
+    starts_with_ignore_case(line.trim_start_matches('*'), name)
+}
+
+/// Whether `lines[index]` starts a quoted reply
+fn is_reply_header(lines: &[&str], index: usize) -> bool {
+    let line = lines[index].trim();
+    let following: Vec<&str> = lines[index + 1..]
+        .iter()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .take(5)
+        .collect();
+
+    // "On Mon, 4 Mar 2024 at 10:00, Ann <ann@example.com> wrote:", possibly
+    // wrapped onto a second line
+    if ["On ", "Am ", "Le ", "El "]
+        .iter()
+        .any(|prefix| line.starts_with(prefix))
+    {
+        let ends = |l: &str| ATTRIBUTION_ENDINGS.iter().any(|e| l.ends_with(e));
+        if ends(line) || following.first().is_some_and(|l| ends(l)) {
+            return true;
+        }
+    }
+
+    // Forwarded messages repeat the same header fields, but are content
+    let forwarded = lines[..index]
+        .iter()
+        .rev()
+        .find(|l| !l.trim().is_empty())
+        .is_some_and(|l| l.to_lowercase().contains("forwarded message"));
+    if forwarded {
+        return false;
+    }
+
+    // "-----Original Message-----"
+    let lower = line.to_lowercase();
+    if lower.starts_with("-----") && lower.contains("original message") {
+        return true;
+    }
+
+    // Outlook header block: "From: ..." followed by "Sent: ...", optionally
+    // below a line of underscores
+    let from_line = if line.len() >= 10 && line.chars().all(|c| c == '_') {
+        match following.first() {
+            Some(next) => *next,
+            None => return false,
+        }
+    } else {
+        line
+    };
+    is_header_field(from_line, "from:")
+        && following
+            .iter()
+            .any(|l| is_header_field(l, "sent:") || is_header_field(l, "date:"))
+}
+
+/// Whether a line starts a signature or legal footer
+fn is_signature_start(line: &str) -> bool {
+    let trimmed = line.trim();
+    line.trim_end() == "--"
+        || CLIENT_SIGNATURES
+            .iter()
+            .chain(FOOTER_OPENINGS)
+            .any(|prefix| starts_with_ignore_case(trimmed, prefix))
+}
+
+fn is_quoted_line(line: &str) -> bool {
+    line.trim_start().starts_with('>')
+}
+
+/// Remove one level of `>` quoting
+fn unquote_line(line: &str) -> &str {
+    let line = line.trim_start();
+    let line = line.strip_prefix('>').unwrap_or(line);
+    line.strip_prefix(' ').unwrap_or(line)
+}
+
+/// Split a plain text body into body, quoted and signature parts
+fn split_text(text: &str) -> Vec<(Part, String)> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut parts: Vec<(Part, Vec<&str>)> = Vec::new();
+    let mut state = Part::Body;
+    for (index, line) in lines.iter().enumerate() {
+        if state != Part::Quoted && is_reply_header(&lines, index) {
+            // Everything below the reply header is the earlier conversation
+            state = Part::Quoted;
+        } else if state == Part::Body && is_signature_start(line) {
+            state = Part::Signature;
+        }
+
+        let part = if state == Part::Body && is_quoted_line(line) {
+            Part::Quoted
+        } else {
+            state
+        };
+        match parts.last_mut() {
+            Some((last, part_lines)) if *last == part => part_lines.push(line),
+            _ => parts.push((part, vec![line])),
+        }
+    }
+
+    parts
+        .into_iter()
+        .map(|(part, lines)| {
+            let text = if part == Part::Quoted {
+                lines
+                    .iter()
+                    .map(|l| unquote_line(l))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            } else {
+                lines.join("\n")
+            };
+            (part, text.trim().to_string())
+        })
+        .filter(|(_, text)| !text.is_empty())
+        .collect()
+}
+
+/// Handle quoted replies and signatures in a plain text body
+pub(crate) fn text_body_blocks(text: &str, mode: QuotedReplyMode) -> Vec<ContentBlock> {
+    if mode == QuotedReplyMode::Keep {
+        return vec![ContentBlock::Text(text.to_string())];
+    }
+    split_text(text)
+        .into_iter()
+        .filter_map(|(part, text)| match (part, mode) {
+            (Part::Body, _) => Some(ContentBlock::Text(text)),
+            (_, QuotedReplyMode::Collapse) => Some(ContentBlock::Quote(text)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Handle quoted replies and signatures in the blocks of a converted HTML
+/// body. Blockquotes are quoted replies; a block that opens with a reply
+/// header starts the quoted conversation.
+pub(crate) fn html_body_blocks(
+    blocks: Vec<ContentBlock>,
+    mode: QuotedReplyMode,
+) -> Vec<ContentBlock> {
+    if mode == QuotedReplyMode::Keep {
+        return blocks;
+    }
+
+    let mut state = Part::Body;
+    let mut result: Vec<ContentBlock> = Vec::new();
+    // Text of the quoted or signature part being collapsed
+    let mut collapsed: Vec<String> = Vec::new();
+    let mut collapsed_part = Part::Body;
+    let flush = |collapsed: &mut Vec<String>, result: &mut Vec<ContentBlock>| {
+        if !collapsed.is_empty() {
+            result.push(ContentBlock::Quote(collapsed.join("\n\n")));
+            collapsed.clear();
+        }
+    };
+
+    for block in blocks {
+        let markdown = match &block {
+            ContentBlock::Quote(text) => text.clone(),
+            other => other.to_markdown().trim().to_string(),
+        };
+        if state != Part::Quoted {
+            let lines: Vec<&str> = markdown.lines().collect();
+            if !lines.is_empty() && is_reply_header(&lines, 0) {
+                state = Part::Quoted;
+                // Clients separate the quoted conversation with a rule
+                if collapsed.last().is_some_and(|md| md == "---") {
+                    collapsed.pop();
+                } else if matches!(result.last(), Some(ContentBlock::Rule)) {
+                    result.pop();
+                }
+            } else if state == Part::Body && lines.first().is_some_and(|l| is_signature_start(l)) {
+                state = Part::Signature;
+            }
+        }
+        let part = match (&block, state) {
+            (ContentBlock::Quote(_), Part::Body) => Part::Quoted,
+            _ => state,
+        };
+
+        match (part, &block) {
+            (Part::Body, _) => {
+                flush(&mut collapsed, &mut result);
+                result.push(block);
+            }
+            (_, _) if mode == QuotedReplyMode::Strip => {}
+            // Images stay images, between the collapsed text around them
+            (_, ContentBlock::Image(_)) => {
+                flush(&mut collapsed, &mut result);
+                result.push(block);
+            }
+            _ => {
+                if part != collapsed_part {
+                    flush(&mut collapsed, &mut result);
+                    collapsed_part = part;
+                }
+                collapsed.push(markdown);
+            }
+        }
+    }
+    flush(&mut collapsed, &mut result);
+    result
+}
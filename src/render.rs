@@ -0,0 +1,739 @@
+//! Rendering of documents to Markdown.
+//!
+//! [`MarkdownRenderer`] turns content blocks into one consistent Markdown
+//! dialect: blocks are separated by exactly one blank line, table cells are
+//! escaped, and heading levels, emoji and line length can be adjusted for the
+//! target. `Document::to_markdown` uses the default renderer (GitHub
+//! Flavored Markdown); `Document::to_markdown_with` takes a custom one.
+
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+use crate::model::{
+    Alignment, ContentBlock, Definition, Document, ExtractedImage, Inline, ListItem, Page, Table,
+    TableCell,
+};
+
+static ATX_HEADING: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(#{1,6})(\s+|$)").expect("valid heading regex"));
+
+/// Markdown dialect to render
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MarkdownFlavor {
+    /// CommonMark without extensions; tables are written as HTML
+    CommonMark,
+    /// GitHub Flavored Markdown with pipe tables
+    #[default]
+    Gfm,
+    /// GFM with Obsidian image embeds (`![[id]]`) and the metadata as
+    /// front matter properties
+    Obsidian,
+    /// Plain text without Markdown markup. Headings, tables, code, quotes,
+    /// paragraph spans and nested lists lose their markup; markup inside
+    /// text that converters pass already formatted (`Text`, `Markdown` and
+    /// flat `List` items) is kept.
+    Plain,
+}
+
+/// Renders documents, pages and content blocks to Markdown
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownRenderer {
+    /// Markdown dialect
+    pub flavor: MarkdownFlavor,
+    /// Escape pipes and line breaks inside table cells
+    pub escape_table_cells: bool,
+    /// Added to the level of every heading (capped at 6)
+    pub heading_offset: u8,
+    /// Remove emoji (e.g. the status markers of log and calendar output)
+    pub strip_emoji: bool,
+    /// Wrap paragraphs, quotes and list items at this many characters
+    pub line_width: Option<usize>,
+}
+
+impl Default for MarkdownRenderer {
+    fn default() -> Self {
+        Self {
+            flavor: MarkdownFlavor::Gfm,
+            escape_table_cells: true,
+            heading_offset: 0,
+            strip_emoji: false,
+            line_width: None,
+        }
+    }
+}
+
+impl MarkdownRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_flavor(mut self, flavor: MarkdownFlavor) -> Self {
+        self.flavor = flavor;
+        self
+    }
+
+    /// Write table cells verbatim instead of escaping pipes and line breaks
+    pub fn with_table_escaping(mut self, escape: bool) -> Self {
+        self.escape_table_cells = escape;
+        self
+    }
+
+    /// Shift every heading down by `offset` levels, e.g. to embed the output
+    /// below an existing heading
+    pub fn with_heading_offset(mut self, offset: u8) -> Self {
+        self.heading_offset = offset;
+        self
+    }
+
+    pub fn without_emoji(mut self) -> Self {
+        self.strip_emoji = true;
+        self
+    }
+
+    /// Wrap long lines of text at `width` characters
+    pub fn with_line_width(mut self, width: usize) -> Self {
+        self.line_width = Some(width.max(1));
+        self
+    }
+
+    /// Render a whole document: front matter (Obsidian), title and pages
+    pub fn render(&self, document: &Document) -> String {
+        let mut parts = Vec::new();
+
+        if self.flavor == MarkdownFlavor::Obsidian && !document.metadata.is_empty() {
+            let properties: BTreeMap<&String, &String> = document.metadata.iter().collect();
+            let yaml = serde_yaml::to_string(&properties).unwrap_or_default();
+            parts.push(format!("---\n{}---", yaml));
+        }
+        if let Some(title) = &document.title {
+            parts.push(self.heading(1, title));
+        }
+        for page in &document.pages {
+            if document.pages.len() > 1 {
+                let heading = self.heading(2, &format!("Page {}", page.page_number));
+                parts.push(match self.flavor {
+                    MarkdownFlavor::Plain => heading,
+                    _ => format!("---\n{}", heading),
+                });
+            }
+            parts.push(self.render_page(page));
+        }
+
+        finish(&parts)
+    }
+
+    /// Render the content blocks of one page
+    pub fn render_page(&self, page: &Page) -> String {
+        let blocks: Vec<String> = page.content.iter().map(|b| self.block(b)).collect();
+        finish(&blocks)
+    }
+
+    /// Render one content block, ending with a newline
+    pub fn render_block(&self, block: &ContentBlock) -> String {
+        format!("{}\n", self.block(block))
+    }
+
+    fn block(&self, block: &ContentBlock) -> String {
+        let plain = self.flavor == MarkdownFlavor::Plain;
+        match block {
+            ContentBlock::Text(text) => self.wrap(&self.text(text), 0),
+            ContentBlock::Heading { level, text } => self.heading(*level, text),
+            ContentBlock::Image(image) => self.image(image),
+            ContentBlock::Table { headers, rows } => {
+                self.table(&Table::from_rows(headers.clone(), rows.clone()))
+            }
+            ContentBlock::List { ordered, items } => items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    let marker = if *ordered {
+                        format!("{}. ", i + 1)
+                    } else {
+                        "- ".to_string()
+                    };
+                    let item = self.wrap(&self.text(item), marker.len());
+                    format!("{}{}", marker, item)
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            ContentBlock::Code { code, .. } if plain => code.trim_end_matches('\n').to_string(),
+            ContentBlock::Code { language, code } => {
+                let code = code.trim_end_matches('\n');
+                let fence = "`".repeat(longest_run(code, '`').max(2) + 1);
+                format!(
+                    "{}{}\n{}\n{}",
+                    fence,
+                    language.as_deref().unwrap_or(""),
+                    code,
+                    fence
+                )
+            }
+            ContentBlock::Quote(text) => {
+                let text = self.wrap(&self.text(text), 0);
+                if plain {
+                    return text;
+                }
+                text.lines()
+                    .map(|line| {
+                        if line.is_empty() {
+                            ">".to_string()
+                        } else {
+                            format!("> {}", line)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            ContentBlock::Markdown(md) => self.markdown(md),
+            ContentBlock::Paragraph(spans) => self.wrap(&self.text(&self.inlines(spans)), 0),
+            ContentBlock::NestedList { ordered, items } => self.nested_list(*ordered, items),
+            ContentBlock::FootnoteDefinition { label, content } => {
+                let (marker, width) = if plain {
+                    (format!("[{}] ", label), label.len() + 3)
+                } else {
+                    (format!("[^{}]: ", label), 4)
+                };
+                let body = self.indented(width).blocks(content);
+                format!("{}{}", marker, indent_continuation(&body, width))
+            }
+            ContentBlock::Math(latex) if plain => latex.trim().to_string(),
+            ContentBlock::Math(latex) => format!("$$\n{}\n$$", latex.trim()),
+            ContentBlock::Rule | ContentBlock::PageBreak if plain => String::new(),
+            ContentBlock::Rule | ContentBlock::PageBreak => "---".to_string(),
+            ContentBlock::DefinitionList(definitions) => self.definition_list(definitions),
+            ContentBlock::RichTable(table) => self.table(table),
+        }
+    }
+
+    /// Blocks nested in a list item or footnote, separated by blank lines
+    fn blocks(&self, blocks: &[ContentBlock]) -> String {
+        let parts: Vec<String> = blocks.iter().map(|b| self.block(b)).collect();
+        finish(&parts).trim_end().to_string()
+    }
+
+    /// This renderer for content indented by `width` characters
+    fn indented(&self, width: usize) -> MarkdownRenderer {
+        MarkdownRenderer {
+            line_width: self.line_width.map(|w| w.saturating_sub(width).max(1)),
+            ..self.clone()
+        }
+    }
+
+    fn nested_list(&self, ordered: bool, items: &[ListItem]) -> String {
+        items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let marker = if ordered {
+                    format!("{}. ", i + 1)
+                } else {
+                    "- ".to_string()
+                };
+                let checkbox = match item.checked {
+                    Some(true) => "[x] ",
+                    Some(false) => "[ ] ",
+                    None => "",
+                };
+                let inner = self.indented(marker.len());
+                let text = inner.wrap(&inner.text(&inner.inlines(&item.content)), checkbox.len());
+                // Nested lists and other blocks follow the item text directly
+                let body = std::iter::once(text)
+                    .chain(item.children.iter().map(|child| inner.block(child)))
+                    .filter(|part| !part.trim().is_empty())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                format!(
+                    "{}{}{}",
+                    marker,
+                    checkbox,
+                    indent_continuation(&body, marker.len())
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn definition_list(&self, definitions: &[Definition]) -> String {
+        let plain = self.flavor == MarkdownFlavor::Plain;
+        let inner = self.indented(2);
+        definitions
+            .iter()
+            .map(|definition| {
+                let mut lines = vec![self.text(&self.inlines(&definition.term))];
+                for details in &definition.definitions {
+                    let text = inner.wrap(&inner.text(&inner.inlines(details)), 0);
+                    let marker = if plain { "  " } else { ": " };
+                    lines.push(format!("{}{}", marker, indent_continuation(&text, 2)));
+                }
+                lines.join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Inline spans as markdown (or their text for plain output)
+    fn inlines(&self, spans: &[Inline]) -> String {
+        let plain = self.flavor == MarkdownFlavor::Plain;
+        spans
+            .iter()
+            .map(|span| match span {
+                Inline::Text(text) => text.clone(),
+                Inline::Emphasis(spans) | Inline::Strong(spans) | Inline::Strikethrough(spans)
+                    if plain =>
+                {
+                    self.inlines(spans)
+                }
+                Inline::Emphasis(spans) => delimit("*", "*", &self.inlines(spans)),
+                Inline::Strong(spans) => delimit("**", "**", &self.inlines(spans)),
+                Inline::Strikethrough(spans) if self.flavor == MarkdownFlavor::CommonMark => {
+                    delimit("<del>", "</del>", &self.inlines(spans))
+                }
+                Inline::Strikethrough(spans) => delimit("~~", "~~", &self.inlines(spans)),
+                Inline::Code(code) | Inline::Math(code) if plain => code.clone(),
+                Inline::Code(code) => code_span(code),
+                Inline::Math(latex) => format!("${}$", latex.trim()),
+                Inline::Link { content, url, .. } if plain => {
+                    let text = self.inlines(content);
+                    if text.is_empty() || text == *url {
+                        url.clone()
+                    } else {
+                        format!("{} ({})", text, url)
+                    }
+                }
+                Inline::Link {
+                    content,
+                    url,
+                    title,
+                } => {
+                    let text = self.inlines(content);
+                    let destination = if url.contains([' ', '(', ')']) {
+                        format!("<{}>", url)
+                    } else {
+                        url.clone()
+                    };
+                    match title {
+                        _ if text.is_empty() => format!("<{}>", url),
+                        Some(title) => format!(
+                            "[{}]({} \"{}\")",
+                            text,
+                            destination,
+                            title.replace('"', "\\\"")
+                        ),
+                        None if text == *url && !url.contains(' ') => format!("<{}>", url),
+                        None => format!("[{}]({})", text, destination),
+                    }
+                }
+                Inline::FootnoteReference(label) if plain => format!("[{}]", label),
+                Inline::FootnoteReference(label) => format!("[^{}]", label),
+                Inline::LineBreak if plain => "\n".to_string(),
+                Inline::LineBreak => "\\\n".to_string(),
+            })
+            .collect()
+    }
+
+    fn heading(&self, level: u8, text: &str) -> String {
+        let text = self.text(text);
+        let text = text.trim();
+        if self.flavor == MarkdownFlavor::Plain {
+            return text.to_string();
+        }
+        let level = level.saturating_add(self.heading_offset).clamp(1, 6);
+        format!("{} {}", "#".repeat(level as usize), text)
+    }
+
+    fn image(&self, image: &ExtractedImage) -> String {
+        let description = image.get_display_text();
+        let mut md = match self.flavor {
+            MarkdownFlavor::Plain => {
+                format!("[Image: {}]", description.unwrap_or(&image.id))
+            }
+            flavor => {
+                let mut md = if flavor == MarkdownFlavor::Obsidian {
+                    format!("![[{}]]", image.id)
+                } else {
+                    format!("![{}]({})", image.id, image.id)
+                };
+                if let Some(description) = description {
+                    md.push_str(&format!("\n\n*{}*", description));
+                }
+                md
+            }
+        };
+        if let Some(caption) = &image.caption {
+            md.push_str(&format!("\n\n{}", self.text(caption)));
+        }
+        md
+    }
+
+    /// Render a table: a pipe table padded to the full column count, or HTML
+    /// for CommonMark and for tables a pipe table cannot express
+    fn table(&self, table: &Table) -> String {
+        let columns = table.column_count();
+        if columns == 0 {
+            return String::new();
+        }
+        match self.flavor {
+            MarkdownFlavor::Plain => {
+                let line = |cells: &Vec<TableCell>| {
+                    cells
+                        .iter()
+                        .map(|c| self.text(&c.text).replace('\n', " "))
+                        .collect::<Vec<_>>()
+                        .join(" | ")
+                };
+                table
+                    .caption
+                    .iter()
+                    .map(|caption| self.text(caption))
+                    .chain(
+                        [&table.header, &table.body, &table.footer]
+                            .into_iter()
+                            .flatten()
+                            .map(line),
+                    )
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            MarkdownFlavor::Gfm | MarkdownFlavor::Obsidian if table.is_simple() => {
+                let line = |cells: &[TableCell]| {
+                    let cells: Vec<String> = (0..columns)
+                        .map(|i| {
+                            let c = cells.get(i).map(|c| self.text(&c.text)).unwrap_or_default();
+                            if self.escape_table_cells {
+                                escape_cell(&c)
+                            } else {
+                                c
+                            }
+                        })
+                        .collect();
+                    format!("| {} |", cells.join(" | "))
+                };
+                let separator = (0..columns)
+                    .map(|i| match table.alignment(i) {
+                        Alignment::Default => "---",
+                        Alignment::Left => ":---",
+                        Alignment::Center => ":---:",
+                        Alignment::Right => "---:",
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" | ");
+                // A pipe table always has a header row; headerless tables get
+                // an empty one
+                let header = table.header.first().map_or(&[][..], Vec::as_slice);
+                let mut lines: Vec<String> = table.caption.iter().map(|c| self.text(c)).collect();
+                if !lines.is_empty() {
+                    lines.push(String::new());
+                }
+                lines.push(line(header));
+                lines.push(format!("| {} |", separator));
+                lines.extend(table.body.iter().chain(&table.footer).map(|row| line(row)));
+                lines.join("\n")
+            }
+            _ => self.html_table(table),
+        }
+    }
+
+    fn html_table(&self, table: &Table) -> String {
+        let cell = |tag: &str, c: &TableCell, column: usize| {
+            let text = if self.escape_table_cells {
+                escape_html(&c.text).replace('\n', "<br>")
+            } else {
+                c.text.clone()
+            };
+            let mut attributes = String::new();
+            if c.columns() > 1 {
+                attributes.push_str(&format!(" colspan=\"{}\"", c.columns()));
+            }
+            if c.rows() > 1 {
+                attributes.push_str(&format!(" rowspan=\"{}\"", c.rows()));
+            }
+            match table.alignment(column) {
+                Alignment::Default => {}
+                Alignment::Left => attributes.push_str(" align=\"left\""),
+                Alignment::Center => attributes.push_str(" align=\"center\""),
+                Alignment::Right => attributes.push_str(" align=\"right\""),
+            }
+            format!("<{}{}>{}</{}>", tag, attributes, self.text(&text), tag)
+        };
+        let rows = |section: &[Vec<TableCell>], tag: &str| -> Vec<String> {
+            let (starts, _) = Table::cell_columns(section);
+            section
+                .iter()
+                .zip(starts)
+                .map(|(row, starts)| {
+                    let cells: String = row
+                        .iter()
+                        .zip(starts)
+                        .map(|(c, column)| cell(tag, c, column))
+                        .collect();
+                    format!("<tr>{}</tr>", cells)
+                })
+                .collect()
+        };
+
+        let mut html = vec!["<table>".to_string()];
+        if let Some(caption) = &table.caption {
+            html.push(format!(
+                "<caption>{}</caption>",
+                self.text(&escape_html(caption))
+            ));
+        }
+        if !table.header.is_empty() {
+            html.push(format!(
+                "<thead>{}</thead>",
+                rows(&table.header, "th").concat()
+            ));
+        }
+        html.push("<tbody>".to_string());
+        html.extend(rows(&table.body, "td"));
+        html.push("</tbody>".to_string());
+        if !table.footer.is_empty() {
+            html.push("<tfoot>".to_string());
+            html.extend(rows(&table.footer, "td"));
+            html.push("</tfoot>".to_string());
+        }
+        html.push("</table>".to_string());
+        html.join("\n")
+    }
+
+    /// Raw markdown from a converter: headings are shifted (or unmarked for
+    /// plain text) and emoji removed, except inside code fences
+    fn markdown(&self, md: &str) -> String {
+        let mut fence: Option<String> = None;
+        let mut lines = Vec::new();
+        for line in md.trim_end_matches('\n').lines() {
+            if let Some(marker) = fence_marker(line) {
+                match &fence {
+                    Some(open) if marker.starts_with(open.as_str()) => fence = None,
+                    None => fence = Some(marker),
+                    _ => {}
+                }
+                lines.push(line.to_string());
+                continue;
+            }
+            if fence.is_some() {
+                lines.push(line.to_string());
+                continue;
+            }
+
+            let line = self.text(line);
+            let line = match ATX_HEADING.captures(&line) {
+                Some(caps) => self.heading(caps[1].len() as u8, &line[caps[0].len()..]),
+                None => line,
+            };
+            lines.push(line);
+        }
+        lines.join("\n")
+    }
+
+    fn text(&self, text: &str) -> String {
+        let text = text.trim_end_matches('\n');
+        if self.strip_emoji {
+            strip_emoji(text)
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// Wrap lines longer than the line width at spaces. Continuation lines
+    /// keep the indentation of their line plus `indent`.
+    fn wrap(&self, text: &str, indent: usize) -> String {
+        let Some(width) = self.line_width else {
+            return text.to_string();
+        };
+
+        let mut fence: Option<String> = None;
+        let mut out = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            if let Some(marker) = fence_marker(line) {
+                match &fence {
+                    Some(open) if marker.starts_with(open.as_str()) => fence = None,
+                    None => fence = Some(marker),
+                    _ => {}
+                }
+            }
+            let leading = line.len() - line.trim_start().len();
+            if fence.is_some() || line.trim_start().starts_with(['|', '`', '~']) {
+                out.push(line.to_string());
+                continue;
+            }
+
+            // Continuation lines repeat the line's own indentation, tabs included
+            let padding = format!(
+                "{}{}",
+                &line[..leading],
+                " ".repeat(if i == 0 { indent } else { 0 })
+            );
+            let first_width = width.saturating_sub(if i == 0 { indent } else { 0 });
+            let mut current = line[..leading].to_string();
+            let mut current_width = first_width;
+            for word in line.split_whitespace() {
+                let fits = current.trim().is_empty()
+                    || current.chars().count() + 1 + word.chars().count() <= current_width;
+                if fits || starts_block(word) {
+                    if !current.trim().is_empty() {
+                        current.push(' ');
+                    }
+                    current.push_str(word);
+                } else {
+                    out.push(current);
+                    current = format!("{}{}", padding, word);
+                    current_width = width;
+                }
+            }
+            out.push(current);
+        }
+        out.join("\n")
+    }
+}
+
+/// Join rendered parts with one blank line, collapsing runs of blank lines
+/// outside code fences
+fn finish(parts: &[String]) -> String {
+    let joined = parts
+        .iter()
+        .map(|part| part.trim_matches('\n'))
+        .filter(|part| !part.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    let mut fence: Option<String> = None;
+    let mut out = String::new();
+    let mut blank = false;
+    for line in joined.lines() {
+        if let Some(marker) = fence_marker(line) {
+            match &fence {
+                Some(open) if marker.starts_with(open.as_str()) => fence = None,
+                None => fence = Some(marker),
+                _ => {}
+            }
+        } else if fence.is_none() && line.trim().is_empty() {
+            if !blank {
+                out.push('\n');
+            }
+            blank = true;
+            continue;
+        }
+        blank = false;
+        out.push_str(line);
+        out.push('\n');
+    }
+    out
+}
+
+/// The backtick or tilde run opening or closing a code fence
+fn fence_marker(line: &str) -> Option<String> {
+    let trimmed = line.trim_start();
+    let marker = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let run = trimmed.chars().take_while(|c| *c == marker).count();
+    (run >= 3).then(|| marker.to_string().repeat(run))
+}
+
+/// Indent every line but the first so it continues a list item or footnote
+pub(crate) fn indent_continuation(text: &str, width: usize) -> String {
+    let padding = " ".repeat(width);
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| {
+            if i == 0 || line.is_empty() {
+                line.to_string()
+            } else {
+                format!("{}{}", padding, line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Wrap text in emphasis delimiters, keeping surrounding spaces outside so
+/// the markup stays valid
+fn delimit(open: &str, close: &str, text: &str) -> String {
+    let inner = text.trim();
+    if inner.is_empty() {
+        return text.to_string();
+    }
+    let leading = &text[..text.len() - text.trim_start().len()];
+    let trailing = &text[text.trim_end().len()..];
+    format!("{}{}{}{}{}", leading, open, inner, close, trailing)
+}
+
+/// Inline code with a backtick run longer than any inside the code
+fn code_span(code: &str) -> String {
+    let ticks = "`".repeat(longest_run(code, '`') + 1);
+    if code.starts_with('`') || code.ends_with('`') {
+        format!("{} {} {}", ticks, code, ticks)
+    } else {
+        format!("{}{}{}", ticks, code, ticks)
+    }
+}
+
+fn longest_run(text: &str, c: char) -> usize {
+    text.split(|ch| ch != c)
+        .map(|run| run.len())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Words that would start a new block (heading, quote, list item, table
+/// row) if a wrapped line began with them
+fn starts_block(word: &str) -> bool {
+    word.starts_with(['#', '>', '-', '+', '*', '=', '|'])
+        || word
+            .strip_suffix(['.', ')'])
+            .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Escape pipes and turn line breaks into `<br>`
+fn escape_cell(cell: &str) -> String {
+    cell.replace("\r\n", "\n")
+        .replace('|', "\\|")
+        .replace('\n', "<br>")
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn is_emoji(c: char) -> bool {
+    matches!(c,
+        '\u{1F000}'..='\u{1FAFF}'
+        | '\u{2600}'..='\u{27BF}'
+        | '\u{231A}' | '\u{231B}' | '\u{2328}' | '\u{23CF}'
+        | '\u{23E9}'..='\u{23F3}'
+        | '\u{23F8}'..='\u{23FA}'
+        | '\u{2B05}'..='\u{2B07}'
+        | '\u{2B1B}' | '\u{2B1C}' | '\u{2B50}' | '\u{2B55}'
+        | '\u{FE0F}' | '\u{200D}' | '\u{20E3}'
+        | '\u{E0020}'..='\u{E007F}')
+}
+
+/// Remove emoji along with the space that separated them from the text
+fn strip_emoji(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < chars.len() {
+        if !is_emoji(chars[i]) {
+            out.push(chars[i]);
+            i += 1;
+            continue;
+        }
+        while i < chars.len() && is_emoji(chars[i]) {
+            i += 1;
+        }
+        let after_space = out.is_empty() || out.ends_with(char::is_whitespace);
+        match chars.get(i) {
+            Some(' ') if after_space => i += 1,
+            next if out.ends_with(' ')
+                && next.is_none_or(|c| *c == '\n' || c.is_ascii_punctuation()) =>
+            {
+                out.pop();
+            }
+            _ => {}
+        }
+    }
+    out
+}
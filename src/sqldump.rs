@@ -0,0 +1,1347 @@
+//! SQL script (.sql) to Markdown converter.
+//!
+//! Database dumps (`sqlite3 .dump`, pg_dump, mysqldump, DuckDB
+//! `EXPORT DATABASE`) are parsed statement by statement: `CREATE TABLE` and
+//! `ALTER TABLE ... ADD` give the schema, `INSERT` and `COPY ... FROM stdin`
+//! give the rows, and indexes, views and triggers are listed like those of a
+//! SQLite database. Scripts without any table or view are shown as a `sql`
+//! code block.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use object_store::ObjectStore;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::error::MarkitdownError;
+use crate::model::{ContentBlock, ConversionOptions, Document, DocumentConverter, Page};
+use crate::sqlite::DatabaseOptions;
+use crate::tabular::{
+    index_item, list_blocks, relationship, trigger_blocks, ColumnStats, SchemaColumn, TableSummary,
+    Value,
+};
+
+/// Words that end a column's type and start its constraints
+const COLUMN_CONSTRAINTS: &[&str] = &[
+    "CONSTRAINT",
+    "NOT",
+    "NULL",
+    "DEFAULT",
+    "PRIMARY",
+    "REFERENCES",
+    "UNIQUE",
+    "CHECK",
+    "COLLATE",
+    "GENERATED",
+    "AUTO_INCREMENT",
+    "AUTOINCREMENT",
+    "COMMENT",
+    "ON",
+    "IDENTITY",
+];
+
+/// Whether a column constraint starts at `i` (MySQL `CHARACTER SET` included,
+/// the PostgreSQL `character varying` type not)
+fn is_constraint_start(tokens: &[Token], i: usize) -> bool {
+    let Some(token) = tokens.get(i) else {
+        return false;
+    };
+    COLUMN_CONSTRAINTS.iter().any(|w| token.is_word(w))
+        || (token.is_word("CHARACTER") && tokens.get(i + 1).is_some_and(|t| t.is_word("SET")))
+}
+
+/// A statement of the script; `COPY ... FROM stdin` carries its data lines
+#[derive(Debug, Clone, PartialEq)]
+enum Statement {
+    Sql(String),
+    Copy(String, Vec<String>),
+}
+
+/// Split a script into statements, dropping comments. Semicolons inside
+/// quotes, dollar-quoted bodies and `BEGIN ... END` trigger bodies do not end
+/// a statement. `backslash_escapes` enables MySQL-style `\'` in strings.
+fn split_statements(script: &str, backslash_escapes: bool) -> Vec<Statement> {
+    let chars: Vec<char> = script.chars().collect();
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '-' if chars.get(i + 1) == Some(&'-') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            '/' if chars.get(i + 1) == Some(&'*') => {
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    i += 1;
+                }
+                i += 2;
+                current.push(' ');
+                continue;
+            }
+            '\'' | '"' | '`' | '[' => {
+                let close = if c == '[' { ']' } else { c };
+                current.push(c);
+                i += 1;
+                while i < chars.len() {
+                    current.push(chars[i]);
+                    if chars[i] == '\\' && c == '\'' && backslash_escapes && i + 1 < chars.len() {
+                        current.push(chars[i + 1]);
+                        i += 2;
+                        continue;
+                    }
+                    i += 1;
+                    if chars[i - 1] == close {
+                        // A doubled quote is an escaped quote
+                        if chars.get(i) == Some(&close) && close != ']' {
+                            current.push(close);
+                            i += 1;
+                            continue;
+                        }
+                        break;
+                    }
+                }
+                continue;
+            }
+            '$' => {
+                // Dollar quoting: $$ ... $$ or $tag$ ... $tag$
+                let tag_end = chars[i + 1..]
+                    .iter()
+                    .position(|c| !(c.is_alphanumeric() || *c == '_'))
+                    .map(|p| i + 1 + p);
+                if let Some(end) = tag_end.filter(|&end| chars[end] == '$') {
+                    let tag = &chars[i..=end];
+                    let body_end = chars[end + 1..]
+                        .windows(tag.len())
+                        .position(|w| w == tag)
+                        .map_or(chars.len(), |p| end + 1 + p + tag.len());
+                    current.extend(&chars[i..body_end]);
+                    i = body_end;
+                    continue;
+                }
+            }
+            ';' if !is_open_trigger(&current) => {
+                let sql = current.trim().to_string();
+                current.clear();
+                i += 1;
+                if sql.is_empty() {
+                    continue;
+                }
+                if is_copy_from_stdin(&sql) {
+                    // Data follows on the next line, up to a line holding "\."
+                    while i < chars.len() && chars[i] != '\n' {
+                        i += 1;
+                    }
+                    i += 1;
+                    let mut lines = Vec::new();
+                    while i < chars.len() {
+                        let end = chars[i..]
+                            .iter()
+                            .position(|&c| c == '\n')
+                            .map_or(chars.len(), |p| i + p);
+                        let line: String = chars[i..end].iter().collect();
+                        i = end + 1;
+                        let line = line.trim_end_matches('\r').to_string();
+                        if line == "\\." {
+                            break;
+                        }
+                        lines.push(line);
+                    }
+                    statements.push(Statement::Copy(sql, lines));
+                } else {
+                    statements.push(Statement::Sql(sql));
+                }
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+        i += 1;
+    }
+
+    let sql = current.trim();
+    if !sql.is_empty() {
+        statements.push(Statement::Sql(sql.to_string()));
+    }
+    statements
+}
+
+/// A `CREATE TRIGGER ... BEGIN` statement whose `END` has not been reached
+fn is_open_trigger(sql: &str) -> bool {
+    let upper = sql.to_uppercase();
+    let words: Vec<&str> = upper.split_whitespace().collect();
+    words.first() == Some(&"CREATE")
+        && words.iter().take(4).any(|w| *w == "TRIGGER")
+        && words.contains(&"BEGIN")
+        && words.last() != Some(&"END")
+}
+
+fn is_copy_from_stdin(sql: &str) -> bool {
+    let upper = sql.to_uppercase();
+    upper.starts_with("COPY ") && upper.split_whitespace().any(|w| w == "STDIN")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// Unquoted identifier or keyword
+    Word(String),
+    /// Quoted identifier
+    Ident(String),
+    Str(String),
+    Number(String),
+    Blob(Vec<u8>),
+    Symbol(char),
+}
+
+impl Token {
+    fn is_word(&self, keyword: &str) -> bool {
+        matches!(self, Token::Word(w) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    /// Identifier or keyword text
+    fn name(&self) -> Option<&str> {
+        match self {
+            Token::Word(w) | Token::Ident(w) => Some(w),
+            _ => None,
+        }
+    }
+
+    /// SQL text of the token
+    fn sql(&self) -> String {
+        match self {
+            Token::Word(w) | Token::Number(w) => w.clone(),
+            Token::Ident(w) => format!("\"{}\"", w.replace('"', "\"\"")),
+            Token::Str(s) => format!("'{}'", s.replace('\'', "''")),
+            Token::Blob(b) => format!(
+                "X'{}'",
+                b.iter().map(|b| format!("{:02X}", b)).collect::<String>()
+            ),
+            Token::Symbol(c) => c.to_string(),
+        }
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Split a statement into tokens. `backslash_escapes` enables MySQL-style
+/// `\'` and `\n` escapes in string literals.
+fn tokenize(sql: &str, backslash_escapes: bool) -> Vec<Token> {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    let read_quoted = |i: &mut usize, close: char| -> String {
+        let mut text = String::new();
+        *i += 1;
+        while *i < chars.len() {
+            let c = chars[*i];
+            if c == '\\' && close == '\'' && backslash_escapes && *i + 1 < chars.len() {
+                text.push(match chars[*i + 1] {
+                    'n' => '\n',
+                    't' => '\t',
+                    'r' => '\r',
+                    '0' => '\0',
+                    other => other,
+                });
+                *i += 2;
+                continue;
+            }
+            *i += 1;
+            if c == close {
+                if chars.get(*i) == Some(&close) && close != ']' {
+                    text.push(close);
+                    *i += 1;
+                    continue;
+                }
+                break;
+            }
+            text.push(c);
+        }
+        text
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if (c == 'x' || c == 'X') && chars.get(i + 1) == Some(&'\'') {
+            i += 1;
+            let hex = read_quoted(&mut i, '\'');
+            tokens.push(match decode_hex(&hex) {
+                Some(data) => Token::Blob(data),
+                None => Token::Str(hex),
+            });
+        } else if (c == 'e' || c == 'E') && chars.get(i + 1) == Some(&'\'') {
+            // PostgreSQL escape string
+            i += 1;
+            tokens.push(Token::Str(read_quoted(&mut i, '\'')));
+        } else if c == '\'' {
+            tokens.push(Token::Str(read_quoted(&mut i, '\'')));
+        } else if c == '"' || c == '`' {
+            tokens.push(Token::Ident(read_quoted(&mut i, c)));
+        } else if c == '[' {
+            tokens.push(Token::Ident(read_quoted(&mut i, ']')));
+        } else if c.is_ascii_digit()
+            || (c == '.' && chars.get(i + 1).is_some_and(char::is_ascii_digit))
+        {
+            let start = i;
+            while i < chars.len()
+                && (chars[i].is_ascii_alphanumeric()
+                    || chars[i] == '.'
+                    || ((chars[i] == '+' || chars[i] == '-') && matches!(chars[i - 1], 'e' | 'E')))
+            {
+                i += 1;
+            }
+            tokens.push(Token::Number(chars[start..i].iter().collect()));
+        } else if c.is_alphanumeric() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || matches!(chars[i], '_' | '$')) {
+                i += 1;
+            }
+            tokens.push(Token::Word(chars[start..i].iter().collect()));
+        } else {
+            tokens.push(Token::Symbol(c));
+            i += 1;
+        }
+    }
+    tokens
+}
+
+/// SQL text of `tokens`, spaced like hand-written SQL (`NUMERIC(10, 2)`)
+fn tokens_sql(tokens: &[Token]) -> String {
+    let mut text = String::new();
+    let mut previous: Option<&Token> = None;
+    for token in tokens {
+        let attach = matches!(token, Token::Symbol('(' | ')' | ',' | ':' | '.'))
+            || matches!(previous, Some(Token::Symbol('(' | ':' | '.')));
+        if !text.is_empty() && !attach {
+            text.push(' ');
+        }
+        text.push_str(&token.sql());
+        previous = Some(token);
+    }
+    text
+}
+
+/// Split `tokens` at commas outside parentheses
+fn split_top_level(tokens: &[Token]) -> Vec<&[Token]> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Symbol('(') => depth += 1,
+            Token::Symbol(')') => depth = depth.saturating_sub(1),
+            Token::Symbol(',') if depth == 0 => {
+                parts.push(&tokens[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if start < tokens.len() {
+        parts.push(&tokens[start..]);
+    }
+    parts
+}
+
+/// Tokens inside the parenthesized group opening at `open`, and the index
+/// after its closing parenthesis
+fn group(tokens: &[Token], open: usize) -> Option<(&[Token], usize)> {
+    if tokens.get(open) != Some(&Token::Symbol('(')) {
+        return None;
+    }
+    let mut depth = 0usize;
+    for (i, token) in tokens.iter().enumerate().skip(open) {
+        match token {
+            Token::Symbol('(') => depth += 1,
+            Token::Symbol(')') => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((&tokens[open + 1..i], i + 1));
+                }
+            }
+            _ => {}
+        }
+    }
+    Some((&tokens[open + 1..], tokens.len()))
+}
+
+/// A possibly schema-qualified name (`public.products`) starting at `start`,
+/// and the index after it
+fn qualified_name(tokens: &[Token], start: usize) -> Option<(String, usize)> {
+    let mut parts = vec![tokens.get(start)?.name()?.to_string()];
+    let mut i = start + 1;
+    while tokens.get(i) == Some(&Token::Symbol('.')) {
+        match tokens.get(i + 1).and_then(Token::name) {
+            Some(part) => parts.push(part.to_string()),
+            None => break,
+        }
+        i += 2;
+    }
+    Some((parts.join("."), i))
+}
+
+/// Skip the given optional keywords in order
+fn skip_words(tokens: &[Token], mut i: usize, words: &[&str]) -> usize {
+    for word in words {
+        if tokens.get(i).is_some_and(|t| t.is_word(word)) {
+            i += 1;
+        }
+    }
+    i
+}
+
+/// Names in a parenthesized column list (`(id, "name")`)
+fn column_list(tokens: &[Token]) -> Vec<String> {
+    split_top_level(tokens)
+        .into_iter()
+        .filter_map(|part| match part {
+            [single] => single.name().map(String::from),
+            _ if !part.is_empty() => Some(tokens_sql(part)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Typed value of a literal in an `INSERT` row for a column of `data_type`
+fn literal_value(tokens: &[Token], data_type: &str) -> Value {
+    match tokens {
+        [] => Value::Null,
+        [Token::Word(w)] if w.eq_ignore_ascii_case("NULL") => Value::Null,
+        [Token::Word(w)] if w.eq_ignore_ascii_case("TRUE") || w.eq_ignore_ascii_case("FALSE") => {
+            Value::Text(w.to_lowercase())
+        }
+        [Token::Str(s)] => Value::Text(s.clone()),
+        [Token::Blob(b)] => Value::Blob(b.clone()),
+        [Token::Number(n)] => number_value(n, data_type).unwrap_or_else(|| Value::Text(n.clone())),
+        [Token::Symbol(sign @ ('-' | '+')), Token::Number(n)] => {
+            let n = format!("{}{}", sign, n);
+            number_value(&n, data_type).unwrap_or(Value::Text(n))
+        }
+        // Casts such as '2024-01-01'::date keep the literal
+        [Token::Str(s), Token::Symbol(':'), Token::Symbol(':'), ..] => Value::Text(s.clone()),
+        other => Value::Text(tokens_sql(other)),
+    }
+}
+
+/// A number literal as an integer, or as a float in a floating-point column.
+/// Other numbers (`NUMERIC`/`DECIMAL` values, integers beyond i64) stay text
+/// so that their digits are kept exactly.
+fn number_value(text: &str, data_type: &str) -> Option<Value> {
+    if let Ok(n) = text.parse::<i64>() {
+        return Some(Value::Integer(n));
+    }
+    if is_float_type(data_type) {
+        return text.parse::<f64>().ok().map(Value::Real);
+    }
+    None
+}
+
+/// Whether a column of `data_type` holds floating-point numbers
+fn is_float_type(data_type: &str) -> bool {
+    let data_type = data_type.to_uppercase();
+    ["REAL", "FLOA", "DOUB"]
+        .iter()
+        .any(|t| data_type.contains(t))
+}
+
+/// Whether exact numbers stored as text in a column of `data_type` count as
+/// numbers in its statistics
+fn is_exact_numeric_type(data_type: &str) -> bool {
+    let data_type = data_type.to_uppercase();
+    ["INT", "NUMERIC", "DECIMAL"]
+        .iter()
+        .any(|t| data_type.contains(t))
+}
+
+/// Typed value of a text field from `COPY` data, using the column type
+fn text_value(text: String, data_type: &str) -> Value {
+    let number = if data_type.to_uppercase().contains("INT") {
+        text.parse::<i64>().ok().map(Value::Integer)
+    } else if is_float_type(data_type) {
+        text.parse::<f64>().ok().map(Value::Real)
+    } else {
+        None
+    };
+    number.unwrap_or(Value::Text(text))
+}
+
+/// A field of `COPY` text format data; `\N` is NULL
+fn copy_field(field: &str) -> Option<String> {
+    if field == "\\N" {
+        return None;
+    }
+    let mut text = String::new();
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => text.push('\n'),
+            Some('t') => text.push('\t'),
+            Some('r') => text.push('\r'),
+            Some(other) => text.push(other),
+            None => text.push('\\'),
+        }
+    }
+    Some(text)
+}
+
+/// A foreign key of a dumped table
+#[derive(Debug, Clone)]
+struct ForeignKey {
+    column: String,
+    table: String,
+    target: String,
+    on_delete: Option<String>,
+}
+
+/// A table built up from the statements of a dump
+#[derive(Debug)]
+struct DumpTable {
+    name: String,
+    columns: Vec<SchemaColumn>,
+    foreign_keys: Vec<ForeignKey>,
+    indexes: Vec<String>,
+    triggers: Vec<(String, String)>,
+    sample: Vec<Vec<Value>>,
+    stats: Vec<ColumnStats>,
+    row_count: u64,
+}
+
+impl DumpTable {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            columns: Vec::new(),
+            foreign_keys: Vec::new(),
+            indexes: Vec::new(),
+            triggers: Vec::new(),
+            sample: Vec::new(),
+            stats: Vec::new(),
+            row_count: 0,
+        }
+    }
+
+    fn column_index(&self, name: &str) -> Option<usize> {
+        self.columns
+            .iter()
+            .position(|c| c.name == name)
+            .or_else(|| {
+                self.columns
+                    .iter()
+                    .position(|c| c.name.eq_ignore_ascii_case(name))
+            })
+    }
+
+    /// Index of column `name`, adding it when the table has no such column
+    fn column_or_add(&mut self, name: &str) -> usize {
+        self.column_index(name).unwrap_or_else(|| {
+            self.columns.push(SchemaColumn {
+                name: name.to_string(),
+                nullable: true,
+                ..Default::default()
+            });
+            self.columns.len() - 1
+        })
+    }
+
+    fn set_primary_key(&mut self, columns: &[String]) {
+        for name in columns {
+            if let Some(index) = self.column_index(name) {
+                self.columns[index].primary_key = true;
+                self.columns[index].nullable = false;
+            }
+        }
+    }
+
+    /// Statistics and sampled rows for columns added after rows were stored,
+    /// which count those rows as NULL
+    fn pad_columns(&mut self) {
+        while self.stats.len() < self.columns.len() {
+            let mut stats = ColumnStats::default();
+            for _ in 0..self.row_count {
+                stats.add(&Value::Null);
+            }
+            self.stats.push(stats);
+        }
+        for row in &mut self.sample {
+            row.resize(self.columns.len(), Value::Null);
+        }
+    }
+
+    /// Store a row whose values belong to `positions` in the schema
+    fn add_row(&mut self, positions: &[usize], values: Vec<Value>, limit: Option<usize>) {
+        let mut row = vec![Value::Null; self.columns.len()];
+        for (&position, value) in positions.iter().zip(values) {
+            row[position] = value;
+        }
+        self.pad_columns();
+        for ((stats, value), column) in self.stats.iter_mut().zip(&row).zip(&self.columns) {
+            // Exact numbers kept as text are counted by their approximate value
+            let approximate = match value {
+                Value::Text(text) if is_exact_numeric_type(&column.data_type) => {
+                    text.parse::<f64>().ok().map(Value::Real)
+                }
+                _ => None,
+            };
+            stats.add(approximate.as_ref().unwrap_or(value));
+        }
+        if limit.is_none_or(|limit| self.sample.len() < limit) {
+            self.sample.push(row);
+        }
+        self.row_count += 1;
+    }
+}
+
+/// Tables, views and counts collected from a script
+struct DumpParser<'a> {
+    options: &'a DatabaseOptions,
+    backslash_escapes: bool,
+    tables: Vec<DumpTable>,
+    /// Lowercase table name to index in `tables`
+    lookup: HashMap<String, usize>,
+    views: Vec<(String, String)>,
+    index_count: usize,
+    trigger_count: usize,
+}
+
+impl<'a> DumpParser<'a> {
+    fn table_index(&self, name: &str) -> Option<usize> {
+        self.lookup.get(&name.to_lowercase()).copied().or_else(|| {
+            // `public.products` and `products` name the same table
+            let short = name.rsplit('.').next()?.to_lowercase();
+            self.tables
+                .iter()
+                .position(|t| t.name.rsplit('.').next().unwrap_or("").to_lowercase() == short)
+        })
+    }
+
+    fn table_or_add(&mut self, name: &str) -> usize {
+        self.table_index(name).unwrap_or_else(|| {
+            self.tables.push(DumpTable::new(name.to_string()));
+            self.lookup
+                .insert(name.to_lowercase(), self.tables.len() - 1);
+            self.tables.len() - 1
+        })
+    }
+
+    fn statement(&mut self, statement: Statement) {
+        match statement {
+            Statement::Sql(sql) => {
+                let tokens = tokenize(&sql, self.backslash_escapes);
+                let first = |word: &str| tokens.first().is_some_and(|t| t.is_word(word));
+                if first("CREATE") {
+                    self.create(&sql, &tokens);
+                } else if first("INSERT") || first("REPLACE") {
+                    self.insert(&tokens);
+                } else if first("ALTER") {
+                    self.alter(&tokens);
+                }
+            }
+            Statement::Copy(sql, lines) => self.copy(&sql, lines),
+        }
+    }
+
+    fn create(&mut self, sql: &str, tokens: &[Token]) {
+        let mut i = skip_words(tokens, 1, &["OR", "REPLACE"]);
+        i = skip_words(
+            tokens,
+            i,
+            &["TEMP", "TEMPORARY", "UNLOGGED", "UNIQUE", "VIRTUAL"],
+        );
+        let unique = tokens[1..i].iter().any(|t| t.is_word("UNIQUE"));
+        let Some(kind) = tokens.get(i) else {
+            return;
+        };
+        if kind.is_word("TABLE") {
+            self.create_table(tokens, i + 1);
+        } else if kind.is_word("INDEX") {
+            self.create_index(tokens, i + 1, unique);
+        } else if kind.is_word("VIEW") || kind.is_word("MATERIALIZED") {
+            let i = skip_words(tokens, i, &["MATERIALIZED", "VIEW", "IF", "NOT", "EXISTS"]);
+            if let Some((name, _)) = qualified_name(tokens, i) {
+                self.views.push((name, sql.to_string()));
+            }
+        } else if kind.is_word("TRIGGER") {
+            self.create_trigger(sql, tokens, i + 1);
+        }
+    }
+
+    fn create_table(&mut self, tokens: &[Token], start: usize) {
+        let i = skip_words(tokens, start, &["IF", "NOT", "EXISTS"]);
+        let Some((name, i)) = qualified_name(tokens, i) else {
+            return;
+        };
+        // CREATE TABLE ... AS SELECT has no column definitions
+        let Some((body, _)) = group(tokens, i) else {
+            return;
+        };
+        let index = self.table_or_add(&name);
+
+        for definition in split_top_level(body) {
+            let mut definition = definition;
+            if definition.first().is_some_and(|t| t.is_word("CONSTRAINT")) {
+                definition = definition.get(2..).unwrap_or_default();
+            }
+            let Some(first) = definition.first() else {
+                continue;
+            };
+            let is_key = |t: &Token| t.is_word("KEY") || t.is_word("INDEX");
+            if ["PRIMARY", "FOREIGN", "CHECK", "EXCLUDE"]
+                .iter()
+                .any(|w| first.is_word(w))
+                || (first.is_word("UNIQUE") && !definition.get(1).is_some_and(is_key))
+            {
+                self.table_constraint(index, definition);
+            } else if is_key(first)
+                || ["UNIQUE", "FULLTEXT", "SPATIAL"]
+                    .iter()
+                    .any(|w| first.is_word(w))
+            {
+                self.inline_index(index, definition);
+            } else {
+                self.column_definition(index, definition);
+            }
+        }
+    }
+
+    fn column_definition(&mut self, table: usize, tokens: &[Token]) {
+        let Some(name) = tokens.first().and_then(Token::name) else {
+            return;
+        };
+        let mut type_end = 1;
+        let mut depth = 0usize;
+        while let Some(token) = tokens.get(type_end) {
+            match token {
+                Token::Symbol('(') => depth += 1,
+                Token::Symbol(')') => depth = depth.saturating_sub(1),
+                _ if depth == 0 && is_constraint_start(tokens, type_end) => break,
+                _ => {}
+            }
+            type_end += 1;
+        }
+
+        let mut column = SchemaColumn {
+            name: name.to_string(),
+            data_type: tokens_sql(&tokens[1..type_end]),
+            nullable: true,
+            ..Default::default()
+        };
+        let mut foreign_key = None;
+        let mut i = type_end;
+        while let Some(token) = tokens.get(i) {
+            if token.is_word("NOT") && tokens.get(i + 1).is_some_and(|t| t.is_word("NULL")) {
+                column.nullable = false;
+                i += 2;
+            } else if token.is_word("PRIMARY") {
+                column.primary_key = true;
+                column.nullable = false;
+                i += 2;
+            } else if token.is_word("DEFAULT") {
+                let start = i + 1;
+                let mut end = start;
+                if tokens.get(end) == Some(&Token::Symbol('(')) {
+                    end = group(tokens, end).map_or(tokens.len(), |(_, next)| next);
+                } else {
+                    // A literal, possibly signed or cast
+                    if matches!(tokens.get(end), Some(Token::Symbol('-' | '+'))) {
+                        end += 1;
+                    }
+                    end += 1;
+                    if tokens.get(end) == Some(&Token::Symbol('(')) {
+                        end = group(tokens, end).map_or(tokens.len(), |(_, next)| next);
+                    }
+                    // Casts such as 'x'::character varying
+                    while tokens.get(end) == Some(&Token::Symbol(':')) {
+                        end += 2;
+                        while matches!(tokens.get(end), Some(Token::Word(_)))
+                            && !is_constraint_start(tokens, end)
+                        {
+                            end += 1;
+                        }
+                    }
+                }
+                let end = end.min(tokens.len());
+                column.default = Some(tokens_sql(&tokens[start..end]));
+                i = end;
+            } else if token.is_word("REFERENCES") {
+                let (reference, next) = references(tokens, i + 1);
+                foreign_key = reference.map(|reference| ForeignKey {
+                    column: name.to_string(),
+                    target: reference
+                        .columns
+                        .into_iter()
+                        .next()
+                        .unwrap_or_else(|| "id".to_string()),
+                    table: reference.table,
+                    on_delete: reference.on_delete,
+                });
+                i = next;
+            } else {
+                i += 1;
+            }
+        }
+
+        let table = &mut self.tables[table];
+        match table.column_index(name) {
+            Some(existing) => table.columns[existing] = column,
+            None => table.columns.push(column),
+        }
+        table.foreign_keys.extend(foreign_key);
+    }
+
+    /// `PRIMARY KEY (...)` and `FOREIGN KEY (...) REFERENCES ...`
+    fn table_constraint(&mut self, table: usize, tokens: &[Token]) {
+        let Some(first) = tokens.first() else {
+            return;
+        };
+        let Some((columns, next)) = group(tokens, 2) else {
+            return;
+        };
+        let columns = column_list(columns);
+        if first.is_word("PRIMARY") {
+            self.tables[table].set_primary_key(&columns);
+        } else if first.is_word("FOREIGN")
+            && tokens.get(next).is_some_and(|t| t.is_word("REFERENCES"))
+        {
+            if let (Some(reference), _) = references(tokens, next + 1) {
+                let table = &mut self.tables[table];
+                for (position, column) in columns.into_iter().enumerate() {
+                    table.foreign_keys.push(ForeignKey {
+                        column,
+                        table: reference.table.clone(),
+                        target: reference
+                            .columns
+                            .get(position)
+                            .cloned()
+                            .unwrap_or_else(|| "id".to_string()),
+                        on_delete: reference.on_delete.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    /// MySQL `KEY name (...)` and `UNIQUE KEY name (...)` inside `CREATE TABLE`
+    fn inline_index(&mut self, table: usize, tokens: &[Token]) {
+        let unique = tokens.first().is_some_and(|t| t.is_word("UNIQUE"));
+        let open = tokens.iter().position(|t| *t == Token::Symbol('('));
+        let Some((columns, _)) = open.and_then(|open| group(tokens, open)) else {
+            return;
+        };
+        let name = tokens[..open.unwrap_or(0)]
+            .iter()
+            .filter(|t| {
+                !["UNIQUE", "KEY", "INDEX", "FULLTEXT", "SPATIAL"]
+                    .iter()
+                    .any(|w| t.is_word(w))
+            })
+            .find_map(Token::name)
+            .unwrap_or("(unnamed)")
+            .to_string();
+        self.add_index(table, &name, column_list(columns), unique);
+    }
+
+    fn create_index(&mut self, tokens: &[Token], start: usize, unique: bool) {
+        let i = skip_words(tokens, start, &["CONCURRENTLY", "IF", "NOT", "EXISTS"]);
+        let Some((name, i)) = qualified_name(tokens, i) else {
+            return;
+        };
+        if !tokens.get(i).is_some_and(|t| t.is_word("ON")) {
+            return;
+        }
+        let i = skip_words(tokens, i + 1, &["ONLY"]);
+        let Some((table, mut i)) = qualified_name(tokens, i) else {
+            return;
+        };
+        if tokens.get(i).is_some_and(|t| t.is_word("USING")) {
+            i += 2;
+        }
+        let columns = group(tokens, i)
+            .map(|(columns, _)| column_list(columns))
+            .unwrap_or_default();
+        let table = self.table_or_add(&table);
+        self.add_index(table, &name, columns, unique);
+    }
+
+    fn add_index(&mut self, table: usize, name: &str, columns: Vec<String>, unique: bool) {
+        let notes: &[&str] = if unique { &["unique"] } else { &[] };
+        self.tables[table]
+            .indexes
+            .push(index_item(name, &columns, notes));
+        self.index_count += 1;
+    }
+
+    fn create_trigger(&mut self, sql: &str, tokens: &[Token], start: usize) {
+        let i = skip_words(tokens, start, &["IF", "NOT", "EXISTS"]);
+        let Some((name, i)) = qualified_name(tokens, i) else {
+            return;
+        };
+        let Some(on) = tokens[i..].iter().position(|t| t.is_word("ON")) else {
+            return;
+        };
+        let Some((table, _)) = qualified_name(tokens, i + on + 1) else {
+            return;
+        };
+        let table = self.table_or_add(&table);
+        self.tables[table]
+            .triggers
+            .push((name, format!("{};", sql.trim_end_matches(';'))));
+        self.trigger_count += 1;
+    }
+
+    /// `ALTER TABLE [ONLY] name ADD [CONSTRAINT name] PRIMARY KEY / FOREIGN KEY`
+    fn alter(&mut self, tokens: &[Token]) {
+        if !tokens.get(1).is_some_and(|t| t.is_word("TABLE")) {
+            return;
+        }
+        let i = skip_words(tokens, 2, &["IF", "EXISTS", "ONLY"]);
+        let Some((name, i)) = qualified_name(tokens, i) else {
+            return;
+        };
+        if !tokens.get(i).is_some_and(|t| t.is_word("ADD")) {
+            return;
+        }
+        let mut constraint = &tokens[i + 1..];
+        if constraint.first().is_some_and(|t| t.is_word("CONSTRAINT")) {
+            constraint = constraint.get(2..).unwrap_or_default();
+        }
+        if constraint
+            .first()
+            .is_some_and(|t| t.is_word("PRIMARY") || t.is_word("FOREIGN"))
+        {
+            let table = self.table_or_add(&name);
+            self.table_constraint(table, constraint);
+        }
+    }
+
+    /// `INSERT [OR ...] INTO name [(columns)] VALUES (...), (...)`
+    fn insert(&mut self, tokens: &[Token]) {
+        let Some(into) = tokens.iter().position(|t| t.is_word("INTO")) else {
+            return;
+        };
+        let Some((name, mut i)) = qualified_name(tokens, into + 1) else {
+            return;
+        };
+        // Optional alias: INSERT INTO products AS p ...
+        if tokens.get(i).is_some_and(|t| t.is_word("AS")) {
+            i += 2;
+        }
+        let table = self.table_or_add(&name);
+        let names = match group(tokens, i) {
+            Some((columns, next)) => {
+                i = next;
+                Some(column_list(columns))
+            }
+            None => None,
+        };
+        if !tokens.get(i).is_some_and(|t| t.is_word("VALUES")) {
+            return;
+        }
+
+        let limit = self.options.sample_rows;
+        let mut i = i + 1;
+        while let Some((row, next)) = group(tokens, i) {
+            let literals = split_top_level(row);
+            let table = &mut self.tables[table];
+            let positions: Vec<usize> = match &names {
+                Some(names) => names.iter().map(|n| table.column_or_add(n)).collect(),
+                None => (0..literals.len())
+                    .map(|position| {
+                        table
+                            .columns
+                            .get(position)
+                            .map(|_| position)
+                            .unwrap_or_else(|| {
+                                table.column_or_add(&format!("column{}", position + 1))
+                            })
+                    })
+                    .collect(),
+            };
+            let values: Vec<Value> = literals
+                .into_iter()
+                .zip(&positions)
+                .map(|(literal, &position)| {
+                    literal_value(literal, &table.columns[position].data_type)
+                })
+                .collect();
+            table.add_row(&positions, values, limit);
+
+            i = next;
+            if tokens.get(i) != Some(&Token::Symbol(',')) {
+                break;
+            }
+            i += 1;
+        }
+    }
+
+    /// `COPY name (columns) FROM stdin` followed by tab-separated rows
+    fn copy(&mut self, sql: &str, lines: Vec<String>) {
+        let tokens = tokenize(sql, false);
+        let Some((name, i)) = qualified_name(&tokens, 1) else {
+            return;
+        };
+        let table = self.table_or_add(&name);
+        let table = &mut self.tables[table];
+        let positions: Vec<usize> = match group(&tokens, i) {
+            Some((columns, _)) => column_list(columns)
+                .iter()
+                .map(|n| table.column_or_add(n))
+                .collect(),
+            None => (0..table.columns.len()).collect(),
+        };
+
+        let limit = self.options.sample_rows;
+        for line in lines {
+            let values = line
+                .split('\t')
+                .zip(&positions)
+                .map(|(field, &position)| match copy_field(field) {
+                    Some(text) => text_value(text, &table.columns[position].data_type),
+                    None => Value::Null,
+                })
+                .collect();
+            table.add_row(&positions, values, limit);
+        }
+    }
+}
+
+/// Target of a `REFERENCES` clause
+struct Reference {
+    table: String,
+    columns: Vec<String>,
+    on_delete: Option<String>,
+}
+
+/// `REFERENCES table [(columns)] [ON DELETE action]` starting after
+/// `REFERENCES`, and the index after the clause
+fn references(tokens: &[Token], start: usize) -> (Option<Reference>, usize) {
+    let Some((table, mut i)) = qualified_name(tokens, start) else {
+        return (None, start);
+    };
+    let mut columns = Vec::new();
+    if let Some((list, next)) = group(tokens, i) {
+        columns = column_list(list);
+        i = next;
+    }
+    let mut on_delete = None;
+    while tokens
+        .get(i)
+        .is_some_and(|t| t.is_word("ON") || t.is_word("MATCH"))
+    {
+        let is_delete = tokens.get(i + 1).is_some_and(|t| t.is_word("DELETE"));
+        let mut end = i + 2;
+        while tokens.get(end).is_some_and(|t| {
+            [
+                "CASCADE", "RESTRICT", "SET", "NULL", "DEFAULT", "NO", "ACTION",
+            ]
+            .iter()
+            .any(|w| t.is_word(w))
+        }) {
+            end += 1;
+        }
+        if is_delete {
+            on_delete = Some(tokens_sql(&tokens[i + 2..end]).to_uppercase());
+        }
+        i = end.max(i + 2);
+    }
+    (
+        Some(Reference {
+            table,
+            columns,
+            on_delete,
+        }),
+        i,
+    )
+}
+
+/// The database that wrote a dump, from its header or syntax
+fn detect_dialect(script: &str) -> Option<&'static str> {
+    let head: String = script.chars().take(4096).collect();
+    if head.contains("PostgreSQL database dump") {
+        Some("PostgreSQL")
+    } else if head.contains("MySQL dump") || head.contains("MariaDB dump") {
+        Some("MySQL")
+    } else if head.contains("BEGIN TRANSACTION;") && head.contains("PRAGMA foreign_keys") {
+        Some("SQLite")
+    } else {
+        None
+    }
+}
+
+/// SQL script and database dump converter
+pub struct SqlDumpConverter;
+
+impl SqlDumpConverter {
+    fn convert_script(script: &str, options: &ConversionOptions) -> Document {
+        let database = &options.database;
+        let dialect = detect_dialect(script);
+        let mut parser = DumpParser {
+            options: database,
+            backslash_escapes: dialect == Some("MySQL"),
+            tables: Vec::new(),
+            lookup: HashMap::new(),
+            views: Vec::new(),
+            index_count: 0,
+            trigger_count: 0,
+        };
+        let statements = split_statements(script, parser.backslash_escapes);
+        let statement_count = statements.len();
+        for statement in statements {
+            parser.statement(statement);
+        }
+        for table in &mut parser.tables {
+            table.pad_columns();
+        }
+
+        let mut document = Document::new();
+        document.title = Some("SQL Script".to_string());
+        let mut page = Page::new(1);
+
+        if parser.tables.is_empty() && parser.views.is_empty() {
+            page.add_content(ContentBlock::Code {
+                language: Some("sql".to_string()),
+                code: script.trim().to_string(),
+            });
+        } else {
+            for block in Self::render(&parser, options) {
+                page.add_content(block);
+            }
+        }
+
+        let row_count: u64 = parser.tables.iter().map(|t| t.row_count).sum();
+        for (key, count) in [
+            ("statement_count", statement_count),
+            ("table_count", parser.tables.len()),
+            ("view_count", parser.views.len()),
+            ("index_count", parser.index_count),
+            ("trigger_count", parser.trigger_count),
+            ("row_count", row_count as usize),
+        ] {
+            document.metadata.insert(key.to_string(), count.to_string());
+        }
+        if let Some(dialect) = dialect {
+            document
+                .metadata
+                .insert("dialect".to_string(), dialect.to_string());
+        }
+
+        document.add_page(page);
+        document
+    }
+
+    fn render(parser: &DumpParser, options: &ConversionOptions) -> Vec<ContentBlock> {
+        let database = &options.database;
+        let summary = TableSummary {
+            options: database,
+            extract_images: options.extract_images,
+            level: 3,
+        };
+
+        let mut line = format!("**Tables:** {}", parser.tables.len());
+        if database.include_views {
+            line.push_str(&format!(" · **Views:** {}", parser.views.len()));
+        }
+        let mut blocks = vec![ContentBlock::Text(line)];
+        let mut relationships = Vec::new();
+
+        for table in &parser.tables {
+            blocks.push(ContentBlock::Heading {
+                level: 2,
+                text: format!("Table: `{}`", table.name),
+            });
+
+            let mut columns = table.columns.clone();
+            if database.include_foreign_keys {
+                for fk in &table.foreign_keys {
+                    if let Some(column) = columns.iter_mut().find(|c| c.name == fk.column) {
+                        column
+                            .references
+                            .push(format!("{}.{}", fk.table, fk.target));
+                    }
+                    relationships.push(relationship(
+                        &table.name,
+                        &fk.column,
+                        &fk.table,
+                        &fk.target,
+                        fk.on_delete.as_deref(),
+                    ));
+                }
+            }
+            blocks.extend(summary.schema_blocks(&columns, true));
+
+            if database.include_indexes {
+                blocks.extend(list_blocks(3, "Indexes", table.indexes.clone()));
+            }
+            if database.include_triggers {
+                let triggers: Vec<(&str, Option<&str>)> = table
+                    .triggers
+                    .iter()
+                    .map(|(name, sql)| (name.as_str(), Some(sql.as_str())))
+                    .collect();
+                blocks.extend(trigger_blocks(3, &triggers));
+            }
+
+            let headers: Vec<String> = columns.iter().map(|c| c.name.clone()).collect();
+            blocks.extend(summary.rows_blocks(
+                &table.name,
+                &headers,
+                table.sample.clone(),
+                table.row_count,
+            ));
+            if table.row_count > 0 {
+                let names_and_types: Vec<(String, String)> = columns
+                    .iter()
+                    .map(|c| (c.name.clone(), c.data_type.clone()))
+                    .collect();
+                blocks.extend(summary.statistics_blocks(&names_and_types, &table.stats));
+            }
+        }
+
+        if database.include_views {
+            for (name, sql) in &parser.views {
+                blocks.push(ContentBlock::Heading {
+                    level: 2,
+                    text: format!("View: `{}`", name),
+                });
+                blocks.push(ContentBlock::Code {
+                    language: Some("sql".to_string()),
+                    code: format!("{};", sql.trim_end_matches(';')),
+                });
+            }
+        }
+        blocks.extend(list_blocks(2, "Relationships", relationships));
+        blocks
+    }
+}
+
+#[async_trait]
+impl DocumentConverter for SqlDumpConverter {
+    async fn convert(
+        &self,
+        store: Arc<dyn ObjectStore>,
+        path: &object_store::path::Path,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        let result = store.get(path).await?;
+        let bytes = result.bytes().await?;
+        self.convert_bytes(bytes, options).await
+    }
+
+    async fn convert_bytes(
+        &self,
+        bytes: Bytes,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        if let Some(opts) = &options {
+            if let Some(ext) = &opts.file_extension {
+                if ext != ".sql" {
+                    return Err(MarkitdownError::InvalidFile(format!(
+                        "Expected .sql file, got {}",
+                        ext
+                    )));
+                }
+            }
+        }
+
+        let opts = options.unwrap_or_default();
+        let script = String::from_utf8_lossy(&bytes);
+        let mut document = Self::convert_script(&script, &opts);
+
+        // If LLM client is provided, get descriptions for all images
+        if let Some(ref llm_client) = opts.llm_client {
+            if let Some(path) = opts.image_context_path.as_deref() {
+                document.apply_image_context_path(path);
+            }
+            document = document
+                .with_image_descriptions(llm_client.as_ref())
+                .await?;
+        }
+
+        Ok(document)
+    }
+
+    fn supported_extensions(&self) -> &[&str] {
+        &[".sql"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_statements_keeps_quoted_semicolons_and_trigger_bodies() {
+        let script = "-- header; comment\n\
+            INSERT INTO t VALUES ('a;b');\n\
+            CREATE TRIGGER tr AFTER INSERT ON t BEGIN UPDATE t SET x = 1; END;\n\
+            /* block; */ SELECT 1";
+        let statements = split_statements(script, false);
+        assert_eq!(statements.len(), 3);
+        assert_eq!(
+            statements[0],
+            Statement::Sql("INSERT INTO t VALUES ('a;b')".to_string())
+        );
+        assert!(matches!(&statements[1], Statement::Sql(s) if s.ends_with("END")));
+    }
+
+    #[test]
+    fn test_split_statements_reads_copy_data() {
+        let script = "COPY public.t (id, name) FROM stdin;\n1\tone\n2\t\\N\n\\.\nSELECT 1;";
+        let statements = split_statements(script, false);
+        assert_eq!(
+            statements[0],
+            Statement::Copy(
+                "COPY public.t (id, name) FROM stdin".to_string(),
+                vec!["1\tone".to_string(), "2\t\\N".to_string()]
+            )
+        );
+        assert_eq!(statements[1], Statement::Sql("SELECT 1".to_string()));
+    }
+
+    #[test]
+    fn test_literal_values() {
+        let value = |sql: &str| literal_value(&tokenize(sql, false), "REAL");
+        assert_eq!(value("NULL"), Value::Null);
+        assert_eq!(value("-42"), Value::Integer(-42));
+        assert_eq!(value("4.5"), Value::Real(4.5));
+        // Exact numbers keep their digits
+        let exact = |sql: &str, data_type: &str| literal_value(&tokenize(sql, false), data_type);
+        assert_eq!(
+            exact("-0.10", "NUMERIC(10, 2)"),
+            Value::Text("-0.10".to_string())
+        );
+        assert_eq!(
+            exact("12345678901234567890", "BIGINT"),
+            Value::Text("12345678901234567890".to_string())
+        );
+        assert_eq!(value("'it''s'"), Value::Text("it's".to_string()));
+        assert_eq!(value("X'0102'"), Value::Blob(vec![1, 2]));
+        assert_eq!(
+            value("'2024-01-01'::date"),
+            Value::Text("2024-01-01".to_string())
+        );
+    }
+
+    #[test]
+    fn test_tokens_sql_spacing() {
+        let tokens = tokenize("NUMERIC ( 10 , 2 )", false);
+        assert_eq!(tokens_sql(&tokens), "NUMERIC(10, 2)");
+        let tokens = tokenize("nextval('seq'::regclass)", false);
+        assert_eq!(tokens_sql(&tokens), "nextval('seq'::regclass)");
+    }
+}
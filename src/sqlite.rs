@@ -1,23 +1,394 @@
 //! SQLite database to Markdown converter.
 //!
 //! Converts SQLite databases to markdown by extracting table schemas and sample data.
+//! Views, indexes, triggers and foreign keys are included according to
+//! [`DatabaseOptions`]. Table and view names are only ever passed to SQLite
+//! as bound parameters or quoted identifiers.
 
 use async_trait::async_trait;
 use bytes::Bytes;
 use object_store::ObjectStore;
-use sqlx::{Row, SqlitePool};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Column, Row, SqlitePool, TypeInfo, ValueRef};
 use std::io::Write;
 use std::sync::Arc;
 use tempfile::NamedTempFile;
 
 use crate::error::MarkitdownError;
 use crate::model::{ContentBlock, ConversionOptions, Document, DocumentConverter, Page};
+use crate::tabular::{
+    index_item, list_blocks, relationship, trigger_blocks, SchemaColumn, TableSummary, Value,
+};
+
+/// How BLOB values are shown in data tables
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BlobMode {
+    /// Only the size (`<BLOB 68 bytes>`)
+    #[default]
+    Size,
+    /// The size and the first bytes in hex
+    HexPreview,
+    /// Images are extracted below the table; other BLOBs show their size
+    Images,
+}
+
+/// What the database and columnar converters (SQLite, SQL dumps, Parquet,
+/// Arrow) show of each table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatabaseOptions {
+    /// Rows shown per table or view; `None` shows all rows
+    pub sample_rows: Option<usize>,
+    /// Include views with their definition and sample rows
+    pub include_views: bool,
+    /// List the indexes of each table
+    pub include_indexes: bool,
+    /// List the triggers of each table with their SQL
+    pub include_triggers: bool,
+    /// Show foreign keys in the schema and summarize the relationships
+    pub include_foreign_keys: bool,
+    /// How BLOB values are rendered
+    pub blobs: BlobMode,
+}
+
+impl Default for DatabaseOptions {
+    fn default() -> Self {
+        Self {
+            sample_rows: Some(5),
+            include_views: true,
+            include_indexes: true,
+            include_triggers: true,
+            include_foreign_keys: true,
+            blobs: BlobMode::Size,
+        }
+    }
+}
+
+impl DatabaseOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show at most `rows` rows per table
+    pub fn with_sample_rows(mut self, rows: usize) -> Self {
+        self.sample_rows = Some(rows);
+        self
+    }
+
+    /// Show every row of every table
+    pub fn with_full_dump(mut self) -> Self {
+        self.sample_rows = None;
+        self
+    }
+
+    pub fn with_views(mut self, include: bool) -> Self {
+        self.include_views = include;
+        self
+    }
+
+    pub fn with_indexes(mut self, include: bool) -> Self {
+        self.include_indexes = include;
+        self
+    }
+
+    pub fn with_triggers(mut self, include: bool) -> Self {
+        self.include_triggers = include;
+        self
+    }
+
+    pub fn with_foreign_keys(mut self, include: bool) -> Self {
+        self.include_foreign_keys = include;
+        self
+    }
+
+    pub fn with_blobs(mut self, mode: BlobMode) -> Self {
+        self.blobs = mode;
+        self
+    }
+}
+
+/// Quote an identifier for use in SQL (`"my ""table"""`)
+fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+fn to_parse_error(context: &str) -> impl Fn(sqlx::Error) -> MarkitdownError + '_ {
+    move |e| MarkitdownError::ParseError(format!("{}: {}", context, e))
+}
+
+/// A column from `pragma_table_info`
+struct ColumnInfo {
+    name: String,
+    column_type: String,
+    notnull: i64,
+    dflt_value: Option<String>,
+    pk: i64,
+}
+
+/// A foreign key from `pragma_foreign_key_list`
+struct ForeignKey {
+    table: String,
+    from: String,
+    to: Option<String>,
+    on_delete: String,
+}
+
+/// A table, view or trigger from `sqlite_master`
+struct SchemaObject {
+    name: String,
+    tbl_name: String,
+    sql: Option<String>,
+}
+
+/// Builds the document for one database
+struct DatabaseRenderer<'a> {
+    pool: &'a SqlitePool,
+    options: &'a DatabaseOptions,
+    extract_images: bool,
+    blocks: Vec<ContentBlock>,
+    /// "`orders.customer_id` → `customers.id`"
+    relationships: Vec<String>,
+    index_count: usize,
+    trigger_count: usize,
+}
+
+impl<'a> DatabaseRenderer<'a> {
+    async fn objects(&self, kind: &str) -> Result<Vec<SchemaObject>, MarkitdownError> {
+        let rows: Vec<(String, String, Option<String>)> = sqlx::query_as(
+            "SELECT name, tbl_name, sql FROM sqlite_master \
+             WHERE type = ?1 AND name NOT LIKE 'sqlite_%' ORDER BY name",
+        )
+        .bind(kind)
+        .fetch_all(self.pool)
+        .await
+        .map_err(to_parse_error("Failed to list schema objects"))?;
+        Ok(rows
+            .into_iter()
+            .map(|(name, tbl_name, sql)| SchemaObject {
+                name,
+                tbl_name,
+                sql,
+            })
+            .collect())
+    }
+
+    async fn columns(&self, name: &str) -> Vec<ColumnInfo> {
+        let rows: Vec<(String, String, i64, Option<String>, i64)> = sqlx::query_as(
+            "SELECT name, type, \"notnull\", dflt_value, pk FROM pragma_table_info(?1)",
+        )
+        .bind(name)
+        .fetch_all(self.pool)
+        .await
+        .unwrap_or_default();
+        rows.into_iter()
+            .map(|(name, column_type, notnull, dflt_value, pk)| ColumnInfo {
+                name,
+                column_type,
+                notnull,
+                dflt_value,
+                pk,
+            })
+            .collect()
+    }
+
+    async fn foreign_keys(&self, table: &str) -> Vec<ForeignKey> {
+        let rows: Vec<(String, String, Option<String>, String)> = sqlx::query_as(
+            "SELECT \"table\", \"from\", \"to\", on_delete FROM pragma_foreign_key_list(?1) \
+             ORDER BY id, seq",
+        )
+        .bind(table)
+        .fetch_all(self.pool)
+        .await
+        .unwrap_or_default();
+        rows.into_iter()
+            .map(|(table, from, to, on_delete)| ForeignKey {
+                table,
+                from,
+                to,
+                on_delete,
+            })
+            .collect()
+    }
+
+    fn summary(&self) -> TableSummary<'a> {
+        TableSummary {
+            options: self.options,
+            extract_images: self.extract_images,
+            level: 3,
+        }
+    }
+
+    async fn add_table(
+        &mut self,
+        table: &SchemaObject,
+        triggers: &[SchemaObject],
+    ) -> Result<(), MarkitdownError> {
+        self.blocks.push(ContentBlock::Heading {
+            level: 2,
+            text: format!("Table: `{}`", table.name),
+        });
+
+        let columns = self.columns(&table.name).await;
+        let foreign_keys = if self.options.include_foreign_keys {
+            self.foreign_keys(&table.name).await
+        } else {
+            Vec::new()
+        };
+        self.add_schema(&columns, &foreign_keys);
+        for fk in &foreign_keys {
+            self.relationships.push(relationship(
+                &table.name,
+                &fk.from,
+                &fk.table,
+                fk.to.as_deref().unwrap_or("rowid"),
+                Some(&fk.on_delete),
+            ));
+        }
+
+        if self.options.include_indexes {
+            self.add_indexes(&table.name).await;
+        }
+        if self.options.include_triggers {
+            let table_triggers: Vec<(&str, Option<&str>)> = triggers
+                .iter()
+                .filter(|t| t.tbl_name == table.name)
+                .map(|t| (t.name.as_str(), t.sql.as_deref()))
+                .collect();
+            self.trigger_count += table_triggers.len();
+            self.blocks.extend(trigger_blocks(3, &table_triggers));
+        }
+
+        self.add_rows(&table.name).await
+    }
+
+    async fn add_view(&mut self, view: &SchemaObject) -> Result<(), MarkitdownError> {
+        self.blocks.push(ContentBlock::Heading {
+            level: 2,
+            text: format!("View: `{}`", view.name),
+        });
+        if let Some(sql) = &view.sql {
+            self.blocks.push(ContentBlock::Code {
+                language: Some("sql".to_string()),
+                code: sql.trim().to_string(),
+            });
+        }
+        let columns = self.columns(&view.name).await;
+        self.add_schema(&columns, &[]);
+        self.add_rows(&view.name).await
+    }
+
+    fn add_schema(&mut self, columns: &[ColumnInfo], foreign_keys: &[ForeignKey]) {
+        let columns: Vec<SchemaColumn> = columns
+            .iter()
+            .map(|column| SchemaColumn {
+                name: column.name.clone(),
+                data_type: column.column_type.clone(),
+                nullable: column.notnull == 0 && column.pk == 0,
+                default: column.dflt_value.clone(),
+                primary_key: column.pk > 0,
+                references: foreign_keys
+                    .iter()
+                    .filter(|fk| fk.from == column.name)
+                    .map(|fk| format!("{}.{}", fk.table, fk.to.as_deref().unwrap_or("rowid")))
+                    .collect(),
+            })
+            .collect();
+        let blocks = self.summary().schema_blocks(&columns, true);
+        self.blocks.extend(blocks);
+    }
+
+    async fn add_indexes(&mut self, table: &str) {
+        let indexes: Vec<(String, i64, String)> = sqlx::query_as(
+            "SELECT name, \"unique\", origin FROM pragma_index_list(?1) ORDER BY name",
+        )
+        .bind(table)
+        .fetch_all(self.pool)
+        .await
+        .unwrap_or_default();
+        let mut items = Vec::new();
+        for (name, unique, origin) in indexes {
+            let columns: Vec<(Option<String>,)> =
+                sqlx::query_as("SELECT name FROM pragma_index_info(?1) ORDER BY seqno")
+                    .bind(&name)
+                    .fetch_all(self.pool)
+                    .await
+                    .unwrap_or_default();
+            let columns: Vec<String> = columns
+                .into_iter()
+                .map(|(column,)| column.unwrap_or_else(|| "<expression>".to_string()))
+                .collect();
+            let mut notes = Vec::new();
+            if unique != 0 {
+                notes.push("unique");
+            }
+            match origin.as_str() {
+                "pk" => notes.push("primary key"),
+                "u" => notes.push("from UNIQUE constraint"),
+                _ => {}
+            }
+            items.push(index_item(&name, &columns, &notes));
+        }
+        self.index_count += items.len();
+        self.blocks.extend(list_blocks(3, "Indexes", items));
+    }
+
+    /// Row count and the sampled (or all) rows of a table or view
+    async fn add_rows(&mut self, name: &str) -> Result<(), MarkitdownError> {
+        let quoted = quote_identifier(name);
+        let count: (i64,) = sqlx::query_as(&format!("SELECT COUNT(*) FROM {}", quoted))
+            .fetch_one(self.pool)
+            .await
+            .unwrap_or((0,));
+
+        let summary = self.summary();
+        let query = match summary.row_limit() {
+            Some(limit) => format!("SELECT * FROM {} LIMIT {}", quoted, limit),
+            None => format!("SELECT * FROM {}", quoted),
+        };
+        let rows = if count.0 > 0 && summary.row_limit() != Some(0) {
+            sqlx::query(&query)
+                .fetch_all(self.pool)
+                .await
+                .unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        let headers: Vec<String> = rows
+            .first()
+            .map(|row| row.columns().iter().map(|c| c.name().to_string()).collect())
+            .unwrap_or_default();
+        let values = rows
+            .iter()
+            .map(|row| (0..row.len()).map(|i| Self::value(row, i)).collect())
+            .collect();
+
+        self.blocks
+            .extend(summary.rows_blocks(name, &headers, values, count.0.max(0) as u64));
+        Ok(())
+    }
+
+    fn value(row: &SqliteRow, column: usize) -> Value {
+        let kind = match row.try_get_raw(column) {
+            Ok(value) if !value.is_null() => value.type_info().name().to_string(),
+            _ => return Value::Null,
+        };
+        let value = match kind.as_str() {
+            "INTEGER" => row.try_get(column).map(Value::Integer),
+            "REAL" => row.try_get(column).map(Value::Real),
+            "BLOB" => row.try_get(column).map(Value::Blob),
+            _ => row.try_get(column).map(Value::Text),
+        };
+        value.unwrap_or(Value::Null)
+    }
+}
 
 /// SQLite database converter
 pub struct SqliteConverter;
 
 impl SqliteConverter {
-    async fn convert_sqlite(bytes: &[u8]) -> Result<Document, MarkitdownError> {
+    async fn convert_sqlite(
+        bytes: &[u8],
+        options: &ConversionOptions,
+    ) -> Result<Document, MarkitdownError> {
         // Write bytes to a temporary file (SQLite needs file access)
         let mut temp_file = NamedTempFile::new().map_err(|e| {
             MarkitdownError::ParseError(format!("Failed to create temp file: {}", e))
@@ -31,112 +402,77 @@ impl SqliteConverter {
             .await
             .map_err(|e| MarkitdownError::ParseError(format!("SQLite connection error: {}", e)))?;
 
-        let mut document = Document::new();
-        let mut page = Page::new(1);
-        let mut markdown = String::new();
+        let result = Self::render(&pool, options).await;
+        pool.close().await;
+        result
+    }
 
-        markdown.push_str("# SQLite Database\n\n");
+    async fn render(
+        pool: &SqlitePool,
+        options: &ConversionOptions,
+    ) -> Result<Document, MarkitdownError> {
+        let database = &options.database;
+        let mut renderer = DatabaseRenderer {
+            pool,
+            options: database,
+            extract_images: options.extract_images,
+            blocks: Vec::new(),
+            relationships: Vec::new(),
+            index_count: 0,
+            trigger_count: 0,
+        };
 
-        // Get list of tables
-        let tables: Vec<(String,)> = sqlx::query_as(
-            "SELECT name FROM sqlite_master WHERE type='table' AND name NOT LIKE 'sqlite_%' ORDER BY name"
-        )
-            .fetch_all(&pool)
-            .await
-            .map_err(|e| MarkitdownError::ParseError(format!("Failed to list tables: {}", e)))?;
+        let tables = renderer.objects("table").await?;
+        let views = if database.include_views {
+            renderer.objects("view").await?
+        } else {
+            Vec::new()
+        };
+        let triggers = if database.include_triggers {
+            renderer.objects("trigger").await?
+        } else {
+            Vec::new()
+        };
 
-        if tables.is_empty() {
-            markdown.push_str("*No tables found in database.*\n");
+        let mut document = Document::new();
+        document.title = Some("SQLite Database".to_string());
+
+        if tables.is_empty() && views.is_empty() {
+            renderer.blocks.push(ContentBlock::Text(
+                "*No tables found in database.*".to_string(),
+            ));
         } else {
-            markdown.push_str(&format!("**Tables:** {}\n\n", tables.len()));
-
-            for (table_name,) in &tables {
-                markdown.push_str(&format!("## Table: `{}`\n\n", table_name));
-
-                // Get table schema
-                let schema_query = format!("PRAGMA table_info('{}')", table_name);
-                let columns: Vec<(i32, String, String, i32, Option<String>, i32)> =
-                    sqlx::query_as(&schema_query)
-                        .fetch_all(&pool)
-                        .await
-                        .unwrap_or_default();
-
-                if !columns.is_empty() {
-                    markdown.push_str("### Schema\n\n");
-                    markdown.push_str("| Column | Type | Nullable | Primary Key |\n");
-                    markdown.push_str("|--------|------|----------|-------------|\n");
-
-                    for (_, name, col_type, notnull, _, pk) in &columns {
-                        markdown.push_str(&format!(
-                            "| {} | {} | {} | {} |\n",
-                            name,
-                            col_type,
-                            if *notnull == 0 { "Yes" } else { "No" },
-                            if *pk > 0 { "Yes" } else { "No" }
-                        ));
-                    }
-                    markdown.push('\n');
-                }
+            let mut summary = format!("**Tables:** {}", tables.len());
+            if database.include_views {
+                summary.push_str(&format!(" · **Views:** {}", views.len()));
+            }
+            renderer.blocks.push(ContentBlock::Text(summary));
 
-                // Get row count
-                let count_query = format!("SELECT COUNT(*) FROM \"{}\"", table_name);
-                let count: (i64,) = sqlx::query_as(&count_query)
-                    .fetch_one(&pool)
-                    .await
-                    .unwrap_or((0,));
-
-                markdown.push_str(&format!("**Row count:** {}\n\n", count.0));
-
-                // Get sample data (first 5 rows)
-                if count.0 > 0 && !columns.is_empty() {
-                    let sample_query = format!("SELECT * FROM \"{}\" LIMIT 5", table_name);
-
-                    if let Ok(rows) = sqlx::query(&sample_query).fetch_all(&pool).await {
-                        if !rows.is_empty() {
-                            markdown.push_str("### Sample Data (first 5 rows)\n\n");
-
-                            // Header
-                            let col_names: Vec<&str> = columns
-                                .iter()
-                                .map(|(_, n, _, _, _, _)| n.as_str())
-                                .collect();
-                            markdown.push_str(&format!("| {} |\n", col_names.join(" | ")));
-                            markdown.push_str(&format!(
-                                "|{}|\n",
-                                col_names
-                                    .iter()
-                                    .map(|_| "---")
-                                    .collect::<Vec<_>>()
-                                    .join("|")
-                            ));
-
-                            // Rows
-                            for row in &rows {
-                                let values: Vec<String> = (0..columns.len())
-                                    .map(|i| {
-                                        row.try_get::<String, _>(i)
-                                            .or_else(|_| {
-                                                row.try_get::<i64, _>(i).map(|v| v.to_string())
-                                            })
-                                            .or_else(|_| {
-                                                row.try_get::<f64, _>(i).map(|v| v.to_string())
-                                            })
-                                            .unwrap_or_else(|_| "NULL".to_string())
-                                            .replace('|', "\\|")
-                                    })
-                                    .collect();
-                                markdown.push_str(&format!("| {} |\n", values.join(" | ")));
-                            }
-                            markdown.push('\n');
-                        }
-                    }
-                }
+            for table in &tables {
+                renderer.add_table(table, &triggers).await?;
+            }
+            for view in &views {
+                renderer.add_view(view).await?;
             }
+            let relationships = std::mem::take(&mut renderer.relationships);
+            renderer
+                .blocks
+                .extend(list_blocks(2, "Relationships", relationships));
         }
 
-        pool.close().await;
+        for (key, count) in [
+            ("table_count", tables.len()),
+            ("view_count", views.len()),
+            ("index_count", renderer.index_count),
+            ("trigger_count", renderer.trigger_count),
+        ] {
+            document.metadata.insert(key.to_string(), count.to_string());
+        }
 
-        page.add_content(ContentBlock::Markdown(markdown));
+        let mut page = Page::new(1);
+        for block in renderer.blocks {
+            page.add_content(block);
+        }
         document.add_page(page);
         Ok(document)
     }
@@ -186,7 +522,20 @@ impl DocumentConverter for SqliteConverter {
             }
         }
 
-        Self::convert_sqlite(&bytes).await
+        let opts = options.unwrap_or_default();
+        let mut document = Self::convert_sqlite(&bytes, &opts).await?;
+
+        // If LLM client is provided, get descriptions for all images
+        if let Some(ref llm_client) = opts.llm_client {
+            if let Some(path) = opts.image_context_path.as_deref() {
+                document.apply_image_context_path(path);
+            }
+            document = document
+                .with_image_descriptions(llm_client.as_ref())
+                .await?;
+        }
+
+        Ok(document)
     }
 
     fn supported_extensions(&self) -> &[&str] {
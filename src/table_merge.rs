@@ -1,11 +1,16 @@
 //! Multi-page table detection and merging.
 //!
 //! This module provides functionality to detect tables that span multiple pages
-//! and merge them into a single coherent table.
+//! and merge them into a single coherent table. [`merge_page_tables`] works on
+//! the `ContentBlock::Table` values of each page and leaves all other blocks in
+//! place; the `TableFragment` functions work on rendered markdown.
 
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::sync::LazyLock;
 
+use crate::model::{ContentBlock, Page, Provenance};
+
 /// Represents a detected table fragment in markdown content
 #[derive(Debug, Clone)]
 pub struct TableFragment {
@@ -200,10 +205,20 @@ fn parse_table_row(line: &str) -> Vec<String> {
     // Remove leading and trailing |
     let inner = &trimmed[1..trimmed.len() - 1];
 
-    inner
-        .split('|')
-        .map(|cell| cell.trim().to_string())
-        .collect()
+    // Cells split at unescaped pipes and hold the unescaped text; the
+    // renderer escapes pipes again
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = inner.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => cell.push(chars.next().unwrap_or('|')),
+            '|' => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            c => cell.push(c),
+        }
+    }
+    cells.push(cell.trim().to_string());
+    cells
 }
 
 /// Check if two table fragments can be merged (appear to be continuation)
@@ -395,6 +410,278 @@ pub fn merge_tables_across_pages(pages: &[(u32, String)]) -> Vec<MergedPageConte
     results
 }
 
+/// A table merged from several pages
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableSpan {
+    /// Page the merged table was placed on
+    pub page_number: u32,
+    /// Index of the merged table in that page's content blocks
+    pub block_index: usize,
+    /// Pages the table's rows came from, in order
+    pub pages: Vec<u32>,
+}
+
+/// "(continued)", "Table 3 – continued", "Continued on next page" and similar
+static CONTINUATION_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)^[\s*_(\[]*(?:table\s+\S+?\s*[-–—:,.]?\s*)?[(\[]?\s*(?:continued|cont'd|contd\.?|cont\.)(?:\s+(?:from|on)\s+(?:the\s+)?(?:previous|preceding|next|following)\s+page)?\s*[)\]]?[\s*_.:]*$",
+    )
+    .unwrap()
+});
+
+/// Text of a block that only holds a short line of prose
+fn block_text(block: &ContentBlock) -> Option<&str> {
+    match block {
+        ContentBlock::Text(text) | ContentBlock::Markdown(text) => Some(text),
+        ContentBlock::Heading { text, .. } => Some(text),
+        _ => None,
+    }
+}
+
+fn is_blank(block: &ContentBlock) -> bool {
+    block_text(block).is_some_and(|t| t.trim().is_empty())
+        && !matches!(block, ContentBlock::Heading { .. })
+}
+
+fn is_continuation_caption(block: &ContentBlock) -> bool {
+    block_text(block).is_some_and(|t| CONTINUATION_PATTERN.is_match(t.trim()))
+}
+
+fn column_count(headers: &[String], rows: &[Vec<String>]) -> usize {
+    rows.iter()
+        .map(Vec::len)
+        .chain(std::iter::once(headers.len()))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Header cells compared without case and spacing differences
+fn same_cells(first: &[String], second: &[String]) -> bool {
+    let normalize = |cell: &String| {
+        cell.split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+    };
+    !first.is_empty()
+        && first.len() == second.len()
+        && first.iter().map(normalize).eq(second.iter().map(normalize))
+}
+
+/// The pipe table at the end (or start) of a markdown block
+fn edge_fragment(markdown: &str, at_end: bool) -> Option<TableFragment> {
+    let fragments = detect_table_fragments(markdown);
+    if at_end {
+        fragments.into_iter().last().filter(|f| f.at_content_end)
+    } else {
+        fragments.into_iter().next().filter(|f| f.at_content_start)
+    }
+}
+
+fn fragment_table(fragment: &TableFragment) -> ContentBlock {
+    ContentBlock::Table {
+        headers: fragment.headers.clone().unwrap_or_default(),
+        rows: fragment.data_rows.clone(),
+    }
+}
+
+/// The table a block holds: a table block, or the pipe table at the end (or
+/// start) of a markdown block
+fn block_table(block: &ContentBlock, at_end: bool) -> Option<ContentBlock> {
+    match block {
+        ContentBlock::Table { .. } => Some(block.clone()),
+        ContentBlock::Markdown(markdown) => {
+            edge_fragment(markdown, at_end).map(|f| fragment_table(&f))
+        }
+        _ => None,
+    }
+}
+
+/// Give a pipe table at the end (or start) of a markdown block its own table
+/// block. Returns the index of the table block.
+fn split_markdown_table(page: &mut Page, index: usize, at_end: bool) -> Option<usize> {
+    let ContentBlock::Markdown(markdown) = &page.content[index] else {
+        return Some(index);
+    };
+    let fragment = edge_fragment(markdown, at_end)?;
+    let before = markdown[..fragment.start_pos].trim_end();
+    let after = markdown[fragment.end_pos.min(markdown.len())..].trim_start();
+
+    let mut blocks = Vec::new();
+    if !before.is_empty() {
+        blocks.push(ContentBlock::Markdown(format!("{}\n", before)));
+    }
+    let table_index = index + blocks.len();
+    blocks.push(fragment_table(&fragment));
+    if !after.is_empty() {
+        blocks.push(ContentBlock::Markdown(after.to_string()));
+    }
+    split_provenance(page, index, blocks.len());
+    page.content.splice(index..=index, blocks);
+    Some(table_index)
+}
+
+/// Give the `count` blocks that replace block `index` its provenance
+fn split_provenance(page: &mut Page, index: usize, count: usize) {
+    let mut parts = Vec::new();
+    for provenance in &mut page.provenance {
+        if provenance.block_index > index {
+            provenance.block_index += count - 1;
+        } else if provenance.block_index == index {
+            parts.extend((1..count).map(|i| Provenance {
+                block_index: index + i,
+                ..provenance.clone()
+            }));
+        }
+    }
+    page.provenance.extend(parts);
+    page.provenance.sort_by_key(|p| p.block_index);
+}
+
+/// Drop the provenance of blocks `start..end` and renumber the blocks after them
+fn remove_provenance(page: &mut Page, start: usize, end: usize) {
+    page.provenance
+        .retain(|p| p.block_index < start || p.block_index >= end);
+    for provenance in &mut page.provenance {
+        if provenance.block_index >= end {
+            provenance.block_index -= end - start;
+        }
+    }
+}
+
+/// The table that ends a page, ignoring blank blocks and "continued" notes after it
+fn trailing_table(page: &Page) -> Option<usize> {
+    let index = page
+        .content
+        .iter()
+        .rposition(|b| !is_blank(b) && !is_continuation_caption(b))?;
+    block_table(&page.content[index], true).map(|_| index)
+}
+
+/// The table that starts a page, ignoring blank blocks and "continued"
+/// captions before it. Also returns whether such a caption was found.
+fn leading_table(page: &Page) -> Option<(usize, bool)> {
+    let index = page
+        .content
+        .iter()
+        .position(|b| !is_blank(b) && !is_continuation_caption(b))?;
+    let captioned = page.content[..index].iter().any(is_continuation_caption);
+    block_table(&page.content[index], false).map(|_| (index, captioned))
+}
+
+/// Rows the continuation adds to the first table, or `None` when the two
+/// tables do not belong together
+fn continuation_rows(
+    first: &ContentBlock,
+    second: &ContentBlock,
+    captioned: bool,
+) -> Option<Vec<Vec<String>>> {
+    let (
+        ContentBlock::Table {
+            headers: first_headers,
+            rows: first_rows,
+        },
+        ContentBlock::Table { headers, rows },
+    ) = (first, second)
+    else {
+        return None;
+    };
+
+    let columns = column_count(first_headers, first_rows);
+    if columns == 0 || columns != column_count(headers, rows) {
+        return None;
+    }
+
+    if same_cells(first_headers, headers) {
+        // Header repeated on the new page
+        Some(rows.clone())
+    } else if headers.is_empty() {
+        let repeated = rows
+            .first()
+            .is_some_and(|row| same_cells(first_headers, row));
+        Some(rows[usize::from(repeated)..].to_vec())
+    } else if captioned {
+        // The first row of the continuation was read as its header
+        Some(
+            std::iter::once(headers.clone())
+                .chain(rows.iter().cloned())
+                .collect(),
+        )
+    } else {
+        None
+    }
+}
+
+/// Move the table starting page `next` into the table at `block` of page
+/// `page` when it continues it. Returns the merged table's block index.
+fn merge_continuation(pages: &mut [Page], page: usize, block: usize, next: usize) -> Option<usize> {
+    let (start, captioned) = leading_table(&pages[next])?;
+    let first = block_table(&pages[page].content[block], true)?;
+    let second = block_table(&pages[next].content[start], false)?;
+    let rows = continuation_rows(&first, &second, captioned)?;
+
+    // Drop the continuation with its caption, and any note after the first table
+    let start = split_markdown_table(&mut pages[next], start, false)?;
+    pages[next].content.drain(..=start);
+    remove_provenance(&mut pages[next], 0, start + 1);
+    let block = split_markdown_table(&mut pages[page], block, true)?;
+    pages[page].content.truncate(block + 1);
+    remove_provenance(&mut pages[page], block + 1, usize::MAX);
+    if let ContentBlock::Table { rows: merged, .. } = &mut pages[page].content[block] {
+        merged.extend(rows);
+    }
+    Some(block)
+}
+
+/// Merge tables that continue from one page onto the next.
+///
+/// A table at the end of a page is merged with the table starting the next
+/// page when their column counts match and the second repeats the header, has
+/// no header, or is introduced by a "continued" caption. The merged table stays
+/// on the first page; the continuation, its caption and any "continued on next
+/// page" note are removed. A table can span any number of pages. Returns where
+/// each merged table was placed and which pages it spanned.
+pub fn merge_page_tables(pages: &mut [Page]) -> Vec<TableSpan> {
+    let mut spans = Vec::new();
+    // Page index, block index and pages spanned of the table that may continue
+    let mut open: Option<(usize, usize, Vec<u32>)> = None;
+
+    for next in 0..pages.len() {
+        if let Some((page, mut block, mut spanned)) = open.take() {
+            if let Some(merged) = merge_continuation(pages, page, block, next) {
+                block = merged;
+                spanned.push(pages[next].page_number);
+                // A page holding nothing but the continuation lets it go on
+                if pages[next].content.iter().all(is_blank) {
+                    open = Some((page, block, spanned));
+                    continue;
+                }
+            }
+            if spanned.len() > 1 {
+                spans.push(TableSpan {
+                    page_number: pages[page].page_number,
+                    block_index: block,
+                    pages: spanned,
+                });
+            }
+        }
+
+        open =
+            trailing_table(&pages[next]).map(|block| (next, block, vec![pages[next].page_number]));
+    }
+
+    if let Some((page, block, spanned)) = open {
+        if spanned.len() > 1 {
+            spans.push(TableSpan {
+                page_number: pages[page].page_number,
+                block_index: block,
+                pages: spanned,
+            });
+        }
+    }
+    spans
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,398 @@
+//! Summaries of tabular data shared by the database and columnar converters.
+//!
+//! SQLite tables, Parquet and Arrow files and SQL dumps are all rendered the
+//! same way: a schema table, the row count, the sampled rows selected by
+//! [`DatabaseOptions`] and, where the whole table is read, column statistics.
+
+use bytes::Bytes;
+use std::collections::HashSet;
+
+use crate::model::{ContentBlock, ExtractedImage};
+use crate::sqlite::{BlobMode, DatabaseOptions};
+
+/// Bytes of a BLOB shown by [`BlobMode::HexPreview`]
+const HEX_PREVIEW_BYTES: usize = 16;
+
+/// Distinct values counted per column before reporting "N+"
+const DISTINCT_LIMIT: usize = 10_000;
+
+/// A single cell
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Null,
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+/// A column in a schema table
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SchemaColumn {
+    pub name: String,
+    pub data_type: String,
+    pub nullable: bool,
+    pub default: Option<String>,
+    pub primary_key: bool,
+    /// Referenced columns (`customers.id`)
+    pub references: Vec<String>,
+}
+
+/// Put a value on one line for a table cell; the renderer escapes pipes
+pub(crate) fn table_cell(text: &str) -> String {
+    text.replace('\n', " ")
+}
+
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{:.0}", value)
+    } else {
+        let text = format!("{:.4}", value);
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+/// Renders rows according to the sampling and BLOB options
+pub(crate) struct TableSummary<'a> {
+    pub options: &'a DatabaseOptions,
+    pub extract_images: bool,
+    /// Level of the Schema, Data and Statistics headings
+    pub level: u8,
+}
+
+impl TableSummary<'_> {
+    /// "Schema" heading and table. `constraints` adds the Default and Primary Key
+    /// columns of SQL databases; References is added when any column has one.
+    pub fn schema_blocks(&self, columns: &[SchemaColumn], constraints: bool) -> Vec<ContentBlock> {
+        if columns.is_empty() {
+            return Vec::new();
+        }
+        let with_references = columns.iter().any(|c| !c.references.is_empty());
+        let mut headers = vec!["Column", "Type", "Nullable"];
+        if constraints {
+            headers.extend(["Default", "Primary Key"]);
+        }
+        if with_references {
+            headers.push("References");
+        }
+        let yes_no = |flag: bool| if flag { "Yes" } else { "No" }.to_string();
+        let rows = columns
+            .iter()
+            .map(|column| {
+                let mut row = vec![
+                    table_cell(&column.name),
+                    table_cell(&column.data_type),
+                    yes_no(column.nullable),
+                ];
+                if constraints {
+                    row.push(table_cell(column.default.as_deref().unwrap_or("")));
+                    row.push(yes_no(column.primary_key));
+                }
+                if with_references {
+                    row.push(table_cell(&column.references.join(", ")));
+                }
+                row
+            })
+            .collect();
+
+        vec![
+            ContentBlock::Heading {
+                level: self.level,
+                text: "Schema".to_string(),
+            },
+            ContentBlock::Table {
+                headers: headers.into_iter().map(String::from).collect(),
+                rows,
+            },
+        ]
+    }
+
+    /// Rows to read per table; `None` reads all of them
+    pub fn row_limit(&self) -> Option<usize> {
+        self.options.sample_rows
+    }
+
+    /// Row count, then the sampled rows of table `name` with any extracted
+    /// images below them. `rows` must already be limited to [`Self::row_limit`].
+    pub fn rows_blocks(
+        &self,
+        name: &str,
+        headers: &[String],
+        rows: Vec<Vec<Value>>,
+        total_rows: u64,
+    ) -> Vec<ContentBlock> {
+        let mut blocks = vec![ContentBlock::Text(format!("**Row count:** {}", total_rows))];
+        if rows.is_empty() {
+            return blocks;
+        }
+
+        let heading = match self.options.sample_rows {
+            Some(limit) => format!("Sample Data (first {} rows)", limit),
+            None => "Data".to_string(),
+        };
+        let mut images = Vec::new();
+        let table_rows = rows
+            .into_iter()
+            .enumerate()
+            .map(|(row_index, row)| {
+                row.into_iter()
+                    .enumerate()
+                    .map(|(column, value)| {
+                        let header = headers.get(column).map(String::as_str).unwrap_or("");
+                        match value {
+                            Value::Blob(data) => {
+                                let label = format!("{}.{} (row {})", name, header, row_index + 1);
+                                let id = format!(
+                                    "{}_{}_{}",
+                                    name.replace(|c: char| !c.is_ascii_alphanumeric(), "_"),
+                                    header.replace(|c: char| !c.is_ascii_alphanumeric(), "_"),
+                                    row_index + 1
+                                );
+                                self.render_blob(data, &id, &label, &mut images)
+                            }
+                            other => table_cell(&other.to_string()),
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        blocks.push(ContentBlock::Heading {
+            level: self.level,
+            text: heading,
+        });
+        blocks.push(ContentBlock::Table {
+            headers: headers.iter().map(|h| table_cell(h)).collect(),
+            rows: table_rows,
+        });
+        blocks.extend(images.into_iter().map(ContentBlock::Image));
+        blocks
+    }
+
+    /// "Column Statistics" heading and table for `(name, type)` columns
+    pub fn statistics_blocks(
+        &self,
+        columns: &[(String, String)],
+        stats: &[ColumnStats],
+    ) -> Vec<ContentBlock> {
+        if columns.is_empty() {
+            return Vec::new();
+        }
+        let headers = ["Column", "Type", "Nulls", "Distinct", "Min", "Max", "Mean"];
+        vec![
+            ContentBlock::Heading {
+                level: self.level,
+                text: "Column Statistics".to_string(),
+            },
+            ContentBlock::Table {
+                headers: headers.into_iter().map(String::from).collect(),
+                rows: columns
+                    .iter()
+                    .zip(stats)
+                    .map(|((name, data_type), stats)| stats.row(name, data_type))
+                    .collect(),
+            },
+        ]
+    }
+
+    fn render_blob(
+        &self,
+        data: Vec<u8>,
+        id: &str,
+        label: &str,
+        images: &mut Vec<ExtractedImage>,
+    ) -> String {
+        let size = format!("{} bytes", data.len());
+        match self.options.blobs {
+            BlobMode::Size => format!("<BLOB {}>", size),
+            BlobMode::HexPreview => {
+                let hex: Vec<String> = data
+                    .iter()
+                    .take(HEX_PREVIEW_BYTES)
+                    .map(|b| format!("{:02x}", b))
+                    .collect();
+                let more = if data.len() > HEX_PREVIEW_BYTES {
+                    " …"
+                } else {
+                    ""
+                };
+                format!("<BLOB {}: {}{}>", size, hex.join(" "), more)
+            }
+            BlobMode::Images => match infer::get(&data) {
+                Some(kind) if kind.matcher_type() == infer::MatcherType::Image => {
+                    let mime = kind.mime_type().to_string();
+                    if self.extract_images {
+                        let mut image = ExtractedImage::new(id, Bytes::from(data), mime.clone());
+                        image.alt_text = Some(label.to_string());
+                        images.push(image);
+                    }
+                    format!("<image {}: {}, {}>", id, mime, size)
+                }
+                _ => format!("<BLOB {}>", size),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Null => write!(f, "NULL"),
+            Value::Integer(v) => write!(f, "{}", v),
+            Value::Real(v) => write!(f, "{}", v),
+            Value::Text(v) => write!(f, "{}", v),
+            Value::Blob(v) => write!(f, "<BLOB {} bytes>", v.len()),
+        }
+    }
+}
+
+/// Running statistics of one column
+#[derive(Debug, Default)]
+pub(crate) struct ColumnStats {
+    nulls: u64,
+    blobs: u64,
+    distinct: HashSet<String>,
+    distinct_overflow: bool,
+    numbers: u64,
+    sum: f64,
+    min_number: Option<f64>,
+    max_number: Option<f64>,
+    min_text: Option<String>,
+    max_text: Option<String>,
+}
+
+impl ColumnStats {
+    pub fn add(&mut self, value: &Value) {
+        let number = match value {
+            Value::Null => {
+                self.nulls += 1;
+                return;
+            }
+            // BLOBs have neither distinct values nor a range
+            Value::Blob(_) => {
+                self.blobs += 1;
+                return;
+            }
+            Value::Integer(v) => Some(*v as f64),
+            Value::Real(v) => Some(*v),
+            Value::Text(_) => None,
+        };
+
+        let key = value.to_string();
+        if self.distinct.len() < DISTINCT_LIMIT {
+            self.distinct.insert(key);
+        } else if !self.distinct.contains(&key) {
+            self.distinct_overflow = true;
+        }
+
+        if let Some(number) = number {
+            self.numbers += 1;
+            self.sum += number;
+            self.min_number = Some(self.min_number.map_or(number, |m| m.min(number)));
+            self.max_number = Some(self.max_number.map_or(number, |m| m.max(number)));
+        } else if let Value::Text(text) = value {
+            if self.min_text.as_ref().is_none_or(|m| text < m) {
+                self.min_text = Some(text.clone());
+            }
+            if self.max_text.as_ref().is_none_or(|m| text > m) {
+                self.max_text = Some(text.clone());
+            }
+        }
+    }
+
+    fn row(&self, name: &str, data_type: &str) -> Vec<String> {
+        let distinct = if self.distinct_overflow {
+            format!("{}+", DISTINCT_LIMIT)
+        } else if self.distinct.is_empty() && self.blobs > 0 {
+            String::new()
+        } else {
+            self.distinct.len().to_string()
+        };
+        let (min, max, mean) = match (self.min_number, self.max_number) {
+            (Some(min), Some(max)) => (
+                format_number(min),
+                format_number(max),
+                format_number(self.sum / self.numbers as f64),
+            ),
+            _ => (
+                self.min_text.clone().unwrap_or_default(),
+                self.max_text.clone().unwrap_or_default(),
+                String::new(),
+            ),
+        };
+        vec![
+            table_cell(name),
+            table_cell(data_type),
+            self.nulls.to_string(),
+            distinct,
+            table_cell(&min),
+            table_cell(&max),
+            mean,
+        ]
+    }
+}
+
+/// "`orders.customer_id` → `customers.id` (on delete cascade)"
+pub(crate) fn relationship(
+    table: &str,
+    column: &str,
+    target_table: &str,
+    target_column: &str,
+    on_delete: Option<&str>,
+) -> String {
+    let mut item = format!(
+        "`{}.{}` → `{}.{}`",
+        table, column, target_table, target_column
+    );
+    if let Some(action) = on_delete.filter(|a| !a.eq_ignore_ascii_case("NO ACTION")) {
+        item.push_str(&format!(" (on delete {})", action.to_lowercase()));
+    }
+    item
+}
+
+/// "`idx_orders_customer` on (customer_id) — unique"
+pub(crate) fn index_item(name: &str, columns: &[String], notes: &[&str]) -> String {
+    let mut item = format!("`{}` on ({})", name, columns.join(", "));
+    if !notes.is_empty() {
+        item.push_str(&format!(" — {}", notes.join(", ")));
+    }
+    item
+}
+
+/// A titled bullet list, or nothing when `items` is empty
+pub(crate) fn list_blocks(level: u8, title: &str, items: Vec<String>) -> Vec<ContentBlock> {
+    if items.is_empty() {
+        return Vec::new();
+    }
+    vec![
+        ContentBlock::Heading {
+            level,
+            text: title.to_string(),
+        },
+        ContentBlock::List {
+            ordered: false,
+            items,
+        },
+    ]
+}
+
+/// "Triggers" heading with the name and SQL of each `(name, sql)` trigger
+pub(crate) fn trigger_blocks(level: u8, triggers: &[(&str, Option<&str>)]) -> Vec<ContentBlock> {
+    if triggers.is_empty() {
+        return Vec::new();
+    }
+    let mut blocks = vec![ContentBlock::Heading {
+        level,
+        text: "Triggers".to_string(),
+    }];
+    for (name, sql) in triggers {
+        blocks.push(ContentBlock::Text(format!("**{}**", name)));
+        if let Some(sql) = sql {
+            blocks.push(ContentBlock::Code {
+                language: Some("sql".to_string()),
+                code: sql.trim().to_string(),
+            });
+        }
+    }
+    blocks
+}
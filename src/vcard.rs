@@ -1,96 +1,525 @@
 // vCard (.vcf) to Markdown converter.
 //
-// Supports conversion of vCard contact files to markdown.
+// Supports vCard 2.1, 3.0 and 4.0 contact files: every contact becomes a
+// section listing its names, organization, typed phone numbers, emails and
+// addresses, dates, links and notes, with embedded photos extracted as
+// images. Large address books can be rendered as one compact table instead.
 
 use async_trait::async_trait;
+use base64::prelude::*;
 use bytes::Bytes;
+use encoding_rs::Encoding;
 use object_store::ObjectStore;
 use std::sync::Arc;
-use vcard_parser::parse_vcards;
-use vcard_parser::traits::HasValue;
 
+use crate::content_lines::{parse_components, Component, Property};
 use crate::error::MarkitdownError;
-use crate::model::{ContentBlock, ConversionOptions, Document, DocumentConverter, Page};
+use crate::model::{
+    ContentBlock, ConversionOptions, Document, DocumentConverter, ExtractedImage, Page,
+};
 
-/// vCard (.vcf) converter
-pub struct VCardConverter;
+/// Type parameters that carry no information for a reader
+const IGNORED_TYPES: &[&str] = &[
+    "internet",
+    "x400",
+    "voice",
+    "pref",
+    "quoted-printable",
+    "base64",
+    "b",
+    "8bit",
+    "7bit",
+];
 
-impl VCardConverter {
-    fn convert_vcf(bytes: &[u8]) -> Result<Document, MarkitdownError> {
-        let content = String::from_utf8_lossy(bytes);
-        let vcards = parse_vcards(&content)
-            .map_err(|e| MarkitdownError::ParseError(format!("vCard parse error: {:?}", e)))?;
+/// Decode a quoted-printable value (`=C3=A4`), honouring `CHARSET`
+fn decode_quoted_printable(value: &str, charset: Option<&str>) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'=' {
+            let hex = bytes
+                .get(i + 1..i + 3)
+                .and_then(|h| std::str::from_utf8(h).ok())
+                .and_then(|h| u8::from_str_radix(h, 16).ok());
+            if let Some(byte) = hex {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
 
-        let mut document = Document::new();
-        let mut page = Page::new(1);
-        let mut markdown = String::new();
+    match charset_encoding(charset) {
+        Some(encoding) => encoding
+            .decode_without_bom_handling(&decoded)
+            .0
+            .into_owned(),
+        None => match String::from_utf8(decoded) {
+            Ok(text) => text,
+            Err(e) => e.into_bytes().iter().map(|b| *b as char).collect(),
+        },
+    }
+}
 
-        markdown.push_str("# Contacts\n\n");
+/// The encoding a `CHARSET` parameter names. ISO-8859-1 is read as its
+/// windows-1252 superset, as browsers do; a declared single-byte charset wins
+/// even where its bytes happen to form valid UTF-8 (`=C3=A4` is "Ã¤").
+fn charset_encoding(charset: Option<&str>) -> Option<&'static Encoding> {
+    Encoding::for_label(charset?.trim().as_bytes()).filter(|e| e.is_ascii_compatible())
+}
 
-        if vcards.is_empty() {
-            markdown.push_str("*No contacts found.*\n");
-        } else {
-            for vcard in &vcards {
-                markdown.push_str("## 👤 Contact\n\n");
+/// Decode a vCard file. Lines carrying a `CHARSET` parameter (vCard 2.1
+/// 8-bit values), and their folded continuations, are read in that charset;
+/// the rest as UTF-8.
+fn decode_lines(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(bytes.len());
+    let mut encoding = None;
+    for line in bytes.split_inclusive(|b| *b == b'\n') {
+        if !line.starts_with(b" ") && !line.starts_with(b"\t") {
+            encoding = line_charset(line);
+        }
+        match encoding {
+            Some(encoding) => text.push_str(&encoding.decode_without_bom_handling(line).0),
+            None => text.push_str(&String::from_utf8_lossy(line)),
+        }
+    }
+    text
+}
 
-                // Get formatted name (FN property)
-                if let Some(fn_prop) = vcard.get_property_by_name("FN") {
-                    markdown.push_str(&format!("**{}**\n\n", fn_prop.get_value()));
-                }
+/// The encoding named by the `CHARSET` parameter of a content line
+fn line_charset(line: &[u8]) -> Option<&'static Encoding> {
+    let end = line.iter().position(|b| *b == b':')?;
+    let name_and_params = String::from_utf8_lossy(&line[..end]);
+    let charset = name_and_params.split(';').skip(1).find_map(|param| {
+        let (key, value) = param.split_once('=')?;
+        key.trim()
+            .eq_ignore_ascii_case("charset")
+            .then_some(value.trim_matches('"'))
+    });
+    charset_encoding(charset)
+}
 
-                // Organization
-                if let Some(org) = vcard.get_property_by_name("ORG") {
-                    markdown.push_str(&format!("- **Organization:** {}\n", org.get_value()));
-                }
+/// Whether a property uses this `ENCODING` (also written as a bare vCard
+/// 2.1 parameter)
+fn has_encoding(property: &Property, encodings: &[&str]) -> bool {
+    property
+        .param_values("ENCODING")
+        .iter()
+        .chain(property.param_values("TYPE").iter())
+        .any(|value| encodings.contains(&value.as_str()))
+}
 
-                // Title
-                if let Some(title) = vcard.get_property_by_name("TITLE") {
-                    markdown.push_str(&format!("- **Title:** {}\n", title.get_value()));
-                }
+/// A property with quoted-printable values decoded
+fn decode_property(property: &Property) -> Property {
+    if !has_encoding(property, &["quoted-printable"]) {
+        return property.clone();
+    }
+    Property {
+        value: decode_quoted_printable(&property.value, property.param("CHARSET")),
+        ..property.clone()
+    }
+}
 
-                // Email addresses
-                for email in vcard.get_properties_by_name("EMAIL") {
-                    markdown.push_str(&format!("- **Email:** {}\n", email.get_value()));
-                }
+/// "work, preferred"
+fn type_label(property: &Property) -> String {
+    let types = property.param_values("TYPE");
+    let mut labels: Vec<String> = types
+        .iter()
+        .filter(|t| !IGNORED_TYPES.contains(&t.as_str()) && !t.starts_with("x-"))
+        .filter(|t| {
+            !t.starts_with("image/") && !["jpeg", "jpg", "png", "gif"].contains(&t.as_str())
+        })
+        .cloned()
+        .collect();
+    if is_preferred(property) {
+        labels.push("preferred".to_string());
+    }
+    labels.join(", ")
+}
 
-                // Phone numbers
-                for tel in vcard.get_properties_by_name("TEL") {
-                    markdown.push_str(&format!("- **Phone:** {}\n", tel.get_value()));
-                }
+fn is_preferred(property: &Property) -> bool {
+    property.param_values("TYPE").iter().any(|t| t == "pref")
+        || property.param("PREF").is_some_and(|p| p.trim() == "1")
+}
 
-                // Address - Value needs to be converted to string first
-                if let Some(adr) = vcard.get_property_by_name("ADR") {
-                    let addr_str = format!("{}", adr.get_value());
-                    let addr = addr_str
-                        .replace(';', ", ")
-                        .trim_matches(',')
-                        .trim()
-                        .to_string();
-                    if !addr.is_empty() && addr != ", , , , , ," {
-                        markdown.push_str(&format!("- **Address:** {}\n", addr));
-                    }
-                }
+/// "1985-03-04" / "19850304" / "--0304" -> "4 March 1985" / "4 March"
+fn format_date(value: &str) -> String {
+    const MONTHS: [&str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+    let date = value.trim().split('T').next().unwrap_or_default();
+    let (no_year, digits) = match date.strip_prefix("--") {
+        Some(rest) => (true, rest.replace('-', "")),
+        None => (false, date.replace('-', "")),
+    };
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return value.trim().to_string();
+    }
+    let day_month = |md: &str| -> Option<String> {
+        let month: usize = md.get(0..2)?.parse().ok()?;
+        let day: u32 = md.get(2..4)?.parse().ok()?;
+        let name = MONTHS.get(month.checked_sub(1)?)?;
+        Some(format!("{} {}", day, name))
+    };
+    let formatted = match (no_year, digits.len()) {
+        (true, 4) => day_month(&digits),
+        (false, 8) => day_month(&digits[4..]).map(|dm| format!("{} {}", dm, &digits[..4])),
+        _ => None,
+    };
+    formatted.unwrap_or_else(|| value.trim().to_string())
+}
 
-                // URL
-                if let Some(url) = vcard.get_property_by_name("URL") {
-                    markdown.push_str(&format!("- **Website:** {}\n", url.get_value()));
-                }
+/// A single contact
+struct Contact {
+    properties: Vec<Property>,
+}
+
+impl Contact {
+    fn new(card: &Component) -> Self {
+        Self {
+            properties: card.properties.iter().map(decode_property).collect(),
+        }
+    }
+
+    fn all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Property> {
+        self.properties
+            .iter()
+            .filter(move |p| p.name.eq_ignore_ascii_case(name))
+    }
 
-                // Note
-                if let Some(note) = vcard.get_property_by_name("NOTE") {
-                    markdown.push_str(&format!("\n> {}\n", note.get_value()));
+    fn text(&self, name: &str) -> Option<String> {
+        self.all(name)
+            .map(|p| p.text().trim().to_string())
+            .find(|t| !t.is_empty())
+    }
+
+    /// The preferred (or else first) non-empty value of a property
+    fn preferred(&self, name: &str, format: fn(&Property) -> String) -> Option<String> {
+        let mut values: Vec<&Property> = self.all(name).collect();
+        values.sort_by_key(|p| !is_preferred(p));
+        values.into_iter().map(format).find(|v| !v.is_empty())
+    }
+
+    /// FN, or the parts of N, or the organization or email address
+    fn name(&self) -> String {
+        if let Some(name) = self.text("FN") {
+            return name;
+        }
+        if let Some(n) = self.all("N").next() {
+            // Family;Given;Additional;Prefix;Suffix
+            let parts = n.list(';');
+            let get = |i: usize| parts.get(i).map(|s| s.trim()).unwrap_or_default();
+            let name = [get(3), get(1), get(2), get(0), get(4)]
+                .iter()
+                .filter(|s| !s.is_empty())
+                .copied()
+                .collect::<Vec<_>>()
+                .join(" ");
+            if !name.is_empty() {
+                return name;
+            }
+        }
+        self.organization()
+            .or_else(|| self.text("EMAIL"))
+            .unwrap_or_else(|| "Unnamed contact".to_string())
+    }
+
+    /// "Acme Inc., Engineering"
+    fn organization(&self) -> Option<String> {
+        self.all("ORG")
+            .map(|org| {
+                org.list(';')
+                    .iter()
+                    .map(|s| s.trim())
+                    .filter(|s| !s.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .find(|o| !o.is_empty())
+    }
+
+    /// Labelled list items such as "**Phone (cell):** +1 555 0100"
+    fn typed_items(&self, name: &str, label: &str, format: fn(&Property) -> String) -> Vec<String> {
+        self.all(name)
+            .filter_map(|p| {
+                let value = format(p);
+                if value.is_empty() {
+                    return None;
                 }
+                Some(match type_label(p) {
+                    types if types.is_empty() => format!("**{}:** {}", label, value),
+                    types => format!("**{} ({}):** {}", label, types, value),
+                })
+            })
+            .collect()
+    }
+
+    fn details(&self) -> Vec<String> {
+        let mut items = Vec::new();
+        let mut push = |label: &str, value: Option<String>| {
+            if let Some(value) = value {
+                items.push(format!("**{}:** {}", label, value));
+            }
+        };
+        push("Nickname", self.text("NICKNAME"));
+        push("Organization", self.organization());
+        push("Title", self.text("TITLE"));
+        push("Role", self.text("ROLE"));
+        match self.text("KIND").map(|k| k.to_lowercase()).as_deref() {
+            None | Some("individual") => {}
+            Some(kind) => push("Kind", Some(kind.to_string())),
+        }
+
+        items.extend(self.typed_items("EMAIL", "Email", text_value));
+        items.extend(self.typed_items("TEL", "Phone", phone_value));
+        items.extend(self.typed_items("ADR", "Address", format_address));
+        items.extend(self.typed_items("IMPP", "Messaging", text_value));
+        items.extend(self.typed_items("URL", "Website", text_value));
 
-                markdown.push('\n');
+        let mut push = |label: &str, value: Option<String>| {
+            if let Some(value) = value {
+                items.push(format!("**{}:** {}", label, value));
             }
+        };
+        push("Birthday", self.text("BDAY").map(|d| format_date(&d)));
+        push(
+            "Anniversary",
+            self.text("ANNIVERSARY").map(|d| format_date(&d)),
+        );
+        let categories: Vec<String> = self
+            .all("CATEGORIES")
+            .flat_map(|p| p.list(','))
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty())
+            .collect();
+        if !categories.is_empty() {
+            push("Categories", Some(categories.join(", ")));
+        }
+        for related in self.all("RELATED") {
+            let value = text_value(related);
+            let label = match type_label(related) {
+                types if types.is_empty() => "Related".to_string(),
+                types => format!("Related ({})", types),
+            };
+            push(&label, Some(value).filter(|v| !v.is_empty()));
+        }
+        items
+    }
 
-            markdown.push_str(&format!("---\n*{} contact(s)*\n", vcards.len()));
+    /// The photo as image data, or its URL when it is not embedded
+    fn photo(&self) -> Option<Photo> {
+        let photo = self.all("PHOTO").next()?;
+        let value: String = photo.value.split_whitespace().collect();
+        if value.is_empty() {
+            return None;
+        }
+
+        // vCard 4.0: data:image/png;base64,...
+        if let Some(data) = value.strip_prefix("data:") {
+            let Some((header, encoded)) = data.split_once(',') else {
+                return Some(Photo::Invalid);
+            };
+            let mime = header.split(';').next().unwrap_or_default();
+            let Ok(bytes) = BASE64_STANDARD.decode(encoded) else {
+                return Some(Photo::Invalid);
+            };
+            let mime = image_mime(&declared_mime(mime), &bytes);
+            return Some(Photo::Embedded(Bytes::from(bytes), mime));
+        }
+        if has_encoding(photo, &["b", "base64"]) {
+            let Ok(bytes) = BASE64_STANDARD.decode(value.as_bytes()) else {
+                return Some(Photo::Invalid);
+            };
+            let declared = photo
+                .param_values("TYPE")
+                .into_iter()
+                .find(|t| {
+                    ["jpeg", "jpg", "png", "gif"].contains(&t.as_str()) || t.starts_with("image/")
+                })
+                .unwrap_or_default();
+            let mime = image_mime(&declared_mime(&declared), &bytes);
+            return Some(Photo::Embedded(Bytes::from(bytes), mime));
+        }
+        Some(Photo::Link(value))
+    }
+}
+
+/// A contact photo
+enum Photo {
+    /// Embedded image data and its MIME type
+    Embedded(Bytes, String),
+    /// URL of an image that is not embedded
+    Link(String),
+    /// Embedded data that could not be decoded
+    Invalid,
+}
+
+/// MIME type for a declared image type (`JPEG`, `image/png`)
+fn declared_mime(declared: &str) -> String {
+    match declared.to_ascii_lowercase().as_str() {
+        "" => String::new(),
+        "jpeg" | "jpg" => "image/jpeg".to_string(),
+        "png" => "image/png".to_string(),
+        "gif" => "image/gif".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// The declared MIME type, or the one detected from the data
+fn image_mime(declared: &str, data: &[u8]) -> String {
+    if !declared.is_empty() {
+        return declared.to_string();
+    }
+    infer::get(data)
+        .map(|kind| kind.mime_type().to_string())
+        .unwrap_or_else(|| "image/jpeg".to_string())
+}
+
+fn text_value(property: &Property) -> String {
+    property.text().trim().to_string()
+}
+
+/// Phone number without the `tel:` scheme of vCard 4.0
+fn phone_value(property: &Property) -> String {
+    let value = text_value(property);
+    match value.get(..4) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("tel:") => value[4..].to_string(),
+        _ => value,
+    }
+}
+
+/// "PO Box 12, 1 Main St, Springfield, IL, 62701, USA"
+fn format_address(property: &Property) -> String {
+    // PO box;extended;street;locality;region;postal code;country
+    property
+        .list(';')
+        .iter()
+        .map(|part| part.replace('\n', ", ").trim().to_string())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Put a value on one line for a table cell; the renderer escapes pipes
+fn table_cell(text: &str) -> String {
+    text.replace('\n', " ")
+}
+
+/// vCard (.vcf) converter
+pub struct VCardConverter;
+
+impl VCardConverter {
+    fn convert_vcf(bytes: &[u8], options: &ConversionOptions) -> Result<Document, MarkitdownError> {
+        let content = decode_lines(bytes);
+        let contacts: Vec<Contact> = parse_components(&content)
+            .map_err(|e| MarkitdownError::ParseError(format!("vCard parse error: {}", e)))?
+            .iter()
+            .filter(|c| c.name == "VCARD")
+            .map(Contact::new)
+            .collect();
+
+        let mut document = Document::new();
+        document.title = Some("Contacts".to_string());
+        document
+            .metadata
+            .insert("contact_count".to_string(), contacts.len().to_string());
+        let mut page = Page::new(1);
+
+        if contacts.is_empty() {
+            page.add_content(ContentBlock::Text("*No contacts found.*".to_string()));
+        } else if options.contact_table {
+            page.add_content(Self::contact_table(&contacts));
+        } else {
+            for (index, contact) in contacts.iter().enumerate() {
+                for block in Self::contact_blocks(contact, index + 1, options) {
+                    page.add_content(block);
+                }
+            }
         }
 
-        page.add_content(ContentBlock::Markdown(markdown));
         document.add_page(page);
         Ok(document)
     }
+
+    /// One section per contact
+    fn contact_blocks(
+        contact: &Contact,
+        number: usize,
+        options: &ConversionOptions,
+    ) -> Vec<ContentBlock> {
+        let name = contact.name();
+        let mut blocks = vec![ContentBlock::Heading {
+            level: 2,
+            text: name.clone(),
+        }];
+
+        let mut details = contact.details();
+        match contact.photo() {
+            Some(Photo::Embedded(data, mime)) if options.extract_images => {
+                let mut image =
+                    ExtractedImage::new(format!("contact_{}_photo", number), data, mime);
+                image.alt_text = Some(name);
+                blocks.push(ContentBlock::Image(image));
+            }
+            Some(Photo::Link(url)) => details.push(format!("**Photo:** {}", url)),
+            Some(Photo::Invalid) => {
+                details.push("**Photo:** embedded image could not be decoded".to_string())
+            }
+            _ => {}
+        }
+        if !details.is_empty() {
+            blocks.push(ContentBlock::List {
+                ordered: false,
+                items: details,
+            });
+        }
+        if let Some(note) = contact.text("NOTE") {
+            blocks.push(ContentBlock::Quote(note));
+        }
+        blocks
+    }
+
+    /// One row per contact with the preferred email, phone and address
+    fn contact_table(contacts: &[Contact]) -> ContentBlock {
+        let rows = contacts
+            .iter()
+            .map(|contact| {
+                [
+                    Some(contact.name()),
+                    contact.organization(),
+                    contact.text("TITLE"),
+                    contact.preferred("EMAIL", text_value),
+                    contact.preferred("TEL", phone_value),
+                    contact.preferred("ADR", format_address),
+                ]
+                .into_iter()
+                .map(|cell| table_cell(&cell.unwrap_or_default()))
+                .collect()
+            })
+            .collect();
+
+        ContentBlock::Table {
+            headers: ["Name", "Organization", "Title", "Email", "Phone", "Address"]
+                .iter()
+                .map(|h| h.to_string())
+                .collect(),
+            rows,
+        }
+    }
 }
 
 #[async_trait]
@@ -133,7 +562,20 @@ impl DocumentConverter for VCardConverter {
             }
         }
 
-        Self::convert_vcf(&bytes)
+        let opts = options.unwrap_or_default();
+        let mut document = Self::convert_vcf(&bytes, &opts)?;
+
+        // If LLM client is provided, get descriptions for all images
+        if let Some(ref llm_client) = opts.llm_client {
+            if let Some(path) = opts.image_context_path.as_deref() {
+                document.apply_image_context_path(path);
+            }
+            document = document
+                .with_image_descriptions(llm_client.as_ref())
+                .await?;
+        }
+
+        Ok(document)
     }
 
     fn supported_extensions(&self) -> &[&str] {
@@ -0,0 +1,702 @@
+//! Specializations of the HTML converter for well-known web sources.
+//!
+//! Pages are recognised by their URL (`ConversionOptions::url`, canonical or
+//! OpenGraph URL) or, for saved pages, by their content:
+//!
+//! - **Wikipedia** (and other MediaWiki sites): article body and infobox only
+//! - **YouTube** watch pages: video metadata, description and transcript from
+//!   the embedded player JSON
+//! - **Search result pages** (Bing, DuckDuckGo): the query and result list
+
+use base64::prelude::*;
+use regex::Regex;
+use scraper::{ElementRef, Html, Selector};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use url::Url;
+
+use crate::html::{collapse_whitespace, HtmlWalker};
+use crate::links::resolve_url;
+use crate::model::{ContentBlock, ConversionOptions, Document, Inline, Page};
+
+/// A web source with dedicated handling
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebSource {
+    Wikipedia,
+    YouTube,
+    BingSearch,
+    DuckDuckGoSearch,
+}
+
+impl WebSource {
+    /// Identifier recorded as `web_source` in the document metadata
+    pub fn name(&self) -> &'static str {
+        match self {
+            WebSource::Wikipedia => "wikipedia",
+            WebSource::YouTube => "youtube",
+            WebSource::BingSearch => "bing",
+            WebSource::DuckDuckGoSearch => "duckduckgo",
+        }
+    }
+
+    /// Recognise a source from a page URL
+    pub fn from_url(url: &Url) -> Option<Self> {
+        let host = url.host_str()?.trim_start_matches("www.").to_lowercase();
+        let path = url.path();
+
+        if host.ends_with("wikipedia.org") && path.starts_with("/wiki/") {
+            Some(WebSource::Wikipedia)
+        } else if ((host == "youtube.com" || host == "m.youtube.com") && path == "/watch")
+            || host == "youtu.be"
+        {
+            Some(WebSource::YouTube)
+        } else if host == "bing.com" && path.starts_with("/search") {
+            Some(WebSource::BingSearch)
+        } else if host.ends_with("duckduckgo.com") && !path.starts_with("/l/") {
+            Some(WebSource::DuckDuckGoSearch)
+        } else {
+            None
+        }
+    }
+}
+
+static MEDIAWIKI_CONTENT_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("#mw-content-text").unwrap());
+static MEDIAWIKI_TITLE_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("#firstHeading").unwrap());
+static INFOBOX_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("table.infobox").unwrap());
+static INFOBOX_CAPTION_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse(".infobox-caption").unwrap());
+static ROW_SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("tr").unwrap());
+/// Wikipedia chrome inside the article body
+static MEDIAWIKI_SKIP_SELECTOR: LazyLock<Selector> = LazyLock::new(|| {
+    Selector::parse(
+        "table.infobox, .navbox, .navbox-styles, .vertical-navbox, .mw-editsection, \
+         sup.reference, .reflist, .mw-references-wrap, .refbegin, .noprint, .mw-empty-elt, \
+         .hatnote, .ambox, .sistersitebox, .side-box, .shortdescription, #toc, .toc, \
+         .mw-jump-link, .portalbox, .printfooter, .catlinks, link, meta",
+    )
+    .unwrap()
+});
+static SCRIPT_SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("script").unwrap());
+static BING_RESULTS_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("#b_results").unwrap());
+static BING_RESULT_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("#b_results > li.b_algo").unwrap());
+static BING_TITLE_SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("h2 a").unwrap());
+static BING_SNIPPET_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse(".b_caption p, p").unwrap());
+static DDG_RESULT_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse(".result:not(.result--ad)").unwrap());
+static DDG_TITLE_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("a.result__a").unwrap());
+static DDG_SNIPPET_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse(".result__snippet").unwrap());
+static QUERY_INPUT_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("input[name=q]").unwrap());
+static GENERATOR_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("meta[name=generator]").unwrap());
+
+/// Start of the JSON assigned to a `var x = {...}` style variable
+static PLAYER_RESPONSE_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"ytInitialPlayerResponse\s*=\s*\{").unwrap());
+static INITIAL_DATA_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"ytInitialData\s*=\s*\{").unwrap());
+
+/// Recognise a well-known source from the page URL, falling back to the
+/// canonical/OpenGraph URL and finally to the page content
+pub fn detect_web_source(
+    html: &Html,
+    url: Option<&Url>,
+    metadata: &HashMap<String, String>,
+) -> Option<WebSource> {
+    let page_urls = url.cloned().into_iter().chain(
+        ["canonical_url", "og:url"]
+            .iter()
+            .filter_map(|key| metadata.get(*key))
+            .filter_map(|u| Url::parse(u).ok()),
+    );
+    for page_url in page_urls {
+        if let Some(source) = WebSource::from_url(&page_url) {
+            return Some(source);
+        }
+    }
+
+    let is_mediawiki = html
+        .select(&GENERATOR_SELECTOR)
+        .filter_map(|m| m.value().attr("content"))
+        .any(|g| g.starts_with("MediaWiki"));
+    if is_mediawiki && html.select(&MEDIAWIKI_CONTENT_SELECTOR).next().is_some() {
+        return Some(WebSource::Wikipedia);
+    }
+    if metadata.get("og:site_name").is_some_and(|s| s == "YouTube")
+        || scripts(html).any(|s| PLAYER_RESPONSE_PATTERN.is_match(&s))
+    {
+        return Some(WebSource::YouTube);
+    }
+    if html.select(&BING_RESULTS_SELECTOR).next().is_some() {
+        return Some(WebSource::BingSearch);
+    }
+    if html.select(&DDG_TITLE_SELECTOR).next().is_some() {
+        return Some(WebSource::DuckDuckGoSearch);
+    }
+    None
+}
+
+/// Convert a page of a recognised source into `document`. Returns `false`
+/// (leaving the document untouched) when the expected structure is missing,
+/// so the generic conversion can take over.
+pub(crate) fn convert_web_page(
+    source: WebSource,
+    html: &Html,
+    options: &ConversionOptions,
+    base: Option<Url>,
+    document: &mut Document,
+) -> bool {
+    // Saved pages have no URL of their own; their canonical URL still lets
+    // site-relative links resolve
+    let base = base.or_else(|| {
+        document
+            .metadata
+            .get("canonical_url")
+            .and_then(|u| Url::parse(u).ok())
+    });
+    let mut metadata = HashMap::new();
+    let page = match source {
+        WebSource::Wikipedia => convert_wikipedia(html, options, base),
+        WebSource::YouTube => convert_youtube(
+            html,
+            document.title.as_deref(),
+            &document.metadata,
+            &mut metadata,
+        ),
+        WebSource::BingSearch | WebSource::DuckDuckGoSearch => {
+            convert_search(html, source, base.as_ref(), &mut metadata)
+        }
+    };
+    let Some((title, page)) = page else {
+        return false;
+    };
+
+    if title.is_some() {
+        document.title = title;
+    }
+    document.metadata.extend(metadata);
+    document
+        .metadata
+        .insert("web_source".to_string(), source.name().to_string());
+    document.add_page(page);
+    true
+}
+
+/// Iterate over the text of inline `<script>` elements
+fn scripts(html: &Html) -> impl Iterator<Item = String> + '_ {
+    html.select(&SCRIPT_SELECTOR)
+        .map(|s| s.text().collect::<String>())
+}
+
+fn element_text(element: ElementRef) -> String {
+    collapse_whitespace(&element.text().collect::<String>())
+}
+
+// ============================================================================
+// Wikipedia
+// ============================================================================
+
+fn convert_wikipedia(
+    html: &Html,
+    options: &ConversionOptions,
+    base: Option<Url>,
+) -> Option<(Option<String>, Page)> {
+    let content = html.select(&MEDIAWIKI_CONTENT_SELECTOR).next()?;
+    let title = html
+        .select(&MEDIAWIKI_TITLE_SELECTOR)
+        .next()
+        .map(element_text)
+        .filter(|t| !t.is_empty());
+
+    let mut page = Page::new(1);
+
+    let mut infobox_walker = HtmlWalker::new(options.extract_images, true, base.clone());
+    if let Some(infobox) = content.select(&INFOBOX_SELECTOR).next() {
+        for block in infobox_blocks(infobox, &mut infobox_walker) {
+            page.add_content(block);
+        }
+    }
+
+    let mut walker = HtmlWalker::new(options.extract_images, true, base)
+        .with_skip(MEDIAWIKI_SKIP_SELECTOR.clone())
+        .with_image_index(infobox_walker.image_count());
+    walker.walk_element(content);
+    for block in drop_empty_sections(walker.finish()) {
+        page.add_content(block);
+    }
+
+    Some((title, page))
+}
+
+/// Render an infobox as a two-column table, followed by its images
+fn infobox_blocks(infobox: ElementRef, walker: &mut HtmlWalker) -> Vec<ContentBlock> {
+    let mut rows = Vec::new();
+    let mut images = Vec::new();
+
+    for row in infobox.select(&ROW_SELECTOR) {
+        // Rows of nested tables are handled through their parent cell
+        if row
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .find(|a| a.value().name() == "table")
+            .is_some_and(|t| t.id() != infobox.id())
+        {
+            continue;
+        }
+
+        let cells: Vec<ElementRef> = row
+            .children()
+            .filter_map(ElementRef::wrap)
+            .filter(|c| matches!(c.value().name(), "th" | "td"))
+            .collect();
+        let has_class = |cell: &ElementRef, class: &str| cell.value().classes().any(|c| c == class);
+
+        match cells.as_slice() {
+            [cell] if has_class(cell, "infobox-image") => {
+                walker.walk_element(*cell);
+                images.extend(with_infobox_caption(*cell, walker.take_blocks()));
+            }
+            [cell] if has_class(cell, "infobox-header") || has_class(cell, "infobox-subheader") => {
+                let text = infobox_cell(walker, *cell);
+                if !text.is_empty() {
+                    rows.push(vec![format!("**{}**", text), String::new()]);
+                }
+            }
+            [label, data] => {
+                let label = infobox_cell(walker, *label);
+                let data = infobox_cell(walker, *data);
+                if !label.is_empty() || !data.is_empty() {
+                    rows.push(vec![label, data]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut blocks = Vec::new();
+    if !rows.is_empty() {
+        blocks.push(ContentBlock::Table {
+            headers: vec!["Field".to_string(), "Value".to_string()],
+            rows,
+        });
+    }
+    blocks.extend(images);
+    blocks
+}
+
+/// Attach the `.infobox-caption` text of an image cell to its first image
+/// instead of emitting it as a separate paragraph
+fn with_infobox_caption(cell: ElementRef, mut blocks: Vec<ContentBlock>) -> Vec<ContentBlock> {
+    let Some(caption) = cell
+        .select(&INFOBOX_CAPTION_SELECTOR)
+        .next()
+        .map(element_text)
+        .filter(|c| !c.is_empty())
+    else {
+        return blocks;
+    };
+    let Some(image) = blocks.iter_mut().find_map(|b| match b {
+        ContentBlock::Image(img) => Some(img),
+        _ => None,
+    }) else {
+        return blocks;
+    };
+    image.caption = Some(caption.clone());
+    blocks.retain(|b| match b {
+        ContentBlock::Text(t) => collapse_whitespace(t) != caption,
+        ContentBlock::Paragraph(spans) => {
+            collapse_whitespace(&Inline::plain_text(spans)) != caption
+        }
+        _ => true,
+    });
+    blocks
+}
+
+/// Inline text of an infobox cell; line breaks and list items become ", "
+fn infobox_cell(walker: &mut HtmlWalker, cell: ElementRef) -> String {
+    walker
+        .inline_text(cell)
+        .lines()
+        .map(collapse_whitespace)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Remove headings whose section ended up empty (e.g. "References" once the
+/// reference list is dropped)
+fn drop_empty_sections(blocks: Vec<ContentBlock>) -> Vec<ContentBlock> {
+    let mut kept: Vec<ContentBlock> = Vec::with_capacity(blocks.len());
+    for block in blocks.into_iter().rev() {
+        if let ContentBlock::Heading { level, .. } = &block {
+            let empty = match kept.last() {
+                None => true,
+                Some(ContentBlock::Heading { level: next, .. }) => next <= level,
+                Some(_) => false,
+            };
+            if empty {
+                continue;
+            }
+        }
+        kept.push(block);
+    }
+    kept.reverse();
+    kept
+}
+
+// ============================================================================
+// YouTube
+// ============================================================================
+
+fn convert_youtube(
+    html: &Html,
+    page_title: Option<&str>,
+    page_metadata: &HashMap<String, String>,
+    metadata: &mut HashMap<String, String>,
+) -> Option<(Option<String>, Page)> {
+    let player = embedded_json(html, &PLAYER_RESPONSE_PATTERN);
+    let details = player.as_ref().and_then(|p| p.get("videoDetails"));
+    let microformat = player
+        .as_ref()
+        .and_then(|p| p.pointer("/microformat/playerMicroformatRenderer"));
+    let detail = |key: &str| {
+        details
+            .and_then(|d| d.get(key))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .filter(|s| !s.is_empty())
+    };
+
+    let title = detail("title")
+        .or_else(|| page_metadata.get("og:title").cloned())
+        .or_else(|| page_title.map(|t| t.trim_end_matches("- YouTube").trim().to_string()))
+        .filter(|t| !t.is_empty());
+    let description =
+        detail("shortDescription").or_else(|| page_metadata.get("description").cloned());
+
+    if details.is_none() && title.is_none() {
+        return None;
+    }
+
+    let mut page = Page::new(1);
+
+    let mut facts = Vec::new();
+    if let Some(video_id) = detail("videoId") {
+        metadata.insert("video_id".to_string(), video_id);
+    }
+    if let Some(channel) = detail("author") {
+        facts.push(format!("**Channel:** {}", channel));
+        metadata.insert("channel".to_string(), channel);
+    }
+    if let Some(views) = detail("viewCount") {
+        facts.push(format!("**Views:** {}", group_digits(&views)));
+        metadata.insert("view_count".to_string(), views);
+    }
+    if let Some(seconds) = detail("lengthSeconds").and_then(|s| s.parse::<u64>().ok()) {
+        facts.push(format!(
+            "**Duration:** {}",
+            format_timestamp(seconds * 1000)
+        ));
+        metadata.insert("duration_seconds".to_string(), seconds.to_string());
+    }
+    if let Some(date) = microformat
+        .and_then(|m| m.get("publishDate").or_else(|| m.get("uploadDate")))
+        .and_then(Value::as_str)
+    {
+        facts.push(format!("**Published:** {}", date));
+        metadata.insert("publish_date".to_string(), date.to_string());
+    }
+    let keywords: Vec<&str> = details
+        .and_then(|d| d.get("keywords"))
+        .and_then(Value::as_array)
+        .map(|k| k.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    if !keywords.is_empty() {
+        facts.push(format!("**Keywords:** {}", keywords.join(", ")));
+        metadata.insert("keywords".to_string(), keywords.join(", "));
+    }
+    if !facts.is_empty() {
+        page.add_content(ContentBlock::List {
+            ordered: false,
+            items: facts,
+        });
+    }
+
+    if let Some(description) = &description {
+        page.add_content(ContentBlock::Heading {
+            level: 2,
+            text: "Description".to_string(),
+        });
+        page.add_content(ContentBlock::Text(description.clone()));
+    }
+
+    let tracks = caption_tracks(player.as_ref());
+    if !tracks.is_empty() {
+        metadata.insert("caption_languages".to_string(), tracks.join(", "));
+    }
+
+    let transcript = embedded_json(html, &INITIAL_DATA_PATTERN)
+        .map(|data| transcript_lines(&data))
+        .unwrap_or_default();
+    if !transcript.is_empty() {
+        page.add_content(ContentBlock::Heading {
+            level: 2,
+            text: "Transcript".to_string(),
+        });
+        page.add_content(ContentBlock::Text(transcript.join("\n")));
+    }
+
+    Some((title, page))
+}
+
+/// Parse the JSON object assigned in an inline script, e.g.
+/// `var ytInitialPlayerResponse = {...};`
+fn embedded_json(html: &Html, pattern: &Regex) -> Option<Value> {
+    scripts(html).find_map(|script| {
+        let m = pattern.find(&script)?;
+        let json = &script[m.end() - 1..];
+        serde_json::Deserializer::from_str(json)
+            .into_iter::<Value>()
+            .next()?
+            .ok()
+    })
+}
+
+/// Languages of the available caption tracks ("en", "de (auto-generated)")
+fn caption_tracks(player: Option<&Value>) -> Vec<String> {
+    player
+        .and_then(|p| p.pointer("/captions/playerCaptionsTracklistRenderer/captionTracks"))
+        .and_then(Value::as_array)
+        .map(|tracks| {
+            tracks
+                .iter()
+                .filter_map(|track| {
+                    let code = track.get("languageCode")?.as_str()?;
+                    let generated = track.get("kind").and_then(Value::as_str) == Some("asr");
+                    Some(if generated {
+                        format!("{} (auto-generated)", code)
+                    } else {
+                        code.to_string()
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Collect transcript segments ("[0:01] text") from the page data
+fn transcript_lines(data: &Value) -> Vec<String> {
+    let mut lines = Vec::new();
+    collect_transcript(data, &mut lines);
+    lines
+}
+
+fn collect_transcript(value: &Value, lines: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(segment) = map.get("transcriptSegmentRenderer") {
+                let text = runs_text(segment.get("snippet"));
+                if !text.is_empty() {
+                    let start = segment
+                        .get("startMs")
+                        .and_then(Value::as_str)
+                        .and_then(|ms| ms.parse::<u64>().ok())
+                        .map(format_timestamp);
+                    lines.push(match start {
+                        Some(start) => format!("[{}] {}", start, text),
+                        None => text,
+                    });
+                }
+                return;
+            }
+            for child in map.values() {
+                collect_transcript(child, lines);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_transcript(item, lines);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Text of a YouTube `{"runs": [{"text": ...}]}` or `{"simpleText": ...}` value
+fn runs_text(value: Option<&Value>) -> String {
+    let Some(value) = value else {
+        return String::new();
+    };
+    if let Some(text) = value.get("simpleText").and_then(Value::as_str) {
+        return collapse_whitespace(text);
+    }
+    let text = value
+        .get("runs")
+        .and_then(Value::as_array)
+        .map(|runs| {
+            runs.iter()
+                .filter_map(|r| r.get("text").and_then(Value::as_str))
+                .collect::<String>()
+        })
+        .unwrap_or_default();
+    collapse_whitespace(&text)
+}
+
+/// Format milliseconds as `m:ss` or `h:mm:ss`
+fn format_timestamp(ms: u64) -> String {
+    let seconds = ms / 1000;
+    let (h, m, s) = (seconds / 3600, (seconds % 3600) / 60, seconds % 60);
+    if h > 0 {
+        format!("{}:{:02}:{:02}", h, m, s)
+    } else {
+        format!("{}:{:02}", m, s)
+    }
+}
+
+/// Group the digits of a number with commas ("1234567" -> "1,234,567")
+fn group_digits(number: &str) -> String {
+    if !number.chars().all(|c| c.is_ascii_digit()) {
+        return number.to_string();
+    }
+    let mut out = String::new();
+    for (i, c) in number.chars().enumerate() {
+        if i > 0 && (number.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
+// ============================================================================
+// Search result pages
+// ============================================================================
+
+fn convert_search(
+    html: &Html,
+    source: WebSource,
+    base: Option<&Url>,
+    metadata: &mut HashMap<String, String>,
+) -> Option<(Option<String>, Page)> {
+    let (engine, result_selector, title_selector, snippet_selector) = match source {
+        WebSource::BingSearch => (
+            "Bing",
+            &*BING_RESULT_SELECTOR,
+            &*BING_TITLE_SELECTOR,
+            &*BING_SNIPPET_SELECTOR,
+        ),
+        _ => (
+            "DuckDuckGo",
+            &*DDG_RESULT_SELECTOR,
+            &*DDG_TITLE_SELECTOR,
+            &*DDG_SNIPPET_SELECTOR,
+        ),
+    };
+
+    let query = base
+        .and_then(|url| {
+            url.query_pairs()
+                .find(|(key, _)| key == "q")
+                .map(|(_, value)| value.into_owned())
+        })
+        .or_else(|| {
+            html.select(&QUERY_INPUT_SELECTOR)
+                .filter_map(|input| input.value().attr("value"))
+                .map(collapse_whitespace)
+                .find(|q| !q.is_empty())
+        });
+
+    let mut results = Vec::new();
+    for result in html.select(result_selector) {
+        let Some(link) = result.select(title_selector).next() else {
+            continue;
+        };
+        let title = element_text(link);
+        let href = link.value().attr("href").unwrap_or("").trim();
+        if title.is_empty() || href.is_empty() {
+            continue;
+        }
+        let url = unwrap_redirect(&resolve_url(base, href));
+        let snippet = result
+            .select(snippet_selector)
+            .map(element_text)
+            .find(|s| !s.is_empty());
+        results.push((title, url, snippet));
+    }
+    if results.is_empty() {
+        return None;
+    }
+
+    let heading = match &query {
+        Some(query) => format!(
+            "A {} search for '{}' found the following results:",
+            engine, query
+        ),
+        None => format!("{} search results", engine),
+    };
+    let mut page = Page::new(1);
+    page.add_content(ContentBlock::Heading {
+        level: 2,
+        text: heading,
+    });
+    for (title, url, snippet) in &results {
+        page.add_content(ContentBlock::Text(format!("**[{}]({})**", title, url)));
+        if let Some(snippet) = snippet {
+            page.add_content(ContentBlock::Text(snippet.clone()));
+        }
+    }
+
+    metadata.insert("search_engine".to_string(), engine.to_string());
+    metadata.insert("result_count".to_string(), results.len().to_string());
+    if let Some(query) = &query {
+        metadata.insert("search_query".to_string(), query.clone());
+    }
+
+    let title = query.map(|q| format!("{} - {}", q, engine));
+    Some((title, page))
+}
+
+/// Replace search engine click-tracking redirects with the target URL
+fn unwrap_redirect(href: &str) -> String {
+    // Saved pages keep protocol-relative links ("//duckduckgo.com/l/?...")
+    let parsed = match href.strip_prefix("//") {
+        Some(rest) => Url::parse(&format!("https://{}", rest)),
+        None => Url::parse(href),
+    };
+    let Ok(url) = parsed else {
+        return href.to_string();
+    };
+    let host = url.host_str().unwrap_or("");
+    let param = |name: &str| {
+        url.query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.into_owned())
+    };
+
+    // Bing: /ck/a?...&u=a1<base64url of the target>
+    if host.ends_with("bing.com") && url.path().starts_with("/ck/") {
+        if let Some(target) = param("u")
+            .and_then(|u| u.strip_prefix("a1").map(str::to_string))
+            .and_then(|u| BASE64_URL_SAFE_NO_PAD.decode(u.trim_end_matches('=')).ok())
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+        {
+            return target;
+        }
+    }
+    // DuckDuckGo: /l/?uddg=<percent-encoded target>
+    if host.ends_with("duckduckgo.com") && url.path().starts_with("/l/") {
+        if let Some(target) = param("uddg") {
+            return target;
+        }
+    }
+    href.to_string()
+}
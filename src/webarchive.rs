@@ -0,0 +1,972 @@
+//! Saved web page converters (.mht/.mhtml, .webarchive, .warc).
+//!
+//! These formats bundle a page's HTML with the resources it references. The
+//! root HTML is converted by [`HtmlConverter`]; images referenced through
+//! `cid:` URLs or their original location are served from the bundle by an
+//! [`ImageFetcher`], so they become regular extracted images.
+//!
+//! WARC files written by crawlers hold many pages: every HTML response record
+//! becomes one page of the resulting document.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use flate2::read::MultiGzDecoder;
+use mail_parser::{MessageParser, MimeHeaders};
+use object_store::ObjectStore;
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::collections::HashMap;
+use std::io::Read;
+use std::sync::Arc;
+use url::Url;
+
+use crate::error::MarkitdownError;
+use crate::fetch::{ImageFetcher, SharedImageFetcher};
+use crate::html::HtmlConverter;
+use crate::model::{ContentBlock, ConversionOptions, Document, DocumentConverter, Page};
+
+/// Resources bundled with a saved page or email, keyed by URL (`cid:` or
+/// the original location). Lookups that miss fall through to the fetcher
+/// configured in the conversion options.
+pub(crate) struct BundledResources {
+    resources: HashMap<String, Bytes>,
+    fallback: Option<SharedImageFetcher>,
+}
+
+impl BundledResources {
+    pub(crate) fn new(fallback: Option<SharedImageFetcher>) -> Self {
+        Self {
+            resources: HashMap::new(),
+            fallback,
+        }
+    }
+
+    pub(crate) fn insert(&mut self, key: &str, data: Bytes) {
+        self.resources.entry(resource_key(key)).or_insert(data);
+    }
+}
+
+#[async_trait]
+impl ImageFetcher for BundledResources {
+    async fn fetch(&self, url: &str) -> Result<Option<Bytes>, MarkitdownError> {
+        if let Some(data) = self.resources.get(&resource_key(url)) {
+            return Ok(Some(data.clone()));
+        }
+        match &self.fallback {
+            Some(fallback) => fallback.fetch(url).await,
+            None => Ok(None),
+        }
+    }
+}
+
+/// Normalise a resource reference for lookup: `cid:` references lose their
+/// angle brackets and case, URLs lose their fragment
+pub(crate) fn resource_key(reference: &str) -> String {
+    let reference = reference.trim();
+    if reference.len() > 4
+        && reference
+            .get(..4)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("cid:"))
+    {
+        let id = reference[4..].trim_matches(|c| c == '<' || c == '>');
+        return format!("cid:{}", id.to_lowercase());
+    }
+    reference.split('#').next().unwrap_or(reference).to_string()
+}
+
+/// Convert the root HTML of a saved page, serving images from `resources`
+pub(crate) async fn convert_bundled_html(
+    html: &[u8],
+    url: Option<String>,
+    resources: SharedImageFetcher,
+    options: &ConversionOptions,
+) -> Result<Document, MarkitdownError> {
+    let mut html_options = options.clone();
+    html_options.file_extension = Some(".html".to_string());
+    html_options.url = url.or_else(|| options.url.clone());
+    html_options.image_fetcher = Some(resources);
+
+    let html = String::from_utf8_lossy(html).into_owned();
+    HtmlConverter
+        .convert_bytes(Bytes::from(html), Some(html_options))
+        .await
+}
+
+/// Reject an explicit file extension this converter does not handle
+fn check_extension(
+    options: &Option<ConversionOptions>,
+    supported: &[&str],
+) -> Result<(), MarkitdownError> {
+    if let Some(ext) = options.as_ref().and_then(|o| o.file_extension.as_ref()) {
+        let ext_lower = ext.to_lowercase();
+        if !supported.contains(&ext_lower.as_str()) {
+            return Err(MarkitdownError::InvalidFile(format!(
+                "Expected {} file, got {}",
+                supported.join(" or "),
+                ext
+            )));
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// MHTML
+// ============================================================================
+
+/// MHTML (`multipart/related`) saved page converter
+pub struct MhtmlConverter;
+
+impl MhtmlConverter {
+    async fn convert_mhtml(
+        bytes: &[u8],
+        options: &ConversionOptions,
+    ) -> Result<Document, MarkitdownError> {
+        let message = MessageParser::default()
+            .parse(bytes)
+            .ok_or_else(|| MarkitdownError::ParseError("Failed to parse MHTML".to_string()))?;
+
+        // The root is named by the `start` parameter, or is the first HTML part
+        let start = message
+            .content_type()
+            .and_then(|ct| ct.attribute("start"))
+            .map(resource_key_from_id);
+        let is_html = |part: &mail_parser::MessagePart| {
+            part.content_type().is_some_and(|ct| {
+                ct.ctype().eq_ignore_ascii_case("text")
+                    && ct.subtype().is_some_and(|s| s.eq_ignore_ascii_case("html"))
+            })
+        };
+        let leaves: Vec<_> = message.parts.iter().filter(|p| !p.is_multipart()).collect();
+        let root = start
+            .as_ref()
+            .and_then(|start| {
+                leaves
+                    .iter()
+                    .find(|p| p.content_id().map(resource_key_from_id).as_ref() == Some(start))
+            })
+            .or_else(|| leaves.iter().find(|p| is_html(p)))
+            .ok_or_else(|| {
+                MarkitdownError::ParseError("MHTML file has no HTML part".to_string())
+            })?;
+
+        let page_url = root
+            .content_location()
+            .map(str::to_string)
+            .or_else(|| {
+                message
+                    .header_raw("Snapshot-Content-Location")
+                    .map(|l| l.trim().to_string())
+            })
+            .filter(|l| !l.is_empty());
+        let page_base = page_url.as_deref().and_then(|u| Url::parse(u).ok());
+
+        let mut resources = BundledResources::new(options.image_fetcher.clone());
+        for part in &leaves {
+            if std::ptr::eq(*part, *root) {
+                continue;
+            }
+            let data = Bytes::copy_from_slice(part.contents());
+            if let Some(id) = part.content_id() {
+                resources.insert(&resource_key_from_id(id), data.clone());
+            }
+            if let Some(location) = part.content_location() {
+                let location = match &page_base {
+                    Some(base) => base
+                        .join(location.trim())
+                        .map(|u| u.to_string())
+                        .unwrap_or_else(|_| location.to_string()),
+                    None => location.to_string(),
+                };
+                resources.insert(&location, data);
+            }
+        }
+
+        let mut document = convert_bundled_html(
+            root.contents(),
+            page_url.clone(),
+            Arc::new(resources),
+            options,
+        )
+        .await?;
+        if document.title.is_none() {
+            document.title = message.subject().map(|s| s.trim().to_string());
+        }
+        if let Some(url) = page_url {
+            document.metadata.insert("source_url".to_string(), url);
+        }
+        if let Some(date) = message.date() {
+            document
+                .metadata
+                .insert("saved_at".to_string(), date.to_rfc3339());
+        }
+        Ok(document)
+    }
+}
+
+/// `cid:` key for a Content-ID value or `start` parameter
+fn resource_key_from_id(id: &str) -> String {
+    resource_key(&format!("cid:{}", id.trim()))
+}
+
+#[async_trait]
+impl DocumentConverter for MhtmlConverter {
+    async fn convert(
+        &self,
+        store: Arc<dyn ObjectStore>,
+        path: &object_store::path::Path,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        check_extension(&options, self.supported_extensions())?;
+
+        let result = store.get(path).await?;
+        let bytes = result.bytes().await?;
+        self.convert_bytes(bytes, options).await
+    }
+
+    async fn convert_bytes(
+        &self,
+        bytes: Bytes,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        check_extension(&options, self.supported_extensions())?;
+
+        Self::convert_mhtml(&bytes, &options.unwrap_or_default()).await
+    }
+
+    fn supported_extensions(&self) -> &[&str] {
+        &[".mht", ".mhtml"]
+    }
+}
+
+// ============================================================================
+// Safari web archive
+// ============================================================================
+
+/// Safari `.webarchive` converter (binary or XML property list)
+pub struct WebArchiveConverter;
+
+impl WebArchiveConverter {
+    async fn convert_webarchive(
+        bytes: &[u8],
+        options: &ConversionOptions,
+    ) -> Result<Document, MarkitdownError> {
+        let archive = if bytes.starts_with(b"bplist00") {
+            parse_binary_plist(bytes)?
+        } else {
+            parse_xml_plist(bytes)?
+        };
+
+        let main = archive
+            .get("WebMainResource")
+            .ok_or_else(|| MarkitdownError::ParseError("Missing WebMainResource".to_string()))?;
+        let html = main
+            .get("WebResourceData")
+            .and_then(PlistValue::as_data)
+            .ok_or_else(|| MarkitdownError::ParseError("Missing main resource data".to_string()))?;
+        let page_url = main
+            .get("WebResourceURL")
+            .and_then(PlistValue::as_str)
+            .map(str::to_string);
+
+        let mut resources = BundledResources::new(options.image_fetcher.clone());
+        collect_subresources(&archive, &mut resources);
+
+        let mut document =
+            convert_bundled_html(html, page_url.clone(), Arc::new(resources), options).await?;
+        if let Some(url) = page_url {
+            document.metadata.insert("source_url".to_string(), url);
+        }
+        Ok(document)
+    }
+}
+
+/// Add the subresources of an archive and its subframe archives
+fn collect_subresources(archive: &PlistValue, resources: &mut BundledResources) {
+    for resource in archive
+        .get("WebSubresources")
+        .and_then(PlistValue::as_array)
+        .unwrap_or_default()
+    {
+        let url = resource.get("WebResourceURL").and_then(PlistValue::as_str);
+        let data = resource
+            .get("WebResourceData")
+            .and_then(PlistValue::as_data);
+        if let (Some(url), Some(data)) = (url, data) {
+            resources.insert(url, Bytes::copy_from_slice(data));
+        }
+    }
+    for frame in archive
+        .get("WebSubframeArchives")
+        .and_then(PlistValue::as_array)
+        .unwrap_or_default()
+    {
+        collect_subresources(frame, resources);
+    }
+}
+
+#[async_trait]
+impl DocumentConverter for WebArchiveConverter {
+    async fn convert(
+        &self,
+        store: Arc<dyn ObjectStore>,
+        path: &object_store::path::Path,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        check_extension(&options, self.supported_extensions())?;
+
+        let result = store.get(path).await?;
+        let bytes = result.bytes().await?;
+        self.convert_bytes(bytes, options).await
+    }
+
+    async fn convert_bytes(
+        &self,
+        bytes: Bytes,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        check_extension(&options, self.supported_extensions())?;
+
+        Self::convert_webarchive(&bytes, &options.unwrap_or_default()).await
+    }
+
+    fn supported_extensions(&self) -> &[&str] {
+        &[".webarchive"]
+    }
+}
+
+/// The subset of property list values used by web archives
+#[derive(Debug, Clone, PartialEq)]
+enum PlistValue {
+    String(String),
+    Data(Vec<u8>),
+    Integer(i64),
+    Boolean(bool),
+    Array(Vec<PlistValue>),
+    Dict(HashMap<String, PlistValue>),
+    /// Reals, dates, UIDs and other values web archives do not rely on
+    Other,
+}
+
+impl PlistValue {
+    fn get(&self, key: &str) -> Option<&PlistValue> {
+        match self {
+            PlistValue::Dict(dict) => dict.get(key),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            PlistValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn as_data(&self) -> Option<&[u8]> {
+        match self {
+            PlistValue::Data(data) => Some(data),
+            // Some writers store the main resource as a string
+            PlistValue::String(s) => Some(s.as_bytes()),
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&[PlistValue]> {
+        match self {
+            PlistValue::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+/// Nesting limit guarding against reference cycles in malformed files
+const MAX_PLIST_DEPTH: usize = 64;
+
+/// Parse a `bplist00` binary property list
+fn parse_binary_plist(bytes: &[u8]) -> Result<PlistValue, MarkitdownError> {
+    let invalid = || MarkitdownError::ParseError("Invalid binary property list".to_string());
+    if bytes.len() < 8 + 32 {
+        return Err(invalid());
+    }
+
+    let trailer = &bytes[bytes.len() - 32..];
+    let offset_size = trailer[6] as usize;
+    let ref_size = trailer[7] as usize;
+    let read_u64 = |b: &[u8]| u64::from_be_bytes(b.try_into().unwrap()) as usize;
+    let object_count = read_u64(&trailer[8..16]);
+    let top_object = read_u64(&trailer[16..24]);
+    let table_offset = read_u64(&trailer[24..32]);
+    if !(1..=8).contains(&offset_size) || !(1..=8).contains(&ref_size) {
+        return Err(invalid());
+    }
+    let table_end = object_count
+        .checked_mul(offset_size)
+        .and_then(|len| len.checked_add(table_offset))
+        .ok_or_else(invalid)?;
+    if table_end > bytes.len() {
+        return Err(invalid());
+    }
+
+    let parser = BinaryPlist {
+        bytes,
+        offsets: bytes[table_offset..table_end]
+            .chunks(offset_size)
+            .map(read_be)
+            .collect(),
+        ref_size,
+    };
+    parser.object(top_object, 0).ok_or_else(invalid)
+}
+
+fn read_be(bytes: &[u8]) -> usize {
+    bytes.iter().fold(0usize, |acc, &b| (acc << 8) | b as usize)
+}
+
+struct BinaryPlist<'a> {
+    bytes: &'a [u8],
+    offsets: Vec<usize>,
+    ref_size: usize,
+}
+
+impl BinaryPlist<'_> {
+    fn object(&self, index: usize, depth: usize) -> Option<PlistValue> {
+        if depth > MAX_PLIST_DEPTH {
+            return None;
+        }
+        let offset = *self.offsets.get(index)?;
+        let marker = *self.bytes.get(offset)?;
+        let (kind, info) = (marker >> 4, (marker & 0x0f) as usize);
+
+        match kind {
+            0x0 => Some(match info {
+                0x8 => PlistValue::Boolean(false),
+                0x9 => PlistValue::Boolean(true),
+                _ => PlistValue::Other,
+            }),
+            0x1 => {
+                let len = 1usize << info;
+                let data = self.bytes.get(offset + 1..offset + 1 + len)?;
+                Some(PlistValue::Integer(read_be(data) as i64))
+            }
+            0x4 => {
+                let (len, start) = self.length(offset, info)?;
+                let data = self.bytes.get(start..start.checked_add(len)?)?;
+                Some(PlistValue::Data(data.to_vec()))
+            }
+            0x5 => {
+                let (len, start) = self.length(offset, info)?;
+                let data = self.bytes.get(start..start.checked_add(len)?)?;
+                Some(PlistValue::String(
+                    String::from_utf8_lossy(data).into_owned(),
+                ))
+            }
+            0x6 => {
+                let (len, start) = self.length(offset, info)?;
+                let data = self
+                    .bytes
+                    .get(start..start.checked_add(len.checked_mul(2)?)?)?;
+                let units: Vec<u16> = data
+                    .chunks(2)
+                    .map(|c| u16::from_be_bytes([c[0], c[1]]))
+                    .collect();
+                Some(PlistValue::String(String::from_utf16_lossy(&units)))
+            }
+            0xA => {
+                let (len, start) = self.length(offset, info)?;
+                let refs = self.refs(start, len)?;
+                let items = refs
+                    .into_iter()
+                    .map(|r| self.object(r, depth + 1))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(PlistValue::Array(items))
+            }
+            0xD => {
+                let (len, start) = self.length(offset, info)?;
+                let refs = self.refs(start, len.checked_mul(2)?)?;
+                let (keys, values) = refs.split_at(len);
+                let mut dict = HashMap::new();
+                for (&key, &value) in keys.iter().zip(values) {
+                    let PlistValue::String(key) = self.object(key, depth + 1)? else {
+                        return None;
+                    };
+                    dict.insert(key, self.object(value, depth + 1)?);
+                }
+                Some(PlistValue::Dict(dict))
+            }
+            _ => Some(PlistValue::Other),
+        }
+    }
+
+    /// Object length and the offset of its payload. A length nibble of 0xF
+    /// means the length follows as an integer object.
+    fn length(&self, offset: usize, info: usize) -> Option<(usize, usize)> {
+        if info != 0x0f {
+            return Some((info, offset + 1));
+        }
+        let marker = *self.bytes.get(offset + 1)?;
+        if marker >> 4 != 0x1 {
+            return None;
+        }
+        let size = 1usize << (marker & 0x0f);
+        let len = read_be(self.bytes.get(offset + 2..offset + 2 + size)?);
+        Some((len, offset + 2 + size))
+    }
+
+    fn refs(&self, start: usize, count: usize) -> Option<Vec<usize>> {
+        let end = start.checked_add(count.checked_mul(self.ref_size)?)?;
+        Some(
+            self.bytes
+                .get(start..end)?
+                .chunks(self.ref_size)
+                .map(read_be)
+                .collect(),
+        )
+    }
+}
+
+/// Parse an XML property list (as written by `plutil -convert xml1`)
+fn parse_xml_plist(bytes: &[u8]) -> Result<PlistValue, MarkitdownError> {
+    let mut reader = Reader::from_reader(bytes);
+    reader.config_mut().trim_text(true);
+
+    // Containers being built; dictionaries remember their pending key
+    enum Frame {
+        Array(Vec<PlistValue>),
+        Dict(HashMap<String, PlistValue>, Option<String>),
+    }
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut text = String::new();
+    let mut root = None;
+    let mut buf = Vec::new();
+
+    let push_value =
+        |stack: &mut Vec<Frame>, root: &mut Option<PlistValue>, value| match stack.last_mut() {
+            Some(Frame::Array(items)) => items.push(value),
+            Some(Frame::Dict(dict, key)) => {
+                if let Some(key) = key.take() {
+                    dict.insert(key, value);
+                }
+            }
+            None => *root = Some(value),
+        };
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) => {
+                text.clear();
+                match e.name().as_ref() {
+                    b"array" => stack.push(Frame::Array(Vec::new())),
+                    b"dict" => stack.push(Frame::Dict(HashMap::new(), None)),
+                    _ => {}
+                }
+            }
+            Ok(Event::Empty(e)) => {
+                let value = match e.name().as_ref() {
+                    b"true" => PlistValue::Boolean(true),
+                    b"false" => PlistValue::Boolean(false),
+                    b"array" => PlistValue::Array(Vec::new()),
+                    b"dict" => PlistValue::Dict(HashMap::new()),
+                    b"string" => PlistValue::String(String::new()),
+                    b"data" => PlistValue::Data(Vec::new()),
+                    _ => continue,
+                };
+                push_value(&mut stack, &mut root, value);
+            }
+            Ok(Event::Text(e)) => {
+                text.push_str(&e.decode().unwrap_or_default());
+            }
+            Ok(Event::GeneralRef(e)) => {
+                if let Ok(Some(c)) = e.resolve_char_ref() {
+                    text.push(c);
+                } else if let Some(entity) = e
+                    .decode()
+                    .ok()
+                    .and_then(|name| resolve_predefined_entity(&name))
+                {
+                    text.push_str(entity);
+                }
+            }
+            Ok(Event::CData(e)) => {
+                text.push_str(&String::from_utf8_lossy(&e));
+            }
+            Ok(Event::End(e)) => {
+                let value = match e.name().as_ref() {
+                    b"key" => {
+                        if let Some(Frame::Dict(_, key)) = stack.last_mut() {
+                            *key = Some(std::mem::take(&mut text));
+                        }
+                        continue;
+                    }
+                    b"string" => PlistValue::String(std::mem::take(&mut text)),
+                    b"integer" => PlistValue::Integer(text.trim().parse().unwrap_or(0)),
+                    b"data" => {
+                        use base64::prelude::*;
+                        let encoded: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+                        PlistValue::Data(BASE64_STANDARD.decode(encoded).map_err(|e| {
+                            MarkitdownError::ParseError(format!("Invalid plist data: {}", e))
+                        })?)
+                    }
+                    b"array" => match stack.pop() {
+                        Some(Frame::Array(items)) => PlistValue::Array(items),
+                        _ => continue,
+                    },
+                    b"dict" => match stack.pop() {
+                        Some(Frame::Dict(dict, _)) => PlistValue::Dict(dict),
+                        _ => continue,
+                    },
+                    b"real" | b"date" => PlistValue::Other,
+                    _ => continue,
+                };
+                text.clear();
+                push_value(&mut stack, &mut root, value);
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(MarkitdownError::ParseError(format!(
+                    "Property list parse error: {}",
+                    e
+                )))
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    root.ok_or_else(|| MarkitdownError::ParseError("Empty property list".to_string()))
+}
+
+// ============================================================================
+// WARC
+// ============================================================================
+
+/// A captured HTTP response (or resource) from a WARC file
+struct WarcCapture {
+    target_uri: String,
+    date: Option<String>,
+    status: u16,
+    mime_type: String,
+    body: Bytes,
+}
+
+/// WARC (Web ARChive) crawl converter; plain or gzip-compressed
+pub struct WarcConverter;
+
+impl WarcConverter {
+    async fn convert_warc(
+        bytes: &[u8],
+        options: &ConversionOptions,
+    ) -> Result<Document, MarkitdownError> {
+        let data = if bytes.starts_with(&[0x1f, 0x8b]) {
+            let mut decompressed = Vec::new();
+            MultiGzDecoder::new(bytes)
+                .read_to_end(&mut decompressed)
+                .map_err(|e| {
+                    MarkitdownError::ParseError(format!("WARC decompression error: {}", e))
+                })?;
+            decompressed
+        } else {
+            bytes.to_vec()
+        };
+
+        let (record_count, captures) = parse_warc(&data)?;
+
+        // Every successful capture can serve images to the pages
+        let successful: Vec<&WarcCapture> = captures
+            .iter()
+            .filter(|c| (200..300).contains(&c.status))
+            .collect();
+        let mut resources = BundledResources::new(options.image_fetcher.clone());
+        for capture in &successful {
+            resources.insert(&capture.target_uri, capture.body.clone());
+        }
+        let resources: SharedImageFetcher = Arc::new(resources);
+
+        let mut document = Document::new();
+        let mut page_number = 0;
+        let mut failed_pages = 0;
+        for capture in &successful {
+            if !matches!(
+                capture.mime_type.as_str(),
+                "text/html" | "application/xhtml+xml"
+            ) {
+                continue;
+            }
+            // A capture that cannot be converted does not spoil the crawl
+            let Ok(captured) = convert_bundled_html(
+                &capture.body,
+                Some(capture.target_uri.clone()),
+                resources.clone(),
+                options,
+            )
+            .await
+            else {
+                failed_pages += 1;
+                continue;
+            };
+
+            page_number += 1;
+            let mut page = Page::new(page_number);
+            page.add_content(ContentBlock::Heading {
+                level: 1,
+                text: captured
+                    .title
+                    .clone()
+                    .unwrap_or_else(|| capture.target_uri.clone()),
+            });
+            page.add_content(ContentBlock::Text(format!(
+                "**URL:** {}",
+                capture.target_uri
+            )));
+            if let Some(date) = &capture.date {
+                page.add_content(ContentBlock::Text(format!("**Captured:** {}", date)));
+            }
+            for mut block in captured.pages.into_iter().flat_map(|p| p.content) {
+                if let ContentBlock::Image(image) = &mut block {
+                    // Image ids restart for every captured page
+                    image.id = format!("warc_page_{}_{}", page_number, image.id);
+                    image.page_number = Some(page_number);
+                }
+                page.add_content(block);
+            }
+            document.add_page(page);
+        }
+
+        if document.pages.is_empty() {
+            return Err(MarkitdownError::ParseError(
+                "WARC file contains no HTML responses".to_string(),
+            ));
+        }
+        document
+            .metadata
+            .insert("warc_records".to_string(), record_count.to_string());
+        document
+            .metadata
+            .insert("warc_pages".to_string(), page_number.to_string());
+        if failed_pages > 0 {
+            document
+                .metadata
+                .insert("warc_failed_pages".to_string(), failed_pages.to_string());
+        }
+        Ok(document)
+    }
+}
+
+/// Split a WARC file into records, returning the record count and the
+/// `response`/`resource` captures
+fn parse_warc(data: &[u8]) -> Result<(usize, Vec<WarcCapture>), MarkitdownError> {
+    let mut captures = Vec::new();
+    let mut record_count = 0;
+    let mut pos = 0;
+
+    loop {
+        while data.get(pos).is_some_and(|b| matches!(b, b'\r' | b'\n')) {
+            pos += 1;
+        }
+        if pos >= data.len() {
+            break;
+        }
+
+        let Some(header_end) = find_header_end(&data[pos..]) else {
+            return Err(MarkitdownError::ParseError(
+                "Truncated WARC record header".to_string(),
+            ));
+        };
+        let header = String::from_utf8_lossy(&data[pos..pos + header_end.0]);
+        let mut lines = header.lines();
+        if !lines.next().is_some_and(|l| l.trim().starts_with("WARC/")) {
+            return Err(MarkitdownError::ParseError(format!(
+                "Invalid WARC record at byte {}",
+                pos
+            )));
+        }
+        let fields = parse_header_fields(lines);
+        let length: usize = fields
+            .get("content-length")
+            .and_then(|l| l.parse().ok())
+            .ok_or_else(|| {
+                MarkitdownError::ParseError("WARC record without Content-Length".to_string())
+            })?;
+        let block_start = pos + header_end.1;
+        let block_end = block_start.saturating_add(length).min(data.len());
+        let block = &data[block_start..block_end];
+        pos = block_end;
+        record_count += 1;
+
+        let record_type = fields.get("warc-type").map(|t| t.to_lowercase());
+        let Some(target_uri) = fields.get("warc-target-uri") else {
+            continue;
+        };
+        let target_uri = target_uri
+            .trim_matches(|c| c == '<' || c == '>')
+            .to_string();
+        let date = fields.get("warc-date").cloned();
+        let content_type = fields.get("content-type").map(|c| c.to_lowercase());
+
+        match record_type.as_deref() {
+            Some("response")
+                if content_type
+                    .as_deref()
+                    .is_some_and(|c| c.starts_with("application/http")) =>
+            {
+                if let Some((status, mime_type, body)) = parse_http_response(block) {
+                    captures.push(WarcCapture {
+                        target_uri,
+                        date,
+                        status,
+                        mime_type,
+                        body,
+                    });
+                }
+            }
+            Some("resource") => captures.push(WarcCapture {
+                target_uri,
+                date,
+                status: 200,
+                mime_type: mime_essence(content_type.as_deref().unwrap_or("")),
+                body: Bytes::copy_from_slice(block),
+            }),
+            _ => {}
+        }
+    }
+
+    Ok((record_count, captures))
+}
+
+/// Find the blank line ending a header block. Returns the header length and
+/// the offset of the body.
+fn find_header_end(data: &[u8]) -> Option<(usize, usize)> {
+    let crlf = data.windows(4).position(|w| w == b"\r\n\r\n");
+    let lf = data.windows(2).position(|w| w == b"\n\n");
+    match (crlf, lf) {
+        (Some(c), Some(l)) if l < c => Some((l, l + 2)),
+        (Some(c), _) => Some((c, c + 4)),
+        (None, Some(l)) => Some((l, l + 2)),
+        (None, None) => None,
+    }
+}
+
+/// Parse `Name: value` lines into a map keyed by lowercase name
+fn parse_header_fields<'a>(lines: impl Iterator<Item = &'a str>) -> HashMap<String, String> {
+    lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_lowercase(), value.trim().to_string()))
+        .collect()
+}
+
+/// MIME type without parameters, lowercased
+fn mime_essence(content_type: &str) -> String {
+    content_type
+        .split(';')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_lowercase()
+}
+
+/// Split a captured HTTP response into status, MIME type and decoded body
+fn parse_http_response(block: &[u8]) -> Option<(u16, String, Bytes)> {
+    let (header_len, body_start) = find_header_end(block)?;
+    let header = String::from_utf8_lossy(&block[..header_len]);
+    let mut lines = header.lines();
+    let status = lines
+        .next()?
+        .split_whitespace()
+        .nth(1)
+        .and_then(|s| s.parse().ok())?;
+    let fields = parse_header_fields(lines);
+
+    let mut body = block[body_start..].to_vec();
+    if fields
+        .get("transfer-encoding")
+        .is_some_and(|t| t.to_lowercase().contains("chunked"))
+    {
+        body = dechunk(&body);
+    }
+    match fields
+        .get("content-encoding")
+        .map(|e| e.to_lowercase())
+        .as_deref()
+    {
+        Some("gzip") | Some("x-gzip") => {
+            let mut decoded = Vec::new();
+            if MultiGzDecoder::new(&body[..])
+                .read_to_end(&mut decoded)
+                .is_ok()
+            {
+                body = decoded;
+            }
+        }
+        Some("deflate") => {
+            let mut decoded = Vec::new();
+            if flate2::read::ZlibDecoder::new(&body[..])
+                .read_to_end(&mut decoded)
+                .is_ok()
+            {
+                body = decoded;
+            }
+        }
+        _ => {}
+    }
+
+    let mime_type = mime_essence(fields.get("content-type").map_or("", String::as_str));
+    Some((status, mime_type, Bytes::from(body)))
+}
+
+/// Decode an HTTP chunked body; a malformed tail is kept as-is
+fn dechunk(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut pos = 0;
+    while let Some(line_end) = data[pos..].windows(2).position(|w| w == b"\r\n") {
+        let size_line = String::from_utf8_lossy(&data[pos..pos + line_end]);
+        let Ok(size) = usize::from_str_radix(size_line.split(';').next().unwrap_or("").trim(), 16)
+        else {
+            out.extend_from_slice(&data[pos..]);
+            return out;
+        };
+        if size == 0 {
+            return out;
+        }
+        let start = pos + line_end + 2;
+        // A malformed size may point past the end (or overflow); keep what
+        // is there
+        let end = start.saturating_add(size).min(data.len());
+        out.extend_from_slice(&data[start..end]);
+        if end >= data.len() {
+            return out;
+        }
+        pos = (end + 2).min(data.len());
+    }
+    out
+}
+
+#[async_trait]
+impl DocumentConverter for WarcConverter {
+    async fn convert(
+        &self,
+        store: Arc<dyn ObjectStore>,
+        path: &object_store::path::Path,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        check_extension(&options, self.supported_extensions())?;
+
+        let result = store.get(path).await?;
+        let bytes = result.bytes().await?;
+        self.convert_bytes(bytes, options).await
+    }
+
+    async fn convert_bytes(
+        &self,
+        bytes: Bytes,
+        options: Option<ConversionOptions>,
+    ) -> Result<Document, MarkitdownError> {
+        check_extension(&options, self.supported_extensions())?;
+
+        Self::convert_warc(&bytes, &options.unwrap_or_default()).await
+    }
+
+    fn supported_extensions(&self) -> &[&str] {
+        &[".warc"]
+    }
+}
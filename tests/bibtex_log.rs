@@ -3,7 +3,7 @@
 //! Test files sourced from kreuzberg test documents
 
 use bytes::Bytes;
-use markitdown::{ConversionOptions, MarkItDown};
+use markitdown::{ConversionOptions, FullLog, MarkItDown};
 
 const TEST_DIR: &str = "tests/test_documents";
 
@@ -111,3 +111,172 @@ async fn test_log_wrapped_in_code_block() {
     // Log files should be wrapped in code blocks
     assert!(markdown.contains("```"), "Log should be in code block");
 }
+
+async fn convert_log(content: &str, options: ConversionOptions) -> markitdown::Document {
+    let md = MarkItDown::new();
+    md.convert_bytes(
+        Bytes::from(content.to_string()),
+        Some(options.with_extension(".log")),
+    )
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_log_timestamped_with_stack_trace() {
+    let log_content = "2024-01-15 10:30:45,123 INFO  [main] com.example.App - Application started
+2024-01-15 10:30:46,001 WARN  [pool-1] com.example.Db - Slow query took 2300 ms
+2024-01-15 10:31:10,500 ERROR [pool-1] com.example.Db - Query failed
+java.sql.SQLException: Connection reset
+\tat com.example.Db.query(Db.java:42)
+\tat com.example.App.run(App.java:17)
+Caused by: java.net.SocketException: Broken pipe
+\t... 2 more
+2024-01-15 10:32:45,000 INFO  [main] com.example.App - Shutting down";
+    let doc = convert_log(log_content, ConversionOptions::default()).await;
+
+    for (key, value) in [
+        ("format", "Timestamped text"),
+        ("line_count", "9"),
+        ("event_count", "4"),
+        ("error_count", "1"),
+        ("warning_count", "1"),
+        ("first_timestamp", "2024-01-15 10:30:45,123"),
+        ("last_timestamp", "2024-01-15 10:32:45,000"),
+    ] {
+        assert_eq!(doc.metadata.get(key).map(String::as_str), Some(value));
+    }
+
+    let markdown = doc.to_markdown();
+    assert!(markdown.contains("| Line | Time | Level | Source | Message |"));
+    assert!(markdown.contains(
+        "| 1 | 2024-01-15 10:30:45,123 | INFO | com.example.App [main] | Application started |"
+    ));
+    assert!(markdown.contains(
+        "| 3 | 2024-01-15 10:31:10,500 | ERROR | com.example.Db [pool-1] | Query failed *(+5 more lines)* |"
+    ));
+    assert!(markdown.contains("- **Stack traces:** 1"));
+    assert!(markdown.contains("- **Duration:** 1m 59s"));
+    assert!(markdown.contains("- **Rate:** 2.0 events/min"));
+    assert!(markdown.contains("| ERROR | 1 |"));
+    // The whole stack trace is listed with its error
+    assert!(markdown.contains("**Line 3:**"));
+    assert!(markdown.contains("Query failed\njava.sql.SQLException: Connection reset\n"));
+    assert!(markdown.contains("Caused by: java.net.SocketException: Broken pipe"));
+}
+
+#[tokio::test]
+async fn test_log_syslog() {
+    let log_content = "Jan 15 10:30:45 web01 sshd[1234]: Accepted publickey for deploy
+Jan 15 10:30:47 web01 sshd[1234]: error: maximum authentication attempts exceeded
+<28>Jan 15 10:31:02 web01 kernel: disk almost full";
+    let doc = convert_log(log_content, ConversionOptions::default()).await;
+    assert_eq!(
+        doc.metadata.get("format").map(String::as_str),
+        Some("Syslog")
+    );
+    assert_eq!(
+        doc.metadata.get("error_count").map(String::as_str),
+        Some("1")
+    );
+    assert_eq!(
+        doc.metadata.get("warning_count").map(String::as_str),
+        Some("1")
+    );
+
+    let markdown = doc.to_markdown();
+    assert!(markdown
+        .contains("| 1 | Jan 15 10:30:45 |  | web01 sshd[1234] | Accepted publickey for deploy |"));
+    assert!(markdown.contains("| 3 | Jan 15 10:31:02 | WARN | web01 kernel | disk almost full |"));
+    assert!(markdown.contains("- **Time range:** Jan 15 10:30:45 – Jan 15 10:31:02"));
+}
+
+#[tokio::test]
+async fn test_log_json_lines() {
+    let log_content = r#"{"ts": 1705314645, "level": "info", "logger": "api", "msg": "request served"}
+{"ts": 1705314705, "level": "error", "logger": "api", "msg": "upstream timeout", "stack": "Error: timeout\n    at fetch (client.js:10)"}"#;
+    let doc = convert_log(log_content, ConversionOptions::default()).await;
+    assert_eq!(
+        doc.metadata.get("format").map(String::as_str),
+        Some("JSON lines")
+    );
+    assert_eq!(
+        doc.metadata.get("first_timestamp").map(String::as_str),
+        Some("2024-01-15 10:30:45")
+    );
+
+    let markdown = doc.to_markdown();
+    assert!(markdown.contains("| 1 | 1705314645 | INFO | api | request served |"));
+    assert!(
+        markdown.contains("| 2 | 1705314705 | ERROR | api | upstream timeout *(+2 more lines)* |")
+    );
+    assert!(markdown.contains("at fetch (client.js:10)"));
+}
+
+#[tokio::test]
+async fn test_log_access_log() {
+    let log_content = r#"192.168.1.10 - - [15/Jan/2024:10:30:45 +0000] "GET /index.html HTTP/1.1" 200 5120 "-" "curl/8.0"
+192.168.1.11 - alice [15/Jan/2024:10:30:50 +0000] "POST /api/login HTTP/1.1" 401 32 "-" "Mozilla/5.0"
+10.0.0.5 - - [15/Jan/2024:10:31:20 +0000] "GET /api/report HTTP/1.1" 502 0 "-" "Mozilla/5.0""#;
+    let doc = convert_log(log_content, ConversionOptions::default()).await;
+    assert_eq!(
+        doc.metadata.get("format").map(String::as_str),
+        Some("Apache/Nginx access log")
+    );
+    assert_eq!(
+        doc.metadata.get("error_count").map(String::as_str),
+        Some("1")
+    );
+    assert_eq!(
+        doc.metadata.get("warning_count").map(String::as_str),
+        Some("1")
+    );
+
+    let markdown = doc.to_markdown();
+    assert!(markdown.contains("| Line | Time | Client | Request | Status | Size |"));
+    assert!(markdown.contains(
+        "| 2 | 15/Jan/2024:10:30:50 +0000 | 192.168.1.11 | POST /api/login HTTP/1.1 | 401 | 32 |"
+    ));
+    assert!(markdown.contains("- **Duration:** 35s"));
+}
+
+#[tokio::test]
+async fn test_log_logfmt() {
+    let log_content = r#"time=2024-01-15T10:30:45Z level=info component=scheduler msg="job started" job=42
+time=2024-01-15T10:30:55Z level=warning component=scheduler msg="job slow" job=42"#;
+    let doc = convert_log(log_content, ConversionOptions::default()).await;
+    assert_eq!(
+        doc.metadata.get("format").map(String::as_str),
+        Some("logfmt")
+    );
+    let markdown = doc.to_markdown();
+    assert!(markdown.contains("| 1 | 2024-01-15T10:30:45Z | INFO | scheduler | job started |"));
+    assert!(markdown.contains("| 2 | 2024-01-15T10:30:55Z | WARN | scheduler | job slow |"));
+}
+
+#[tokio::test]
+async fn test_log_full_log_option() {
+    let log_content = "2024-01-15 10:30:45 INFO one\n2024-01-15 10:30:46 INFO two";
+
+    let doc = convert_log(
+        log_content,
+        ConversionOptions::default().with_full_log(FullLog::UpToLines(10)),
+    )
+    .await;
+    assert!(doc
+        .to_markdown()
+        .contains("```log\n2024-01-15 10:30:45 INFO one"));
+
+    for full_log in [FullLog::UpToLines(1), FullLog::Never] {
+        let doc = convert_log(
+            log_content,
+            ConversionOptions::default().with_full_log(full_log),
+        )
+        .await;
+        let markdown = doc.to_markdown();
+        assert!(!markdown.contains("```log"));
+        assert!(markdown.contains("*Full log omitted (2 lines).*"));
+        // The analysis is still there
+        assert!(markdown.contains("| 2 | 2024-01-15 10:30:46 | INFO |  | two |"));
+    }
+}
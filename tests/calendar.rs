@@ -0,0 +1,268 @@
+//! iCalendar (.ics) conversion tests
+
+use bytes::Bytes;
+use chrono::{TimeZone, Utc};
+use markitdown::{ConversionOptions, MarkItDown, RecurrenceWindow};
+use std::fs;
+
+fn default_options(ext: &str) -> ConversionOptions {
+    ConversionOptions {
+        file_extension: Some(ext.to_string()),
+        url: None,
+        llm_client: None,
+        image_context_path: None,
+        extract_images: true,
+        force_llm_ocr: false,
+        merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
+    }
+}
+
+const TEST_DIR: &str = "tests/test_documents/calendar";
+
+fn test_file(name: &str) -> String {
+    format!("{}/{}", TEST_DIR, name)
+}
+
+fn march_2024() -> RecurrenceWindow {
+    RecurrenceWindow::new(
+        Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap(),
+        Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap(),
+    )
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[tokio::test]
+async fn test_ics_events_table_sorted_by_start() {
+    let md = MarkItDown::new();
+    let doc = md.convert(&test_file("team.ics"), None).await.unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Platform Team"));
+    assert_eq!(
+        doc.metadata.get("event_count").map(String::as_str),
+        Some("6")
+    );
+    assert_eq!(
+        doc.metadata.get("todo_count").map(String::as_str),
+        Some("1")
+    );
+
+    let content = doc.to_markdown();
+    assert!(content.contains("| Start | End | Event | Location | Repeats |"));
+    // The kickoff is last in the file but first in time
+    let kickoff = content.find("| Quarter kickoff |").unwrap();
+    let standup = content.find("| Daily standup |").unwrap();
+    let demo = content.find("| Sprint demo |").unwrap();
+    assert!(kickoff < standup && standup < demo);
+    assert!(content.contains("| Room 4, HQ |"));
+}
+
+#[tokio::test]
+async fn test_ics_timezone_formatting() {
+    let md = MarkItDown::new();
+    let doc = md.convert(&test_file("team.ics"), None).await.unwrap();
+    let content = doc.to_markdown();
+
+    assert!(content.contains("**Start:** Mon 4 Mar 2024, 09:30 (Europe/Berlin, UTC+01:00)"));
+    // Daylight saving time from the VTIMEZONE rules
+    assert!(content.contains("**Start:** Fri 26 Apr 2024, 16:00 (Europe/Berlin, UTC+02:00)"));
+    assert!(content.contains("**Start:** Fri 15 Mar 2024, 14:00 UTC"));
+    // DURATION instead of DTEND
+    assert!(content.contains("**End:** Fri 15 Mar 2024, 15:30 UTC"));
+    // All-day end dates are exclusive
+    assert!(content.contains("**Start:** Wed 20 Mar 2024 (all day)"));
+    assert!(content.contains("**End:** Thu 21 Mar 2024"));
+    assert!(!content.contains("DateTime"));
+}
+
+#[tokio::test]
+async fn test_ics_attendees_and_alarms() {
+    let md = MarkItDown::new();
+    let doc = md.convert(&test_file("team.ics"), None).await.unwrap();
+    let content = doc.to_markdown();
+
+    assert!(content.contains("**Organizer:** Ann Lee <ann@example.com>"));
+    assert!(content.contains("- Ann Lee <ann@example.com> (chair, accepted)"));
+    // Folded line
+    assert!(content.contains("- Carol Diaz <carol@example.com> (optional, tentative)"));
+    assert!(content.contains("- Bob Chen <bob@example.com> (required, declined)"));
+    assert!(content.contains("- Erin Park <erin@example.com> (optional, no response yet)"));
+    assert!(content.contains("- Room 4 <room4@example.com> (room, accepted)"));
+
+    assert!(content.contains("- Notification 10 minutes before start: Standup starts soon"));
+    assert!(content.contains("- Notification 1 day before start: Prepare slides"));
+    assert!(content.contains("- Email at end: Send the meeting notes"));
+    assert!(content.contains("Agenda:\n1. Roadmap\n2. Hiring; budget"));
+}
+
+#[tokio::test]
+async fn test_ics_todos() {
+    let md = MarkItDown::new();
+    let doc = md.convert(&test_file("team.ics"), None).await.unwrap();
+    let content = doc.to_markdown();
+
+    assert!(content.contains("## Tasks"));
+    assert!(content.contains("### Prepare review slides"));
+    assert!(content.contains("**Due:** Thu 14 Mar 2024, 18:00 (Europe/Berlin, UTC+01:00)"));
+    assert!(content.contains("**Priority:** 1 (high)"));
+    assert!(content.contains("**Progress:** 40%"));
+}
+
+// ============================================================================
+// Recurrence
+// ============================================================================
+
+#[tokio::test]
+async fn test_ics_recurrence_descriptions() {
+    let md = MarkItDown::new();
+    let team = md
+        .convert(&test_file("team.ics"), None)
+        .await
+        .unwrap()
+        .to_markdown();
+    assert!(team.contains(
+        "**Repeats:** Every week on Monday, Wednesday and Friday, until Fri 29 Mar 2024"
+    ));
+    assert!(team.contains("**Except:** Wed 13 Mar 2024, 09:30"));
+    assert!(team.contains("**Repeats:** Every month on the last Friday, 6 times"));
+
+    let holidays = md
+        .convert(&test_file("holidays.ics"), None)
+        .await
+        .unwrap()
+        .to_markdown();
+    assert!(holidays.contains("**Repeats:** Every year on the fourth Thursday in November"));
+    assert!(holidays.contains("**Repeats:** Every month on the last day"));
+    assert!(holidays.contains("**Repeats:** Every 2 weeks on Tuesday and Thursday, 8 times"));
+}
+
+#[tokio::test]
+async fn test_ics_expands_occurrences_in_window() {
+    let md = MarkItDown::new();
+    let options = default_options(".ics").with_recurrence_window(march_2024());
+    let doc = md
+        .convert(&test_file("team.ics"), Some(options))
+        .await
+        .unwrap();
+    let content = doc.to_markdown();
+
+    // 12 standups until 29 March, minus one exception and one moved
+    // occurrence, plus the moved one, the review, the offsite and the kickoff
+    assert_eq!(
+        doc.metadata.get("occurrence_count").map(String::as_str),
+        Some("14")
+    );
+    assert!(content.contains("**Occurrences in window:** 10"));
+    assert!(content.contains("| Fri 29 Mar 2024, 09:30 (Europe/Berlin, UTC+01:00) |"));
+    assert!(!content.contains("| Wed 13 Mar 2024, 09:30"));
+    assert!(!content.contains("| Wed 6 Mar 2024, 09:30"));
+    assert!(content.contains("| Wed 6 Mar 2024, 11:00 (Europe/Berlin, UTC+01:00) |"));
+    // The sprint demo starts in April
+    assert!(!content.contains("| Sprint demo |"));
+}
+
+#[tokio::test]
+async fn test_ics_yearly_and_monthly_expansion() {
+    let md = MarkItDown::new();
+    let window = RecurrenceWindow::new(
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+    );
+    let bytes = fs::read(test_file("holidays.ics")).expect("Failed to read file");
+    let doc = md
+        .convert_bytes(
+            Bytes::from(bytes),
+            Some(default_options(".ics").with_recurrence_window(window)),
+        )
+        .await
+        .unwrap();
+    let content = doc.to_markdown();
+
+    assert!(content.contains("| Thu 28 Nov 2024 (all day) |  | Thanksgiving |"));
+    assert!(content.contains("| Thu 29 Feb 2024 (all day) |  | Payday |"));
+    assert!(content.contains("| Mon 1 Jan 2024 (all day) |  | New Year |"));
+    // COUNT=8 ends the gym sessions in April
+    assert!(content.contains("| Thu 18 Apr 2024, 18:30 |"));
+    assert!(!content.contains("| Tue 30 Apr 2024, 18:30 |"));
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[tokio::test]
+async fn test_ics_out_of_range_values() {
+    let ics = "BEGIN:VCALENDAR\r\n\
+        BEGIN:VEVENT\r\n\
+        UID:long@example.com\r\n\
+        DTSTART:20240304T090000Z\r\n\
+        DURATION:P99999999999D\r\n\
+        SUMMARY:Endless\r\n\
+        BEGIN:VALARM\r\n\
+        ACTION:DISPLAY\r\n\
+        TRIGGER:-PT9999999999999999S\r\n\
+        END:VALARM\r\n\
+        END:VEVENT\r\n\
+        BEGIN:VEVENT\r\n\
+        UID:rare@example.com\r\n\
+        DTSTART:20240305T090000Z\r\n\
+        DURATION:P99999999D\r\n\
+        RRULE:FREQ=YEARLY;INTERVAL=4294967295\r\n\
+        SUMMARY:Rare\r\n\
+        END:VEVENT\r\n\
+        BEGIN:VEVENT\r\n\
+        UID:hourly@example.com\r\n\
+        DTSTART:20240306T090000Z\r\n\
+        RRULE:FREQ=HOURLY;INTERVAL=4294967295\r\n\
+        SUMMARY:Hourly\r\n\
+        END:VEVENT\r\n\
+        BEGIN:VEVENT\r\n\
+        UID:accent@example.com\r\n\
+        DTSTART:20240307T090000Z\r\n\
+        RRULE:FREQ=WEEKLY;BYDAY=éx,TH\r\n\
+        SUMMARY:Accented\r\n\
+        END:VEVENT\r\n\
+        END:VCALENDAR\r\n";
+
+    let md = MarkItDown::new();
+    let doc = md
+        .convert_bytes(
+            Bytes::from_static(ics.as_bytes()),
+            Some(default_options(".ics").with_recurrence_window(march_2024())),
+        )
+        .await
+        .unwrap();
+    let content = doc.to_markdown();
+
+    // Values chrono cannot represent are dropped instead of panicking
+    assert!(content.contains("Endless"));
+    assert!(content.contains("| Tue 5 Mar 2024, 09:00 UTC |"));
+    assert!(content.contains("Every 100000 years"));
+    assert!(content.contains("| Wed 6 Mar 2024, 09:00 UTC |"));
+    // Malformed weekdays are ignored
+    assert!(content.contains("Accented"));
+}
+
+#[tokio::test]
+async fn test_ics_rejects_invalid_input() {
+    let md = MarkItDown::new();
+    let result = md
+        .convert_bytes(
+            Bytes::from_static(b"not a calendar"),
+            Some(default_options(".ics")),
+        )
+        .await;
+    assert!(result.is_err());
+}
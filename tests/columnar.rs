@@ -0,0 +1,192 @@
+//! Parquet (.parquet) and Arrow IPC (.arrow, .feather) conversion tests
+
+use arrow_array::{ArrayRef, Decimal128Array, RecordBatch, UInt64Array};
+use arrow_ipc::writer::StreamWriter;
+use bytes::Bytes;
+use markitdown::{BlobMode, ConversionOptions, DatabaseOptions, MarkItDown};
+use std::fs;
+use std::sync::Arc;
+
+fn default_options(ext: &str) -> ConversionOptions {
+    ConversionOptions {
+        file_extension: Some(ext.to_string()),
+        url: None,
+        llm_client: None,
+        image_context_path: None,
+        extract_images: true,
+        force_llm_ocr: false,
+        merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
+    }
+}
+
+const TEST_DIR: &str = "tests/test_documents/data_formats";
+
+fn test_file(name: &str) -> String {
+    format!("{}/{}", TEST_DIR, name)
+}
+
+async fn convert_products(name: &str, database: DatabaseOptions) -> markitdown::Document {
+    let md = MarkItDown::new();
+    let ext = format!(".{}", name.rsplit('.').next().unwrap());
+    let bytes = fs::read(test_file(name)).expect("Failed to read file");
+    md.convert_bytes(
+        Bytes::from(bytes),
+        Some(default_options(&ext).with_database_options(database)),
+    )
+    .await
+    .unwrap()
+}
+
+// ============================================================================
+// Parquet
+// ============================================================================
+
+#[tokio::test]
+async fn test_parquet_schema_and_metadata() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("products.parquet"), None)
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Parquet File"));
+    for (key, value) in [
+        ("row_count", "10"),
+        ("column_count", "6"),
+        ("row_group_count", "2"),
+        ("compression", "SNAPPY"),
+        ("created_by", "markitdown test fixture"),
+    ] {
+        assert_eq!(doc.metadata.get(key).map(String::as_str), Some(value));
+    }
+
+    let content = doc.to_markdown();
+    assert!(content.contains("**Columns:** 6 · **Row groups:** 2 · **Compression:** SNAPPY"));
+    assert!(content.contains("| Column | Type | Nullable |"));
+    assert!(content.contains("| id | Int32 | No |"));
+    assert!(content.contains("| released | Date32 | Yes |"));
+}
+
+#[tokio::test]
+async fn test_parquet_sample_and_statistics() {
+    let content = convert_products("products.parquet", DatabaseOptions::default())
+        .await
+        .to_markdown();
+    assert!(content.contains("**Row count:** 10"));
+    assert!(content.contains("## Sample Data (first 5 rows)"));
+    assert!(content.contains("| 2 | Office chair | 149 | true | 2024-02-01 | <BLOB 4 bytes> |"));
+    assert!(content.contains("| 3 | Notebook \\| A5 | 4.5 | true | 2024-03-03 | NULL |"));
+    assert!(!content.contains("| 6 | Monitor |"));
+
+    // Statistics cover every row, not just the sample
+    assert!(content.contains("| Column | Type | Nulls | Distinct | Min | Max | Mean |"));
+    assert!(content.contains("| id | Int32 | 0 | 10 | 1 | 10 | 5.5 |"));
+    assert!(content.contains("| product | Utf8 | 1 | 8 | Cable | Pen |  |"));
+    assert!(content.contains("| price | Float64 | 1 | 9 | 1.25 | 229 | 54.1378 |"));
+    assert!(content.contains("| thumbnail | Binary | 8 |  |  |  |  |"));
+
+    let content = convert_products("products.parquet", DatabaseOptions::new().with_full_dump())
+        .await
+        .to_markdown();
+    assert!(content.contains("## Data"));
+    assert!(content.contains("| 10 | Desk lamp | 21 | false | 2024-10-06 | NULL |"));
+}
+
+#[tokio::test]
+async fn test_parquet_binary_images() {
+    let doc = convert_products(
+        "products.parquet",
+        DatabaseOptions::new().with_blobs(BlobMode::Images),
+    )
+    .await;
+    let images = doc.images();
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].id, "data_thumbnail_1");
+    assert_eq!(images[0].mime_type, "image/png");
+    assert!(doc
+        .to_markdown()
+        .contains("<image data_thumbnail_1: image/png, 69 bytes>"));
+}
+
+// ============================================================================
+// Arrow IPC
+// ============================================================================
+
+#[tokio::test]
+async fn test_arrow_stream_and_feather_file() {
+    let stream = convert_products("products.arrow", DatabaseOptions::default()).await;
+    assert_eq!(
+        stream.metadata.get("format").map(String::as_str),
+        Some("Arrow IPC stream")
+    );
+    assert_eq!(
+        stream.metadata.get("batch_count").map(String::as_str),
+        Some("2")
+    );
+
+    let file = convert_products("products.feather", DatabaseOptions::default()).await;
+    assert_eq!(
+        file.metadata.get("format").map(String::as_str),
+        Some("Arrow IPC file")
+    );
+
+    for doc in [&stream, &file] {
+        let content = doc.to_markdown();
+        assert!(content.contains("**Columns:** 6 · **Record batches:** 2"));
+        assert!(content.contains("**Row count:** 10"));
+        assert!(
+            content.contains("| 1 | Desk lamp | 19.99 | false | 2024-01-01 | <BLOB 69 bytes> |")
+        );
+        assert!(content.contains("| in_stock | Boolean | 0 | 2 | false | true |  |"));
+    }
+}
+
+#[tokio::test]
+async fn test_arrow_large_unsigned_and_decimal_values() {
+    let ids: ArrayRef = Arc::new(UInt64Array::from(vec![u64::MAX, 7]));
+    let amounts: ArrayRef = Arc::new(
+        Decimal128Array::from(vec![123_456_789_012_345_678_901_234_567_891_i128, 1])
+            .with_precision_and_scale(38, 10)
+            .unwrap(),
+    );
+    let batch = RecordBatch::try_from_iter([("id", ids), ("amount", amounts)]).unwrap();
+    let mut bytes = Vec::new();
+    let mut writer = StreamWriter::try_new(&mut bytes, &batch.schema()).unwrap();
+    writer.write(&batch).unwrap();
+    writer.finish().unwrap();
+    drop(writer);
+
+    let md = MarkItDown::new();
+    let content = md
+        .convert_bytes(Bytes::from(bytes), Some(default_options(".arrow")))
+        .await
+        .unwrap()
+        .to_markdown();
+    // Neither value goes through i64 or f64
+    assert!(content.contains("| 18446744073709551615 | 12345678901234567890.1234567891 |"));
+    assert!(content.contains("| 7 | 0.0000000001 |"));
+}
+
+#[tokio::test]
+async fn test_columnar_rejects_invalid_input() {
+    let md = MarkItDown::new();
+    for ext in [".parquet", ".arrow"] {
+        let result = md
+            .convert_bytes(
+                Bytes::from_static(b"not a columnar file"),
+                Some(default_options(ext)),
+            )
+            .await;
+        assert!(result.is_err(), "{} should fail", ext);
+    }
+}
@@ -11,6 +11,16 @@ fn default_options(ext: &str) -> ConversionOptions {
         extract_images: true,
         force_llm_ocr: false,
         merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
     }
 }
 
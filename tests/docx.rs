@@ -11,6 +11,16 @@ fn default_options(ext: &str) -> ConversionOptions {
         extract_images: true,
         force_llm_ocr: false,
         merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
     }
 }
 
@@ -252,3 +262,26 @@ async fn test_docx_word_sample() {
     let content = doc.to_markdown();
     assert!(!content.is_empty(), "Content should not be empty");
 }
+
+#[tokio::test]
+async fn test_docx_core_properties() {
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert(
+            "tests/test_documents/documents/word_sample.docx",
+            Some(default_options(".docx")),
+        )
+        .await
+        .unwrap();
+
+    // An empty `dc:title` leaves the document untitled
+    assert_eq!(doc.title, None);
+    assert_eq!(doc.properties.authors, vec!["Christoph Auer".to_string()]);
+    assert_eq!(
+        doc.properties.modified.map(|d| d.to_rfc3339()).as_deref(),
+        Some("2024-10-15T11:34:00+00:00")
+    );
+    assert!(doc.properties.keywords.is_empty());
+    assert_eq!(doc.properties.source_format.as_deref(), Some("docx"));
+    assert_eq!(doc.metadata.get("revision").map(String::as_str), Some("7"));
+}
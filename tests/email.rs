@@ -3,7 +3,7 @@
 //! Tests for email file format conversions.
 
 use bytes::Bytes;
-use markitdown::{ConversionOptions, MarkItDown};
+use markitdown::{ConversionOptions, MarkItDown, QuotedReplyMode};
 use std::fs;
 
 fn default_options(ext: &str) -> ConversionOptions {
@@ -15,6 +15,16 @@ fn default_options(ext: &str) -> ConversionOptions {
         extract_images: true,
         force_llm_ocr: false,
         merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
     }
 }
 
@@ -66,33 +76,312 @@ async fn test_eml_bytes_conversion() {
 }
 
 // ============================================================================
-// MSG Tests (if .msg files exist)
+// Threading Headers and Inline Images
+// ============================================================================
+
+#[tokio::test]
+async fn test_eml_thread_headers_metadata() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("eml/with_attachments/thread_reply.eml"), None)
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Re: Weekly shipment report"));
+    let meta = |key: &str| doc.metadata.get(key).map(String::as_str);
+    assert_eq!(
+        meta("reply_to"),
+        Some("Shipping Desk <shipping-desk@example.com>")
+    );
+    assert_eq!(meta("message_id"), Some("report-0305@example.com"));
+    assert_eq!(meta("in_reply_to"), Some("report-0227@example.com"));
+    assert_eq!(
+        meta("references"),
+        Some("report-0220@example.com report-0227@example.com")
+    );
+    assert_eq!(meta("date"), Some("2024-03-05T09:30:00Z"));
+    assert_eq!(
+        doc.properties.authors,
+        vec!["Priya Nair <priya@example.com>".to_string()]
+    );
+    assert_eq!(
+        doc.properties.created.map(|d| d.to_rfc3339()).as_deref(),
+        Some("2024-03-05T09:30:00+00:00")
+    );
+
+    let content = doc.to_markdown();
+    assert!(content.contains("**Reply-To:** Shipping Desk <shipping-desk@example.com>"));
+    assert!(content.contains("**CC:** Sam Ortiz <sam@example.com>"));
+}
+
+#[tokio::test]
+async fn test_eml_cid_inline_images() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("eml/with_attachments/thread_reply.eml"), None)
+        .await
+        .unwrap();
+
+    // The HTML body's cid: image and the unreferenced inline logo
+    let images = doc.images();
+    assert_eq!(images.len(), 2);
+    assert!(images.iter().all(|img| img.mime_type == "image/png"));
+    assert!(images.iter().all(|img| !img.data.is_empty()));
+    assert_eq!(
+        images[0].alt_text.as_deref(),
+        Some("Weekly shipments chart")
+    );
+    assert_eq!(images[1].alt_text.as_deref(), Some("logo.png"));
+
+    // Inline images are not listed as attachments
+    assert_eq!(
+        doc.metadata.get("attachment_count").map(String::as_str),
+        Some("2")
+    );
+    assert!(!doc.to_markdown().contains("**chart.png**"));
+}
+
+// ============================================================================
+// Attachment Conversion
+// ============================================================================
+
+#[tokio::test]
+async fn test_eml_attachments_converted() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("eml/with_attachments/thread_reply.eml"), None)
+        .await
+        .unwrap();
+    let content = doc.to_markdown();
+
+    assert!(content.contains("## Attachments"));
+    assert!(content.contains("- **shipments.csv** (text/csv, "));
+
+    // The CSV attachment is converted by the CSV converter
+    assert!(content.contains("### shipments.csv"));
+    assert!(content.contains("| Week | Shipments | Returns |"));
+
+    // The forwarded message is converted by the email converter
+    assert!(content.contains("### Carrier rates for Q2"));
+    assert!(content.contains("**From:** Dana Okafor <dana@example.com>"));
+    assert!(content.contains("4.10 per parcel"));
+}
+
+#[tokio::test]
+async fn test_eml_png_attachment_extracted() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(
+            &test_file("eml/with_attachments/thunderbird_png_attachment.eml"),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let images = doc.images();
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].mime_type, "image/png");
+    assert_eq!(images[0].alt_text.as_deref(), Some("test_image.png"));
+}
+
+#[tokio::test]
+async fn test_nested_converter_from_registry() {
+    let md = MarkItDown::new();
+    let nested = md.nested_converter();
+
+    let doc = nested
+        .convert_nested(
+            Bytes::from_static(b"name,qty\nbolts,12\n"),
+            Some("parts.csv"),
+            None,
+            ConversionOptions::default(),
+        )
+        .await
+        .unwrap()
+        .expect("CSV should be converted");
+    assert!(doc.to_markdown().contains("| bolts | 12 |"));
+
+    // Unknown formats are left alone
+    let unknown = nested
+        .convert_nested(
+            Bytes::from_static(b"\x00\x01\x02"),
+            Some("blob.unknownext"),
+            Some("application/octet-stream"),
+            ConversionOptions::default(),
+        )
+        .await
+        .unwrap();
+    assert!(unknown.is_none());
+}
+
+// ============================================================================
+// Quoted Replies and Signatures
+// ============================================================================
+
+async fn convert_reply(name: &str, mode: QuotedReplyMode) -> String {
+    let md = MarkItDown::new();
+    let options = default_options(".eml").with_quoted_replies(mode);
+    md.convert(&test_file(&format!("eml/replies/{}", name)), Some(options))
+        .await
+        .unwrap()
+        .to_markdown()
+}
+
+#[tokio::test]
+async fn test_quoted_replies_kept_by_default() {
+    let content = convert_reply("plain_reply.eml", QuotedReplyMode::Keep).await;
+
+    assert!(content.contains("Platform Team Lead"));
+    assert!(content.contains("> Sounds good. I will freeze deploys on Friday evening."));
+}
+
+#[tokio::test]
+async fn test_quoted_replies_stripped() {
+    let content = convert_reply("plain_reply.eml", QuotedReplyMode::Strip).await;
+
+    assert!(content.contains("a deploy freeze from Friday 18:00 works for me."));
+    assert!(!content.contains("Platform Team Lead"));
+    assert!(!content.contains("wrote:"));
+    assert!(!content.contains("Sounds good"));
+}
+
+#[tokio::test]
+async fn test_quoted_replies_collapsed() {
+    let content = convert_reply("plain_reply.eml", QuotedReplyMode::Collapse).await;
+
+    assert!(content.contains("a deploy freeze from Friday 18:00 works for me."));
+    // Signature and earlier conversation become quote blocks, keeping
+    // deeper quoting levels
+    assert!(content.contains("> Alice Moreno\n> Platform Team Lead"));
+    assert!(content.contains("> Sounds good. I will freeze deploys on Friday evening."));
+    assert!(content.contains("> > We move the orders table"));
+}
+
+#[tokio::test]
+async fn test_inline_quotes_stripped() {
+    let content = convert_reply("inline_reply.eml", QuotedReplyMode::Strip).await;
+
+    assert!(content.contains("Yes, I will have it ready by Thursday."));
+    assert!(content.contains("Carol is on it."));
+    assert!(!content.contains("rollback script?"));
+    assert!(!content.contains("Sent from my phone"));
+}
+
+#[tokio::test]
+async fn test_outlook_html_reply() {
+    let stripped = convert_reply("outlook_html_reply.eml", QuotedReplyMode::Strip).await;
+    assert!(stripped.contains("Approved, go ahead with the new cluster."));
+    assert!(!stripped.contains("CONFIDENTIALITY NOTICE"));
+    assert!(!stripped.contains("Could you approve the budget"));
+
+    let collapsed = convert_reply("outlook_html_reply.eml", QuotedReplyMode::Collapse).await;
+    assert!(collapsed.contains("> **From:** Alice Moreno <alice@example.com>"));
+    assert!(collapsed.contains("> Could you approve the budget"));
+    assert!(collapsed.contains("> CONFIDENTIALITY NOTICE"));
+}
+
+#[tokio::test]
+async fn test_forwarded_message_not_stripped() {
+    let md = MarkItDown::new();
+    let email = "From: Bob Chen <bob@example.com>\r\n\
+        To: Alice Moreno <alice@example.com>\r\n\
+        Subject: Fwd: Carrier rates\r\n\
+        Content-Type: text/plain; charset=utf-8\r\n\r\n\
+        FYI, see below.\r\n\r\n\
+        ---------- Forwarded message ---------\r\n\
+        From: Dana Okafor <dana@example.com>\r\n\
+        Date: Mon, 4 Mar 2024 16:20\r\n\
+        Subject: Carrier rates\r\n\r\n\
+        The regional carrier quoted 4.10 per parcel.\r\n";
+    let options = default_options(".eml").with_quoted_replies(QuotedReplyMode::Strip);
+    let doc = md
+        .convert_bytes(Bytes::from(email), Some(options))
+        .await
+        .unwrap();
+
+    assert!(doc.to_markdown().contains("4.10 per parcel"));
+}
+
+// ============================================================================
+// MSG Tests
 // ============================================================================
 
 #[tokio::test]
 async fn test_msg_fake_email() {
     let md = MarkItDown::new();
-    let path = test_file("fake_email.msg");
+    let doc = md
+        .convert(&test_file("fake_email.msg"), None)
+        .await
+        .unwrap();
 
-    // Check if file exists first
-    if !std::path::Path::new(&path).exists() {
-        return; // Skip if no .msg file
-    }
+    assert_eq!(doc.title.as_deref(), Some("Test Email"));
+    assert_eq!(
+        doc.metadata.get("from").map(String::as_str),
+        Some("Matthew Robinson <mrobinson@unstructured.io>")
+    );
+    assert_eq!(
+        doc.metadata.get("date").map(String::as_str),
+        Some("2023-03-28T17:00:31Z")
+    );
+    // Message-ID comes from the stored transport headers
+    assert!(doc
+        .metadata
+        .get("message_id")
+        .is_some_and(|id| id.ends_with("@mail.gmail.com")));
 
-    let result = md.convert(&path, None).await;
-    // MSG format may not be fully supported, so we just check it doesn't panic
-    let _ = result;
+    let content = doc.to_markdown();
+    assert!(content.contains("**To:** Matthew Robinson <mrobinson@unstructured.io>"));
+    assert!(content.contains("Roses are red"));
 }
 
 #[tokio::test]
 async fn test_msg_with_attachment() {
     let md = MarkItDown::new();
-    let path = test_file("fake_email_attachment.msg");
+    let doc = md
+        .convert(&test_file("fake_email_attachment.msg"), None)
+        .await
+        .unwrap();
+    let content = doc.to_markdown();
 
-    if !std::path::Path::new(&path).exists() {
-        return;
-    }
+    assert_eq!(
+        doc.metadata.get("attachment_count").map(String::as_str),
+        Some("1")
+    );
+    assert!(content.contains("- **fake-attachment.txt** (text/plain, 30 bytes)"));
+    assert!(content.contains("### fake-attachment.txt"));
+    assert!(content.contains("Hey this is a fake attachment!"));
+}
+
+#[tokio::test]
+async fn test_msg_png_attachment() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(
+            &test_file("msg/with_attachments/msg_with_png_attachment.msg"),
+            None,
+        )
+        .await
+        .unwrap();
 
-    let result = md.convert(&path, None).await;
-    let _ = result;
+    assert!(doc.to_markdown().contains("This is a message"));
+    let images = doc.images();
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].mime_type, "image/png");
+    assert_eq!(images[0].alt_text.as_deref(), Some("canvas.png"));
+}
+
+#[tokio::test]
+async fn test_msg_bytes_conversion() {
+    let md = MarkItDown::new();
+    let bytes = fs::read(test_file("msg/simple/simple_msg.msg")).expect("Failed to read file");
+    let doc = md
+        .convert_bytes(Bytes::from(bytes), Some(default_options(".msg")))
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("This is the subject"));
+    assert_eq!(
+        doc.metadata.get("to").map(String::as_str),
+        Some("crocodile@neverland.com")
+    );
 }
@@ -11,6 +11,16 @@ fn default_options(ext: &str) -> ConversionOptions {
         extract_images: true,
         force_llm_ocr: false,
         merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
     }
 }
 
@@ -159,3 +169,32 @@ async fn test_xlsx_table_format() {
         "Excel should be converted to markdown table format"
     );
 }
+
+// ============================================================================
+// Provenance
+// ============================================================================
+
+#[tokio::test]
+async fn test_xlsx_sheet_and_cell_range_provenance() {
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert(
+            "tests/test_documents/spreadsheets/stanley_cups.xlsx",
+            Some(default_options(".xlsx")),
+        )
+        .await
+        .unwrap();
+
+    let page = &doc.pages[1];
+    let heading = page.provenance_of(0).unwrap();
+    assert_eq!(heading.sheet.as_deref(), Some("Stanley Cups Since 67"));
+    assert_eq!(heading.cell_range, None);
+    let table = page.provenance_of(1).unwrap();
+    assert_eq!(table.sheet.as_deref(), Some("Stanley Cups Since 67"));
+    assert_eq!(table.cell_range.as_deref(), Some("A1:C5"));
+
+    // Provenance is part of the JSON form of a page
+    let json = serde_json::to_value(page).unwrap();
+    assert_eq!(json["provenance"][1]["cell_range"], "A1:C5");
+    assert!(json["provenance"][1].get("slide").is_none());
+}
@@ -1,6 +1,13 @@
 //! HTML conversion tests using kreuzberg test documents
+use async_trait::async_trait;
 use bytes::Bytes;
-use markitdown::{model::ConversionOptions, MarkItDown};
+use markitdown::error::MarkitdownError;
+use markitdown::{
+    model::ConversionOptions, Alignment, ContentBlock, ImageFetcher, MarkItDown,
+    ObjectStoreImageFetcher, TableCell,
+};
+use object_store::{memory::InMemory, path::Path, ObjectStore};
+use std::sync::Arc;
 
 fn default_options(ext: &str) -> ConversionOptions {
     ConversionOptions {
@@ -11,6 +18,16 @@ fn default_options(ext: &str) -> ConversionOptions {
         extract_images: true,
         force_llm_ocr: false,
         merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
     }
 }
 
@@ -231,3 +248,651 @@ async fn test_html_medical() {
     let content = doc.to_markdown();
     assert!(!content.is_empty(), "Content should not be empty");
 }
+
+// ============================================================================
+// Structured Conversion
+// ============================================================================
+
+const ARTICLE_PAGE: &str = r#"<!doctype html>
+<html lang="en">
+<head>
+  <title>Rust Ownership Explained</title>
+  <meta name="description" content="A short guide to ownership in Rust.">
+  <meta property="og:title" content="Ownership in Rust">
+  <meta property="og:type" content="article">
+  <link rel="canonical" href="https://example.com/rust/ownership">
+  <script>var tracking = "should not appear";</script>
+</head>
+<body>
+  <nav class="site-nav"><ul><li><a href="/">Home</a></li><li><a href="/blog">Blog</a></li></ul></nav>
+  <div id="cookie-banner">We use cookies to improve your experience.</div>
+  <main>
+    <article>
+      <h1>Ownership Explained</h1>
+      <p>Every value in Rust has a single owner, and the value is dropped when the owner goes out of scope.
+         This rule lets the compiler free memory without a garbage collector.</p>
+      <pre><code class="language-rust">fn main() {
+    let s = String::from("hello");
+}</code></pre>
+      <figure>
+        <img src="data:image/png;base64,iVBORw0KGgo=" alt="Ownership diagram">
+        <figcaption>Moving a value transfers ownership.</figcaption>
+      </figure>
+      <table>
+        <thead>
+          <tr><th rowspan="2">Type</th><th colspan="2">Semantics</th></tr>
+          <tr><th>Copy</th><th>Move</th></tr>
+        </thead>
+        <tbody>
+          <tr><td>i32</td><td>yes</td><td>no</td></tr>
+          <tr><td>String</td><td>no</td><td>yes</td></tr>
+        </tbody>
+      </table>
+    </article>
+  </main>
+  <footer class="site-footer">Copyright 2024 Example Corp. All rights reserved.</footer>
+</body>
+</html>"#;
+
+#[tokio::test]
+async fn test_html_metadata() {
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert_bytes(
+            Bytes::from_static(ARTICLE_PAGE.as_bytes()),
+            Some(default_options(".html")),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Rust Ownership Explained"));
+    assert_eq!(
+        doc.metadata.get("description").map(String::as_str),
+        Some("A short guide to ownership in Rust.")
+    );
+    assert_eq!(
+        doc.metadata.get("og:title").map(String::as_str),
+        Some("Ownership in Rust")
+    );
+    assert_eq!(
+        doc.metadata.get("canonical_url").map(String::as_str),
+        Some("https://example.com/rust/ownership")
+    );
+    assert_eq!(doc.metadata.get("language").map(String::as_str), Some("en"));
+    assert_eq!(doc.properties.language.as_deref(), Some("en"));
+    assert_eq!(doc.properties.source_format.as_deref(), Some("html"));
+    assert!(!doc.to_markdown().contains("should not appear"));
+}
+
+#[tokio::test]
+async fn test_html_main_content_only() {
+    let markitdown = MarkItDown::new();
+
+    let full = markitdown
+        .convert_bytes(
+            Bytes::from_static(ARTICLE_PAGE.as_bytes()),
+            Some(default_options(".html")),
+        )
+        .await
+        .unwrap()
+        .to_markdown();
+    assert!(full.contains("We use cookies"));
+    assert!(full.contains("Copyright 2024"));
+
+    let main = markitdown
+        .convert_bytes(
+            Bytes::from_static(ARTICLE_PAGE.as_bytes()),
+            Some(default_options(".html").with_main_content_only(true)),
+        )
+        .await
+        .unwrap()
+        .to_markdown();
+    assert!(main.contains("Every value in Rust has a single owner"));
+    assert!(
+        !main.contains("We use cookies"),
+        "Cookie banner kept: {}",
+        main
+    );
+    assert!(!main.contains("Copyright 2024"), "Footer kept: {}", main);
+    assert!(!main.contains("[Blog](/blog)"), "Navigation kept: {}", main);
+}
+
+#[tokio::test]
+async fn test_html_structured_blocks() {
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert_bytes(
+            Bytes::from_static(ARTICLE_PAGE.as_bytes()),
+            Some(default_options(".html").with_main_content_only(true)),
+        )
+        .await
+        .unwrap();
+
+    let blocks = &doc.pages[0].content;
+    assert!(blocks.iter().any(|b| matches!(
+        b,
+        ContentBlock::Heading { level: 1, text } if text == "Ownership Explained"
+    )));
+    assert!(blocks.iter().any(|b| matches!(
+        b,
+        ContentBlock::Code { language: Some(lang), code } if lang == "rust" && code.contains("String::from")
+    )));
+
+    let image = doc.images()[0];
+    assert_eq!(image.alt_text.as_deref(), Some("Ownership diagram"));
+    assert_eq!(
+        image.caption.as_deref(),
+        Some("Moving a value transfers ownership.")
+    );
+}
+
+#[tokio::test]
+async fn test_html_table_spans() {
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert_bytes(
+            Bytes::from_static(ARTICLE_PAGE.as_bytes()),
+            Some(default_options(".html")),
+        )
+        .await
+        .unwrap();
+
+    let table = doc.pages[0]
+        .content
+        .iter()
+        .find_map(|b| match b {
+            ContentBlock::RichTable(table) => Some(table),
+            _ => None,
+        })
+        .expect("RichTable block expected");
+
+    assert_eq!(
+        table.header,
+        vec![
+            vec![
+                TableCell::new("Type").with_rowspan(2),
+                TableCell::new("Semantics").with_colspan(2),
+            ],
+            vec![TableCell::new("Copy"), TableCell::new("Move")],
+        ]
+    );
+    assert_eq!(table.body.len(), 2);
+    assert_eq!(
+        table.body[1],
+        vec![
+            TableCell::new("String"),
+            TableCell::new("no"),
+            TableCell::new("yes")
+        ]
+    );
+    assert_eq!(table.column_count(), 3);
+
+    let markdown = doc.to_markdown();
+    assert!(markdown.contains(r#"<th rowspan="2">Type</th><th colspan="2">Semantics</th>"#));
+}
+
+#[tokio::test]
+async fn test_html_table_caption_alignment_and_footer() {
+    let html = r#"<table>
+      <caption>Quarterly | sales</caption>
+      <thead><tr><th>Region</th><th align="right">Total</th></tr></thead>
+      <tfoot><tr><td>All</td><td style="text-align: right">10</td></tr></tfoot>
+      <tbody>
+        <tr><td>North</td><td>4</td></tr>
+        <tr><td>South | East</td><td>6</td></tr>
+      </tbody>
+    </table>"#;
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert_bytes(Bytes::from(html), Some(default_options(".html")))
+        .await
+        .unwrap();
+
+    let ContentBlock::RichTable(table) = &doc.pages[0].content[0] else {
+        panic!("RichTable block expected");
+    };
+    assert_eq!(table.caption.as_deref(), Some("Quarterly | sales"));
+    assert_eq!(table.alignments, vec![Alignment::Default, Alignment::Right]);
+    assert_eq!(table.header.len(), 1);
+    // The footer is moved after the body, as browsers show it
+    assert_eq!(table.body.len(), 2);
+    assert_eq!(table.body[1][0].text, "South | East");
+    assert_eq!(
+        table.footer,
+        vec![vec![TableCell::new("All"), TableCell::new("10")]]
+    );
+}
+
+#[tokio::test]
+async fn test_html_structured_blocks() {
+    let html = r#"<body>
+      <p>Read the <a href="https://example.com/guide">setup <b>guide</b></a> first.<br>
+        Then run <code>make</code>.</p>
+      <hr>
+      <ul>
+        <li><input type="checkbox" checked disabled> Write tests</li>
+        <li><input type="checkbox"> Ship
+          <ol><li>Tag</li><li>Publish</li></ol>
+        </li>
+      </ul>
+      <dl>
+        <dt>Crate</dt><dd>A compilation unit</dd>
+        <div><dt>Edition</dt><dd>A set of <em>language</em> changes</dd></div>
+      </dl>
+    </body>"#;
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert_bytes(Bytes::from(html), Some(default_options(".html")))
+        .await
+        .unwrap();
+    let blocks = &doc.pages[0].content;
+
+    assert_eq!(blocks.len(), 4);
+    let ContentBlock::Paragraph(spans) = &blocks[0] else {
+        panic!("Paragraph expected, got {:?}", blocks[0]);
+    };
+    assert_eq!(
+        spans,
+        &vec![
+            Inline::text("Read the "),
+            Inline::Link {
+                content: vec![
+                    Inline::text("setup "),
+                    Inline::Strong(vec![Inline::text("guide")])
+                ],
+                url: "https://example.com/guide".to_string(),
+                title: None,
+            },
+            Inline::text(" first."),
+            Inline::LineBreak,
+            Inline::text("Then run "),
+            Inline::Code("make".to_string()),
+            Inline::text("."),
+        ]
+    );
+    assert!(matches!(blocks[1], ContentBlock::Rule));
+
+    let ContentBlock::NestedList {
+        ordered: false,
+        items,
+    } = &blocks[2]
+    else {
+        panic!("NestedList expected, got {:?}", blocks[2]);
+    };
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].checked, Some(true));
+    assert_eq!(items[0].content, vec![Inline::text("Write tests")]);
+    assert_eq!(items[1].checked, Some(false));
+    assert!(matches!(
+        &items[1].children[..],
+        [ContentBlock::NestedList { ordered: true, items }] if items.len() == 2
+    ));
+
+    let ContentBlock::DefinitionList(definitions) = &blocks[3] else {
+        panic!("DefinitionList expected, got {:?}", blocks[3]);
+    };
+    assert_eq!(definitions.len(), 2);
+    assert_eq!(definitions[0].term, vec![Inline::text("Crate")]);
+    assert_eq!(
+        definitions[1].definitions,
+        vec![vec![
+            Inline::text("A set of "),
+            Inline::Emphasis(vec![Inline::text("language")]),
+            Inline::text(" changes"),
+        ]]
+    );
+
+    let content = doc.to_markdown();
+    assert!(content.contains(
+        "Read the [setup **guide**](https://example.com/guide) first.\\\nThen run `make`."
+    ));
+    assert!(content.contains("- [x] Write tests\n- [ ] Ship\n  1. Tag\n  2. Publish"));
+    assert!(content.contains("Crate\n: A compilation unit"));
+}
+
+#[tokio::test]
+async fn test_html_simple_table_block() {
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert(
+            "tests/test_documents/web/simple_table.html",
+            Some(default_options(".html")),
+        )
+        .await
+        .unwrap();
+
+    let headers = doc.pages[0]
+        .content
+        .iter()
+        .find_map(|b| match b {
+            ContentBlock::Table { headers, .. } => Some(headers.clone()),
+            _ => None,
+        })
+        .expect("Table block expected");
+    assert_eq!(headers, vec!["Product", "Category", "Price", "Stock"]);
+}
+
+// ============================================================================
+// Link Resolution Tests
+// ============================================================================
+
+const LINKED_PAGE: &str = r##"<html><body>
+<p>See the <a href="../guide/intro.html">guide</a>, the <a href="#usage">usage notes</a>
+and <a href="mailto:team@example.com">mail us</a>.</p>
+<img src="img/logo.png" alt="Logo">
+</body></html>"##;
+
+#[tokio::test]
+async fn test_html_resolves_relative_links_against_url() {
+    let markitdown = MarkItDown::new();
+    let mut options = default_options(".html").with_images(false);
+    options.url = Some("https://example.com/docs/page.html".to_string());
+
+    let doc = markitdown
+        .convert_bytes(Bytes::from_static(LINKED_PAGE.as_bytes()), Some(options))
+        .await
+        .unwrap();
+    let content = doc.to_markdown();
+
+    assert!(content.contains("[guide](https://example.com/guide/intro.html)"));
+    assert!(content.contains("[usage notes](#usage)"));
+    assert!(content.contains("[mail us](mailto:team@example.com)"));
+    assert!(content.contains("![Logo](https://example.com/docs/img/logo.png)"));
+}
+
+#[tokio::test]
+async fn test_html_base_href_overrides_url() {
+    let html = r#"<html><head><base href="/static/"></head><body>
+<p><a href="faq.html">FAQ</a></p>
+<img src="chart.png" alt="Chart">
+</body></html>"#;
+
+    let markitdown = MarkItDown::new();
+    let options = default_options(".html").with_url("https://example.com/blog/post.html");
+    let doc = markitdown
+        .convert_bytes(Bytes::from(html), Some(options))
+        .await
+        .unwrap();
+
+    assert!(doc
+        .to_markdown()
+        .contains("[FAQ](https://example.com/static/faq.html)"));
+    let image = doc.pages[0]
+        .content
+        .iter()
+        .find_map(|b| match b {
+            ContentBlock::Image(img) => Some(img),
+            _ => None,
+        })
+        .expect("Image block expected");
+    assert_eq!(
+        image.source_path.as_deref(),
+        Some("https://example.com/static/chart.png")
+    );
+}
+
+#[tokio::test]
+async fn test_html_links_unchanged_without_base() {
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert_bytes(
+            Bytes::from_static(LINKED_PAGE.as_bytes()),
+            Some(default_options(".html").with_images(false)),
+        )
+        .await
+        .unwrap();
+
+    let content = doc.to_markdown();
+    assert!(content.contains("[guide](../guide/intro.html)"));
+    assert!(content.contains("![Logo](img/logo.png)"));
+}
+
+#[tokio::test]
+async fn test_html_image_fetcher_loads_linked_images() {
+    let png = std::fs::read("tests/test_documents/images/test_hello_world.png").unwrap();
+    let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+    store
+        .put(
+            &Path::from("site/img/logo.png"),
+            Bytes::from(png.clone()).into(),
+        )
+        .await
+        .unwrap();
+
+    let fetcher = ObjectStoreImageFetcher::new(store).with_base_dir("site");
+    let options = default_options(".html").with_image_fetcher(Arc::new(fetcher));
+
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert_bytes(Bytes::from_static(LINKED_PAGE.as_bytes()), Some(options))
+        .await
+        .unwrap();
+
+    let image = doc.pages[0]
+        .content
+        .iter()
+        .find_map(|b| match b {
+            ContentBlock::Image(img) => Some(img),
+            _ => None,
+        })
+        .expect("Image block expected");
+    assert_eq!(image.mime_type, "image/png");
+    assert_eq!(image.data.len(), png.len());
+    assert_eq!(image.alt_text.as_deref(), Some("Logo"));
+}
+
+#[tokio::test]
+async fn test_html_image_fetcher_keeps_missing_images_as_placeholders() {
+    let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+    let options =
+        default_options(".html").with_image_fetcher(Arc::new(ObjectStoreImageFetcher::new(store)));
+
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert_bytes(Bytes::from_static(LINKED_PAGE.as_bytes()), Some(options))
+        .await
+        .unwrap();
+
+    let image = doc.pages[0]
+        .content
+        .iter()
+        .find_map(|b| match b {
+            ContentBlock::Image(img) => Some(img),
+            _ => None,
+        })
+        .expect("Image block expected");
+    assert!(image.data.is_empty());
+    assert_eq!(image.mime_type, "image/unknown");
+}
+
+/// An image fetcher whose every request fails
+struct FailingFetcher;
+
+#[async_trait]
+impl ImageFetcher for FailingFetcher {
+    async fn fetch(&self, url: &str) -> Result<Option<Bytes>, MarkitdownError> {
+        Err(MarkitdownError::NetworkError(format!(
+            "HTTP 503 fetching {}",
+            url
+        )))
+    }
+}
+
+#[tokio::test]
+async fn test_html_image_fetch_errors_keep_placeholders() {
+    let options = default_options(".html").with_image_fetcher(Arc::new(FailingFetcher));
+
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert_bytes(Bytes::from_static(LINKED_PAGE.as_bytes()), Some(options))
+        .await
+        .unwrap();
+
+    let images = doc.images();
+    assert_eq!(images.len(), 1);
+    assert!(images[0].data.is_empty());
+    assert!(doc
+        .metadata
+        .get("image_fetch_errors")
+        .is_some_and(|errors| errors.contains("img/logo.png") && errors.contains("503")));
+}
+
+// ============================================================================
+// Well-known Web Sources
+// ============================================================================
+
+#[tokio::test]
+async fn test_html_wikipedia_article() {
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert(
+            "tests/test_documents/web/taylor_swift.html",
+            Some(default_options(".html")),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Taylor Swift"));
+    assert_eq!(
+        doc.metadata.get("web_source").map(String::as_str),
+        Some("wikipedia")
+    );
+
+    let blocks = &doc.pages[0].content;
+    let infobox = blocks
+        .iter()
+        .find_map(|b| match b {
+            ContentBlock::Table { headers, rows } => Some((headers, rows)),
+            _ => None,
+        })
+        .expect("Infobox table expected");
+    assert_eq!(infobox.0, &vec!["Field".to_string(), "Value".to_string()]);
+    assert!(infobox.1.iter().any(|row| row[0] == "Born"));
+
+    let image = blocks
+        .iter()
+        .find_map(|b| match b {
+            ContentBlock::Image(img) => Some(img),
+            _ => None,
+        })
+        .expect("Infobox image expected");
+    assert_eq!(image.caption.as_deref(), Some("Swift in 2023"));
+
+    let headings: Vec<&str> = blocks
+        .iter()
+        .filter_map(|b| match b {
+            ContentBlock::Heading { text, .. } => Some(text.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert!(headings.contains(&"Life and career"));
+    assert!(!headings.contains(&"References"));
+
+    let content = doc.to_markdown();
+    assert_eq!(content.matches("# Taylor Swift\n").count(), 1);
+    // Site-relative links resolve against the canonical URL
+    assert!(content.contains("(https://en.wikipedia.org/wiki/Big_Machine_Records)"));
+    assert!(!content.contains("Retrieved from"));
+}
+
+#[tokio::test]
+async fn test_html_youtube_watch_page() {
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert(
+            "tests/test_documents/web/youtube_watch.html",
+            Some(default_options(".html")),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Rust Ownership in Ten Minutes"));
+    let meta = |key: &str| doc.metadata.get(key).map(String::as_str);
+    assert_eq!(meta("web_source"), Some("youtube"));
+    assert_eq!(meta("video_id"), Some("abc123XYZ_0"));
+    assert_eq!(meta("channel"), Some("Ferris Explains"));
+    assert_eq!(meta("duration_seconds"), Some("612"));
+    assert_eq!(meta("caption_languages"), Some("en, de (auto-generated)"));
+
+    let content = doc.to_markdown();
+    assert!(content.contains("**Views:** 1,234,567"));
+    assert!(content.contains("## Description"));
+    assert!(content.contains("Code samples: https://example.com/ownership"));
+    assert!(content.contains("## Transcript"));
+    assert!(content.contains("[0:04] Today we talk about ownership."));
+    assert!(content.contains("[1:05] Every value has a single owner."));
+    assert!(!content.contains("Comments are turned off"));
+}
+
+#[tokio::test]
+async fn test_html_bing_results() {
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert(
+            "tests/test_documents/web/bing_serp.html",
+            Some(default_options(".html")),
+        )
+        .await
+        .unwrap();
+
+    let meta = |key: &str| doc.metadata.get(key).map(String::as_str);
+    assert_eq!(meta("web_source"), Some("bing"));
+    assert_eq!(meta("search_query"), Some("rust ownership"));
+    assert_eq!(meta("result_count"), Some("2"));
+
+    let content = doc.to_markdown();
+    assert!(content.contains("A Bing search for 'rust ownership' found the following results:"));
+    // Click-tracking redirects are unwrapped
+    assert!(content
+        .contains("**[Learn Rust - Rust Programming Language](https://www.rust-lang.org/learn)**"));
+    assert!(content.contains("Ownership is a set of rules"));
+    assert!(!content.contains("Sponsored"));
+    assert!(!content.contains("Privacy and Cookies"));
+}
+
+#[tokio::test]
+async fn test_html_duckduckgo_results() {
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert(
+            "tests/test_documents/web/duckduckgo_serp.html",
+            Some(default_options(".html")),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        doc.metadata.get("search_query").map(String::as_str),
+        Some("borrow checker")
+    );
+    let content = doc.to_markdown();
+    assert!(
+        content.contains("(https://doc.rust-lang.org/book/ch04-02-references-and-borrowing.html)")
+    );
+    assert!(content.contains("The borrow checker ensures references are always valid."));
+    assert!(!content.contains("Ad: Learn Rust Fast"));
+}
+
+#[tokio::test]
+async fn test_html_web_source_detected_from_url() {
+    let html = r#"<html><head><title>Search</title></head><body>
+<ol id="results"><li class="b_algo"><h2><a href="/wiki">Not Bing markup</a></h2></li></ol>
+<div id="mw-content-text"><p>Article text about <a href="/wiki/Rust">Rust</a>.</p></div>
+</body></html>"#;
+    let options = default_options(".html")
+        .with_url("https://en.wikipedia.org/wiki/Rust_(programming_language)");
+
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert_bytes(Bytes::from_static(html.as_bytes()), Some(options))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        doc.metadata.get("web_source").map(String::as_str),
+        Some("wikipedia")
+    );
+    let content = doc.to_markdown();
+    assert!(content.contains("[Rust](https://en.wikipedia.org/wiki/Rust)"));
+    assert!(!content.contains("Not Bing markup"));
+}
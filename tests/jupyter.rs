@@ -1,7 +1,7 @@
 //! Jupyter Notebook conversion tests
 
 use bytes::Bytes;
-use markitdown::{ConversionOptions, MarkItDown};
+use markitdown::{ContentBlock, ConversionOptions, MarkItDown, OutputLimits};
 use std::fs;
 
 fn default_options(ext: &str) -> ConversionOptions {
@@ -13,6 +13,16 @@ fn default_options(ext: &str) -> ConversionOptions {
         extract_images: true,
         force_llm_ocr: false,
         merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
     }
 }
 
@@ -98,3 +108,133 @@ async fn test_jupyter_mime_out() {
         result.err()
     );
 }
+
+#[tokio::test]
+async fn test_jupyter_images() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("outputs.ipynb"), Some(default_options(".ipynb")))
+        .await
+        .unwrap();
+
+    let images = doc.images();
+    assert_eq!(images.len(), 2);
+    assert_eq!(images[0].id, "notebook_image_1");
+    assert_eq!(images[0].mime_type, "image/png");
+    assert!(images[0].data.starts_with(b"\x89PNG"));
+    assert_eq!(
+        images[0].alt_text.as_deref(),
+        Some("<Figure size 640x480 with 1 Axes>")
+    );
+    assert_eq!((images[0].width, images[0].height), (Some(640), Some(480)));
+    assert_eq!(images[1].mime_type, "image/svg+xml");
+    assert!(images[1].data.starts_with(b"<svg"));
+
+    // Without image extraction the text representation is shown
+    let mut options = default_options(".ipynb");
+    options.extract_images = false;
+    let doc = md
+        .convert(&test_file("outputs.ipynb"), Some(options))
+        .await
+        .unwrap();
+    assert!(doc.images().is_empty());
+    assert!(doc
+        .to_markdown()
+        .contains("```\n<Figure size 640x480 with 1 Axes>\n```"));
+
+    let doc = md
+        .convert(&test_file("rank.ipynb"), Some(default_options(".ipynb")))
+        .await
+        .unwrap();
+    assert_eq!(doc.images().len(), 1);
+}
+
+#[tokio::test]
+async fn test_jupyter_errors_and_streams() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("outputs.ipynb"), Some(default_options(".ipynb")))
+        .await
+        .unwrap();
+    let markdown = doc.to_markdown();
+
+    // Execution counts come from the notebook
+    assert!(markdown.contains("**In [3]:**\n\n```python\nreadings = load('north')\n```"));
+    assert!(markdown.contains("**In [ ]:**\n\n```python\nexport(readings)"));
+
+    // Consecutive chunks of a stream are joined; stderr is labeled
+    assert!(markdown.contains("**Out:**\n\n```\nLoading readings...\nLoaded 3 files\n```"));
+    assert!(markdown.contains("**Out (stderr):**\n\n```\nWARNING: 2 rows skipped\n```"));
+
+    // Tracebacks are kept without terminal colors
+    assert!(markdown.contains("**Error:**"));
+    assert!(markdown.contains("----> 1 1 / 0\nZeroDivisionError: division by zero"));
+    assert!(!markdown.contains('\x1b'));
+    assert!(!markdown.contains("[Error output]"));
+}
+
+#[tokio::test]
+async fn test_jupyter_rich_outputs() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("outputs.ipynb"), Some(default_options(".ipynb")))
+        .await
+        .unwrap();
+    let blocks = &doc.pages[0].content;
+
+    assert!(blocks
+        .iter()
+        .any(|b| matches!(b, ContentBlock::Markdown(m) if m == "$\\bar{x} = 21.4$\n")));
+    assert!(blocks.iter().any(|b| matches!(
+        b,
+        ContentBlock::Code { language: Some(lang), code }
+            if lang == "json" && code.contains("\"station\": \"north\"")
+    )));
+    assert!(doc.to_markdown().contains("**Out [6]:**"));
+
+    // Markdown representations win over HTML and plain text
+    let doc = md
+        .convert(&test_file("mime.ipynb"), Some(default_options(".ipynb")))
+        .await
+        .unwrap();
+    assert!(doc.to_markdown().contains("**Out [6]:**\n\n$$E = mc^2$$"));
+}
+
+#[tokio::test]
+async fn test_jupyter_output_limits() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("outputs.ipynb"), Some(default_options(".ipynb")))
+        .await
+        .unwrap();
+    let markdown = doc.to_markdown();
+    assert!(markdown.contains("reading 100\n... (50 more lines)"));
+    assert!(!markdown.contains("reading 101"));
+
+    let options = default_options(".ipynb")
+        .with_notebook_output_limits(OutputLimits::new().with_max_lines(2));
+    let doc = md
+        .convert(&test_file("outputs.ipynb"), Some(options))
+        .await
+        .unwrap();
+    assert!(doc
+        .to_markdown()
+        .contains("reading 1\nreading 2\n... (148 more lines)"));
+
+    let options = default_options(".ipynb")
+        .with_notebook_output_limits(OutputLimits::new().with_max_chars(12));
+    let doc = md
+        .convert(&test_file("outputs.ipynb"), Some(options))
+        .await
+        .unwrap();
+    assert!(doc
+        .to_markdown()
+        .contains("```\nreading 1\nre\n... (148 more lines)"));
+
+    let options = default_options(".ipynb").with_notebook_output_limits(OutputLimits::unlimited());
+    let doc = md
+        .convert(&test_file("outputs.ipynb"), Some(options))
+        .await
+        .unwrap();
+    assert!(doc.to_markdown().contains("reading 150\n```"));
+}
@@ -0,0 +1,102 @@
+//! Language identification tests
+
+use bytes::Bytes;
+use markitdown::language::{detect_language, primary_language};
+use markitdown::{ConversionOptions, LanguageDetection, MarkItDown};
+
+const ENGLISH: &str = "The committee met on Tuesday to review the annual budget. \
+    Several members asked for more time to read the report before the vote, \
+    and the chair agreed to move the decision to next month.";
+
+const GERMAN: &str = "Der Ausschuss traf sich am Dienstag, um den Jahreshaushalt zu prüfen. \
+    Mehrere Mitglieder baten um mehr Zeit, den Bericht vor der Abstimmung zu lesen, \
+    und die Vorsitzende verschob die Entscheidung auf den nächsten Monat.";
+
+#[test]
+fn test_detect_language() {
+    assert_eq!(detect_language(ENGLISH).as_deref(), Some("en"));
+    assert_eq!(detect_language(GERMAN).as_deref(), Some("de"));
+    // Too short to identify
+    assert_eq!(detect_language("Hello world"), None);
+
+    assert_eq!(primary_language("en-US"), "en");
+    assert_eq!(primary_language("pt_BR"), "pt");
+}
+
+#[tokio::test]
+async fn test_text_document_and_page_language() {
+    let options = || Some(ConversionOptions::default().with_extension(".txt"));
+
+    // Detection is opt-in
+    let mut md = MarkItDown::new();
+    let doc = md
+        .convert_bytes(Bytes::from_static(GERMAN.as_bytes()), options())
+        .await
+        .unwrap();
+    assert_eq!(doc.language, None);
+    assert_eq!(doc.pages[0].language, None);
+
+    md.set_language_detection(LanguageDetection::PreferDeclared);
+    let doc = md
+        .convert_bytes(Bytes::from_static(GERMAN.as_bytes()), options())
+        .await
+        .unwrap();
+    assert_eq!(doc.language.as_deref(), Some("de"));
+    assert_eq!(doc.pages[0].language.as_deref(), Some("de"));
+}
+
+#[tokio::test]
+async fn test_declared_language() {
+    let html = format!(
+        "<html lang=\"fr-FR\"><head><title>Budget</title></head><body><p>{}</p></body></html>",
+        ENGLISH
+    );
+    let options = || Some(ConversionOptions::default().with_extension(".html"));
+
+    // The declared language is kept for the document, reduced to its primary
+    // subtag like page languages; the page is tagged with what its text is
+    // written in. The declared tag itself stays in the properties.
+    let mut md = MarkItDown::new();
+    md.set_language_detection(LanguageDetection::PreferDeclared);
+    let doc = md
+        .convert_bytes(Bytes::from(html.clone()), options())
+        .await
+        .unwrap();
+    assert_eq!(doc.language.as_deref(), Some("fr"));
+    assert_eq!(doc.properties.language.as_deref(), Some("fr-FR"));
+    assert_eq!(doc.pages[0].language.as_deref(), Some("en"));
+
+    md.set_language_detection(LanguageDetection::PreferDetected);
+    let doc = md
+        .convert_bytes(Bytes::from(html.clone()), options())
+        .await
+        .unwrap();
+    assert_eq!(doc.language.as_deref(), Some("en"));
+    assert_eq!(doc.properties.language.as_deref(), Some("fr-FR"));
+
+    md.set_language_detection(LanguageDetection::Off);
+    let doc = md
+        .convert_bytes(Bytes::from(html), options())
+        .await
+        .unwrap();
+    assert_eq!(doc.language, None);
+    assert_eq!(doc.properties.language.as_deref(), Some("fr-FR"));
+    assert_eq!(doc.pages[0].language, None);
+}
+
+#[tokio::test]
+async fn test_docx_declared_language() {
+    let mut md = MarkItDown::new();
+    md.set_language_detection(LanguageDetection::PreferDeclared);
+    let doc = md
+        .convert(
+            "tests/test_documents/documents/unit_test_formatting.docx",
+            None,
+        )
+        .await
+        .unwrap();
+
+    // Most runs are marked en-US; the style defaults say fr-FR
+    assert_eq!(doc.properties.language.as_deref(), Some("en-US"));
+    assert_eq!(doc.language.as_deref(), Some("en"));
+}
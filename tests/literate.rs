@@ -0,0 +1,240 @@
+//! R Markdown, Quarto, Jupytext and Zeppelin conversion tests
+
+use bytes::Bytes;
+use markitdown::{ContentBlock, ConversionOptions, MarkItDown, OutputLimits};
+
+fn default_options(ext: &str) -> ConversionOptions {
+    ConversionOptions {
+        file_extension: Some(ext.to_string()),
+        url: None,
+        llm_client: None,
+        image_context_path: None,
+        extract_images: true,
+        force_llm_ocr: false,
+        merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
+    }
+}
+
+const TEST_DIR: &str = "tests/test_documents/literate";
+
+fn test_file(name: &str) -> String {
+    format!("{}/{}", TEST_DIR, name)
+}
+
+/// Info strings of the code blocks, in order
+fn code_info(blocks: &[ContentBlock]) -> Vec<String> {
+    blocks
+        .iter()
+        .filter_map(|b| match b {
+            ContentBlock::Code { language, .. } => Some(language.clone().unwrap_or_default()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_rmarkdown_conversion() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("report.Rmd"), Some(default_options(".rmd")))
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Station Report"));
+    assert_eq!(
+        doc.metadata.get("author").map(String::as_str),
+        Some("Ada Park, Lee Chen")
+    );
+    assert_eq!(
+        doc.metadata
+            .get("output.html_document.toc")
+            .map(String::as_str),
+        Some("true")
+    );
+
+    let blocks = &doc.pages[0].content;
+    assert_eq!(
+        code_info(blocks),
+        vec![
+            "r setup, include=FALSE",
+            "r summary, echo=FALSE, fig.width=6",
+            "python"
+        ]
+    );
+    assert!(matches!(
+        &blocks[0],
+        ContentBlock::Code { code, .. } if code == "library(dplyr)\nknitr::opts_chunk$set(echo = TRUE)"
+    ));
+
+    // Inline code stays in the prose; chunks inside other fences are not split out
+    let markdown = doc.to_markdown();
+    assert!(markdown.contains("The station recorded `r nrow(readings)` readings."));
+    assert!(markdown.contains("````markdown\n```{r}\n1 + 1\n```\n````"));
+}
+
+#[tokio::test]
+async fn test_quarto_conversion() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("analysis.qmd"), Some(default_options(".qmd")))
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Rainfall Analysis"));
+    assert_eq!(
+        doc.metadata.get("jupyter").map(String::as_str),
+        Some("python3")
+    );
+
+    // `#|` options move into the info string
+    let blocks = &doc.pages[0].content;
+    assert_eq!(
+        code_info(blocks),
+        vec!["python label=fig-rainfall, echo=false", "r"]
+    );
+    let markdown = doc.to_markdown();
+    assert!(markdown.contains("```python label=fig-rainfall, echo=false\nimport matplotlib"));
+    assert!(!markdown.contains("#|"));
+    // Display-only blocks are left as written
+    assert!(markdown.contains("```{.python}\n# shown but not run\n```"));
+}
+
+#[tokio::test]
+async fn test_jupytext_conversion() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("analysis.py"), Some(default_options(".py")))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        doc.metadata
+            .get("jupyter.kernelspec.language")
+            .map(String::as_str),
+        Some("python")
+    );
+
+    let blocks = &doc.pages[0].content;
+    assert!(matches!(
+        &blocks[0],
+        ContentBlock::Markdown(m) if m == "# Rainfall analysis\n\nLoad the **monthly** totals.\n"
+    ));
+    assert_eq!(
+        code_info(blocks),
+        vec!["python", "python Plot tags=[\"figure\"]", ""]
+    );
+    assert!(doc
+        .to_markdown()
+        .contains("```python\nimport pandas as pd\n\ntotals = pd.read_csv(\"rain.csv\")\n```"));
+}
+
+#[tokio::test]
+async fn test_plain_python_script() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert_bytes(
+            Bytes::from_static(b"# %%% not a cell\nprint('hello')\n"),
+            Some(default_options(".py")),
+        )
+        .await
+        .unwrap();
+
+    assert!(doc
+        .to_markdown()
+        .contains("```python\n# %%% not a cell\nprint('hello')"));
+}
+
+#[tokio::test]
+async fn test_zeppelin_conversion() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("sales.zpln"), Some(default_options(".zpln")))
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Sales Overview"));
+    assert_eq!(
+        doc.metadata.get("id").map(String::as_str),
+        Some("2JQ7ZR6XK")
+    );
+    assert_eq!(
+        doc.metadata.get("interpreter").map(String::as_str),
+        Some("spark")
+    );
+
+    let blocks = &doc.pages[0].content;
+    assert_eq!(
+        code_info(blocks),
+        vec![
+            "python interpreter=spark.pyspark",
+            "",
+            "sql",
+            "python",
+            "scala",
+            ""
+        ]
+    );
+    assert!(blocks.iter().any(|b| matches!(
+        b,
+        ContentBlock::Heading { level: 2, text } if text == "Load data"
+    )));
+    assert!(blocks.iter().any(|b| matches!(
+        b,
+        ContentBlock::Table { headers, rows }
+            if headers == &["region", "total"] && rows.len() == 2
+    )));
+
+    let images = doc.images();
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].id, "notebook_image_1");
+
+    let markdown = doc.to_markdown();
+    // Markdown paragraphs keep their source rather than the rendered HTML
+    assert!(markdown.contains("# Quarterly sales\nNumbers come from the **warehouse**."));
+    assert!(markdown.contains("**Out:**\n\n```\n1204\n```"));
+    assert!(markdown.contains("**Error:**\n\n```\njava.lang.OutOfMemoryError"));
+}
+
+#[tokio::test]
+async fn test_zeppelin_output_limits() {
+    let md = MarkItDown::new();
+    let options = default_options(".zpln")
+        .with_notebook_output_limits(OutputLimits::new().with_max_chars(10));
+    let doc = md
+        .convert(&test_file("sales.zpln"), Some(options))
+        .await
+        .unwrap();
+    assert!(doc
+        .to_markdown()
+        .contains("```\njava.lang.\n... (truncated)\n```"));
+
+    let mut options = default_options(".zpln");
+    options.extract_images = false;
+    let doc = md
+        .convert(&test_file("sales.zpln"), Some(options))
+        .await
+        .unwrap();
+    assert!(doc.images().is_empty());
+}
+
+#[tokio::test]
+async fn test_zeppelin_rejects_invalid_json() {
+    let md = MarkItDown::new();
+    let result = md
+        .convert_bytes(
+            Bytes::from_static(b"not json"),
+            Some(default_options(".zpln")),
+        )
+        .await;
+    assert!(result.is_err());
+}
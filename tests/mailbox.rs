@@ -0,0 +1,229 @@
+//! Mailbox conversion tests (mbox, Maildir)
+
+use bytes::Bytes;
+use chrono::{TimeZone, Utc};
+use markitdown::{ConversionOptions, MailboxFilter, MarkItDown};
+use object_store::{path::Path, ObjectStore, PutPayload};
+use std::fs;
+
+fn default_options(ext: &str) -> ConversionOptions {
+    ConversionOptions {
+        file_extension: Some(ext.to_string()),
+        url: None,
+        llm_client: None,
+        image_context_path: None,
+        extract_images: true,
+        force_llm_ocr: false,
+        merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
+    }
+}
+
+const TEST_DIR: &str = "tests/test_documents/email";
+
+fn test_file(name: &str) -> String {
+    format!("{}/{}", TEST_DIR, name)
+}
+
+// ============================================================================
+// mbox Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_mbox_messages_grouped_by_thread() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("mbox/team.mbox"), None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        doc.metadata.get("message_count").map(String::as_str),
+        Some("5")
+    );
+    assert_eq!(
+        doc.metadata.get("thread_count").map(String::as_str),
+        Some("3")
+    );
+    assert_eq!(doc.pages.len(), 5);
+
+    // The migration thread comes first, its replies in date order even
+    // though they are out of order in the file
+    let first = doc.pages[0].to_markdown();
+    assert!(first.contains("## Database migration plan"));
+    assert!(first.contains("**Thread:** Database migration plan (message 1 of 3)"));
+    let second = doc.pages[1].to_markdown();
+    assert!(second.contains("**From:** Bob Chen <bob@example.com>"));
+    assert!(second.contains("(message 2 of 3)"));
+    assert!(doc.pages[2]
+        .to_markdown()
+        .contains("Monitoring dashboards are ready."));
+
+    // Single-message threads carry no thread line
+    let lunch = doc.pages[3].to_markdown();
+    assert!(lunch.contains("## Lunch on Friday?"));
+    assert!(!lunch.contains("**Thread:**"));
+}
+
+#[tokio::test]
+async fn test_mbox_from_quoting() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("mbox/team.mbox"), None)
+        .await
+        .unwrap();
+
+    let first = doc.pages[0].to_markdown();
+    assert!(first.contains("\nFrom now on, writes go through the proxy."));
+    assert!(!first.contains(">From"));
+}
+
+#[tokio::test]
+async fn test_mbox_bytes_conversion() {
+    let md = MarkItDown::new();
+    let bytes = fs::read(test_file("mbox/team.mbox")).expect("Failed to read file");
+    let doc = md
+        .convert_bytes(Bytes::from(bytes), Some(default_options(".mbox")))
+        .await
+        .unwrap();
+
+    assert_eq!(doc.pages.len(), 5);
+}
+
+#[tokio::test]
+async fn test_mbox_from_object_store() {
+    let md = MarkItDown::in_memory();
+    let bytes = fs::read(test_file("mbox/team.mbox")).expect("Failed to read file");
+    md.store()
+        .put(&Path::from("exports/team.mbox"), PutPayload::from(bytes))
+        .await
+        .unwrap();
+
+    let doc = md.convert("exports/team.mbox", None).await.unwrap();
+    assert_eq!(doc.pages.len(), 5);
+}
+
+#[tokio::test]
+async fn test_mbox_sender_filter() {
+    let md = MarkItDown::new();
+    let options = default_options(".mbox")
+        .with_mailbox_filter(MailboxFilter::new().with_sender("BOB@example.com"));
+    let doc = md
+        .convert(&test_file("mbox/team.mbox"), Some(options))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        doc.metadata.get("message_count").map(String::as_str),
+        Some("2")
+    );
+    assert_eq!(
+        doc.metadata.get("messages_filtered").map(String::as_str),
+        Some("3")
+    );
+    assert!(doc
+        .pages
+        .iter()
+        .all(|p| p.to_markdown().contains("**From:** Bob Chen")));
+}
+
+#[tokio::test]
+async fn test_mbox_date_filter() {
+    let md = MarkItDown::new();
+    let filter = MailboxFilter::new()
+        .with_since(Utc.with_ymd_and_hms(2024, 3, 4, 10, 0, 0).unwrap())
+        .with_until(Utc.with_ymd_and_hms(2024, 3, 6, 0, 0, 0).unwrap());
+    let doc = md
+        .convert(
+            &test_file("mbox/team.mbox"),
+            Some(default_options(".mbox").with_mailbox_filter(filter)),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(doc.pages.len(), 3);
+    let content = doc.to_markdown();
+    assert!(content.contains("I will freeze deploys"));
+    assert!(content.contains("Lunch on Friday?"));
+    assert!(!content.contains("On-call handover"));
+    assert!(!content.contains("We move the orders table"));
+}
+
+#[tokio::test]
+async fn test_mbox_rejects_empty_input() {
+    let md = MarkItDown::new();
+    let result = md
+        .convert_bytes(
+            Bytes::from_static(b"just some text\n"),
+            Some(default_options(".mbox")),
+        )
+        .await;
+
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// Maildir Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_maildir_folder() {
+    let md = MarkItDown::new();
+    let doc = md.convert(&test_file("maildir/team"), None).await.unwrap();
+
+    assert_eq!(
+        doc.metadata.get("message_count").map(String::as_str),
+        Some("3")
+    );
+    assert_eq!(
+        doc.metadata.get("thread_count").map(String::as_str),
+        Some("2")
+    );
+    // Messages from new/ are threaded with those in cur/
+    assert!(doc.pages[1]
+        .to_markdown()
+        .contains("**Thread:** Database migration plan (message 2 of 2)"));
+}
+
+#[tokio::test]
+async fn test_maildir_zip() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("maildir/team_maildir.zip"), None)
+        .await
+        .unwrap();
+
+    // Maildir++ subfolders are included, dovecot index files are not
+    assert_eq!(
+        doc.metadata.get("message_count").map(String::as_str),
+        Some("4")
+    );
+    assert!(doc.to_markdown().contains("Handing over the pager"));
+    assert!(!doc.to_markdown().contains("V1709543100"));
+}
+
+#[tokio::test]
+async fn test_maildir_zip_bytes_with_filter() {
+    let md = MarkItDown::new();
+    let bytes = fs::read(test_file("maildir/team_maildir.zip")).expect("Failed to read file");
+    let options =
+        default_options(".zip").with_mailbox_filter(MailboxFilter::new().with_sender("carol"));
+    let doc = md
+        .convert_bytes(Bytes::from(bytes), Some(options))
+        .await
+        .unwrap();
+
+    assert_eq!(doc.pages.len(), 1);
+    assert!(doc
+        .to_markdown()
+        .contains("Monitoring dashboards are ready."));
+}
@@ -1,8 +1,13 @@
-//! Markdown conversion tests (passthrough and normalization)
+//! Markdown conversion tests (parsing, front matter and link resolution)
 
 use bytes::Bytes;
-use markitdown::{ConversionOptions, MarkItDown};
+use markitdown::{
+    ContentBlock, ConversionOptions, Inline, LineRange, ListItem, MarkItDown,
+    ObjectStoreImageFetcher,
+};
+use object_store::{memory::InMemory, path::Path, ObjectStore};
 use std::fs;
+use std::sync::Arc;
 
 fn default_options(ext: &str) -> ConversionOptions {
     ConversionOptions {
@@ -13,6 +18,16 @@ fn default_options(ext: &str) -> ConversionOptions {
         extract_images: true,
         force_llm_ocr: false,
         merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
     }
 }
 
@@ -74,3 +89,247 @@ async fn test_markdown_bytes_conversion() {
         result.err()
     );
 }
+
+#[tokio::test]
+async fn test_markdown_resolves_relative_links() {
+    let content = "# Links\n\n\
+See [the guide](guide/intro.md \"Guide\") and [top](#links).\n\n\
+![Diagram](../img/diagram.png)\n\n\
+[ref]: ./reference.md\n\n\
+```\n[untouched](code/path.md)\n```\n";
+
+    let md = MarkItDown::new();
+    let options = default_options(".md").with_url("https://example.com/docs/index.md");
+    let doc = md
+        .convert_bytes(Bytes::from(content), Some(options))
+        .await
+        .unwrap();
+    let markdown = doc.to_markdown();
+
+    assert!(markdown.contains("[the guide](https://example.com/docs/guide/intro.md \"Guide\")"));
+    assert!(markdown.contains("[top](#links)"));
+    assert!(markdown.contains("![Diagram](https://example.com/img/diagram.png)"));
+    assert!(markdown.contains("[ref]: https://example.com/docs/reference.md"));
+    assert!(markdown.contains("[untouched](code/path.md)"));
+}
+
+// ============================================================================
+// Structure
+// ============================================================================
+
+#[tokio::test]
+async fn test_markdown_front_matter() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("structured.md"), Some(default_options(".md")))
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Release Notes"));
+    for (key, value) in [
+        ("author", "Dana Reyes"),
+        ("tags", "release, changelog"),
+        ("version", "2.1"),
+        ("project.name", "Atlas"),
+        ("draft", "false"),
+    ] {
+        assert_eq!(doc.metadata.get(key).map(String::as_str), Some(value));
+    }
+    assert!(!doc.metadata.contains_key("title"));
+
+    let content = doc.to_markdown();
+    assert!(content.starts_with("# Release Notes\n\n# Overview\n"));
+    assert!(!content.contains("author: Dana Reyes"));
+}
+
+#[tokio::test]
+async fn test_markdown_toml_front_matter() {
+    let content = "+++\ntitle = \"Notes\"\n\n[params]\nlang = \"en\"\n+++\n\nBody text.\n";
+    let md = MarkItDown::new();
+    let doc = md
+        .convert_bytes(Bytes::from(content), Some(default_options(".md")))
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Notes"));
+    assert_eq!(
+        doc.metadata.get("params.lang").map(String::as_str),
+        Some("en")
+    );
+    assert!(matches!(
+        doc.pages[0].content.as_slice(),
+        [ContentBlock::Text(text)] if text == "Body text."
+    ));
+}
+
+#[tokio::test]
+async fn test_markdown_block_lines() {
+    let content = "---\ntitle: Notes\n---\n\n# Intro\n\nFirst line\nsecond line\n\n\
+                   | A | B |\n| - | - |\n| 1 | 2 |\n\n---\n";
+    let md = MarkItDown::new();
+    let doc = md
+        .convert_bytes(Bytes::from(content), Some(default_options(".md")))
+        .await
+        .unwrap();
+    let page = &doc.pages[0];
+
+    let lines: Vec<(usize, LineRange)> = page
+        .provenance
+        .iter()
+        .map(|p| (p.block_index, p.lines.unwrap()))
+        .collect();
+    assert_eq!(
+        lines,
+        vec![
+            (0, LineRange { start: 5, end: 5 }),
+            (1, LineRange { start: 7, end: 8 }),
+            (2, LineRange { start: 10, end: 12 }),
+            (3, LineRange { start: 14, end: 14 }),
+        ]
+    );
+    assert!(matches!(page.content[2], ContentBlock::Table { .. }));
+}
+
+#[tokio::test]
+async fn test_markdown_content_blocks() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("structured.md"), Some(default_options(".md")))
+        .await
+        .unwrap();
+    let blocks = &doc.pages[0].content;
+
+    assert!(matches!(
+        &blocks[0],
+        ContentBlock::Heading { level: 1, text } if text == "Overview"
+    ));
+    assert!(matches!(
+        &blocks[1],
+        ContentBlock::Text(text) if text.starts_with("Atlas **2.1** ships a faster *indexer*, a new `--watch` flag and\nfixes for [several issues](https://example.com/atlas/issues \"Issue tracker\").")
+    ));
+
+    let (headers, rows) = blocks
+        .iter()
+        .find_map(|b| match b {
+            ContentBlock::Table { headers, rows } => Some((headers, rows)),
+            _ => None,
+        })
+        .expect("Table block expected");
+    assert_eq!(headers, &["Area", "Change", "Issue"]);
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[1], ["CLI", "`--watch` flag", "#131"]);
+    assert_eq!(rows[2][1], "Escaped | pipes");
+
+    // Lists with nested lists or checkboxes keep their structure
+    let lists: Vec<(bool, &Vec<ListItem>)> = blocks
+        .iter()
+        .filter_map(|b| match b {
+            ContentBlock::NestedList { ordered, items } => Some((*ordered, items)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(lists.len(), 2);
+    assert!(lists[0].0);
+    let install = &lists[0].1[1];
+    assert_eq!(
+        Inline::plain_text(&install.content),
+        "Install the new release"
+    );
+    assert!(matches!(
+        &install.children[..],
+        [ContentBlock::List { ordered: false, items }]
+            if items == &["Linux: use the tarball", "macOS: use Homebrew"]
+    ));
+    assert_eq!(
+        lists[0].1[2].content,
+        vec![
+            Inline::text("Rebuild with "),
+            Inline::Code("atlas reindex".to_string())
+        ]
+    );
+    assert!(!lists[1].0);
+    let tasks: Vec<(String, Option<bool>)> = lists[1]
+        .1
+        .iter()
+        .map(|item| (Inline::plain_text(&item.content), item.checked))
+        .collect();
+    assert_eq!(
+        tasks,
+        vec![
+            ("Changelog written".to_string(), Some(true)),
+            ("Announcement posted".to_string(), Some(false))
+        ]
+    );
+
+    assert!(blocks.iter().any(|b| matches!(
+        b,
+        ContentBlock::Code { language: Some(language), code }
+            if language == "bash" && code == "atlas reindex --all"
+    )));
+    assert!(blocks.iter().any(|b| matches!(
+        b,
+        ContentBlock::Quote(text) if text == "Reindexing large repositories can take\nseveral minutes."
+    )));
+
+    let content = doc.to_markdown();
+    assert!(content.contains("| Area | Change | Issue |"));
+    assert!(content.contains("| Docs | Escaped \\| pipes | #140 |"));
+    assert!(content.contains("2. Install the new release\n   - Linux: use the tarball\n"));
+    assert!(content.contains("- [x] Changelog written\n- [ ] Announcement posted"));
+    assert!(content.contains("Known limitations are tracked separately.[^1]"));
+    assert!(content.contains("[^1]: See the issue tracker for details."));
+    // Without an image fetcher, linked images keep their markdown
+    assert!(
+        content.contains("![Architecture diagram](images/architecture.png \"Atlas components\")")
+    );
+    assert!(doc.images().is_empty());
+}
+
+#[tokio::test]
+async fn test_markdown_images() {
+    let png = fs::read("tests/test_documents/images/test_hello_world.png").unwrap();
+    let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+    store
+        .put(
+            &Path::from("docs/images/architecture.png"),
+            Bytes::from(png.clone()).into(),
+        )
+        .await
+        .unwrap();
+
+    let fetcher = ObjectStoreImageFetcher::new(store).with_base_dir("docs");
+    let options = default_options(".md").with_image_fetcher(Arc::new(fetcher));
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("structured.md"), Some(options))
+        .await
+        .unwrap();
+
+    let images = doc.images();
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].id, "markdown_image_0");
+    assert_eq!(images[0].mime_type, "image/png");
+    assert_eq!(images[0].data.len(), png.len());
+    assert_eq!(images[0].alt_text.as_deref(), Some("Architecture diagram"));
+    assert_eq!(images[0].caption.as_deref(), Some("Atlas components"));
+
+    // Embedded images are decoded; with extraction off they stay markdown
+    let content = "Intro\n\n![Dot](data:image/png;base64,iVBORw0KGgo=)\n";
+    let doc = md
+        .convert_bytes(Bytes::from(content), Some(default_options(".md")))
+        .await
+        .unwrap();
+    assert_eq!(doc.images().len(), 1);
+    assert_eq!(doc.images()[0].mime_type, "image/png");
+
+    let mut options = default_options(".md");
+    options.extract_images = false;
+    let doc = md
+        .convert_bytes(Bytes::from(content), Some(options))
+        .await
+        .unwrap();
+    assert!(doc.images().is_empty());
+    assert!(doc
+        .to_markdown()
+        .contains("![Dot](data:image/png;base64,iVBORw0KGgo=)"));
+}
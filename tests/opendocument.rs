@@ -3,7 +3,7 @@
 //! Test files sourced from kreuzberg test documents
 
 use bytes::Bytes;
-use markitdown::{ConversionOptions, MarkItDown};
+use markitdown::{ContentBlock, ConversionOptions, MarkItDown};
 use std::fs;
 use std::io::Write;
 use zip::write::SimpleFileOptions;
@@ -90,6 +90,173 @@ async fn test_odt_formula() {
     );
 }
 
+#[tokio::test]
+async fn test_odt_structure() {
+    let md = MarkItDown::new();
+
+    let doc = md
+        .convert(&test_file("odt/headers.odt"), None)
+        .await
+        .unwrap();
+    let blocks = &doc.pages[0].content;
+    assert!(
+        matches!(&blocks[0], ContentBlock::Heading { level: 1, text } if text == "A header (Lv 1)")
+    );
+    assert!(
+        matches!(&blocks[2], ContentBlock::Heading { level: 2, text } if text == "Another header (Lv 2)")
+    );
+    assert!(!doc.to_markdown().contains("OpenDocument Text"));
+
+    let doc = md
+        .convert(&test_file("odt/orderedListSimple.odt"), None)
+        .await
+        .unwrap();
+    let ContentBlock::List { ordered, items } = &doc.pages[0].content[0] else {
+        panic!("expected a list");
+    };
+    assert!(*ordered);
+    assert_eq!(items.len(), 5);
+    assert_eq!(
+        items[2],
+        "A third\n   1. New level!\n      1. And another!\n      2. It's great up here!\n   2. Oh noes\n   3. We fell!"
+    );
+
+    let doc = md
+        .convert(&test_file("odt/simpleTableWithHeader.odt"), None)
+        .await
+        .unwrap();
+    let ContentBlock::Table { headers, rows } = &doc.pages[0].content[0] else {
+        panic!("expected a table");
+    };
+    assert_eq!(headers, &vec!["A", "B", "C"]);
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[0], vec!["1", "", ""]);
+
+    let doc = md
+        .convert(&test_file("odt/blockquote2.odt"), None)
+        .await
+        .unwrap();
+    assert!(doc.to_markdown().contains("Paragraph\n\n> A blockquote."));
+}
+
+async fn markdown(name: &str) -> String {
+    MarkItDown::new()
+        .convert(&test_file(name), None)
+        .await
+        .unwrap()
+        .to_markdown()
+}
+
+#[tokio::test]
+async fn test_odt_inline_formatting_and_notes() {
+    assert!(markdown("odt/bold.odt")
+        .await
+        .contains("Here comes **bold** text"));
+    assert!(markdown("odt/italic.odt")
+        .await
+        .contains("Here comes *italic* text"));
+    assert!(markdown("odt/inlinedCode.odt")
+        .await
+        .contains("Here comes `inlined code` text and `an another` one."));
+    assert!(markdown("odt/externalLink.odt")
+        .await
+        .contains("Here comes an [external link](http://example.com/) to example.com."));
+
+    let footnote = markdown("odt/footnote.odt").await;
+    assert!(footnote.contains("Some text[^1] with a footnote."));
+    assert!(footnote.contains("[^1]: Footnote text"));
+    assert!(markdown("odt/endnote.odt")
+        .await
+        .contains("[^1]: Endnote text"));
+}
+
+#[tokio::test]
+async fn test_odt_images() {
+    let md = MarkItDown::new();
+
+    let doc = md.convert(&test_file("odt/image.odt"), None).await.unwrap();
+    let images = doc.images();
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].mime_type, "image/jpeg");
+    assert_eq!(
+        images[0].source_path.as_deref(),
+        Some("Pictures/10000000000000FA000000FAD6A15225.jpg")
+    );
+    assert!(!images[0].data.is_empty());
+
+    let doc = md
+        .convert(&test_file("odt/imageWithCaption.odt"), None)
+        .await
+        .unwrap();
+    let images = doc.images();
+    assert_eq!(images.len(), 1);
+    assert_eq!(
+        images[0].caption.as_deref(),
+        Some("Abbildung 1: Image caption")
+    );
+
+    let bytes = fs::read(test_file("odt/imageWithCaption.odt")).unwrap();
+    let options = ConversionOptions::default()
+        .with_extension(".odt")
+        .with_images(false);
+    let doc = md
+        .convert_bytes(Bytes::from(bytes), Some(options))
+        .await
+        .unwrap();
+    assert!(doc.images().is_empty());
+    assert!(doc.to_markdown().contains("Abbildung 1: Image caption"));
+}
+
+#[tokio::test]
+async fn test_odt_metadata() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("metadata_test.odt"), None)
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Test Metadata Document"));
+    assert_eq!(
+        doc.metadata.get("author").map(String::as_str),
+        Some("John Doe")
+    );
+    assert_eq!(
+        doc.metadata.get("subject").map(String::as_str),
+        Some("Testing ODT Metadata Extraction")
+    );
+    assert_eq!(
+        doc.metadata.get("created").map(String::as_str),
+        Some("2025-12-08T15:26:04Z")
+    );
+    assert!(!doc.metadata.contains_key("title"));
+    assert_eq!(doc.properties.authors, vec!["John Doe".to_string()]);
+    assert_eq!(
+        doc.properties.created.map(|d| d.to_rfc3339()).as_deref(),
+        Some("2025-12-08T15:26:04+00:00")
+    );
+    assert_eq!(doc.properties.producer.as_deref(), Some("Pandoc/3.8.3"));
+    assert_eq!(doc.properties.source_format.as_deref(), Some("odt"));
+
+    let doc = md.convert(&test_file("odt/image.odt"), None).await.unwrap();
+    assert_eq!(
+        doc.metadata.get("author").map(String::as_str),
+        Some("Martin Linnemann")
+    );
+    assert_eq!(
+        doc.metadata.get("modified").map(String::as_str),
+        Some("2015-03-01T18:20:30.92")
+    );
+    assert_eq!(
+        doc.metadata.get("image_count").map(String::as_str),
+        Some("1")
+    );
+    // Dates without an offset are read as UTC
+    assert_eq!(
+        doc.properties.modified.map(|d| d.to_rfc3339()).as_deref(),
+        Some("2015-03-01T18:20:30.920+00:00")
+    );
+}
+
 // ============================================================================
 // ODP (OpenDocument Presentation) tests
 // ============================================================================
@@ -161,3 +328,183 @@ async fn test_ott_template_conversion() {
     let result = md.convert_bytes(Bytes::from(bytes), Some(options)).await;
     assert!(result.is_ok(), "OTT conversion failed: {:?}", result.err());
 }
+
+// ============================================================================
+// ODG (OpenDocument Drawing) tests
+// ============================================================================
+
+/// A 1x1 PNG
+const PNG: &[u8] = &[
+    0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x02, 0x00, 0x00, 0x00, 0x90, 0x77, 0x53,
+    0xDE, 0x00, 0x00, 0x00, 0x0C, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9C, 0x63, 0xF8, 0xCF, 0xC0, 0x00,
+    0x00, 0x03, 0x01, 0x01, 0x00, 0xC9, 0xFE, 0x92, 0xEF, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E,
+    0x44, 0xAE, 0x42, 0x60, 0x82,
+];
+
+fn create_odg() -> Bytes {
+    let mut buffer = std::io::Cursor::new(Vec::new());
+    {
+        let mut zip = zip::ZipWriter::new(&mut buffer);
+        let options = SimpleFileOptions::default();
+
+        zip.start_file("mimetype", options).unwrap();
+        zip.write_all(b"application/vnd.oasis.opendocument.graphics")
+            .unwrap();
+
+        zip.start_file("meta.xml", options).unwrap();
+        zip.write_all(
+            br#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-meta xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0"
+    xmlns:dc="http://purl.org/dc/elements/1.1/">
+  <office:meta><dc:title>Deployment Diagram</dc:title></office:meta>
+</office:document-meta>"#,
+        )
+        .unwrap();
+
+        zip.start_file("content.xml", options).unwrap();
+        zip.write_all(
+            br#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0"
+    xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0"
+    xmlns:draw="urn:oasis:names:tc:opendocument:xmlns:drawing:1.0"
+    xmlns:svg="urn:oasis:names:tc:opendocument:xmlns:svg-compatible:1.0"
+    xmlns:xlink="http://www.w3.org/1999/xlink">
+  <office:body>
+    <office:drawing>
+      <draw:page draw:name="Overview">
+        <draw:custom-shape>
+          <text:p>Load balancer</text:p>
+          <draw:enhanced-geometry draw:type="rectangle"/>
+        </draw:custom-shape>
+        <draw:frame>
+          <draw:image xlink:href="Pictures/server.png"/>
+          <svg:title>Application server</svg:title>
+        </draw:frame>
+      </draw:page>
+      <draw:page draw:name="Legend">
+        <draw:frame><draw:text-box><text:p>Arrows show request flow</text:p></draw:text-box></draw:frame>
+      </draw:page>
+    </office:drawing>
+  </office:body>
+</office:document-content>"#,
+        )
+        .unwrap();
+
+        zip.start_file("Pictures/server.png", options).unwrap();
+        zip.write_all(PNG).unwrap();
+
+        zip.finish().unwrap();
+    }
+    Bytes::from(buffer.into_inner())
+}
+
+#[tokio::test]
+async fn test_odg_conversion() {
+    let md = MarkItDown::new();
+    let options = ConversionOptions::default().with_extension(".odg");
+    let doc = md.convert_bytes(create_odg(), Some(options)).await.unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Deployment Diagram"));
+    assert_eq!(doc.pages.len(), 2);
+    let markdown = doc.to_markdown();
+    assert!(markdown.contains("## Overview\n\nLoad balancer"));
+    assert!(markdown.contains("## Legend\n\nArrows show request flow"));
+
+    let images = doc.images();
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].id, "odg_image_1");
+    assert_eq!(images[0].mime_type, "image/png");
+    assert_eq!(images[0].alt_text.as_deref(), Some("Application server"));
+    assert_eq!(images[0].page_number, Some(1));
+
+    let options = ConversionOptions::default()
+        .with_extension(".odg")
+        .with_images(false);
+    let doc = md.convert_bytes(create_odg(), Some(options)).await.unwrap();
+    assert!(doc.images().is_empty());
+}
+
+// ============================================================================
+// Flat OpenDocument (.fodt, .fods, .fodp) tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_fodt_conversion() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("documents/flat.fodt"), None)
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Quarterly Field Report"));
+    assert_eq!(
+        doc.metadata.get("author").map(String::as_str),
+        Some("Priya Natarajan")
+    );
+    assert_eq!(
+        doc.metadata.get("keywords").map(String::as_str),
+        Some("field, survey")
+    );
+
+    let markdown = doc.to_markdown();
+    assert!(markdown.contains("# Summary\n\nAll **three** sites were visited."));
+    assert!(markdown.contains("1. Collect samples\n2. Log readings"));
+    assert!(markdown.contains("| Site | Samples |"));
+    assert!(markdown.contains("| North | 12 |"));
+
+    // Pictures are inlined as base64
+    let images = doc.images();
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].mime_type, "image/png");
+    assert_eq!(images[0].data.as_ref(), PNG);
+    assert_eq!(images[0].alt_text.as_deref(), Some("Site map"));
+}
+
+#[tokio::test]
+async fn test_fods_conversion() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("spreadsheets/flat.fods"), None)
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Inventory"));
+    assert_eq!(doc.pages.len(), 2);
+    let ContentBlock::Table { headers, rows } = &doc.pages[0].content[1] else {
+        panic!("expected a table");
+    };
+    // Column and row repeats that only pad the sheet are dropped
+    assert_eq!(headers, &vec!["Item", "Count"]);
+    assert_eq!(rows, &vec![vec!["Bolts", "250"], vec!["Nuts", "180"]]);
+    assert!(doc
+        .to_markdown()
+        .contains("## Sheet: Notes\n\n*Empty sheet*"));
+}
+
+#[tokio::test]
+async fn test_fodp_conversion() {
+    let md = MarkItDown::new();
+    let bytes = fs::read(test_file("presentations/flat.fodp")).unwrap();
+    let options = ConversionOptions::default().with_extension(".fodp");
+    let doc = md
+        .convert_bytes(Bytes::from(bytes), Some(options))
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Launch Plan"));
+    assert_eq!(doc.pages.len(), 2);
+    let markdown = doc.to_markdown();
+    assert!(markdown.contains("## Slide 1\n\nLaunch Plan\nBeta in May"));
+    assert!(markdown.contains("## Slide 2\n\nRisks"));
+}
+
+#[tokio::test]
+async fn test_flat_odf_rejects_other_xml() {
+    let md = MarkItDown::new();
+    let options = ConversionOptions::default().with_extension(".fodt");
+    let result = md
+        .convert_bytes(Bytes::from("<rss><channel/></rss>"), Some(options))
+        .await;
+    assert!(result.is_err());
+}
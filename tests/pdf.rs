@@ -11,6 +11,16 @@ fn default_options(ext: &str) -> ConversionOptions {
         extract_images: true,
         force_llm_ocr: false,
         merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
     }
 }
 
@@ -56,6 +66,26 @@ async fn test_pdf_google_doc() {
     assert!(content.len() >= 50, "Content should have reasonable length");
 }
 
+#[tokio::test]
+async fn test_pdf_info_dictionary() {
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert(
+            "tests/test_documents/pdfs/google_doc_document.pdf",
+            Some(default_options(".pdf")),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("PDF Example Document"));
+    assert_eq!(
+        doc.properties.producer.as_deref(),
+        Some("Skia/PDF m103 Google Docs Renderer")
+    );
+    assert!(doc.properties.page_count.is_some());
+    assert_eq!(doc.properties.source_format.as_deref(), Some("pdf"));
+}
+
 // ============================================================================
 // PDF with Tables Tests
 // ============================================================================
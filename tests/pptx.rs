@@ -1,6 +1,6 @@
 //! PowerPoint conversion tests using kreuzberg test documents
 use bytes::Bytes;
-use markitdown::{model::ConversionOptions, MarkItDown};
+use markitdown::{model::ConversionOptions, ContentBlock, MarkItDown};
 
 fn default_options(ext: &str) -> ConversionOptions {
     ConversionOptions {
@@ -11,6 +11,16 @@ fn default_options(ext: &str) -> ConversionOptions {
         extract_images: true,
         force_llm_ocr: false,
         merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
     }
 }
 
@@ -171,3 +181,69 @@ async fn test_pptx_slide_structure() {
     // PowerPoint should have multiple slides/pages
     assert!(!doc.pages.is_empty(), "Document should have slides");
 }
+
+#[tokio::test]
+async fn test_pptx_slide_and_shape_provenance() {
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert(
+            "tests/test_documents/presentations/simple.pptx",
+            Some(default_options(".pptx")),
+        )
+        .await
+        .unwrap();
+
+    let page = &doc.pages[1];
+    let shapes: Vec<(u32, Option<&str>)> = page
+        .provenance
+        .iter()
+        .map(|p| (p.slide.unwrap(), p.shape.as_deref()))
+        .collect();
+    assert_eq!(
+        shapes,
+        vec![
+            (2, None),
+            (2, Some("Title 1")),
+            (2, Some("Content Placeholder 2")),
+        ]
+    );
+    assert!(matches!(
+        &page.content[1],
+        ContentBlock::Text(text) if text == "Things to think about"
+    ));
+}
+
+#[tokio::test]
+async fn test_pptx_document_properties() {
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert(
+            "tests/test_documents/presentations/simple.pptx",
+            Some(default_options(".pptx")),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Title Slide"));
+    let properties = &doc.properties;
+    assert_eq!(properties.authors, vec!["Steve Canny".to_string()]);
+    assert_eq!(
+        properties.created.map(|d| d.to_rfc3339()).as_deref(),
+        Some("2024-07-22T23:29:33+00:00")
+    );
+    assert_eq!(
+        properties.modified.map(|d| d.to_rfc3339()).as_deref(),
+        Some("2024-07-22T23:31:59+00:00")
+    );
+    assert_eq!(properties.page_count, Some(2));
+    assert_eq!(properties.word_count, Some(38));
+    assert_eq!(
+        properties.producer.as_deref(),
+        Some("Microsoft Macintosh PowerPoint")
+    );
+    assert_eq!(properties.source_format.as_deref(), Some("pptx"));
+    assert_eq!(
+        doc.metadata.get("last_modified_by").map(String::as_str),
+        Some("Steve Canny")
+    );
+}
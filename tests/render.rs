@@ -0,0 +1,439 @@
+//! Markdown rendering tests (flavors, escaping, headings, emoji, wrapping, structured blocks)
+
+use bytes::Bytes;
+use markitdown::{
+    Alignment, ContentBlock, ConversionOptions, Definition, Document, ExtractedImage, Inline,
+    ListItem, MarkItDown, MarkdownFlavor, MarkdownRenderer, Page, Table, TableCell,
+};
+
+fn sample_document() -> Document {
+    let mut document = Document::new();
+    document.title = Some("📅 Weekly Report".to_string());
+    document
+        .metadata
+        .insert("author".to_string(), "Dana Reyes".to_string());
+
+    let mut page = Page::new(1);
+    page.add_content(ContentBlock::Heading {
+        level: 2,
+        text: "🔴 Errors".to_string(),
+    });
+    page.add_content(ContentBlock::Text(
+        "Two builds failed 🚀 this week.\n\n\n".to_string(),
+    ));
+    page.add_content(ContentBlock::Table {
+        headers: vec!["Job".to_string(), "Result".to_string()],
+        rows: vec![
+            vec!["lint | fmt".to_string(), "failed\nexit 1".to_string()],
+            vec!["test".to_string(), "ok | skipped".to_string()],
+        ],
+    });
+    page.add_content(ContentBlock::Markdown(
+        "### Details\n\n\n\n**Status:** ✅ done\n\n```\n# not a heading 🚀\n\n\n```".to_string(),
+    ));
+    page.add_content(ContentBlock::Code {
+        language: Some("md".to_string()),
+        code: "```\nnested\n```".to_string(),
+    });
+    page.add_content(ContentBlock::Image(ExtractedImage::new(
+        "chart_1",
+        Bytes::new(),
+        "image/png",
+    )));
+    document.add_page(page);
+    document
+}
+
+// ============================================================================
+// Default (GFM)
+// ============================================================================
+
+#[test]
+fn test_render_default_gfm() {
+    let markdown = sample_document().to_markdown();
+
+    assert!(markdown.starts_with("# 📅 Weekly Report\n\n## 🔴 Errors\n\nTwo builds failed"));
+    // Blank lines are collapsed outside code fences
+    assert!(markdown.contains("this week.\n\n| Job | Result |"));
+    assert!(markdown.contains("### Details\n\n**Status:** ✅ done"));
+    assert!(markdown.contains("```\n# not a heading 🚀\n\n\n```"));
+    // The renderer escapes pipes and line breaks in cells
+    assert!(markdown.contains("| lint \\| fmt | failed<br>exit 1 |"));
+    assert!(markdown.contains("| test | ok \\| skipped |"));
+    // Fences are longer than the code's own backtick runs
+    assert!(markdown.contains("````md\n```\nnested\n```\n````"));
+    assert!(markdown.contains("![chart_1](chart_1)"));
+    assert!(markdown.ends_with("![chart_1](chart_1)\n"));
+
+    let verbatim =
+        sample_document().to_markdown_with(&MarkdownRenderer::new().with_table_escaping(false));
+    assert!(verbatim.contains("| lint | fmt | failed\nexit 1 |"));
+}
+
+#[test]
+fn test_render_heading_offset_and_emoji() {
+    let renderer = MarkdownRenderer::new()
+        .with_heading_offset(1)
+        .without_emoji();
+    let markdown = sample_document().to_markdown_with(&renderer);
+
+    assert!(markdown.starts_with("## Weekly Report\n\n### Errors\n\nTwo builds failed this week."));
+    assert!(markdown.contains("#### Details\n\n**Status:** done"));
+    // Code is left alone
+    assert!(markdown.contains("```\n# not a heading 🚀\n"));
+
+    let deep = MarkdownRenderer::new().with_heading_offset(5);
+    assert!(sample_document()
+        .to_markdown_with(&deep)
+        .contains("###### Details"));
+}
+
+#[test]
+fn test_render_line_width() {
+    let mut page = Page::new(1);
+    page.add_content(ContentBlock::Text(
+        "The indexer now scans repositories in parallel and - on large trees - finishes much sooner."
+            .to_string(),
+    ));
+    page.add_content(ContentBlock::List {
+        ordered: true,
+        items: vec!["Back up the index before installing the new release".to_string()],
+    });
+    page.add_content(ContentBlock::Table {
+        headers: vec!["A long header that stays on one line".to_string()],
+        rows: vec![],
+    });
+    let document = Document::from_page(page);
+
+    let markdown = document.to_markdown_with(&MarkdownRenderer::new().with_line_width(30));
+    assert!(markdown.starts_with(
+        "The indexer now scans\nrepositories in parallel and -\non large trees - finishes much\nsooner.\n"
+    ));
+    assert!(markdown.contains("1. Back up the index before\n   installing the new release\n"));
+    assert!(markdown.contains("| A long header that stays on one line |"));
+
+    // Tab-indented lines keep their first word and their indentation
+    let tabbed = ContentBlock::Text("\tThe first word stays with the rest of the line".to_string());
+    assert_eq!(
+        MarkdownRenderer::new()
+            .with_line_width(30)
+            .render_block(&tabbed),
+        "\tThe first word stays with the\n\trest of the line\n"
+    );
+}
+
+// ============================================================================
+// Flavors
+// ============================================================================
+
+#[test]
+fn test_render_commonmark_tables_as_html() {
+    let renderer = MarkdownRenderer::new().with_flavor(MarkdownFlavor::CommonMark);
+    let markdown = sample_document().to_markdown_with(&renderer);
+
+    assert!(markdown.contains("<table>\n<thead><tr><th>Job</th><th>Result</th></tr></thead>"));
+    assert!(markdown.contains("<tr><td>lint | fmt</td><td>failed<br>exit 1</td></tr>"));
+    assert!(markdown.contains("<tr><td>test</td><td>ok | skipped</td></tr>"));
+    assert!(!markdown.contains("| Job |"));
+}
+
+#[test]
+fn test_render_obsidian() {
+    let renderer = MarkdownRenderer::new().with_flavor(MarkdownFlavor::Obsidian);
+    let markdown = sample_document().to_markdown_with(&renderer);
+
+    assert!(markdown.starts_with("---\nauthor: Dana Reyes\n---\n\n# 📅 Weekly Report\n"));
+    assert!(markdown.contains("![[chart_1]]"));
+    assert!(markdown.contains("| lint \\| fmt |"));
+}
+
+#[test]
+fn test_render_plain() {
+    let renderer = MarkdownRenderer::new().with_flavor(MarkdownFlavor::Plain);
+    let markdown = sample_document().to_markdown_with(&renderer);
+
+    assert!(markdown.starts_with("📅 Weekly Report\n\n🔴 Errors\n\n"));
+    assert!(markdown.contains("Job | Result\nlint | fmt | failed exit 1\n"));
+    // Cells are not escaped
+    assert!(markdown.contains("\ntest | ok | skipped"));
+    assert!(!markdown.contains("\\|"));
+    assert!(markdown.contains("\nDetails\n"));
+    assert!(markdown.contains("```\nnested\n```"));
+    assert!(markdown.contains("[Image: chart_1]"));
+    assert!(!markdown.contains("### Details"));
+}
+
+// ============================================================================
+// Converters
+// ============================================================================
+
+#[tokio::test]
+async fn test_render_multi_page_and_converter_output() {
+    let mut document = Document::new();
+    for number in 1..=2 {
+        let mut page = Page::new(number);
+        page.add_content(ContentBlock::Text(format!("Page text {}", number)));
+        document.add_page(page);
+    }
+    assert_eq!(
+        document.to_markdown(),
+        "---\n## Page 1\n\nPage text 1\n\n---\n## Page 2\n\nPage text 2\n"
+    );
+
+    let log = "2024-01-15 10:30:45 ERROR [main] app - Disk full\n";
+    let md = MarkItDown::new();
+    let document = md
+        .convert_bytes(
+            Bytes::from(log),
+            Some(ConversionOptions::default().with_extension(".log")),
+        )
+        .await
+        .unwrap();
+    let markdown = document.to_markdown_with(&MarkdownRenderer::new().without_emoji());
+    assert!(markdown.contains("## Errors\n"));
+    assert!(!markdown.contains('🔴'));
+}
+
+// ============================================================================
+// Structured blocks and inline spans
+// ============================================================================
+
+fn structured_page() -> Page {
+    let mut page = Page::new(1);
+    page.add_content(ContentBlock::Paragraph(vec![
+        Inline::text("Run "),
+        Inline::Code("make `all`".to_string()),
+        Inline::text(" as "),
+        Inline::Strong(vec![Inline::text("root ")]),
+        Inline::text("and read the "),
+        Inline::Link {
+            content: vec![Inline::Emphasis(vec![Inline::text("guide")])],
+            url: "https://example.com/guide".to_string(),
+            title: Some("The \"guide\"".to_string()),
+        },
+        Inline::FootnoteReference("1".to_string()),
+        Inline::text(", see "),
+        Inline::Link {
+            content: vec![Inline::text("https://example.com")],
+            url: "https://example.com".to_string(),
+            title: None,
+        },
+        Inline::text(". "),
+        Inline::Strikethrough(vec![Inline::text("Old")]),
+        Inline::LineBreak,
+        Inline::text("Mean "),
+        Inline::Math("\\bar{x}".to_string()),
+    ]));
+    page.add_content(ContentBlock::NestedList {
+        ordered: false,
+        items: vec![
+            ListItem::new(vec![Inline::text("Setup")])
+                .with_checkbox(true)
+                .with_children(vec![ContentBlock::NestedList {
+                    ordered: true,
+                    items: vec![
+                        ListItem::new(vec![Inline::text("Install")]),
+                        ListItem::new(vec![Inline::text("Configure")]).with_children(vec![
+                            ContentBlock::Code {
+                                language: Some("sh".to_string()),
+                                code: "init\n\nrun".to_string(),
+                            },
+                        ]),
+                    ],
+                }]),
+            ListItem::new(vec![Inline::text("Deploy")]).with_checkbox(false),
+        ],
+    });
+    page.add_content(ContentBlock::Math("E = mc^2".to_string()));
+    page.add_content(ContentBlock::Rule);
+    page.add_content(ContentBlock::DefinitionList(vec![Definition {
+        term: vec![Inline::Strong(vec![Inline::text("Latency")])],
+        definitions: vec![
+            vec![Inline::text("Time to first byte")],
+            vec![Inline::text("Measured in ms")],
+        ],
+    }]));
+    page.add_content(ContentBlock::PageBreak);
+    page.add_content(ContentBlock::FootnoteDefinition {
+        label: "1".to_string(),
+        content: vec![
+            ContentBlock::Text("First paragraph.".to_string()),
+            ContentBlock::Text("Second paragraph.".to_string()),
+        ],
+    });
+    page
+}
+
+#[test]
+fn test_render_inline_spans() {
+    let markdown = structured_page().to_markdown();
+
+    assert!(markdown.starts_with(
+        "Run `` make `all` `` as **root** and read the \
+         [*guide*](https://example.com/guide \"The \\\"guide\\\"\")[^1], \
+         see <https://example.com>. ~~Old~~\\\nMean $\\bar{x}$\n"
+    ));
+
+    let plain = MarkdownRenderer::new()
+        .with_flavor(MarkdownFlavor::Plain)
+        .render_page(&structured_page());
+    assert!(plain.starts_with(
+        "Run make `all` as root and read the guide (https://example.com/guide)[1], \
+         see https://example.com. Old\nMean \\bar{x}\n"
+    ));
+
+    let commonmark = MarkdownRenderer::new()
+        .with_flavor(MarkdownFlavor::CommonMark)
+        .render_page(&structured_page());
+    assert!(commonmark.contains("<del>Old</del>"));
+}
+
+#[test]
+fn test_render_nested_list_and_blocks() {
+    let markdown = structured_page().to_markdown();
+
+    assert!(markdown.contains(
+        "- [x] Setup\n  1. Install\n  2. Configure\n     ```sh\n     init\n\n     run\n     ```\n- [ ] Deploy"
+    ));
+    assert!(markdown.contains(
+        "$$\nE = mc^2\n$$\n\n---\n\n**Latency**\n: Time to first byte\n: Measured in ms\n\n---"
+    ));
+    assert!(markdown.ends_with("[^1]: First paragraph.\n\n    Second paragraph.\n"));
+
+    let plain = MarkdownRenderer::new()
+        .with_flavor(MarkdownFlavor::Plain)
+        .render_page(&structured_page());
+    assert!(
+        plain.contains("E = mc^2\n\nLatency\n  Time to first byte\n  Measured in ms\n\n[1] First")
+    );
+    assert!(!plain.contains("---"));
+}
+
+#[test]
+fn test_render_nested_list_wrapping() {
+    let mut page = Page::new(1);
+    page.add_content(ContentBlock::NestedList {
+        ordered: false,
+        items: vec![
+            ListItem::new(vec![Inline::text("one two three four five six seven")])
+                .with_checkbox(false),
+        ],
+    });
+
+    let markdown = MarkdownRenderer::new()
+        .with_line_width(16)
+        .render_page(&page);
+    assert_eq!(
+        markdown,
+        "- [ ] one two\n      three four\n      five six\n      seven\n"
+    );
+}
+
+// ============================================================================
+// Tables
+// ============================================================================
+
+#[test]
+fn test_render_ragged_and_headerless_tables() {
+    let ragged = ContentBlock::Table {
+        headers: vec!["Name".to_string()],
+        rows: vec![
+            vec!["Ada".to_string(), "1815".to_string()],
+            vec!["Alan".to_string(), "1912".to_string(), "London".to_string()],
+        ],
+    };
+    assert_eq!(
+        ragged.to_markdown(),
+        "| Name |  |  |\n| --- | --- | --- |\n| Ada | 1815 |  |\n| Alan | 1912 | London |\n"
+    );
+
+    let headerless = ContentBlock::RichTable(
+        Table::new()
+            .with_alignments(vec![Alignment::Left, Alignment::Right])
+            .with_row(vec!["a|b".into(), "2".into()]),
+    );
+    assert_eq!(
+        headerless.to_markdown(),
+        "|  |  |\n| :--- | ---: |\n| a\\|b | 2 |\n"
+    );
+}
+
+#[test]
+fn test_render_simple_rich_table_with_caption_and_footer() {
+    let table = Table::from_rows(
+        vec!["Item".to_string(), "Price".to_string()],
+        vec![vec!["Tea".to_string(), "3".to_string()]],
+    )
+    .with_caption("Orders")
+    .with_alignments(vec![Alignment::Default, Alignment::Right])
+    .with_footer_row(vec!["Total".into(), "3".into()]);
+    let block = ContentBlock::RichTable(table);
+
+    assert_eq!(
+        block.to_markdown(),
+        "Orders\n\n| Item | Price |\n| --- | ---: |\n| Tea | 3 |\n| Total | 3 |\n"
+    );
+
+    let plain = MarkdownRenderer::new()
+        .with_flavor(MarkdownFlavor::Plain)
+        .render_block(&block);
+    assert_eq!(plain, "Orders\nItem | Price\nTea | 3\nTotal | 3\n");
+}
+
+#[test]
+fn test_render_merged_cells_as_html() {
+    let table = Table::new()
+        .with_caption("Q1 <draft>")
+        .with_alignments(vec![
+            Alignment::Default,
+            Alignment::Center,
+            Alignment::Center,
+        ])
+        .with_header_row(vec![
+            TableCell::new("Region").with_rowspan(2),
+            TableCell::new("Sales").with_colspan(2),
+        ])
+        .with_header_row(vec!["Jan".into(), "Feb".into()])
+        .with_row(vec![
+            TableCell::new("North").with_rowspan(2),
+            "1".into(),
+            "2".into(),
+        ])
+        .with_row(vec!["3".into(), "4".into()]);
+    assert!(!table.is_simple());
+    assert_eq!(table.column_count(), 3);
+    let block = ContentBlock::RichTable(table);
+
+    let expected = "<table>\n\
+        <caption>Q1 &lt;draft&gt;</caption>\n\
+        <thead><tr><th rowspan=\"2\">Region</th><th colspan=\"2\" align=\"center\">Sales</th></tr>\
+        <tr><th align=\"center\">Jan</th><th align=\"center\">Feb</th></tr></thead>\n\
+        <tbody>\n\
+        <tr><td rowspan=\"2\">North</td><td align=\"center\">1</td><td align=\"center\">2</td></tr>\n\
+        <tr><td align=\"center\">3</td><td align=\"center\">4</td></tr>\n\
+        </tbody>\n\
+        </table>\n";
+    // GFM falls back to HTML, CommonMark always uses it
+    assert_eq!(block.to_markdown(), expected);
+    let commonmark = MarkdownRenderer::new()
+        .with_flavor(MarkdownFlavor::CommonMark)
+        .render_block(&block);
+    assert_eq!(commonmark, expected);
+}
+
+#[test]
+fn test_render_clamps_oversized_spans() {
+    // Spans may come from deserialized input and are not trusted as-is
+    let huge = TableCell {
+        text: "Wide".to_string(),
+        colspan: usize::MAX,
+        rowspan: usize::MAX,
+    };
+    let table = Table::new()
+        .with_header_row(vec![huge, "Next".into()])
+        .with_row(vec!["a".into()]);
+    assert_eq!(table.column_count(), 1001);
+
+    let html = ContentBlock::RichTable(table).to_markdown();
+    assert!(html.contains("<th colspan=\"1000\" rowspan=\"1000\">Wide</th>"));
+}
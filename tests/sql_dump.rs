@@ -0,0 +1,165 @@
+//! SQL script (.sql) conversion tests
+
+use bytes::Bytes;
+use markitdown::{ConversionOptions, DatabaseOptions, MarkItDown};
+use std::fs;
+
+fn default_options(ext: &str) -> ConversionOptions {
+    ConversionOptions {
+        file_extension: Some(ext.to_string()),
+        url: None,
+        llm_client: None,
+        image_context_path: None,
+        extract_images: true,
+        force_llm_ocr: false,
+        merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
+    }
+}
+
+const TEST_DIR: &str = "tests/test_documents/databases";
+
+fn test_file(name: &str) -> String {
+    format!("{}/{}", TEST_DIR, name)
+}
+
+async fn convert_dump(name: &str, database: DatabaseOptions) -> markitdown::Document {
+    let md = MarkItDown::new();
+    let bytes = fs::read(test_file(name)).expect("Failed to read file");
+    md.convert_bytes(
+        Bytes::from(bytes),
+        Some(default_options(".sql").with_database_options(database)),
+    )
+    .await
+    .unwrap()
+}
+
+// ============================================================================
+// SQLite-style dumps
+// ============================================================================
+
+#[tokio::test]
+async fn test_sql_dump_schema_and_relationships() {
+    let md = MarkItDown::new();
+    let doc = md.convert(&test_file("shop_dump.sql"), None).await.unwrap();
+
+    for (key, value) in [
+        ("dialect", "SQLite"),
+        ("table_count", "2"),
+        ("view_count", "1"),
+        ("index_count", "2"),
+        ("trigger_count", "1"),
+        ("row_count", "7"),
+    ] {
+        assert_eq!(doc.metadata.get(key).map(String::as_str), Some(value));
+    }
+
+    let content = doc.to_markdown();
+    assert!(content.contains("## Table: `customers`"));
+    assert!(content.contains("| id | INTEGER | No |  | Yes |"));
+    assert!(content.contains("| created_at | TEXT | Yes | CURRENT_TIMESTAMP | No |"));
+    assert!(content.contains("| customer_id | INTEGER | No |  | No | customers.id |"));
+    assert!(content.contains("| total | NUMERIC(10, 2) | Yes | 0 | No |  |"));
+    assert!(content.contains("- `idx_customers_email` on (email) — unique"));
+    assert!(content.contains("```sql\nCREATE TRIGGER orders_touch AFTER INSERT ON orders"));
+    assert!(content.contains("## View: `big_orders`"));
+    assert!(content.contains("- `orders.customer_id` → `customers.id` (on delete cascade)"));
+}
+
+#[tokio::test]
+async fn test_sql_dump_rows_and_statistics() {
+    let content = convert_dump("shop_dump.sql", DatabaseOptions::default())
+        .await
+        .to_markdown();
+    // Semicolons and doubled quotes inside strings stay in the value
+    assert!(content.contains("| 3 | Alan Turing; Jr. | alan@example.com | 2024-03-20 |"));
+    assert!(content.contains("| 2 | 1 | 5.5 | It's a gift | NULL |"));
+    assert!(content.contains("| 1 | 1 | 19.99 | First order | <BLOB 4 bytes> |"));
+    assert!(content.contains("| 4 | 3 | 120 | Bulk \\| wholesale | NULL |"));
+    assert!(content.contains("| total | NUMERIC(10, 2) | 0 | 4 | -2 | 120 | 35.8725 |"));
+    assert!(content.contains("| email | TEXT | 1 | 2 | ada@example.com | alan@example.com |  |"));
+
+    let content = convert_dump("shop_dump.sql", DatabaseOptions::new().with_sample_rows(2))
+        .await
+        .to_markdown();
+    assert!(content.contains("### Sample Data (first 2 rows)"));
+    assert!(!content.contains("| 3 | 2 | -2 | NULL | NULL |"));
+    assert!(content.contains("**Row count:** 4"));
+}
+
+#[tokio::test]
+async fn test_sql_dump_respects_database_options() {
+    let content = convert_dump(
+        "shop_dump.sql",
+        DatabaseOptions::new()
+            .with_views(false)
+            .with_indexes(false)
+            .with_triggers(false)
+            .with_foreign_keys(false),
+    )
+    .await
+    .to_markdown();
+    assert!(!content.contains("big_orders"));
+    assert!(!content.contains("### Indexes"));
+    assert!(!content.contains("orders_touch"));
+    assert!(!content.contains("## Relationships"));
+    assert!(!content.contains("| References |"));
+}
+
+// ============================================================================
+// pg_dump
+// ============================================================================
+
+#[tokio::test]
+async fn test_pg_dump_copy_and_alter_table() {
+    let doc = convert_dump("inventory_pg_dump.sql", DatabaseOptions::default()).await;
+    assert_eq!(
+        doc.metadata.get("dialect").map(String::as_str),
+        Some("PostgreSQL")
+    );
+    assert_eq!(doc.metadata.get("row_count").map(String::as_str), Some("5"));
+
+    let content = doc.to_markdown();
+    assert!(content.contains("## Table: `public.warehouses`"));
+    assert!(content.contains("| id | integer | No |  | Yes |"));
+    assert!(content.contains("| city | character varying(64) | No |  | No |"));
+    assert!(content
+        .contains("| label | character varying(32) | Yes | 'new'::character varying | No |  |"));
+    // numeric values keep their digits; statistics use their approximate value
+    assert!(content.contains("| 1 | B-200 | 0 | 12.00 | NULL |"));
+    assert!(content.contains("| quantity | integer | 0 | 3 | 0 | 12 | 6.3333 |"));
+    assert!(content.contains("| price | numeric(8, 2) | 0 | 3 | 3.5 | 12 | 6.4167 |"));
+    assert!(content.contains("- `stock_sku_idx` on (sku)"));
+    assert!(content
+        .contains("- `public.stock.warehouse_id` → `public.warehouses.id` (on delete restrict)"));
+    // The function body is not mistaken for a table
+    assert!(!content.contains("touch"));
+}
+
+#[tokio::test]
+async fn test_sql_script_without_tables_is_code() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert_bytes(
+            Bytes::from_static(b"SELECT name FROM customers WHERE id = 1;\n"),
+            Some(default_options(".sql")),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        doc.metadata.get("table_count").map(String::as_str),
+        Some("0")
+    );
+    assert!(doc
+        .to_markdown()
+        .contains("```sql\nSELECT name FROM customers WHERE id = 1;\n```"));
+}
@@ -0,0 +1,182 @@
+//! SQLite (.sqlite, .db) conversion tests
+
+use bytes::Bytes;
+use markitdown::{BlobMode, ConversionOptions, DatabaseOptions, MarkItDown};
+use std::fs;
+
+fn default_options(ext: &str) -> ConversionOptions {
+    ConversionOptions {
+        file_extension: Some(ext.to_string()),
+        url: None,
+        llm_client: None,
+        image_context_path: None,
+        extract_images: true,
+        force_llm_ocr: false,
+        merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
+    }
+}
+
+const TEST_DIR: &str = "tests/test_documents/databases";
+
+fn test_file(name: &str) -> String {
+    format!("{}/{}", TEST_DIR, name)
+}
+
+async fn convert_shop(database: DatabaseOptions) -> markitdown::Document {
+    let md = MarkItDown::new();
+    let bytes = fs::read(test_file("shop.sqlite")).expect("Failed to read file");
+    md.convert_bytes(
+        Bytes::from(bytes),
+        Some(default_options(".sqlite").with_database_options(database)),
+    )
+    .await
+    .unwrap()
+}
+
+// ============================================================================
+// Schema
+// ============================================================================
+
+#[tokio::test]
+async fn test_sqlite_schema_and_relationships() {
+    let md = MarkItDown::new();
+    let doc = md.convert(&test_file("shop.sqlite"), None).await.unwrap();
+
+    for (key, value) in [
+        ("table_count", "3"),
+        ("view_count", "1"),
+        ("index_count", "2"),
+        ("trigger_count", "1"),
+    ] {
+        assert_eq!(doc.metadata.get(key).map(String::as_str), Some(value));
+    }
+
+    let content = doc.to_markdown();
+    assert!(content.contains("## Table: `customers`"));
+    assert!(content.contains("| Column | Type | Nullable | Default | Primary Key | References |"));
+    assert!(content.contains("| customer_id | INTEGER | No |  | No | customers.id |"));
+    assert!(content.contains("| total | REAL | Yes | 0 | No |  |"));
+    assert!(content.contains("## Relationships"));
+    assert!(content.contains("- `orders.customer_id` → `customers.id` (on delete cascade)"));
+    assert!(content.contains("- `order \"items\".order_id` → `orders.id`"));
+}
+
+#[tokio::test]
+async fn test_sqlite_indexes_triggers_and_views() {
+    let doc = convert_shop(DatabaseOptions::default()).await;
+    let content = doc.to_markdown();
+
+    assert!(content.contains("- `idx_orders_customer` on (customer_id)"));
+    assert!(content.contains("on (email) — unique, from UNIQUE constraint"));
+    assert!(content.contains("**orders_touch**"));
+    assert!(content.contains("```sql\nCREATE TRIGGER orders_touch AFTER INSERT ON orders"));
+    assert!(content.contains("## View: `customer_totals`"));
+    assert!(content.contains("| Ann Lee | 168 |"));
+
+    let doc = convert_shop(
+        DatabaseOptions::new()
+            .with_views(false)
+            .with_indexes(false)
+            .with_triggers(false)
+            .with_foreign_keys(false),
+    )
+    .await;
+    let content = doc.to_markdown();
+    assert!(!content.contains("customer_totals"));
+    assert!(!content.contains("### Indexes"));
+    assert!(!content.contains("orders_touch"));
+    assert!(!content.contains("## Relationships"));
+    assert!(!content.contains("| References |"));
+}
+
+// ============================================================================
+// Rows
+// ============================================================================
+
+#[tokio::test]
+async fn test_sqlite_sample_size_and_full_dump() {
+    let content = convert_shop(DatabaseOptions::default()).await.to_markdown();
+    assert!(content.contains("**Row count:** 8"));
+    assert!(content.contains("### Sample Data (first 5 rows)"));
+    assert!(content.contains("| 5 | 1 | 52.5 | NULL |"));
+    assert!(!content.contains("| 6 | 2 | 63 | NULL |"));
+    // Pipes inside values are escaped
+    assert!(content.contains("| 1 | 1 | 10.5 | rush \\| gift |"));
+
+    let content = convert_shop(DatabaseOptions::new().with_sample_rows(2))
+        .await
+        .to_markdown();
+    assert!(content.contains("### Sample Data (first 2 rows)"));
+    assert!(!content.contains("| 3 | 1 | 31.5 | NULL |"));
+
+    let content = convert_shop(DatabaseOptions::new().with_full_dump())
+        .await
+        .to_markdown();
+    assert!(content.contains("### Data"));
+    assert!(content.contains("| 8 | 2 | 84 | NULL |"));
+}
+
+#[tokio::test]
+async fn test_sqlite_quoted_table_names() {
+    let content = convert_shop(DatabaseOptions::default()).await.to_markdown();
+    assert!(content.contains("## Table: `order \"items\"`"));
+    assert!(content.contains("| order_id | sku | qty | payload |"));
+    assert!(content.contains("| 1 | SKU-1 | 2 | <BLOB 20 bytes> |"));
+}
+
+// ============================================================================
+// BLOBs
+// ============================================================================
+
+#[tokio::test]
+async fn test_sqlite_blob_modes() {
+    let doc = convert_shop(DatabaseOptions::default()).await;
+    assert!(doc.images().is_empty());
+    assert!(doc
+        .to_markdown()
+        .contains("| 1 | Ann Lee | ann@example.com | <BLOB 69 bytes> |"));
+
+    let content = convert_shop(DatabaseOptions::new().with_blobs(BlobMode::HexPreview))
+        .await
+        .to_markdown();
+    assert!(content.contains(
+        "| 1 | SKU-1 | 2 | <BLOB 20 bytes: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f …> |"
+    ));
+    assert!(content.contains("<BLOB 69 bytes: 89 50 4e 47"));
+
+    let doc = convert_shop(DatabaseOptions::new().with_blobs(BlobMode::Images)).await;
+    let images = doc.images();
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].id, "customers_avatar_1");
+    assert_eq!(images[0].mime_type, "image/png");
+    assert_eq!(
+        images[0].alt_text.as_deref(),
+        Some("customers.avatar (row 1)")
+    );
+    let content = doc.to_markdown();
+    assert!(content.contains("<image customers_avatar_1: image/png, 69 bytes>"));
+    // Non-image BLOBs still show their size
+    assert!(content.contains("| 1 | SKU-1 | 2 | <BLOB 20 bytes> |"));
+}
+
+#[tokio::test]
+async fn test_sqlite_rejects_invalid_input() {
+    let md = MarkItDown::new();
+    let result = md
+        .convert_bytes(
+            Bytes::from_static(b"not a database"),
+            Some(default_options(".sqlite")),
+        )
+        .await;
+    assert!(result.is_err());
+}
@@ -3,9 +3,38 @@
 //! These tests verify the table merging functionality that combines
 //! tables spanning multiple pages into single coherent tables.
 
+use bytes::Bytes;
 use markitdown::table_merge::{
     can_merge_tables, detect_table_fragments, merge_table_fragments, merge_tables_across_pages,
 };
+use markitdown::{ContentBlock, Document, ExtractedImage, Page, Provenance, TableSpan};
+
+fn cells(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+fn table(headers: &[&str], rows: &[&[&str]]) -> ContentBlock {
+    ContentBlock::Table {
+        headers: cells(headers),
+        rows: rows.iter().map(|row| cells(row)).collect(),
+    }
+}
+
+fn page(number: u32, blocks: Vec<ContentBlock>) -> Page {
+    let mut page = Page::new(number);
+    for block in blocks {
+        page.add_content(block);
+    }
+    page
+}
+
+fn document(pages: Vec<Page>) -> Document {
+    let mut doc = Document::new();
+    for page in pages {
+        doc.add_page(page);
+    }
+    doc
+}
 
 // ============================================================================
 // Table Detection Tests
@@ -403,3 +432,190 @@ fn test_employee_roster_continuation() {
     assert!(content.contains("Carol"));
     assert!(content.contains("David"));
 }
+
+// ============================================================================
+// Content Block Merge Tests
+// ============================================================================
+
+#[test]
+fn test_merge_table_blocks_keeps_other_blocks() {
+    let image = ExtractedImage::new("chart", Bytes::from_static(b"png"), "image/png");
+    let doc = document(vec![
+        page(
+            1,
+            vec![
+                ContentBlock::Heading {
+                    level: 1,
+                    text: "Inventory".to_string(),
+                },
+                ContentBlock::Image(image),
+                table(&["Item", "Count"], &[&["Bolts", "10"]]),
+            ],
+        ),
+        page(
+            2,
+            vec![
+                table(&["Item", "Count"], &[&["Nuts", "20"]]),
+                ContentBlock::Text("Counted in March.".to_string()),
+            ],
+        ),
+    ]);
+
+    let merged = doc.with_merged_tables();
+
+    // The repeated header is dropped and the rows joined on page 1
+    assert_eq!(merged.pages[0].content.len(), 3);
+    assert!(matches!(
+        &merged.pages[0].content[0],
+        ContentBlock::Heading { text, .. } if text == "Inventory"
+    ));
+    assert_eq!(merged.images().len(), 1);
+    assert!(matches!(
+        &merged.pages[0].content[2],
+        ContentBlock::Table { headers, rows }
+            if headers == &cells(&["Item", "Count"])
+                && rows == &vec![cells(&["Bolts", "10"]), cells(&["Nuts", "20"])]
+    ));
+    assert!(matches!(
+        merged.pages[1].content.as_slice(),
+        [ContentBlock::Text(text)] if text == "Counted in March."
+    ));
+    assert_eq!(
+        merged.table_spans,
+        vec![TableSpan {
+            page_number: 1,
+            block_index: 2,
+            pages: vec![1, 2],
+        }]
+    );
+}
+
+#[test]
+fn test_merge_table_blocks_across_three_pages() {
+    let doc = document(vec![
+        page(1, vec![table(&["Year", "Sales"], &[&["2020", "5"]])]),
+        page(2, vec![table(&["Year", "Sales"], &[&["2021", "7"]])]),
+        page(
+            3,
+            vec![
+                table(&[], &[&["Year", "Sales"], &["2022", "9"]]),
+                ContentBlock::Text("Source: ledger".to_string()),
+            ],
+        ),
+    ]);
+
+    let merged = doc.with_merged_tables();
+
+    let ContentBlock::Table { rows, .. } = &merged.pages[0].content[0] else {
+        panic!("expected a table");
+    };
+    assert_eq!(rows.len(), 3, "repeated header rows are dropped");
+    assert!(merged.pages[1].content.is_empty());
+    assert_eq!(merged.pages[2].content.len(), 1);
+    assert_eq!(merged.table_spans[0].pages, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_merge_table_blocks_with_continued_captions() {
+    let doc = document(vec![
+        page(
+            1,
+            vec![
+                table(&["Name", "Role"], &[&["Ada", "Lead"]]),
+                ContentBlock::Text("(continued on next page)".to_string()),
+            ],
+        ),
+        page(
+            2,
+            vec![
+                ContentBlock::Text("Table 4 – continued".to_string()),
+                // The first row of the continuation was taken as its header
+                table(&["Lee", "Analyst"], &[&["Kim", "Engineer"]]),
+            ],
+        ),
+    ]);
+
+    let merged = doc.with_merged_tables();
+
+    assert_eq!(merged.pages[0].content.len(), 1);
+    assert!(matches!(
+        &merged.pages[0].content[0],
+        ContentBlock::Table { rows, .. } if rows.len() == 3 && rows[1] == cells(&["Lee", "Analyst"])
+    ));
+    assert!(merged.pages[1].content.is_empty());
+}
+
+#[test]
+fn test_unrelated_table_blocks_are_not_merged() {
+    let doc = document(vec![
+        page(1, vec![table(&["A", "B"], &[&["1", "2"]])]),
+        page(2, vec![table(&["C", "D"], &[&["3", "4"]])]),
+        page(3, vec![table(&["C", "D", "E"], &[&["5", "6", "7"]])]),
+    ]);
+
+    let merged = doc.with_merged_tables();
+
+    assert!(merged.table_spans.is_empty());
+    assert!(merged.pages.iter().all(|p| p.content.len() == 1));
+}
+
+#[test]
+fn test_merge_tables_in_markdown_blocks() {
+    let doc = document(vec![
+        page(
+            1,
+            vec![ContentBlock::Markdown(
+                "## Results\n\n| Run | Score |\n| --- | --- |\n| 1 | 0.8 |\n".to_string(),
+            )],
+        ),
+        page(
+            2,
+            vec![ContentBlock::Markdown(
+                "| 2 | 0.9 |\n| 3 | 0.7 |\n\nScores are averaged.".to_string(),
+            )],
+        ),
+    ]);
+
+    let merged = doc.with_merged_tables();
+
+    assert_eq!(merged.pages[0].content.len(), 2);
+    assert!(matches!(
+        &merged.pages[0].content[1],
+        ContentBlock::Table { headers, rows } if headers == &cells(&["Run", "Score"]) && rows.len() == 3
+    ));
+    assert_eq!(merged.pages[1].to_markdown().trim(), "Scores are averaged.");
+    assert_eq!(merged.table_spans[0].block_index, 1);
+}
+
+#[test]
+fn test_merge_tables_keeps_provenance() {
+    let mut first = Page::new(1);
+    first.add_content_with_provenance(
+        ContentBlock::Markdown(
+            "## Results\n\n| Run | Score |\n| --- | --- |\n| 1 | 0.8 |\n".to_string(),
+        ),
+        Provenance::pdf_page(1, None),
+    );
+    let mut second = Page::new(2);
+    second.add_content_with_provenance(
+        ContentBlock::Markdown("| 2 | 0.9 |\n\nScores are averaged.".to_string()),
+        Provenance::pdf_page(2, None),
+    );
+
+    let merged = document(vec![first, second]).with_merged_tables();
+
+    // Both parts of the split markdown block keep the page they came from
+    let pages: Vec<(usize, Option<u32>)> = merged.pages[0]
+        .provenance
+        .iter()
+        .map(|p| (p.block_index, p.page))
+        .collect();
+    assert_eq!(pages, vec![(0, Some(1)), (1, Some(1))]);
+    // The note after the continuation moved up to the first block
+    assert_eq!(merged.pages[1].content.len(), 1);
+    assert_eq!(
+        merged.pages[1].provenance_of(0).and_then(|p| p.page),
+        Some(2)
+    );
+    assert_eq!(merged.pages[1].provenance.len(), 1);
+}
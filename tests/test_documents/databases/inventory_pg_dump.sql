@@ -0,0 +1,46 @@
+--
+-- PostgreSQL database dump
+--
+
+SET statement_timeout = 0;
+SET client_encoding = 'UTF8';
+
+CREATE TABLE public.warehouses (
+    id integer NOT NULL,
+    city character varying(64) NOT NULL
+);
+
+CREATE TABLE public.stock (
+    warehouse_id integer NOT NULL,
+    sku text NOT NULL,
+    quantity integer DEFAULT 0,
+    price numeric(8,2),
+    label character varying(32) DEFAULT 'new'::character varying
+);
+
+CREATE FUNCTION public.touch() RETURNS trigger
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    NEW.quantity := NEW.quantity; RETURN NEW;
+END;
+$$;
+
+COPY public.warehouses (id, city) FROM stdin;
+1	Berlin
+2	Lisbon
+\.
+
+COPY public.stock (warehouse_id, sku, quantity, price, label) FROM stdin;
+1	A-100	12	3.50	new
+1	B-200	0	12.00	\N
+2	A-100	7	3.75	tab\there
+\.
+
+ALTER TABLE ONLY public.warehouses
+    ADD CONSTRAINT warehouses_pkey PRIMARY KEY (id);
+
+ALTER TABLE ONLY public.stock
+    ADD CONSTRAINT stock_warehouse_fkey FOREIGN KEY (warehouse_id) REFERENCES public.warehouses(id) ON DELETE RESTRICT;
+
+CREATE INDEX stock_sku_idx ON public.stock USING btree (sku);
@@ -0,0 +1,31 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE customers (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    email TEXT UNIQUE,
+    created_at TEXT DEFAULT CURRENT_TIMESTAMP
+);
+INSERT INTO customers VALUES(1,'Ada Lovelace','ada@example.com','2024-01-05');
+INSERT INTO customers VALUES(2,'Grace Hopper',NULL,'2024-02-11');
+INSERT INTO customers VALUES(3,'Alan Turing; Jr.','alan@example.com','2024-03-20');
+CREATE TABLE orders (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    customer_id INTEGER NOT NULL REFERENCES customers(id) ON DELETE CASCADE,
+    total NUMERIC(10, 2) DEFAULT 0,
+    note TEXT,
+    receipt BLOB
+);
+INSERT INTO orders (id, customer_id, total, note, receipt) VALUES
+    (1, 1, 19.99, 'First order', X'00010203'),
+    (2, 1, 5.5, 'It''s a gift', NULL),
+    (3, 2, -2, NULL, NULL),
+    (4, 3, 120, 'Bulk | wholesale', NULL);
+CREATE INDEX idx_orders_customer ON orders (customer_id);
+CREATE UNIQUE INDEX idx_customers_email ON customers (email);
+CREATE VIEW big_orders AS SELECT * FROM orders WHERE total > 100;
+CREATE TRIGGER orders_touch AFTER INSERT ON orders
+BEGIN
+    UPDATE customers SET created_at = created_at WHERE id = NEW.customer_id;
+END;
+COMMIT;
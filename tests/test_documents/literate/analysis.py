@@ -0,0 +1,25 @@
+# ---
+# jupyter:
+#   jupytext:
+#     formats: ipynb,py:percent
+#   kernelspec:
+#     display_name: Python 3
+#     language: python
+#     name: python3
+# ---
+
+# %% [markdown]
+# # Rainfall analysis
+#
+# Load the **monthly** totals.
+
+# %%
+import pandas as pd
+
+totals = pd.read_csv("rain.csv")
+
+# %% Plot tags=["figure"]
+totals.plot()
+
+# %% [raw]
+not executed
@@ -3,7 +3,7 @@
 //! Tests for text file conversions.
 
 use bytes::Bytes;
-use markitdown::{ConversionOptions, MarkItDown};
+use markitdown::{ConversionOptions, LineRange, MarkItDown};
 use std::fs;
 
 fn default_options(ext: &str) -> ConversionOptions {
@@ -15,6 +15,16 @@ fn default_options(ext: &str) -> ConversionOptions {
         extract_images: true,
         force_llm_ocr: false,
         merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
     }
 }
 
@@ -82,6 +92,20 @@ async fn test_text_bytes_conversion() {
     );
 }
 
+#[tokio::test]
+async fn test_text_line_provenance() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("fake_text.txt"), Some(default_options(".txt")))
+        .await
+        .unwrap();
+
+    let provenance = doc.pages[0]
+        .provenance_of(0)
+        .expect("text block provenance");
+    assert_eq!(provenance.lines, Some(LineRange { start: 1, end: 9 }));
+}
+
 // ============================================================================
 // Markdown Passthrough Tests
 // ============================================================================
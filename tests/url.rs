@@ -0,0 +1,276 @@
+//! URL conversion tests against a local stand-in HTTP server
+use async_trait::async_trait;
+use bytes::Bytes;
+use markitdown::error::MarkitdownError;
+use markitdown::fetch::{FetchLimits, HttpResponse, ReqwestFetcher};
+use markitdown::{HttpFetcher, ImageFetcher, MarkItDown};
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// A canned response served by the stand-in server
+struct Route {
+    path: &'static str,
+    status: &'static str,
+    headers: Vec<(&'static str, String)>,
+    body: Vec<u8>,
+    delay: Option<Duration>,
+}
+
+impl Route {
+    fn ok(path: &'static str, content_type: &str, body: &[u8]) -> Self {
+        Self {
+            path,
+            status: "200 OK",
+            headers: vec![("Content-Type", content_type.to_string())],
+            body: body.to_vec(),
+            delay: None,
+        }
+    }
+}
+
+/// Serve `routes` on a local port; returns the base URL
+fn serve(routes: Vec<Route>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { continue };
+
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                match stream.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => request.extend_from_slice(&buf[..n]),
+                }
+            }
+            let request = String::from_utf8_lossy(&request);
+            let path = request.split_whitespace().nth(1).unwrap_or("/");
+
+            let response = match routes.iter().find(|r| r.path == path) {
+                Some(route) => {
+                    if let Some(delay) = route.delay {
+                        thread::sleep(delay);
+                    }
+                    let mut head = format!(
+                        "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+                        route.status,
+                        route.body.len()
+                    );
+                    for (name, value) in &route.headers {
+                        head.push_str(&format!("{}: {}\r\n", name, value));
+                    }
+                    head.push_str("\r\n");
+                    let mut bytes = head.into_bytes();
+                    bytes.extend_from_slice(&route.body);
+                    bytes
+                }
+                None => b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    .to_vec(),
+            };
+            let _ = stream.write_all(&response);
+        }
+    });
+
+    base
+}
+
+const PAGE: &[u8] = br#"<html><head><title>Stand-in Page</title></head><body>
+<h1>Hello</h1><p>Read the <a href="docs/intro.html">introduction</a>.</p>
+</body></html>"#;
+
+#[tokio::test]
+async fn test_url_html_page() {
+    let base = serve(vec![Route::ok("/page", "text/html; charset=utf-8", PAGE)]);
+    let url = format!("{}/page", base);
+
+    let markitdown = MarkItDown::new();
+    let doc = markitdown.convert(&url, None).await.unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Stand-in Page"));
+    assert_eq!(doc.metadata.get("source_url"), Some(&url));
+    assert_eq!(doc.metadata.get("final_url"), Some(&url));
+    assert_eq!(
+        doc.metadata.get("content_type").map(String::as_str),
+        Some("text/html; charset=utf-8")
+    );
+    let fetched_at = doc.metadata.get("fetched_at").expect("fetch time recorded");
+    assert!(chrono::DateTime::parse_from_rfc3339(fetched_at).is_ok());
+
+    let content = doc.to_markdown();
+    assert!(content.contains("# Hello"));
+    assert!(content.contains(&format!("[introduction]({}/docs/intro.html)", base)));
+}
+
+#[tokio::test]
+async fn test_url_follows_redirects() {
+    let mut redirect = Route::ok("/old", "text/plain", b"");
+    redirect.status = "301 Moved Permanently";
+    redirect.headers = vec![("Location", "/new/page.html".to_string())];
+    let base = serve(vec![
+        redirect,
+        Route::ok("/new/page.html", "text/html", PAGE),
+    ]);
+
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert_url(&format!("{}/old", base), None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        doc.metadata.get("final_url"),
+        Some(&format!("{}/new/page.html", base))
+    );
+    assert!(doc
+        .to_markdown()
+        .contains(&format!("({}/new/docs/intro.html)", base)));
+}
+
+#[tokio::test]
+async fn test_url_content_disposition_detection() {
+    let mut route = Route::ok(
+        "/download",
+        "application/octet-stream",
+        b"name,score\nAda,10\nGrace,12\n",
+    );
+    route.headers.push((
+        "Content-Disposition",
+        "attachment; filename=\"scores.csv\"".to_string(),
+    ));
+    let base = serve(vec![route]);
+
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert(&format!("{}/download", base), None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        doc.metadata.get("file_name").map(String::as_str),
+        Some("scores.csv")
+    );
+    let content = doc.to_markdown();
+    assert!(content.contains("| name | score |"));
+    assert!(content.contains("| Grace | 12 |"));
+}
+
+#[tokio::test]
+async fn test_url_extension_from_path() {
+    let base = serve(vec![Route::ok(
+        "/notes/readme.md",
+        "text/plain",
+        b"# Notes\n\nSee [setup](setup.md).",
+    )]);
+
+    let markitdown = MarkItDown::new();
+    let doc = markitdown
+        .convert(&format!("{}/notes/readme.md", base), None)
+        .await
+        .unwrap();
+
+    assert!(doc
+        .to_markdown()
+        .contains(&format!("[setup]({}/notes/setup.md)", base)));
+}
+
+#[tokio::test]
+async fn test_url_http_error() {
+    let base = serve(vec![]);
+
+    let markitdown = MarkItDown::new();
+    let result = markitdown
+        .convert_url(&format!("{}/missing", base), None)
+        .await;
+
+    assert!(matches!(result, Err(MarkitdownError::NetworkError(msg)) if msg.contains("404")));
+}
+
+#[tokio::test]
+async fn test_image_fetch_errors() {
+    let base = serve(vec![
+        Route::ok("/logo.png", "image/png", b"\x89PNG\r\n\x1a\n"),
+        Route {
+            path: "/broken.png",
+            status: "500 Internal Server Error",
+            headers: Vec::new(),
+            body: Vec::new(),
+            delay: None,
+        },
+    ]);
+    let fetcher = ReqwestFetcher::new();
+
+    let image = fetcher.fetch(&format!("{}/logo.png", base)).await.unwrap();
+    assert!(image.is_some());
+    let missing = fetcher.fetch(&format!("{}/missing.png", base)).await;
+    assert!(matches!(missing, Ok(None)));
+    let broken = fetcher.fetch(&format!("{}/broken.png", base)).await;
+    assert!(matches!(broken, Err(MarkitdownError::NetworkError(msg)) if msg.contains("500")));
+}
+
+#[tokio::test]
+async fn test_url_size_limit() {
+    let base = serve(vec![Route::ok("/page", "text/html", PAGE)]);
+
+    let mut markitdown = MarkItDown::new();
+    markitdown.set_fetch_limits(FetchLimits::default().with_max_bytes(16));
+    let result = markitdown
+        .convert_url(&format!("{}/page", base), None)
+        .await;
+
+    assert!(
+        matches!(result, Err(MarkitdownError::NetworkError(msg)) if msg.contains("size limit"))
+    );
+}
+
+#[tokio::test]
+async fn test_url_timeout() {
+    let mut slow = Route::ok("/slow", "text/html", PAGE);
+    slow.delay = Some(Duration::from_secs(2));
+    let base = serve(vec![slow]);
+
+    let mut markitdown = MarkItDown::new();
+    markitdown.set_fetch_limits(FetchLimits::default().with_timeout(Duration::from_millis(200)));
+    let result = markitdown
+        .convert_url(&format!("{}/slow", base), None)
+        .await;
+
+    assert!(matches!(result, Err(MarkitdownError::NetworkError(_))));
+}
+
+/// Fetcher that never touches the network
+struct StaticFetcher;
+
+#[async_trait]
+impl HttpFetcher for StaticFetcher {
+    async fn get(&self, url: &str, _limits: &FetchLimits) -> Result<HttpResponse, MarkitdownError> {
+        Ok(HttpResponse {
+            final_url: format!("{}?ref=static", url),
+            status: 200,
+            content_type: Some("application/json".to_string()),
+            content_disposition: None,
+            body: Bytes::from_static(br#"{"greeting": "hello"}"#),
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_url_custom_fetcher() {
+    let mut markitdown = MarkItDown::new();
+    markitdown.set_http_fetcher(Arc::new(StaticFetcher));
+
+    let doc = markitdown
+        .convert("https://example.com/data", None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        doc.metadata.get("final_url").map(String::as_str),
+        Some("https://example.com/data?ref=static")
+    );
+    assert!(doc.to_markdown().contains("\"greeting\": \"hello\""));
+}
@@ -0,0 +1,213 @@
+//! vCard (.vcf) conversion tests
+
+use bytes::Bytes;
+use markitdown::{ConversionOptions, MarkItDown};
+use std::fs;
+
+fn default_options(ext: &str) -> ConversionOptions {
+    ConversionOptions {
+        file_extension: Some(ext.to_string()),
+        url: None,
+        llm_client: None,
+        image_context_path: None,
+        extract_images: true,
+        force_llm_ocr: false,
+        merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
+    }
+}
+
+const TEST_DIR: &str = "tests/test_documents/contacts";
+
+fn test_file(name: &str) -> String {
+    format!("{}/{}", TEST_DIR, name)
+}
+
+// ============================================================================
+// vCard 3.0
+// ============================================================================
+
+#[tokio::test]
+async fn test_vcard_typed_properties() {
+    let md = MarkItDown::new();
+    let doc = md.convert(&test_file("team_v3.vcf"), None).await.unwrap();
+
+    assert_eq!(
+        doc.metadata.get("contact_count").map(String::as_str),
+        Some("3")
+    );
+    let content = doc.to_markdown();
+    assert!(content.contains("## Dr. Ann Lee"));
+    assert!(content.contains("**Organization:** Example Corp, Platform Engineering"));
+    assert!(content.contains("**Title:** Engineering Manager"));
+    assert!(content.contains("**Email (work, preferred):** ann.lee@example.com"));
+    // Grouped property (item1.EMAIL)
+    assert!(content.contains("**Email:** ann@home.example"));
+    assert!(content.contains("**Phone (work):** +49 30 1234567"));
+    assert!(content.contains("**Phone (cell, preferred):** +49 170 9876543"));
+    assert!(
+        content.contains("**Address (work):** Floor 3, Friedrichstr. 1, Berlin, 10117, Germany")
+    );
+    assert!(content.contains("**Birthday:** 4 March 1985"));
+    assert!(content.contains("**Categories:** Colleagues, Berlin"));
+    assert!(content.contains("> Prefers mornings for meetings.\n> Speaks German and English."));
+    // Escaped comma inside an address component
+    assert!(content.contains("12 Oak Street, Apt 4, Springfield, IL, 62701, USA"));
+}
+
+#[tokio::test]
+async fn test_vcard_embedded_photo() {
+    let md = MarkItDown::new();
+    let doc = md.convert(&test_file("team_v3.vcf"), None).await.unwrap();
+
+    let images = doc.images();
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].id, "contact_1_photo");
+    assert_eq!(images[0].mime_type, "image/png");
+    assert!(images[0].data.starts_with(b"\x89PNG"));
+    assert_eq!(images[0].alt_text.as_deref(), Some("Dr. Ann Lee"));
+
+    let without_images = md
+        .convert(
+            &test_file("team_v3.vcf"),
+            Some(default_options(".vcf").with_images(false)),
+        )
+        .await
+        .unwrap();
+    assert!(without_images.images().is_empty());
+}
+
+#[tokio::test]
+async fn test_vcard_contact_table() {
+    let md = MarkItDown::new();
+    let bytes = fs::read(test_file("team_v3.vcf")).expect("Failed to read file");
+    let doc = md
+        .convert_bytes(
+            Bytes::from(bytes),
+            Some(default_options(".vcf").with_contact_table(true)),
+        )
+        .await
+        .unwrap();
+    let content = doc.to_markdown();
+
+    assert!(content.contains("| Name | Organization | Title | Email | Phone | Address |"));
+    // The preferred phone number is shown
+    assert!(content.contains(
+        "| Dr. Ann Lee | Example Corp, Platform Engineering | Engineering Manager | ann.lee@example.com | +49 170 9876543 |"
+    ));
+    assert!(content.contains("| Carol Diaz | Partner \\| Co |"));
+    assert!(!content.contains("## Bob Chen"));
+    assert!(doc.images().is_empty());
+}
+
+// ============================================================================
+// vCard 2.1 and 4.0
+// ============================================================================
+
+#[tokio::test]
+async fn test_vcard_21_quoted_printable() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("legacy_v21.vcf"), None)
+        .await
+        .unwrap();
+    let content = doc.to_markdown();
+
+    assert!(content.contains("## Jürgen Müller"));
+    assert!(content.contains("**Address (home):** Hauptstraße 5, München, 80331, Deutschland"));
+    // Soft line break inside the note
+    assert!(content.contains("bitte immer die Durchwahl benutzen, nicht die Zentrale anrufen."));
+    // Bare vCard 2.1 types
+    assert!(content.contains("**Phone (work):** +49 89 555123"));
+    assert!(content.contains("**Email (preferred):** juergen@beispiel.example"));
+    // ISO-8859-1 name built from N
+    assert!(content.contains("## René José"));
+    assert_eq!(doc.images().len(), 1);
+}
+
+#[tokio::test]
+async fn test_vcard_latin1_and_broken_photo() {
+    // The Latin-1 bytes C3 A3 also form valid UTF-8 ("ã")
+    let card = "BEGIN:VCARD\r\n\
+        VERSION:2.1\r\n\
+        FN;CHARSET=ISO-8859-1;ENCODING=QUOTED-PRINTABLE:Jo=C3=A3o\r\n\
+        PHOTO;ENCODING=BASE64;TYPE=JPEG:not*base64\r\n\
+        END:VCARD\r\n";
+    let md = MarkItDown::new();
+    let doc = md
+        .convert_bytes(Bytes::from(card), Some(default_options(".vcf")))
+        .await
+        .unwrap();
+    let content = doc.to_markdown();
+
+    assert!(content.contains("## Jo\u{c3}\u{a3}o"));
+    assert!(content.contains("**Photo:** embedded image could not be decoded"));
+    assert!(doc.images().is_empty());
+}
+
+#[tokio::test]
+async fn test_vcard_windows_1252_and_8bit_values() {
+    let mut card = b"BEGIN:VCARD\r\n\
+        VERSION:2.1\r\n\
+        FN;CHARSET=WINDOWS-1252;ENCODING=QUOTED-PRINTABLE:Pat O=92Brien\r\n\
+        ORG;CHARSET=ISO-8859-1:"
+        .to_vec();
+    // 8-bit Latin-1 bytes, not valid UTF-8
+    card.extend_from_slice(b"Caf\xe9 M\xfcller\r\n");
+    card.extend_from_slice(
+        b"NOTE;CHARSET=WINDOWS-1252;ENCODING=QUOTED-PRINTABLE:=8010 deposit\r\n\
+          END:VCARD\r\n",
+    );
+    let md = MarkItDown::new();
+    let doc = md
+        .convert_bytes(Bytes::from(card), Some(default_options(".vcf")))
+        .await
+        .unwrap();
+    let content = doc.to_markdown();
+
+    assert!(content.contains("## Pat O\u{2019}Brien"));
+    assert!(content.contains("**Organization:** Caf\u{e9} M\u{fc}ller"));
+    assert!(content.contains("\u{20ac}10 deposit"));
+}
+
+#[tokio::test]
+async fn test_vcard_40_properties() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("contacts_v4.vcf"), None)
+        .await
+        .unwrap();
+    let content = doc.to_markdown();
+
+    assert!(content.contains("**Phone (cell, text, preferred):** +1-555-555-0199"));
+    assert!(content.contains("**Messaging (preferred):** xmpp:erin@chat.example.com"));
+    assert!(content.contains("**Birthday:** 15 April"));
+    assert!(content.contains("**Anniversary:** 12 June 2010"));
+    assert!(content.contains("**Related (spouse):** Sam Park"));
+    assert!(content.contains("**Photo:** https://example.com/logo.jpg"));
+
+    let images = doc.images();
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].mime_type, "image/png");
+}
+
+#[tokio::test]
+async fn test_vcard_rejects_invalid_input() {
+    let md = MarkItDown::new();
+    let result = md
+        .convert_bytes(
+            Bytes::from_static(b"no contacts here"),
+            Some(default_options(".vcf")),
+        )
+        .await;
+    assert!(result.is_err());
+}
@@ -0,0 +1,265 @@
+//! Saved web page conversion tests (MHTML, Safari web archive, WARC)
+
+use bytes::Bytes;
+use markitdown::{ContentBlock, ConversionOptions, Document, MarkItDown};
+use std::fs;
+
+fn default_options(ext: &str) -> ConversionOptions {
+    ConversionOptions {
+        file_extension: Some(ext.to_string()),
+        url: None,
+        llm_client: None,
+        image_context_path: None,
+        extract_images: true,
+        force_llm_ocr: false,
+        merge_multipage_tables: false,
+        main_content_only: false,
+        image_fetcher: None,
+        nested_converter: None,
+        mailbox_filter: None,
+        quoted_replies: Default::default(),
+        recurrence_window: None,
+        contact_table: false,
+        database: Default::default(),
+        full_log: Default::default(),
+        notebook_outputs: Default::default(),
+    }
+}
+
+const TEST_DIR: &str = "tests/test_documents/webarchive";
+
+fn test_file(name: &str) -> String {
+    format!("{}/{}", TEST_DIR, name)
+}
+
+fn images(doc: &Document) -> Vec<&markitdown::ExtractedImage> {
+    doc.pages
+        .iter()
+        .flat_map(|p| p.content.iter())
+        .filter_map(|b| match b {
+            ContentBlock::Image(img) => Some(img),
+            _ => None,
+        })
+        .collect()
+}
+
+// ============================================================================
+// MHTML Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_mhtml_tide_pools() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("tide_pools.mht"), None)
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Field Notes: Tide Pools"));
+    assert_eq!(
+        doc.metadata.get("source_url").map(String::as_str),
+        Some("https://notes.example.com/coast/tide-pools.html")
+    );
+    assert_eq!(
+        doc.metadata.get("saved_at").map(String::as_str),
+        Some("2024-03-05T10:15:00Z")
+    );
+
+    let content = doc.to_markdown();
+    assert!(content.contains("# Tide Pools"));
+    // Links resolve against the page's original location
+    assert!(content.contains("[intertidal zone](https://notes.example.com/glossary/intertidal)"));
+
+    // Content-Location and cid: images are loaded from the archive
+    let images = images(&doc);
+    assert_eq!(images.len(), 2);
+    assert_eq!(images[0].mime_type, "image/png");
+    assert_eq!(images[0].caption.as_deref(), Some("A green anemone"));
+    assert_eq!(images[1].mime_type, "image/gif");
+    assert_eq!(images[1].alt_text.as_deref(), Some("Site logo"));
+}
+
+#[tokio::test]
+async fn test_mhtml_bytes_conversion() {
+    let md = MarkItDown::new();
+    let bytes = fs::read(test_file("tide_pools.mht")).expect("Failed to read file");
+    let doc = md
+        .convert_bytes(Bytes::from(bytes), Some(default_options(".mhtml")))
+        .await
+        .unwrap();
+
+    assert!(doc.to_markdown().contains("low tide"));
+}
+
+#[tokio::test]
+async fn test_mhtml_without_images() {
+    let md = MarkItDown::new();
+    let bytes = fs::read(test_file("tide_pools.mht")).expect("Failed to read file");
+    let doc = md
+        .convert_bytes(
+            Bytes::from(bytes),
+            Some(default_options(".mht").with_images(false)),
+        )
+        .await
+        .unwrap();
+
+    assert!(images(&doc).is_empty());
+    assert!(doc
+        .to_markdown()
+        .contains("![Green anemone](https://notes.example.com/coast/images/anemone.png)"));
+}
+
+#[tokio::test]
+async fn test_mhtml_non_ascii_image_source() {
+    let mhtml = "From: <Saved by Blink>\r\n\
+        MIME-Version: 1.0\r\n\
+        Content-Type: multipart/related; type=\"text/html\"; boundary=\"b\"\r\n\
+        \r\n\
+        --b\r\n\
+        Content-Type: text/html; charset=utf-8\r\n\
+        Content-Transfer-Encoding: 8bit\r\n\
+        \r\n\
+        <html><body><p>Summer</p><img src=\"été.png\" alt=\"Beach\"></body></html>\r\n\
+        --b--\r\n";
+    let md = MarkItDown::new();
+    let doc = md
+        .convert_bytes(Bytes::from(mhtml), Some(default_options(".mhtml")))
+        .await
+        .unwrap();
+
+    let content = doc.to_markdown();
+    assert!(content.contains("Summer"));
+    assert!(content.contains("Beach"));
+}
+
+// ============================================================================
+// Web Archive Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_webarchive_tide_pools() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("tide_pools.webarchive"), None)
+        .await
+        .unwrap();
+
+    assert_eq!(doc.title.as_deref(), Some("Field Notes: Tide Pools"));
+    assert_eq!(
+        doc.metadata.get("source_url").map(String::as_str),
+        Some("https://notes.example.com/coast/tide-pools.html")
+    );
+    assert!(doc
+        .to_markdown()
+        .contains("[intertidal zone](https://notes.example.com/glossary/intertidal)"));
+
+    let images = images(&doc);
+    assert_eq!(images.len(), 2);
+    assert!(images.iter().all(|img| !img.data.is_empty()));
+    assert_eq!(images[1].mime_type, "image/gif");
+}
+
+#[tokio::test]
+async fn test_webarchive_invalid() {
+    let md = MarkItDown::new();
+    let result = md
+        .convert_bytes(
+            Bytes::from_static(b"bplist00 truncated"),
+            Some(default_options(".webarchive")),
+        )
+        .await;
+
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// WARC Tests
+// ============================================================================
+
+fn assert_harbor_crawl(doc: &Document) {
+    assert_eq!(
+        doc.metadata.get("warc_records").map(String::as_str),
+        Some("6")
+    );
+    assert_eq!(
+        doc.metadata.get("warc_pages").map(String::as_str),
+        Some("2")
+    );
+    assert_eq!(doc.pages.len(), 2);
+
+    let first = doc.pages[0].to_markdown();
+    assert!(first.contains("**URL:** https://harbor.example.org/timetable.html"));
+    assert!(first.contains("**Captured:** 2024-03-05T10:00:01Z"));
+    assert!(first.contains("Ferries leave every **30 minutes**."));
+
+    // Chunked transfer encoding is decoded; redirects are not pages
+    let second = doc.pages[1].to_markdown();
+    assert!(second.contains("# Lighthouse History"));
+    assert!(second.contains("[timetable](https://harbor.example.org/timetable.html)"));
+
+    // Images come from other response records of the crawl
+    let images = images(doc);
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].mime_type, "image/png");
+    assert_eq!(images[0].page_number, Some(1));
+}
+
+#[tokio::test]
+async fn test_warc_harbor_crawl() {
+    let md = MarkItDown::new();
+    let doc = md.convert(&test_file("harbor.warc"), None).await.unwrap();
+
+    assert_harbor_crawl(&doc);
+}
+
+#[tokio::test]
+async fn test_warc_gzip_records() {
+    let md = MarkItDown::new();
+    let doc = md
+        .convert(&test_file("harbor.warc.gz"), None)
+        .await
+        .unwrap();
+
+    assert_harbor_crawl(&doc);
+}
+
+#[tokio::test]
+async fn test_warc_malformed_chunk_sizes() {
+    let md = MarkItDown::new();
+    for chunked in [
+        // Larger than the body, and so large that adding it overflows
+        "ffffffffffffffff\r\n<html><body><p>Oversized chunk</p></body></html>",
+        // Truncated: the declared chunk runs past the end of the record
+        "400\r\n<html><body><p>Oversized chunk</p></body></html>",
+    ] {
+        let http = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nTransfer-Encoding: chunked\r\n\r\n{}",
+            chunked
+        );
+        let warc = format!(
+            "WARC/1.0\r\nWARC-Type: response\r\nWARC-Target-URI: https://example.org/\r\n\
+             Content-Type: application/http; msgtype=response\r\nContent-Length: {}\r\n\r\n{}\r\n\r\n",
+            http.len(),
+            http
+        );
+
+        let doc = md
+            .convert_bytes(Bytes::from(warc), Some(default_options(".warc")))
+            .await
+            .unwrap();
+        assert!(doc.to_markdown().contains("Oversized chunk"));
+    }
+}
+
+#[tokio::test]
+async fn test_warc_rejects_other_files() {
+    let md = MarkItDown::new();
+    let result = md
+        .convert_bytes(
+            Bytes::from_static(b"<html><body>Not a crawl</body></html>"),
+            Some(default_options(".warc")),
+        )
+        .await;
+
+    assert!(result.is_err());
+}